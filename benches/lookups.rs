@@ -0,0 +1,284 @@
+//! Compares indexed lookups of Library
+//! with linear scans over the same data.
+//! Run with `cargo bench --bench lookups`
+
+use booklibrs::{books::date::Date, data::DataDir, library::Library, storage::yaml::YamlStorage};
+
+use std::{
+    fmt::Write as _,
+    fs,
+    hint::black_box,
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+/// Amounts of readers and The Books in generated libraries
+const SIZES: [usize; 3] = [1_000, 10_000, 50_000];
+
+/// Amount of searches for every measurement
+const LOOKUPS: usize = 1_000;
+
+/// Amount of The Books of every author
+const BOOKS_PER_AUTHOR: usize = 10;
+
+/// Amount of different genres
+const GENRES: usize = 20;
+
+/// Title, author and amount of pages of The Book
+type BookKey = (String, String, u16);
+
+/// Name, 2-nd name, mid. name and birth date of reader
+type ReaderKey = (String, String, String, Date);
+
+fn main() {
+    let root = std::env::temp_dir().join(format!("booklibrs-bench-{}", std::process::id()));
+
+    for &size in SIZES.iter() {
+        let dir = root.join(size.to_string());
+        let (books, readers) = generate(&dir, size);
+
+        let mut library = Library::new(Box::new(YamlStorage::new(DataDir::new(&dir))));
+        library.load().expect("generated data must be correct");
+
+        check(&library, &books, &readers);
+
+        println!("{} readers and {} books", size, size);
+        bench_books(&library, &books);
+        bench_authors(&library, &books);
+        bench_genres(&library, size);
+        bench_readers(&library, &readers);
+        println!();
+    }
+
+    let _ = fs::remove_dir_all(&root);
+}
+
+/// Checks that indexes find all generated data
+
+fn check(library: &Library, books: &[BookKey], readers: &[ReaderKey]) {
+    books
+        .iter()
+        .enumerate()
+        .for_each(|(ind, (title, author, pages))| {
+            assert_eq!(library.find_book(title, author, *pages), Some(ind));
+        });
+
+    readers
+        .iter()
+        .enumerate()
+        .for_each(|(ind, (name, family, father, birth))| {
+            assert_eq!(library.find_reader(name, family, father, *birth), Some(ind));
+        });
+}
+
+/// Prints time of one search with index and with linear scan
+
+fn report(what: &str, indexed: f64, linear: f64) {
+    println!(
+        "  {:<16} indexed {:>10.0} ns, linear {:>12.0} ns ({:.0}x)",
+        what,
+        indexed,
+        linear,
+        linear / indexed
+    );
+}
+
+/// Average time of one call in nanoseconds
+
+fn measure<F: FnMut(usize)>(mut f: F) -> f64 {
+    let start = Instant::now();
+    (0..LOOKUPS).for_each(&mut f);
+    start.elapsed().as_nanos() as f64 / LOOKUPS as f64
+}
+
+/// Chosen element for i-th search,
+/// searches are spread across all data
+
+#[inline]
+fn nth<T>(data: &[T], i: usize) -> &T {
+    &data[i * 7919 % data.len()]
+}
+
+fn bench_books(library: &Library, books: &[BookKey]) {
+    let indexed = measure(|i| {
+        let (title, author, pages) = nth(books, i);
+        black_box(library.find_book(title, author, *pages));
+    });
+
+    let linear = measure(|i| {
+        let key = nth(books, i);
+        black_box(books.iter().position(|b| b == key));
+    });
+
+    report("find_book", indexed, linear);
+}
+
+fn bench_authors(library: &Library, books: &[BookKey]) {
+    let indexed = measure(|i| {
+        black_box(library.find_by_author(&nth(books, i).1));
+    });
+
+    let linear = measure(|i| {
+        let author = &nth(books, i).1;
+
+        black_box(
+            books
+                .iter()
+                .enumerate()
+                .filter(|(_, b)| b.1 == *author)
+                .map(|(ind, _)| ind)
+                .collect::<Vec<_>>(),
+        );
+    });
+
+    report("find_by_author", indexed, linear);
+}
+
+fn bench_genres(library: &Library, size: usize) {
+    let genres = (0..size).map(genre).collect::<Vec<_>>();
+
+    let indexed = measure(|i| {
+        black_box(library.find_by_genre(&genre(i)));
+    });
+
+    let linear = measure(|i| {
+        let wanted = genre(i);
+
+        black_box(
+            genres
+                .iter()
+                .enumerate()
+                .filter(|(_, g)| **g == wanted)
+                .map(|(ind, _)| ind)
+                .collect::<Vec<_>>(),
+        );
+    });
+
+    report("find_by_genre", indexed, linear);
+}
+
+fn bench_readers(library: &Library, readers: &[ReaderKey]) {
+    let indexed = measure(|i| {
+        let (name, family, father, birth) = nth(readers, i);
+        black_box(library.find_reader(name, family, father, *birth));
+    });
+
+    let linear = measure(|i| {
+        let key = nth(readers, i);
+        black_box(readers.iter().position(|r| r == key));
+    });
+
+    report("find_reader", indexed, linear);
+}
+
+/// Genre of i-th The Book
+
+#[inline]
+fn genre(i: usize) -> String {
+    format!("genre {}", i % GENRES)
+}
+
+/// Birth date of i-th reader
+
+#[inline]
+fn birth(i: usize) -> (u8, u8, u16) {
+    (
+        (i % 28 + 1) as u8,
+        (i % 12 + 1) as u8,
+        (1950 + i % 50) as u16,
+    )
+}
+
+/// Writes library with chosen amount of readers and The Books
+/// (each with one simple book) to data directory.
+/// Returns keys of all books and readers
+
+fn generate(dir: &Path, size: usize) -> (Vec<BookKey>, Vec<ReaderKey>) {
+    DataDir::new(dir).create().unwrap();
+
+    let books = (0..size)
+        .map(|i| {
+            (
+                format!("Title {}", i),
+                format!("Author {}", i / BOOKS_PER_AUTHOR),
+                (i % 1000 + 1) as u16,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let readers = (0..size)
+        .map(|i| {
+            (
+                format!("Name {}", i),
+                format!("Family {}", i % 100),
+                format!("Father {}", i % 10),
+                {
+                    let (day, month, year) = birth(i);
+                    Date::new(day, month, year).unwrap()
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut yaml = String::from("---\nVersion: 2\nReaders:\n");
+
+    readers
+        .iter()
+        .enumerate()
+        .for_each(|(i, (name, family, father, _))| {
+            let (day, month, year) = birth(i);
+
+            write!(
+                yaml,
+                "  - \"№\": {}\n    Id: {}\n    Name: {}\n    Family: {}\n    Father: {}\n    \
+                 Info: bench\n    Day: {}\n    Month: {}\n    Year: {}\n    Reading:\n      - None\n",
+                i + 1,
+                i + 1,
+                name,
+                family,
+                father,
+                day,
+                month,
+                year
+            )
+            .unwrap();
+        });
+
+    write_file(DataDir::new(dir).readers(), yaml);
+
+    let mut yaml = String::from("---\nVersion: 2\nBooks:\n");
+
+    books
+        .iter()
+        .enumerate()
+        .for_each(|(i, (title, author, pages))| {
+            write!(
+                yaml,
+                "  - \"№\": {}\n    Id: {}\n    Title: {}\n    Author: {}\n    Pages: {}\n    \
+             Simple Books:\n      - Id: {}\n        Cabinet: 1\n        Shelf: 1\n        \
+             Using: false\n        Readers: []\n    Genres:\n      - {}\n",
+                i + 1,
+                i + 1,
+                title,
+                author,
+                pages,
+                i + 1,
+                genre(i)
+            )
+            .unwrap();
+        });
+
+    write_file(DataDir::new(dir).books(), yaml);
+
+    let mut yaml = String::from("---\nVersion: 2\nGenres:\n");
+
+    (0..GENRES).for_each(|i| writeln!(yaml, "  - {}", genre(i)).unwrap());
+    write_file(DataDir::new(dir).genres(), yaml);
+
+    (books, readers)
+}
+
+#[inline]
+fn write_file(path: PathBuf, data: String) {
+    fs::write(path, data).unwrap();
+}
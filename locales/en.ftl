@@ -0,0 +1,486 @@
+# English messages
+
+language-name = English
+date-format = %m/%d/%Y
+
+# Menus, labels and dialogs
+
+add-books = Add Books
+title = Title
+author = Author
+amount-of-pages = Amount of Pages
+amount-of-pages-input-error = Amount of Pages input error
+remove-book = Remove Book
+add-new-book = Add New Book
+amount-of-books = Amount of Books
+set-amount-of-books = Set amount of books
+incorrect-amount-of-pages-input = Incorrect 'Amount of Pages' input
+remove-books = Remove Books
+successfully-removed = Successfully removed
+books-amount = Books amount
+amount-of-books-to-add = Amount of books to add
+successfully-added = Successfully added
+amount-of-books-input-error = 'Amount of books' input error
+books-number = Book's number
+incorrect-number-of-book = Incorrect number of book
+books-number-input-error = Book's number input error
+set-amount-of-books-2 = Set amount of books
+amount-of-pages-input-error-2 = 'Amount of Pages' input error
+change-title = Change title
+change-author = Change author
+change-amount-of-pages = Change amount of pages
+incorrect-books-number = Incorrect book's number
+change-books-location = Change book's location
+new-title = New Title
+new-title-is-empty = 'New title' is empty
+successfully-changed = Successfully changed
+new-author = New Author
+new-author-is-empty = 'New author' is empty
+new-amount-of-pages = New Amount of Pages
+new-amount-of-pages-2 = New Amount of Pages
+new-amount-of-pages-is-empty = 'New amount of pages' is empty
+new-location = New Location
+new-cabinet = New Cabinet
+new-shelf = New Shelf
+new-cabinet-is-empty = 'New cabinet' is empty
+new-shelf-is-empty = 'New shelf' is empty
+new-cabinet-input-error = 'New cabinet' input error
+new-shelf-input-error = 'New shelf' input error
+find-book = Find book
+all-books = All Books
+amount-of-available-books = Amount of available books
+all-books-by-authors = All Books by Authors
+authors = Authors
+pages = pages
+order-number = Order Number
+now-is-read-by = Now is Read By
+none = None
+cabinet = Cabinet
+shelf = Shelf
+all-readers = All Readers
+1-st-name = 1-st Name
+2-nd-name = 2-nd Name
+middle-name = Middle Name
+age = Age
+start-date = Start Date
+finish-date = Finish Date
+change-location = &Change location\t
+number-of-book = Number of Book
+amount-of-books-2 = Amount of books
+genres = Genres
+change-change-title = &Change/Change title\t
+change-change-author = &Change/Change author\t
+change-change-amount-of-pages = &Change/Change amount of pages\t
+change-customize-book-genres = &Change/Customize book genres\t
+remove-remove-all-books = &Remove/Remove all books\t
+remove-remove-one-book = &Remove/Remove one book\t
+add-books-menu = &Add books
+get-current-books-information = &Get current book's information
+amount-of-pages-2 = Amount of pages
+book-system-is-empty = Book System is empty
+book-isnt-selected = Book isn't selected
+title-is-empty = 'Title' is empty
+author-is-empty = 'Author' is empty
+amount-of-pages-is-empty = 'Amount of pages' is empty
+book-isnt-found = Book isn't found
+location = Location
+cabinets-number = Cabinet's number
+shelfs-number = Shelf's number
+cabinets-number-input-error = Cabinet's number input error
+shelfs-number-input-error = Shelf's number input error
+add-genre = Add Genre
+new-genre = New Genre
+new-genre-is-empty = 'New genre' is empty
+remove-genre = Remove Genre
+genres-title = Genre's title
+genres-title-is-empty = 'Genre's title' is empty
+customize-books-genres = Customize book's genres
+input-genre = Input Genre
+input-genre-2 = Input Genre
+books-with-spec-genre = Books with spec genre
+all-books-with-genres = All Books with Genres
+without-genres = Without Genres
+select-genres = Select Genres
+find-reader = Find Reader
+first-name = First Name
+second-name = Second Name
+choose-finish-date = Choose finish date
+ok = OK
+date-wasnt-selected = Date wasn't selected
+find-book-2 = Find Book
+set-return-date = Set Return Date
+year = Year
+incorrect-return-date = Incorrect return date
+this-reader-wasnt-reading-searching-book = This reader wasn't reading searching book
+book-is-returned-after-deadline = Book is returned after deadline
+book-is-returned = Book is returned
+title-2 = Title
+this-reader-isnt-reading-anything = This reader isn't reading anything
+this-reader-isnt-reading-this-book = This reader isn't reading this book
+date-is-successfully-changed = Date is successfully changed
+set-new-date = Set New Date
+help = Help
+add-reader = Add Reader
+second-names = Second Names
+info-50-symb = Info (< 50 symb.)
+choose-birth-date = Choose birth date
+remove-reader = Remove Reader
+successfully-removed-2 = Successfully removed
+change-name = Change Name
+change-2-nd-name = Change 2-nd Name
+change-middle-name = Change Middle Name
+change-info = Change info
+change-category = Change category
+new-category = New category (empty for none)
+change-age = Change Age
+new-name = New Name
+new-2-nd-name = 'New 2-nd Name
+new-middle-name = New Middle Name
+new-info-50-symb = New Info (< 50 symb)
+choose-new-birth-date = Choose new birth date
+first-name-tabbed = First Name
+second-name-tabbed = Second Name
+middle-name-tabbed = Middle Name
+age-tabbed = Age
+additional-info = Additional Info
+books-read-by-reader-now = Books read by reader now
+change-change-name = &Change/Change name\t
+change-change-2-nd-name = &Change/Change 2-nd name\t
+change-change-mid-name = &Change/Change mid name\t
+change-change-age = &Change/Change age\t
+change-change-additional-information = &Change/Change additional information
+giveaway-give-book = &Giveaway/Give book\t
+giveaway-get-book = &Giveaway/Get book\t
+remove-reader-menu = &Remove reader
+number-of-book-2 = Number of book
+nearest-to-return-deadline = Nearest to return deadline
+none-2 = None
+additional-information = Additional information
+books-read-by-reader = Books read by reader
+reading-now = Reading now
+name-is-empty = 'Name' is empty
+2-nd-name-is-empty = '2-nd Name' is empty
+mid-name-is-empty = 'Mid. Name' is empty
+reader-isnt-found = Reader isn't found
+audit-log-is-damaged = Audit log is damaged:
+audit-log = Audit log
+user = User
+record = Record
+filter = Filter
+reset = Reset
+reader = Reader
+book = Book
+genre = Genre
+login = Login
+password = Password
+change-credentials = Change credentials
+current-password = Current password
+new-login = New login
+new-password = New password
+new-login-and-password-are-saved = New login and password are saved
+seconds-left = Seconds left
+users = Users
+add = Add
+role = Role
+remove = Remove
+user-isnt-selected = User isn't selected
+new-user = New user
+choose-role = Choose role
+access-denied = You don't have rights for this action
+return-date-cant-be-earlier-than-today = Return date can't be earlier than today or than the day of issue
+nothing-inputted = Nothing inputted
+journal-has-no-changes-until-this-day = Journal has no changes until this day
+journal-is-damaged = Journal is damaged:
+library-on = Library on
+readers = Readers
+books = Books
+givings = Givings
+cabinet-lowercase = cabinet
+shelf-lowercase = shelf
+skipped-records = Skipped records
+data-cant-be-loaded = Data can't be loaded:
+start-empty = Start empty
+restore-backup = Restore backup
+skip-bad-records = Skip bad records
+backup-isnt-found = Backup isn't found
+authorization-is-complete = Authorization is complete
+library-system = Library System
+book-library-interface = BOOK LIBRARY INTERFACE
+day = Day
+month = Month
+start-date-2 = Start Date
+readers-add-reader = &Readers/Add reader\t
+readers-remove-reader = &Readers/Remove reader\t
+readers-change-name = &Readers/Change name\t
+readers-change-second-name = &Readers/Change second name\t
+readers-change-middle-name = &Readers/Change middle name\t
+readers-change-age = &Readers/Change age\t
+readers-change-additional-information = &Readers/Change additional information
+readers-change-category = &Readers/Change category\t
+readers-readers-information-with-reading-now-books = &Readers/Reader's information with reading now books\t
+readers-readers-information-with-read-books = &Readers/Reader's information with read books\t
+books-add-books = &Books/Add books\t
+books-remove-book = &Books/Remove book\t
+books-remove-all-specific-books = &Books/Remove all specific books\t
+books-change-books-title = &Books/Change book's title\t
+books-change-books-author = &Books/Change book's author\t
+books-change-books-amount-of-pages = &Books/Change book's amount of pages\t
+books-change-books-location = &Books/Change book's location\t
+books-change-copy-type = &Books/Change copy type\t
+books-get-type-books-information = &Books/Get type book's information\t
+books-get-current-books-information = &Books/Get current book's information\t
+books-list-of-all-books-by-authors = &Books/List of all books by authors\t
+books-list-of-all-books = &Books/List of all books\t
+genres-list-of-all-books-by-genres = &Genres/List of all books by genres\t
+genres-add-genre = &Genres/Add genre\t
+genres-remove-genre = &Genres/Remove genre\t
+genres-customize-book-genres = &Genres/Customize book genres\t
+giveaway-change-return-date = &Giveaway/Change return date\t
+giveaway-opening-calendar = &Giveaway/Opening calendar\t
+giveaway-loan-periods = &Giveaway/Loan periods\t
+restore-restore-previous-data = &Restore/Restore previous data\t
+restore-restore-next-data = &Restore/Restore next data\t
+restore-history = &Restore/History\t
+restore-audit-log = &Restore/Audit log\t
+restore-library-on-date = &Restore/Library on date\t
+restore-restore-backup = &Restore/Restore backup\t
+restore-create-snapshot = &Restore/Create snapshot\t
+restore-snapshots = &Restore/Snapshots\t
+users-accounts = &Users/Accounts\t
+users-change-credentials = &Users/Change credentials\t
+users-lock = &Users/Lock\t
+menu-language = &Language
+menu-help = &Help
+undone = Undone
+first-version = First version
+done-again = Done again
+last-version = Last version
+are-you-sure-you-want-to-change = Are you sure you want to change your language? You'll have to restart the program
+language-isnt-changed = Language can't be changed:
+ok-2 = Ok
+cancel = Cancel
+there-are-no-backups-yet = There are no backups yet
+restore = Restore
+backup-isnt-selected = Backup isn't selected
+backup-is-damaged = Backup is damaged:
+successfully-restored = Successfully restored
+changes-arent-saved = Changes are made, but they can't be saved:
+history = History
+go-to = Go to
+step-isnt-selected = Step isn't selected
+start-of-history = Start of history
+create-snapshot = Create snapshot
+label = Label
+label-is-empty = 'Label' is empty
+snapshot-is-created = Snapshot is created
+snapshot-isnt-created = Snapshot isn't created:
+there-are-no-snapshots-yet = There are no snapshots yet
+snapshots = Snapshots
+compare = Compare
+snapshot-isnt-selected = Snapshot isn't selected
+snapshot-is-damaged = Snapshot is damaged:
+snapshot-is-the-same-as-current-data = Snapshot is the same as current data
+differences-with = Differences with
+changes-since-snapshot = Changes since snapshot
+simple-books = Simple books
+new-amount-of-pages-input-error = 'New amount of pages' input error
+book-successfully-given-to-reader = Book successfully given to reader
+incorrect-date = Incorrect date
+date-from-any = From: any
+date-to-any = To: any
+before = Before
+after = After
+authorization = Authorization
+session-is-locked = Session is locked
+given-mark = \ (given)
+not-returned-mark = \ (not returned)
+undone-mark = \ (undone)
+yearly-mark = \ (every year)
+opening-calendar = Opening calendar
+closed-days = Closed days:
+import-icalendar = Import iCalendar
+choose-icalendar-file = Choose iCalendar file
+imported-holidays = Imported holidays
+skipped-events = Skipped wrong events
+holiday-isnt-selected = Holiday isn't selected
+new-holiday = New holiday
+holiday-name = Name
+is-holiday-repeated = Is holiday repeated every year?
+every-year = Every year
+only-once = Only once
+weekday-monday = Monday
+weekday-tuesday = Tuesday
+weekday-wednesday = Wednesday
+weekday-thursday = Thursday
+weekday-friday = Friday
+weekday-saturday = Saturday
+weekday-sunday = Sunday
+loan-periods = Loan periods
+default-loan-period = Default loan period (days)
+category-of-readers = Category of readers
+days = Days
+any-category = Any category
+any-genre = Any genre
+rule-isnt-selected = Rule isn't selected
+give = Give
+other-date = Other date
+yes = Yes
+no = No
+can-override-limits = Can this librarian give books despite borrowing limits?
+new-copy-type = New copy type (empty for ordinary copy)
+copy-type = Copy type
+any-copy-type = Any copy
+borrowing-limits = Borrowing limits
+max-books = Max books
+incorrect-max-books = Incorrect max books
+limit-kind-max-loans = Max books at once
+limit-kind-no-overdue = No overdue books
+limit-kind-not-loanable = Not loanable
+limit-no-overdue = not given to readers with overdue books
+limit-not-loanable = not given out of library
+refusal-not-loanable = This copy isn't given out of library
+refusal-overdue = Reader has overdue books
+refusal-too-many-loans = Reader already has too many books
+loan-is-refused = Book can't be given:
+give-over-limits = Give book anyway?
+
+# Errors of readers
+
+reader-error-not-found = Reader isn't found
+reader-error-already-exists = Reader with same parameters already exists
+reader-error-empty-name = 'Name' is empty
+reader-error-empty-family = '2-nd Name' is empty
+reader-error-empty-father = 'Mid. Name' is empty
+reader-error-empty-info = 'Info' is empty
+reader-error-still-reading = Reader is already reading book with same params
+reader-error-not-reading = This reader isn't reading this book
+reader-error-io = Changes of readers can't be saved
+
+# Errors of books
+
+book-error-not-found = Book isn't found
+book-error-simple-book-not-found = Book with this number isn't found
+book-error-already-exists = Book with same parameters already exists
+book-error-too-many-books = Too much books
+book-error-no-free-books = There are no free books
+book-error-not-used = Book isn't used now
+book-error-returned-late = Book is returned after deadline
+book-error-unknown-genre = Genre isn't found
+book-error-io = Changes of books can't be saved
+loan-error-io = Giving of book can't be saved
+
+# Errors of genres
+
+genre-error-already-exists = Genre already exists
+genre-error-not-found = Genre isn't found
+genre-error-io = Changes of genres can't be saved
+
+# Errors of accounts
+
+auth-error-empty-login = 'Login' is empty
+auth-error-empty-password = 'Password' is empty
+auth-error-already-exists = User with same login already exists
+auth-error-not-found = User isn't found
+auth-error-last-administrator = There must be at least one administrator
+auth-error-wrong-credentials = Wrong login or password. Try again
+auth-error-locked = Too many failed attempts. Try again later
+auth-error-io = Users can't be saved
+
+# Errors of opening calendar
+
+calendar-error-no-open-days = Library must be open at least one day of week
+calendar-error-already-exists = There is already a holiday on this date
+calendar-error-not-found = Holiday isn't found
+calendar-error-wrong-icalendar = File isn't correct iCalendar
+calendar-error-io = Calendar can't be saved
+
+# Errors of loan policy
+
+policy-error-wrong-period = Loan period must be from 1 to 366 days
+policy-error-already-exists = There is already such a rule
+policy-error-not-found = Rule isn't found
+policy-error-io = Loan periods can't be saved
+
+# Roles of users
+
+role-administrator = Administrator
+role-librarian = Librarian
+role-senior-librarian = Senior librarian
+role-read-only = Read-only
+
+# Operations with library
+
+operation-add-reader = Add reader
+operation-remove-reader = Remove reader
+operation-change-name = Change reader's name
+operation-change-family = Change reader's 2-nd name
+operation-change-father = Change reader's mid. name
+operation-change-info = Change reader's info
+operation-change-category = Change reader's category
+operation-change-age = Change reader's age
+operation-add-book = Add book
+operation-add-books = Add simple books
+operation-remove-book = Remove book
+operation-remove-one-book = Remove simple book
+operation-change-title = Change book's title
+operation-change-author = Change book's author
+operation-change-pages = Change book's pages
+operation-change-location = Change book's location
+operation-change-copy-type = Change copy type
+operation-give-book = Give book
+operation-give-book-over-limits = Give book over limits
+operation-return-book = Get book
+operation-change-return-date = Change return date
+operation-add-genre = Add genre
+operation-remove-genre = Remove genre
+operation-set-book-genres = Customize book's genres
+operation-replace = Restore all data
+
+# Actions in audit log
+
+audit-action-done = Done
+audit-action-undone = Undone
+audit-action-redone = Redone
+
+# Events of journal
+
+event-reader-added = Reader is added
+event-reader-changed = Reader is changed
+event-reader-removed = Reader is removed
+event-book-added = Book is added
+event-book-changed = Book is changed
+event-book-replaced = Book is replaced
+event-book-removed = Book is removed
+event-genres-assigned = Genres of book are set
+event-copy-added = Simple book is added
+event-copy-removed = Simple book is removed
+event-copy-moved = Simple book is moved
+event-copy-given = Simple book is given
+event-copy-returned = Simple book is returned
+event-due-date-changed = Return date is changed
+event-copy-replaced = Simple book is replaced
+event-genre-added = Genre is added
+event-genre-removed = Genre is removed
+
+# Messages with arguments
+
+book-location = Book location: cabinet { $cabinet }, shelf { $shelf }
+copy-location = №{ $id }: cabinet { $cabinet }, shelf { $shelf }
+given-to = , given to { $readers }
+and-more = and { $count } more
+date-from = From { $date }
+date-to = To { $date }
+due-date-is-moved = Library is closed on chosen date, return date is moved to { $date }
+loan-period-days = { $days } days
+loan-period-days.one = { $days } day
+give-book-until = Give book until { $date }?
+limit-max-loans = at most { $max } books at once
+limit-max-loans.one = at most { $max } book at once
+refusal-overdue-details = Reader has { $count } overdue books
+refusal-overdue-details.one = Reader has { $count } overdue book
+refusal-too-many-loans-details = Reader already has { $max } such books, it's the limit
+refusal-too-many-loans-details.one = Reader already has { $max } such book, it's the limit
+
+# Text of help window
+
+help-text = \    Author: dinaraparanid (Follow me on github: https://github.com/dinaraparanid)\n\n    Licence: MIT or Apache-2.0\n\n    Documentation:\n\n        public: https://docs.rs/binartree/1.1.9/booklibrs/\n\n        private (whole project): go to librs/target/doc/booklibrs/all.html (click on it)\n\n\n    About project:\n\n    This is a project that aims to create a system for issuing books in libraries.\n\n    The entire project is written in the Rust programming language using the fltk, chrono, and yaml_rust crates.\n\n\n    How to use:\n\n\n    1. Start\n\n\n    If you a windows user, you need to just press on run.bat, and programme'll start working.\n\n\n    If you are not windows user, than you need to install\n\n    Rust compiler: https://www.rust-lang.org/tools/install\n\n    C/C++ MVSC compiler (NOT MinGW!!!): https://visualstudio.microsoft.com/vs/features/cplusplus/\n\n    Git: https://git-scm.com/book/en/v2/Getting-Started-Installing-Git\n\n    CMake: https://cmake.org/install/\n\n\n    After that you need to use your console, go to project direction, write 'cargo run --release' and programme'll start working.\n\n\n    2. Tools\n\n\n    First of all you will see a table. It contains all readers in an ascending order and their reading status (book / start date / finish date)\n\n    In main menu (on the left top corner) you will see 6 sub menus: Readers, Books, Giveaway, Restore, Language, Help.\n\n\n    Reader menu:\n contains all action that you can do with readers: Add, Remove, Change 1-st name, 2-nd name, mid. name, get information.\n\n    There are also 2 types of getting info: with books that reader is reading now and with all books that reader read\n  \n    All these action requires your input. But you can simply click on reader you need in main table and all needed action will be there\n    (except adding reader, that's requires input for new reader)\n\n\n    Book menu:\n\n    1) Add books (if book in library, it'll add new books, else it'll create new books in library)\n\n    2) Remove book. You can remove only one book in this action. Requires number of book.\n\n    3) Remove all specific books. It'll remove all books with same title, author and amount of pages\n.\n    4) Change title, author, amount of pages. I think it'll be obvious.\n\n    5) Change book's location. You'll change cabinet and shelf where book is located. I don't know anything about your library,\n    so I can't control your input\n\n    6) Get type book's information. As for reader, you'll get a window with info and all actions for it.\n\n    7) Get current book's information. You'll get info window about current book. Also you can change location (cabinet and shelf) of book\n\n    8) List of all books by authors. Window with all books sorted by authors. Authors sorted by ascending.\n\n    9) List of all books.\n\n\n    Genres menu:\n\n    1) List of all books by genres. Sort all books by genres.\n\n    2) Add genre / Remove genre. By default it'll be no genres, so, you need to add it. It can be anything, so you can use your imagination:)\n\n    3) Customize book genres. You'll get window with genres. You need to choose genre. If book has some genres, it'll be a check mark near genre\n\n\n    Giveaway menu is a menu for giving and returning books.\n\n    1) Give book. Requires input for reader and book. At the end you need to choose return date. Also you can do it in reader info\n     (or just click on reader)\n\n    2) Get book from reader. Requires reader input. Also you can do it in reader info or click on reader.\n\n    3) Change return date. You can also click on return date in main table and change it.\n\n\n    Restore menu:\n\n    1) Restore previous data. Also you can use Ctrl-Z.\n\n    2) Restore next date. Also you can use Ctrl-Shift-Z.\n\n    3) Restore backup. Every save keeps a copy of previous data, you can choose one of them.\n\n\n    In language menu you can choose language. My languages are Russian and English, so I don't add others,\n    so I hope your english is not worse than mine :D\n\n\n    In Help menu you can read same things if you forgot something.\n\n\n    Full version with all decompiled files (recommend to download from here):\n    https://drive.google.com/drive/u/0/folders/1mOTWStFbS_NS7iYs0uxS6MlT9jv8qZRf\n\n    Enjoy!\n
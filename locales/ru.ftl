@@ -0,0 +1,492 @@
+# Русские сообщения
+
+language-name = Русский
+date-format = %d.%m.%Y
+
+# Меню, надписи и диалоги
+
+add-books = Добавить книги
+title = Название
+author = Автор
+amount-of-pages = Кол-во страниц
+amount-of-pages-input-error = Ошибка ввода количества страниц
+remove-book = Удалить книгу
+add-new-book = Добавить новую книгу
+amount-of-books = Кол-во Книг
+set-amount-of-books = Укажите Кол-во книг
+incorrect-amount-of-pages-input = Некорретный ввод количества страниц
+remove-books = Удалить книги
+successfully-removed = Успешно удалено
+books-amount = Кол-во книг
+amount-of-books-to-add = Кол-во книг
+successfully-added = Успешно добавлено
+amount-of-books-input-error = Ошибка ввода количества книг
+books-number = Номер книги
+incorrect-number-of-book = Некорректный номер книги
+books-number-input-error = Ошибка ввода номера книги
+set-amount-of-books-2 = Кол-во книг
+amount-of-pages-input-error-2 = Ошибка ввода количества страниц
+change-title = Изменить название
+change-author = Изменить автора
+change-amount-of-pages = Изменить Кол-во страниц
+incorrect-books-number = Некорректный номер книги
+change-books-location = Переместить книгу
+new-title = Новое название
+new-title-is-empty = 'Новое название' пусто
+successfully-changed = Успешно изменено
+new-author = Новый автор
+new-author-is-empty = 'Новый автор' пусто
+new-amount-of-pages = Новое Кол-во страниц
+new-amount-of-pages-2 = Новое Кол-во Страниц
+new-amount-of-pages-is-empty = 'Новое Кол-во страниц' пусто
+new-location = Перемещение Книги
+new-cabinet = Новый Шкаф
+new-shelf = Новая Полка
+new-cabinet-is-empty = 'Новый шкаф' пусто
+new-shelf-is-empty = 'Новая полка' пусто
+new-cabinet-input-error = Ошибка ввода 'Нового шкафа'
+new-shelf-input-error = Ошибка ввода 'Новой полки'
+find-book = Найти книгу
+all-books = Все книги
+amount-of-available-books = Кол-во доступных книг
+all-books-by-authors = Все Книги по Авторам
+authors = Авторы
+pages = страниц
+order-number = Порядковый номер
+now-is-read-by = В данный момент читается
+none = Никем
+cabinet = Шкаф
+shelf = Полка
+all-readers = Все читатели
+1-st-name = Имя
+2-nd-name = Фамилия
+middle-name = Отчество
+age = Возраст
+start-date = Дата начала
+finish-date = Срок Сдачи
+change-location = &Изменить расположение\t
+number-of-book = Номер книги
+amount-of-books-2 = Кол-во книг
+genres = Жанры
+change-change-title = &Изменить/Изменить название\t
+change-change-author = &Изменить/Изменить автора\t
+change-change-amount-of-pages = &Изменить/Изменить Кол-во страниц\t
+change-customize-book-genres = &Изменить/Изменить жанры книги\t
+remove-remove-all-books = &Удалить/Удалить все книги\t
+remove-remove-one-book = &Удалить/Удалить одну книгу\t
+add-books-menu = &Добавить книги
+get-current-books-information = &Информация о конкретной книгe
+amount-of-pages-2 = Кол-во страниц
+book-system-is-empty = Книг нет
+book-isnt-selected = Книга не выбрана
+title-is-empty = 'Название' пусто
+author-is-empty = 'Автор' пусто
+amount-of-pages-is-empty = 'Кол-во страниц' пусто
+book-isnt-found = Книга не найдена
+location = Местонахождения
+cabinets-number = Номер шкафа
+shelfs-number = Номер полки
+cabinets-number-input-error = Ошибка ввода номера шкафа
+shelfs-number-input-error = Ошибка ввода номера полки
+add-genre = Добавить жанр
+new-genre = Новый жанр
+new-genre-is-empty = 'Новый жанр' пусто
+remove-genre = Удалить жанр
+genres-title = Название жанра
+genres-title-is-empty = 'Название жанра' пусто
+customize-books-genres = Изменить жанры книги
+input-genre = Ввод Жанра
+input-genre-2 = Введите Жанр
+books-with-spec-genre = Книги с искомым жанром
+all-books-with-genres = Все Книги с Жанрами
+without-genres = Без Жанров
+select-genres = Выбрать жанры
+find-reader = Поиск Читателя
+first-name = Имя
+second-name = Фамилия
+choose-finish-date = Выберите срок сдачи
+ok = ОК
+date-wasnt-selected = Дата не была выбрана
+find-book-2 = Поиск Книги
+set-return-date = Срок Сдачи
+year = Год
+incorrect-return-date = Некорректная дата возврата
+this-reader-wasnt-reading-searching-book = Этот читатель не читает искомую книгу
+book-is-returned-after-deadline = Книга возвращена после срока сдачи
+book-is-returned = Книга возвращена
+title-2 = Название книги
+this-reader-isnt-reading-anything = Этот читатель ничего не читает
+this-reader-isnt-reading-this-book = Этот читатель не читает искомую книгу
+date-is-successfully-changed = Дата успешно изменена
+set-new-date = Изменить дату
+help = Помощь
+add-reader = Добавить читателя
+second-names = Фамилия
+info-50-symb = Инфо (< 50 симв.)
+choose-birth-date = Выберите дату рождения
+remove-reader = Удалить читателя
+successfully-removed-2 = Успешно удалён
+change-name = Изменить имя
+change-2-nd-name = Изменить фамилию
+change-middle-name = Изменить Отчество
+change-info = Изменить информацию
+change-category = Изменить категорию
+new-category = Новая категория (пусто, чтобы убрать)
+change-age = Изменить Возраст
+new-name = Новое Имя
+new-2-nd-name = Новая Фамилия
+new-middle-name = Новое Отчество
+new-info-50-symb = Инфо (< 50 симв.)
+choose-new-birth-date = Выберите новую дату рождения
+first-name-tabbed = \t\tИмя
+second-name-tabbed = \t\tФамилия
+middle-name-tabbed = \t\tОтчество
+age-tabbed = \t\tВозраст
+additional-info = Дополнительная Информация
+books-read-by-reader-now = Книги, читаемые сейчас
+change-change-name = &Изменить/Изменить имя\t
+change-change-2-nd-name = &Изменить/Изменить фамилию\t
+change-change-mid-name = &Изменить/Изменить отчество\t
+change-change-age = &Изменить/Изменить возраст\t
+change-change-additional-information = &Изменить/Изменить дополнительную информацию
+giveaway-give-book = &Выдача/Выдать книгу\t
+giveaway-get-book = &Выдача/Вернуть книгу\t
+remove-reader-menu = &Удалить читателя
+number-of-book-2 = Номер книги
+nearest-to-return-deadline = Ближайщее к сдаче
+none-2 = Ничего
+additional-information = Дополнительная информация
+books-read-by-reader = Прочитанные книги
+reading-now = Читается сейчас
+name-is-empty = 'Имя' пусто
+2-nd-name-is-empty = 'Фамилия' пусто
+mid-name-is-empty = 'Фамилия' пусто
+reader-isnt-found = Читатель не найден
+audit-log-is-damaged = Журнал аудита повреждён:
+audit-log = Журнал аудита
+user = Логин
+record = Запись
+filter = Найти
+reset = Сбросить
+reader = Читатель
+book = Книга
+genre = Жанр
+login = Логин
+password = Пароль
+change-credentials = Изменить логин и пароль
+current-password = Текущий пароль
+new-login = Новый логин
+new-password = Новый пароль
+new-login-and-password-are-saved = Новые логин и пароль сохранены
+seconds-left = Осталось секунд
+users = Пользователи
+add = Добавить
+role = Роль
+remove = Удалить
+user-isnt-selected = Пользователь не выбран
+new-user = Новый пользователь
+choose-role = Выберите роль
+access-denied = У вас нет прав на это действие
+return-date-cant-be-earlier-than-today = Срок сдачи не может быть раньше сегодняшней даты или дня выдачи
+nothing-inputted = Ничего не введено
+journal-has-no-changes-until-this-day = В журнале нет изменений до этого дня
+journal-is-damaged = Журнал повреждён:
+library-on = Библиотека на
+readers = Читатели
+books = Книги
+givings = Выдачи
+cabinet-lowercase = шкаф
+shelf-lowercase = полка
+skipped-records = Пропущено записей
+data-cant-be-loaded = Не удалось загрузить данные:
+start-empty = Начать с нуля
+restore-backup = Восстановить копию
+skip-bad-records = Пропустить ошибки
+backup-isnt-found = Резервная копия не найдена
+authorization-is-complete = Авторизация пройдена
+library-system = Система Учёта Библиотеки
+book-library-interface = СИСТЕМА УЧЁТА КНИГ
+day = День
+month = Месяц
+start-date-2 = Дата Выдачи
+readers-add-reader = &Читатели/Добавить читателя\t
+readers-remove-reader = &Читатели/Удалить читателя\t
+readers-change-name = &Читатели/Изменить имя\t
+readers-change-second-name = &Читатели/Изменить фамилию\t
+readers-change-middle-name = &Читатели/Изменить отчество\t
+readers-change-age = &Читатели/Изменить возраст\t
+readers-change-additional-information = &Читатели/Изменить дополнительную информацию
+readers-change-category = &Читатели/Изменить категорию\t
+readers-readers-information-with-reading-now-books = &Читатели/Информация о читателе с читаемыми книгами\t
+readers-readers-information-with-read-books = &Читатели/Информация о читателе с прочитанными книгами\t
+books-add-books = &Книги/Добавить книги\t
+books-remove-book = &Книги/Удалить книгу\t
+books-remove-all-specific-books = &Книги/Убрать все схожие книги\t
+books-change-books-title = &Книги/Изменить название\t
+books-change-books-author = &Книги/Изменить автора\t
+books-change-books-amount-of-pages = &Книги/Изменить количество страниц\t
+books-change-books-location = &Книги/Изменить расположение книги\t
+books-change-copy-type = &Книги/Изменить тип экземпляра\t
+books-get-type-books-information = &Книги/Получить информацию о всех схожих книгах\t
+books-get-current-books-information = &Книги/Получить информацию о конкретной книгe\t
+books-list-of-all-books-by-authors = &Книги/Список всех книг по авторам\t
+books-list-of-all-books = &Книги/Все книги\t
+genres-list-of-all-books-by-genres = &Жанры/Список всех книг по жанрам\t
+genres-add-genre = &Жанры/Добавить жанр\t
+genres-remove-genre = &Жанры/Удалить жанр\t
+genres-customize-book-genres = &Жанры/Изменить жанры книги\t
+giveaway-change-return-date = &Выдача/Изменить срок сдачи\t
+giveaway-opening-calendar = &Выдача/Календарь работы\t
+giveaway-loan-periods = &Выдача/Сроки выдачи\t
+restore-restore-previous-data = &Откат/Откатить изменения назад\t
+restore-restore-next-data = &Откат/Откатить изменения вперед\t
+restore-history = &Откат/История изменений\t
+restore-audit-log = &Откат/Журнал аудита\t
+restore-library-on-date = &Откат/Библиотека на дату\t
+restore-restore-backup = &Откат/Восстановить резервную копию\t
+restore-create-snapshot = &Откат/Создать снимок\t
+restore-snapshots = &Откат/Снимки\t
+users-accounts = &Пользователи/Учётные записи\t
+users-change-credentials = &Пользователи/Изменить логин и пароль\t
+users-lock = &Пользователи/Заблокировать\t
+menu-language = &Язык
+menu-help = &Помощь
+undone = Отменено
+first-version = Первая версия
+done-again = Повторено
+last-version = Последняя версия
+are-you-sure-you-want-to-change = Вы уверены, что хотите сменить язык? Для этого придётся перезапустить программу
+language-isnt-changed = Не удалось сменить язык:
+ok-2 = Ок
+cancel = Отмена
+there-are-no-backups-yet = Резервных копий пока нет
+restore = Откатить
+backup-isnt-selected = Копия не выбрана
+backup-is-damaged = Копия повреждена:
+successfully-restored = Успешно восстановленно
+changes-arent-saved = Изменения внесены, но их не удалось сохранить:
+history = История изменений
+go-to = Перейти
+step-isnt-selected = Шаг не выбран
+start-of-history = Начало истории
+create-snapshot = Создать снимок
+label = Название
+label-is-empty = 'Название' пусто
+snapshot-is-created = Снимок создан
+snapshot-isnt-created = Снимок не создан:
+there-are-no-snapshots-yet = Снимков пока нет
+snapshots = Снимки
+compare = Сравнить
+snapshot-isnt-selected = Снимок не выбран
+snapshot-is-damaged = Снимок повреждён:
+snapshot-is-the-same-as-current-data = Снимок совпадает с текущими данными
+differences-with = Отличия от
+changes-since-snapshot = Изменения после снимка
+simple-books = Простые книги
+new-amount-of-pages-input-error = Некорректный ввод для 'Нового количества странциц'
+book-successfully-given-to-reader = Книга успешно выдана читателю
+incorrect-date = Некорректная дата
+date-from-any = С: любой
+date-to-any = По: любой
+before = До
+after = После
+authorization = Авторизация
+session-is-locked = Сеанс заблокирован
+given-mark = \ (выдана)
+not-returned-mark = \ (не возвращена)
+undone-mark = \ (отменено)
+yearly-mark = \ (каждый год)
+opening-calendar = Календарь работы
+closed-days = Выходные дни:
+import-icalendar = Импорт iCalendar
+choose-icalendar-file = Выберите файл iCalendar
+imported-holidays = Импортировано праздников
+skipped-events = Пропущено некорректных событий
+holiday-isnt-selected = Праздник не выбран
+new-holiday = Новый праздник
+holiday-name = Название
+is-holiday-repeated = Праздник повторяется каждый год?
+every-year = Каждый год
+only-once = Только один раз
+weekday-monday = Понедельник
+weekday-tuesday = Вторник
+weekday-wednesday = Среда
+weekday-thursday = Четверг
+weekday-friday = Пятница
+weekday-saturday = Суббота
+weekday-sunday = Воскресенье
+loan-periods = Сроки выдачи
+default-loan-period = Срок выдачи по умолчанию (дней)
+category-of-readers = Категория читателей
+days = Дней
+any-category = Любая категория
+any-genre = Любой жанр
+rule-isnt-selected = Правило не выбрано
+give = Выдать
+other-date = Другая дата
+yes = Да
+no = Нет
+can-override-limits = Может ли библиотекарь выдавать книги сверх ограничений?
+new-copy-type = Новый тип экземпляра (пусто для обычного)
+copy-type = Тип экземпляра
+any-copy-type = Любой экземпляр
+borrowing-limits = Ограничения выдачи
+max-books = Макс. книг
+incorrect-max-books = Некорректное макс. число книг
+limit-kind-max-loans = Макс. книг одновременно
+limit-kind-no-overdue = Без просроченных книг
+limit-kind-not-loanable = Не выдаётся
+limit-no-overdue = не выдаётся читателям с просроченными книгами
+limit-not-loanable = не выдаётся из библиотеки
+refusal-not-loanable = Этот экземпляр не выдаётся из библиотеки
+refusal-overdue = У читателя есть просроченные книги
+refusal-too-many-loans = У читателя уже слишком много книг
+loan-is-refused = Книгу нельзя выдать:
+give-over-limits = Всё равно выдать книгу?
+
+# Ошибки читателей
+
+reader-error-not-found = Читатель не найден
+reader-error-already-exists = Читатель с предложенными параметрами уже существует
+reader-error-empty-name = 'Имя' пусто
+reader-error-empty-family = 'Фамилия' пусто
+reader-error-empty-father = 'Отчество' пусто
+reader-error-empty-info = 'Инфо' пусто
+reader-error-still-reading = Читатель уже читает книгу с такими параметрами
+reader-error-not-reading = Этот читатель не читает искомую книгу
+reader-error-io = Не удалось сохранить изменения читателей
+
+# Ошибки книг
+
+book-error-not-found = Книга не найдена
+book-error-simple-book-not-found = Книга с таким номером не найдена
+book-error-already-exists = Книга с предложенными параметрами уже существует
+book-error-too-many-books = Слишком много книг
+book-error-no-free-books = Свободных книг не осталось
+book-error-not-used = Книга сейчас не используется
+book-error-returned-late = Книга возвращена после срока сдачи
+book-error-unknown-genre = Жанр не найден
+book-error-io = Не удалось сохранить изменения книг
+loan-error-io = Не удалось сохранить выдачу книги
+
+# Ошибки жанров
+
+genre-error-already-exists = Жанр уже существует
+genre-error-not-found = Жанр не найден
+genre-error-io = Не удалось сохранить изменения жанров
+
+# Ошибки учётных записей
+
+auth-error-empty-login = 'Логин' пусто
+auth-error-empty-password = 'Пароль' пусто
+auth-error-already-exists = Пользователь с таким логином уже существует
+auth-error-not-found = Пользователь не найден
+auth-error-last-administrator = Должен остаться хотя бы один администратор
+auth-error-wrong-credentials = Неправильный логин или пароль
+auth-error-locked = Слишком много неудачных попыток. Попробуйте позже
+auth-error-io = Не удалось сохранить пользователей
+
+# Ошибки календаря работы
+
+calendar-error-no-open-days = Библиотека должна работать хотя бы один день в неделю
+calendar-error-already-exists = На эту дату уже есть праздник
+calendar-error-not-found = Праздник не найден
+calendar-error-wrong-icalendar = Файл не является корректным iCalendar
+calendar-error-io = Не удалось сохранить календарь
+
+# Ошибки сроков выдачи
+
+policy-error-wrong-period = Срок выдачи должен быть от 1 до 366 дней
+policy-error-already-exists = Такое правило уже есть
+policy-error-not-found = Правило не найдено
+policy-error-io = Не удалось сохранить сроки выдачи
+
+# Роли пользователей
+
+role-administrator = Администратор
+role-librarian = Библиотекарь
+role-senior-librarian = Старший библиотекарь
+role-read-only = Только просмотр
+
+# Операции с библиотекой
+
+operation-add-reader = Добавить читателя
+operation-remove-reader = Удалить читателя
+operation-change-name = Изменить имя читателя
+operation-change-family = Изменить фамилию читателя
+operation-change-father = Изменить отчество читателя
+operation-change-info = Изменить информацию о читателе
+operation-change-category = Изменить категорию читателя
+operation-change-age = Изменить возраст читателя
+operation-add-book = Добавить книгу
+operation-add-books = Добавить простые книги
+operation-remove-book = Удалить книгу
+operation-remove-one-book = Удалить простую книгу
+operation-change-title = Изменить название книги
+operation-change-author = Изменить автора книги
+operation-change-pages = Изменить кол-во страниц
+operation-change-location = Изменить расположение книги
+operation-change-copy-type = Изменить тип экземпляра
+operation-give-book = Выдать книгу
+operation-give-book-over-limits = Выдать книгу сверх ограничений
+operation-return-book = Получить книгу
+operation-change-return-date = Изменить дату возврата
+operation-add-genre = Добавить жанр
+operation-remove-genre = Удалить жанр
+operation-set-book-genres = Изменить жанры книги
+operation-replace = Восстановить все данные
+
+# Действия в журнале аудита
+
+audit-action-done = Сделано
+audit-action-undone = Отменено
+audit-action-redone = Повторено
+
+# События журнала
+
+event-reader-added = Читатель добавлен
+event-reader-changed = Читатель изменён
+event-reader-removed = Читатель удалён
+event-book-added = Книга добавлена
+event-book-changed = Книга изменена
+event-book-replaced = Книга заменена
+event-book-removed = Книга удалена
+event-genres-assigned = Жанры книги изменены
+event-copy-added = Простая книга добавлена
+event-copy-removed = Простая книга удалена
+event-copy-moved = Простая книга перемещена
+event-copy-given = Простая книга выдана
+event-copy-returned = Простая книга возвращена
+event-due-date-changed = Срок возврата изменён
+event-copy-replaced = Простая книга заменена
+event-genre-added = Жанр добавлен
+event-genre-removed = Жанр удалён
+
+# Сообщения с аргументами
+
+book-location = Расположение книги: шкаф { $cabinet }, полка { $shelf }
+copy-location = №{ $id }: шкаф { $cabinet }, полка { $shelf }
+given-to = , выдана { $readers }
+and-more = и ещё { $count }
+date-from = С { $date }
+date-to = По { $date }
+due-date-is-moved = В выбранный день библиотека не работает, срок сдачи перенесён на { $date }
+loan-period-days = { $days } дн.
+loan-period-days.one = { $days } день
+loan-period-days.few = { $days } дня
+loan-period-days.many = { $days } дней
+give-book-until = Выдать книгу до { $date }?
+limit-max-loans = не более { $max } книг одновременно
+limit-max-loans.one = не более { $max } книги одновременно
+refusal-overdue-details = Просроченных книг у читателя: { $count }
+refusal-overdue-details.one = У читателя { $count } просроченная книга
+refusal-overdue-details.few = У читателя { $count } просроченные книги
+refusal-overdue-details.many = У читателя { $count } просроченных книг
+refusal-too-many-loans-details = У читателя уже { $max } таких книг, это предел
+refusal-too-many-loans-details.one = У читателя уже { $max } такая книга, это предел
+refusal-too-many-loans-details.few = У читателя уже { $max } такие книги, это предел
+refusal-too-many-loans-details.many = У читателя уже { $max } таких книг, это предел
+
+# Текст окна помощи
+
+help-text = \    Автор: dinaraparanid (github: https://github.com/dinaraparanid)\n\n    Лицензия: MIT или Apache-2.0\n\n    Документация:\n\n        публичные модули: https://docs.rs/binartree/1.1.9/booklibrs/\n\n        внутренние модули: перейдите в librs/target/doc/booklibrs/all.html (нажать)\n\n\n    О проекте:\n\n    Это проект, цель которого создать систему учёта книг в библиотеке.\n\n    Весь проект Целиком и полностью написан на ЯП Rust с использованием крейтов fltk, chrono и yaml_rust.\n\n\n    Как пользоваться:\n\n\n    1. Начало\n\n\n    Если вы используете ОС Windows, то достаточно запустить run.bat и программа начнёт работу.\n\n    Если же вы юспользуете другие ОС, то придётся скачать следующее ПО:\n\n    Компилятор Rust: https://www.rust-lang.org/tools/install\n\n    Компилятор C/C++ от Microsoft (MVSC) (НЕ MinGW!!!): https://visualstudio.microsoft.com/vs/features/cplusplus/\n\n    Git: https://git-scm.com/book/en/v2/Getting-Started-Installing-Git\n\n    CMake: https://cmake.org/install/\n\n\n    После этого нужно воспоьзоваться консолью, перейти в папку с проектом и прописать 'cargo run --release'\n     и программа начнёт работу\n\n\n    2. Инструменты\n\n\n    Во первых, вы увидете таблицу со всеми читателями, отсортированнными в лексикографической последовательности (по возратанию имени) и их статус чтения (книга / дата выдачи / дата возврата).\n\n    В главном меню (в левом верхнем углу) расположенно 6 подменю: Читатели, Книги, Выдача, Выдача, Откат, Язык и Помощь.\n\n\n    Меню Читатели:\n \n    Содержит все действия, выполнимые с читателями: Добавить, Удалить, Изменить имя, фамилию, отчество,\n     получить информацию.\n\n    Получить информацию о читателях можно в двух видах: с прочитанными книгами, и с читаемыми сейчас.\n\n    Во всех этих действиях необходим ввод. Но вы можете кликнуть на читателя в главной таблице и все необходимые \n    действия будут там.(кроме добавления читателя - необходим ввод параметров читателя)\n\n\n    Меню Книги:\n\n    1) Добавить книги (если книга есть в библиотеке, добавит к кол-ву, иначе создаст новую книгу в библиотеке)\n\n    2) Удалить книгу. Вы можете удалить только одну книгу по её номеру.\n\n    3) Удалить все схожие книги. Удалит все книги с одинаковыми названием, автором и кол-вом страниц\n.\n    4) Изменить название, автора, кол-во страниц.\n\n    5) Изменить расположение книги. Изменяет номер шкафа и полки. Я не знаю ничего о вашей библиотеке,\n    так что не могу контролировать ввод. Это на вашу совесть.\n\n    6) Получить информацию о всех схожих книгах. Так же, как и для читателя, выведется окно с информациейи доступными действиями.\n\n    7) Получить информацию о конкретной книгe. Выводится окно с информацией о конкретной книге. Так же вы можете изменить расположение (шкаф и полку)\n\n    8) Список всех книг по авторам. Окно со всеми читателями, отсортированными по авторам. Авторы отсортированы по возрастанию (лексикографически).\n\n    9) Все книги. Выводит все книги.\n\n\n    Меню Жанры:\n\n    1) Список всех книг по жанрам. Сортирует все книги по жанрам.\n\n    2) Добавить / удалить жанр. По-умолчанию жанров нет, так что нужно самому добавлять их. Ограничений нет, так что используйте всю свою фантазию :)\n\n    3) Изменить жанры книги. Выводится окно с жанрами. нужно отметить галочкой все нужные жанры. Если жанров нет, то их не будет. Если у книги уже есть жанры, рядом будет галочка.\n\n\n    Меню Выдачи книг:\n\n    1) Выдать книгу. Вводятся параметры читателя и книги. В конце нужно выбрать дату возврата.Так же книгу можно выдать в информации о читателе (или кликнув на нужного читателя в главной таблице)\n\n    2) Вернуть книгу. Вводятся параметры читателя.Так же книгу можно вернуть в информации о читателе (или кликнув на нужного читателя в главной таблице)\n\n    3) Изменить дату выдачи. Можно кликнуть на дату выдачи в главной таблице и сделать тоже самое.\n\n\n    Меню Отката (Восстановления):\n\n    1) Откатить изменения назад. Так же можно нажать Ctrl-Z.\n\n    2) Откатить изменения вперед. Так же можно нажать Ctrl-Shift-Z.\n\n    3) Восстановить резервную копию. Каждое сохранение оставляет копию прежних данных, можно выбрать любую из них.\n\n\n    В меню Язык можно выбрать язык (русский или английский).\n\n    В меню Помощь можно прочитать всё, что вы прочли, если что-то забыли.\n\n\n    Полная версия со всеми скомпилированными файлами (СКАЧИВАТЬ ОТСЮДА):\n    https://drive.google.com/drive/u/0/folders/1mOTWStFbS_NS7iYs0uxS6MlT9jv8qZRf\n\n    Надеюсь, вам понравится!\n
@@ -2,10 +2,8 @@ extern crate fltk;
 
 use crate::{
     actions::book::{add_rem::simple::*, utils::*},
-    books::{book_sys::BookSystem, genres::Genres},
     change::{input1::Input1, input3::Input3, Inputable},
-    reading::read_base::ReaderBase,
-    restore::caretaker::Caretaker,
+    library::Library,
     Lang,
 };
 
@@ -27,32 +25,13 @@ use fltk::{
 /// program will let you know
 
 #[inline]
-pub fn add_books(
-    book_system: &mut BookSystem,
-    reader_base: &ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+pub fn add_books(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = app::channel();
     let mut inp = Input3::<Input, Input, IntInput>::new(
-        match lang {
-            Lang::English => "Add Books",
-            Lang::Russian => "Добавить книги",
-        },
-        match lang {
-            Lang::English => "Title",
-            Lang::Russian => "Название",
-        },
-        match lang {
-            Lang::English => "Author",
-            Lang::Russian => "Автор",
-        },
-        match lang {
-            Lang::English => "Amount of Pages",
-            Lang::Russian => "Кол-во страниц",
-        },
+        lang.tr("add-books"),
+        lang.tr("title"),
+        lang.tr("author"),
+        lang.tr("amount-of-pages"),
     );
 
     inp.show();
@@ -67,43 +46,20 @@ pub fn add_books(
                     if let Ok(books) = inp.set_input(lang) {
                         if !empty_inp_book(&books, lang) {
                             match unsafe { books.get_unchecked(2).trim().parse::<u16>() } {
-                                Ok(x) => match book_system.find_book(
+                                Ok(x) => match library.book_system().find_book(
                                     unsafe { books.get_unchecked(0) },
                                     unsafe { books.get_unchecked(1) },
                                     x,
                                 ) {
                                     Some(i) => {
-                                        add_books_simple(
-                                            i,
-                                            book_system,
-                                            reader_base,
-                                            genres,
-                                            caretaker,
-                                            app,
-                                            lang,
-                                        );
+                                        add_books_simple(i, library, app, lang);
                                     }
 
-                                    None => add_book_simple(
-                                        &books,
-                                        book_system,
-                                        reader_base,
-                                        genres,
-                                        caretaker,
-                                        app,
-                                        lang,
-                                    ),
+                                    None => add_book_simple(&books, library, app, lang),
                                 },
 
                                 Err(_) => {
-                                    alert(
-                                        500,
-                                        500,
-                                        match lang {
-                                            Lang::English => "Amount of Pages input error",
-                                            Lang::Russian => "Ошибка ввода количества страниц",
-                                        },
-                                    );
+                                    alert(500, 500, lang.tr("amount-of-pages-input-error"));
                                 }
                             }
                         }
@@ -130,32 +86,13 @@ pub fn add_books(
 
 #[allow(dead_code)]
 #[deprecated(note = "Used before, requires input. Consider using action_books() instead")]
-fn remove_book(
-    book_system: &mut BookSystem,
-    reader_base: &mut ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+fn remove_book(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = app::channel();
     let mut inp = Input3::<Input, Input, IntInput>::new(
-        match lang {
-            Lang::English => "Remove Book",
-            Lang::Russian => "Удалить книгу",
-        },
-        match lang {
-            Lang::English => "Title",
-            Lang::Russian => "Название",
-        },
-        match lang {
-            Lang::English => "Author",
-            Lang::Russian => "Автор",
-        },
-        match lang {
-            Lang::English => "Amount of Pages",
-            Lang::Russian => "Кол-во страниц",
-        },
+        lang.tr("remove-book"),
+        lang.tr("title"),
+        lang.tr("author"),
+        lang.tr("amount-of-pages"),
     );
 
     inp.show();
@@ -168,16 +105,8 @@ fn remove_book(
                     inp.hide();
 
                     if let Ok(book) = inp.set_input(lang) {
-                        if let Ok(index) = check_book(book_system, &book, lang) {
-                            remove_book_simple(
-                                index,
-                                book_system,
-                                reader_base,
-                                genres,
-                                caretaker,
-                                app,
-                                lang,
-                            );
+                        if let Ok(index) = check_book(library.book_system(), &book, lang) {
+                            remove_book_simple(index, library, app, lang);
                         }
                     }
                 }
@@ -204,36 +133,15 @@ fn remove_book(
 #[deprecated(
     note = "Used before to add new TheBook. Complains if TheBook with same params exists. Use add_books() instead"
 )]
-fn add_book(
-    book_system: &mut BookSystem,
-    reader_base: &ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+fn add_book(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = app::channel();
     let mut inp = Input3::<Input, Input, IntInput>::new(
-        match lang {
-            Lang::English => "Add New Book",
-            Lang::Russian => "Добавить новую книгу",
-        },
-        match lang {
-            Lang::English => "Title",
-            Lang::Russian => "Название",
-        },
-        match lang {
-            Lang::English => "Author",
-            Lang::Russian => "Автор",
-        },
-        match lang {
-            Lang::English => "Amount of Pages",
-            Lang::Russian => "Кол-во страниц",
-        },
+        lang.tr("add-new-book"),
+        lang.tr("title"),
+        lang.tr("author"),
+        lang.tr("amount-of-pages"),
     );
 
-    caretaker.add_memento(reader_base, book_system, genres);
-
     inp.show();
     (*inp.ok).borrow_mut().emit(s2, true);
 
@@ -246,14 +154,8 @@ fn add_book(
                     if !empty_inp_book(&the_book, lang) {
                         let (s, r) = app::channel();
                         let mut am = Input1::<IntInput>::new(
-                            match lang {
-                                Lang::English => "Amount of Books",
-                                Lang::Russian => "Кол-во Книг",
-                            },
-                            match lang {
-                                Lang::English => "Set amount of books",
-                                Lang::Russian => "Укажите Кол-во книг",
-                            },
+                            lang.tr("amount-of-books"),
+                            lang.tr("set-amount-of-books"),
                         );
 
                         am.show();
@@ -270,42 +172,30 @@ fn add_book(
                                                 match the_book.last().unwrap().trim().parse::<u16>()
                                                 {
                                                     Ok(x) => unsafe {
-                                                        match book_system.add_book(
+                                                        let locations = match ask_locations(
+                                                            amount, app, lang,
+                                                        ) {
+                                                            Some(locations) => locations,
+                                                            None => return,
+                                                        };
+
+                                                        match library.add_book(
                                                             the_book.get_unchecked(0).clone(),
                                                             the_book.get_unchecked(1).clone(),
                                                             x,
-                                                            amount,
-                                                            app,
-                                                            lang,
+                                                            &locations,
                                                         ) {
                                                             Ok(_) => {
                                                                 fltk::dialog::message(
                                                                     500,
                                                                     500,
-                                                                    match lang {
-                                                                        Lang::English => {
-                                                                            "Successfully added"
-                                                                        }
-                                                                        Lang::Russian => {
-                                                                            "Успешно добавлено"
-                                                                        }
-                                                                    },
+                                                                    lang.tr("successfully-added"),
                                                                 );
-                                                                book_system.save();
                                                                 return;
                                                             }
 
-                                                            Err(_) => {
-                                                                alert(
-                                                                    500,
-                                                                    500,
-                                                                    match lang {
-                                                                        Lang::English => "Book with same params already exists",
-                                                                        Lang::Russian => concat!("Книга с предложенными",
-                                                                        " параметрами уже существует"),
-                                                                    }
-                                                                );
-                                                                caretaker.pop().unwrap();
+                                                            Err(err) => {
+                                                                alert(500, 500, err.message(lang));
                                                                 return;
                                                             }
                                                         }
@@ -315,12 +205,10 @@ fn add_book(
                                                         alert(
                                                             500,
                                                             500,
-                                                            match lang {
-                                                                Lang::English => "Incorrect 'Amount of Pages' input",
-                                                                Lang::Russian => "Некорретный ввод количества страниц",
-                                                            }
+                                                            lang.tr(
+                                                                "incorrect-amount-of-pages-input",
+                                                            ),
                                                         );
-                                                        caretaker.pop().unwrap();
                                                         return;
                                                     }
                                                 }
@@ -330,16 +218,8 @@ fn add_book(
                                                 alert(
                                                     500,
                                                     500,
-                                                    match lang {
-                                                        Lang::English => {
-                                                            "'Amount of Pages' input error"
-                                                        }
-                                                        Lang::Russian => {
-                                                            "Ошибка ввода количества страниц"
-                                                        }
-                                                    },
+                                                    lang.tr("amount-of-pages-input-error-2"),
                                                 );
-                                                caretaker.pop().unwrap();
                                                 return;
                                             }
                                         }
@@ -372,32 +252,13 @@ fn add_book(
 
 #[allow(dead_code)]
 #[deprecated(note = "Used before, requires input. Consider using remove_the_book() instead")]
-fn remove_the_book_input(
-    book_system: &mut BookSystem,
-    reader_base: &mut ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+fn remove_the_book_input(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = app::channel();
     let mut inp = Input3::<Input, Input, IntInput>::new(
-        match lang {
-            Lang::English => "Remove Books",
-            Lang::Russian => "Удалить книги",
-        },
-        match lang {
-            Lang::English => "Title",
-            Lang::Russian => "Название",
-        },
-        match lang {
-            Lang::English => "Author",
-            Lang::Russian => "Автор",
-        },
-        match lang {
-            Lang::English => "Amount of Pages",
-            Lang::Russian => "Кол-во страниц",
-        },
+        lang.tr("remove-books"),
+        lang.tr("title"),
+        lang.tr("author"),
+        lang.tr("amount-of-pages"),
     );
 
     inp.show();
@@ -409,15 +270,8 @@ fn remove_the_book_input(
                 inp.hide();
 
                 if let Ok(the_book) = inp.set_input(lang) {
-                    if let Ok(index) = check_book(book_system, &the_book, lang) {
-                        remove_the_book_simple(
-                            index,
-                            book_system,
-                            reader_base,
-                            genres,
-                            caretaker,
-                            lang,
-                        );
+                    if let Ok(index) = check_book(library.book_system(), &the_book, lang) {
+                        remove_the_book_simple(index, library, lang);
                     }
                 }
             }
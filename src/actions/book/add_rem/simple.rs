@@ -1,10 +1,9 @@
 extern crate fltk;
 
 use crate::{
-    books::{book_sys::BookSystem, genres::Genres},
+    actions::book::utils::ask_locations,
     change::{input1::Input1, Inputable},
-    reading::read_base::ReaderBase,
-    restore::caretaker::Caretaker,
+    library::Library,
     Lang,
 };
 
@@ -15,41 +14,14 @@ use fltk::{app, app::App, dialog::alert, input::IntInput, prelude::*};
 /// and all simple books
 
 #[inline]
-pub(crate) fn remove_the_book_simple(
-    ind: usize,
-    book_system: &mut BookSystem,
-    reader_base: &mut ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    lang: Lang,
-) {
-    caretaker.add_memento(reader_base, book_system, genres);
-
-    match book_system.remove_book(ind) {
+pub(crate) fn remove_the_book_simple(ind: usize, library: &mut Library, lang: Lang) {
+    match library.remove_book(ind) {
         Ok(_) => {
-            fltk::dialog::message(
-                500,
-                500,
-                match lang {
-                    Lang::English => "Successfully removed",
-                    Lang::Russian => "Успешно удалено",
-                },
-            );
-
-            book_system.save();
-            reader_base.save();
+            fltk::dialog::message(500, 500, lang.tr("successfully-removed"));
         }
 
-        Err(_) => {
-            alert(
-                500,
-                500,
-                match lang {
-                    Lang::English => "Book's number is incorrect",
-                    Lang::Russian => "Номер книги некорректен",
-                },
-            );
-            caretaker.pop().unwrap();
+        Err(err) => {
+            alert(500, 500, err.message(lang));
             return;
         }
     }
@@ -59,28 +31,10 @@ pub(crate) fn remove_the_book_simple(
 /// to already known the book
 
 #[inline]
-pub(crate) fn add_books_simple(
-    ind: usize,
-    book_system: &mut BookSystem,
-    reader_base: &ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) -> bool {
+pub(crate) fn add_books_simple(ind: usize, library: &mut Library, app: &App, lang: Lang) -> bool {
     let (s3, r3) = app::channel();
-    let mut get_amount = Input1::<IntInput>::new(
-        match lang {
-            Lang::English => "Books amount",
-            Lang::Russian => "Кол-во книг",
-        },
-        match lang {
-            Lang::English => "Amount of books to add",
-            Lang::Russian => "Кол-во книг",
-        },
-    );
-
-    caretaker.add_memento(reader_base, book_system, genres);
+    let mut get_amount =
+        Input1::<IntInput>::new(lang.tr("books-amount"), lang.tr("amount-of-books-to-add"));
 
     get_amount.show();
     (*get_amount.ok).borrow_mut().emit(s3, true);
@@ -92,44 +46,24 @@ pub(crate) fn add_books_simple(
 
                 if let Ok(amount) = get_amount.set_input(lang) {
                     return match amount.first().unwrap().trim().parse::<usize>() {
-                        Ok(x) => match book_system.add_books(ind, x, app, lang) {
-                            Ok(_) => {
-                                fltk::dialog::message(
-                                    500,
-                                    500,
-                                    match lang {
-                                        Lang::English => "Successfully added",
-                                        Lang::Russian => "Успешно добавлено",
-                                    },
-                                );
-                                book_system.save();
-                                true
-                            }
+                        Ok(x) => match ask_locations(x, app, lang) {
+                            Some(locations) => match library.add_books(ind, &locations) {
+                                Ok(_) => {
+                                    fltk::dialog::message(500, 500, lang.tr("successfully-added"));
+                                    true
+                                }
 
-                            Err(_) => {
-                                alert(
-                                    500,
-                                    500,
-                                    match lang {
-                                        Lang::English => "Too much books",
-                                        Lang::Russian => "Слишком много книг",
-                                    },
-                                );
-                                caretaker.pop().unwrap();
-                                false
-                            }
+                                Err(err) => {
+                                    alert(500, 500, err.message(lang));
+                                    false
+                                }
+                            },
+
+                            None => false,
                         },
 
                         Err(_) => {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "'Amount of books' input error",
-                                    Lang::Russian => "Ошибка ввода количества книг",
-                                },
-                            );
-                            caretaker.pop().unwrap();
+                            alert(500, 500, lang.tr("amount-of-books-input-error"));
                             false
                         }
                     };
@@ -137,7 +71,6 @@ pub(crate) fn add_books_simple(
             }
             break;
         } else if !get_amount.shown() {
-            caretaker.pop().unwrap();
             return false;
         }
     }
@@ -152,26 +85,12 @@ pub(crate) fn add_books_simple(
 #[inline]
 pub(crate) fn remove_book_simple(
     index: usize,
-    book_system: &mut BookSystem,
-    reader_base: &mut ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     app: &App,
     lang: Lang,
 ) -> bool {
     let (s3, r3) = app::channel();
-    let mut get_ind = Input1::<IntInput>::new(
-        match lang {
-            Lang::English => "Book's number",
-            Lang::Russian => "Номер книги",
-        },
-        match lang {
-            Lang::English => "Book's number",
-            Lang::Russian => "Номер книги",
-        },
-    );
-
-    caretaker.add_memento(reader_base, book_system, genres);
+    let mut get_ind = Input1::<IntInput>::new(lang.tr("books-number"), lang.tr("books-number"));
 
     get_ind.show();
     (*get_ind.ok).borrow_mut().emit(s3, true);
@@ -185,42 +104,21 @@ pub(crate) fn remove_book_simple(
                     return match ind.first().unwrap().trim().parse::<usize>() {
                         Ok(x) => {
                             if x == 0 {
-                                alert(
-                                    500,
-                                    500,
-                                    match lang {
-                                        Lang::English => "Incorrect number of book",
-                                        Lang::Russian => "Некорректный номер книги",
-                                    },
-                                );
-                                caretaker.pop().unwrap();
+                                alert(500, 500, lang.tr("incorrect-number-of-book"));
                                 false
                             } else {
-                                match book_system.remove_one_book(index, x - 1) {
+                                match library.remove_one_book(index, x - 1) {
                                     Ok(_) => {
                                         fltk::dialog::message(
                                             500,
                                             500,
-                                            match lang {
-                                                Lang::English => "Successfully removed",
-                                                Lang::Russian => "Успешно удалено",
-                                            },
+                                            lang.tr("successfully-removed"),
                                         );
-                                        book_system.save();
-                                        reader_base.save();
                                         true
                                     }
 
-                                    Err(_) => {
-                                        alert(
-                                            500,
-                                            500,
-                                            match lang {
-                                                Lang::English => "Incorrect number of book",
-                                                Lang::Russian => "Некорректный номер книги",
-                                            },
-                                        );
-                                        caretaker.pop().unwrap();
+                                    Err(err) => {
+                                        alert(500, 500, err.message(lang));
                                         false
                                     }
                                 }
@@ -228,15 +126,7 @@ pub(crate) fn remove_book_simple(
                         }
 
                         Err(_) => {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "Book's number input error",
-                                    Lang::Russian => "Ошибка ввода номера книги",
-                                },
-                            );
-                            caretaker.pop().unwrap();
+                            alert(500, 500, lang.tr("books-number-input-error"));
                             false
                         }
                     };
@@ -244,7 +134,6 @@ pub(crate) fn remove_book_simple(
             }
             break;
         } else if !get_ind.shown() {
-            caretaker.pop().unwrap();
             return false;
         }
     }
@@ -258,32 +147,10 @@ pub(crate) fn remove_book_simple(
 
 #[inline]
 #[allow(dead_code)]
-pub(crate) fn remove_book_simple2(
-    index: usize,
-    s_index: usize,
-    book_system: &mut BookSystem,
-    reader_base: &mut ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    lang: Lang,
-) {
-    caretaker.add_memento(reader_base, book_system, genres);
-
-    unsafe {
-        book_system.remove_one_book_unchecked(index, s_index);
+pub(crate) fn remove_book_simple2(index: usize, s_index: usize, library: &mut Library, lang: Lang) {
+    if library.remove_one_book(index, s_index).is_ok() {
+        fltk::dialog::message(500, 500, lang.tr("successfully-removed"));
     }
-
-    fltk::dialog::message(
-        500,
-        500,
-        match lang {
-            Lang::English => "Successfully removed",
-            Lang::Russian => "Успешно удалено",
-        },
-    );
-
-    book_system.save();
-    reader_base.save();
 }
 
 /// Adds The Book with known params
@@ -291,26 +158,13 @@ pub(crate) fn remove_book_simple2(
 #[inline]
 pub(crate) fn add_book_simple(
     the_book: &Vec<String>,
-    book_system: &mut BookSystem,
-    reader_base: &ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     app: &App,
     lang: Lang,
 ) {
     let (s, r) = app::channel();
-    let mut am = Input1::<IntInput>::new(
-        match lang {
-            Lang::English => "Amount of Books",
-            Lang::Russian => "Кол-во Книг",
-        },
-        match lang {
-            Lang::English => "Set amount of books",
-            Lang::Russian => "Кол-во книг",
-        },
-    );
-
-    caretaker.add_memento(reader_base, book_system, genres);
+    let mut am =
+        Input1::<IntInput>::new(lang.tr("amount-of-books"), lang.tr("set-amount-of-books-2"));
 
     am.show();
     (*am.ok).borrow_mut().emit(s, true);
@@ -324,65 +178,40 @@ pub(crate) fn add_book_simple(
                     match amount.first().unwrap().trim().parse::<usize>() {
                         Ok(amount) => match the_book.last().unwrap().trim().parse::<u16>() {
                             Ok(x) => {
-                                match book_system.add_book(
+                                let locations = match ask_locations(amount, app, lang) {
+                                    Some(locations) => locations,
+                                    None => return,
+                                };
+
+                                match library.add_book(
                                     unsafe { the_book.get_unchecked(0).clone() },
                                     unsafe { the_book.get_unchecked(1).clone() },
                                     x,
-                                    amount,
-                                    app,
-                                    lang,
+                                    &locations,
                                 ) {
                                     Ok(_) => {
                                         fltk::dialog::message(
                                             500,
                                             500,
-                                            match lang {
-                                                Lang::English => "Successfully added",
-                                                Lang::Russian => "Успешно добавлено",
-                                            },
+                                            lang.tr("successfully-added"),
                                         );
-                                        book_system.save();
                                     }
 
-                                    Err(_) => {
-                                        alert(
-                                            500,
-                                            500,
-                                            match lang {
-                                                Lang::English => "Book with same parameters already exists",
-                                                Lang::Russian => "Книга с предложенными параметрами уже сузествует",
-                                            }
-                                        );
-                                        caretaker.pop().unwrap();
+                                    Err(err) => {
+                                        alert(500, 500, err.message(lang));
                                         return;
                                     }
                                 }
                             }
 
                             Err(_) => {
-                                alert(
-                                    500,
-                                    500,
-                                    match lang {
-                                        Lang::English => "Incorrect 'Amount of Pages' input",
-                                        Lang::Russian => "Некорретный ввод количества страниц",
-                                    },
-                                );
-                                caretaker.pop().unwrap();
+                                alert(500, 500, lang.tr("incorrect-amount-of-pages-input"));
                                 return;
                             }
                         },
 
                         Err(_) => {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "'Amount of Pages' input error",
-                                    Lang::Russian => "Ошибка ввода количества страниц",
-                                },
-                            );
-                            caretaker.pop().unwrap();
+                            alert(500, 500, lang.tr("amount-of-pages-input-error-2"));
                             return;
                         }
                     }
@@ -2,10 +2,8 @@ extern crate fltk;
 
 use crate::{
     actions::book::{change::simple::*, utils::check_book},
-    books::{book_sys::BookSystem, genres::Genres},
     change::{input1::Input1, input3::Input3, Inputable},
-    reading::read_base::ReaderBase,
-    restore::caretaker::Caretaker,
+    library::Library,
     Lang,
 };
 
@@ -29,32 +27,13 @@ use fltk::{
 
 #[allow(dead_code)]
 #[deprecated(note = "Used before, requires input. Consider using action_books() instead")]
-fn change_title(
-    book_system: &mut BookSystem,
-    reader_base: &mut ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+fn change_title(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = app::channel();
     let mut inp = Input3::<Input, Input, IntInput>::new(
-        match lang {
-            Lang::English => "Change title",
-            Lang::Russian => "Изменить название",
-        },
-        match lang {
-            Lang::English => "Title",
-            Lang::Russian => "Название",
-        },
-        match lang {
-            Lang::English => "Author",
-            Lang::Russian => "Автор",
-        },
-        match lang {
-            Lang::English => "Amount of Pages",
-            Lang::Russian => "Кол-во страниц",
-        },
+        lang.tr("change-title"),
+        lang.tr("title"),
+        lang.tr("author"),
+        lang.tr("amount-of-pages"),
     );
 
     inp.show();
@@ -66,16 +45,8 @@ fn change_title(
                 inp.hide();
 
                 if let Ok(book) = inp.set_input(lang) {
-                    if let Ok(index) = check_book(book_system, &book, lang) {
-                        change_title_simple(
-                            index,
-                            book_system,
-                            reader_base,
-                            genres,
-                            caretaker,
-                            app,
-                            lang,
-                        );
+                    if let Ok(index) = check_book(library.book_system(), &book, lang) {
+                        change_title_simple(index, library, app, lang);
                     }
                 }
             }
@@ -98,32 +69,13 @@ fn change_title(
 
 #[allow(dead_code)]
 #[deprecated(note = "Used before, requires input. Consider using action_books() instead")]
-fn change_author(
-    book_system: &mut BookSystem,
-    reader_base: &mut ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+fn change_author(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = app::channel();
     let mut inp = Input3::<Input, Input, IntInput>::new(
-        match lang {
-            Lang::English => "Change author",
-            Lang::Russian => "Изменить автора",
-        },
-        match lang {
-            Lang::English => "Title",
-            Lang::Russian => "Название",
-        },
-        match lang {
-            Lang::English => "Author",
-            Lang::Russian => "Автор",
-        },
-        match lang {
-            Lang::English => "Amount of Pages",
-            Lang::Russian => "Кол-во страниц",
-        },
+        lang.tr("change-author"),
+        lang.tr("title"),
+        lang.tr("author"),
+        lang.tr("amount-of-pages"),
     );
 
     inp.show();
@@ -135,16 +87,8 @@ fn change_author(
                 inp.hide();
 
                 if let Ok(book) = inp.set_input(lang) {
-                    if let Ok(index) = check_book(book_system, &book, lang) {
-                        change_author_simple(
-                            index,
-                            book_system,
-                            reader_base,
-                            genres,
-                            caretaker,
-                            app,
-                            lang,
-                        );
+                    if let Ok(index) = check_book(library.book_system(), &book, lang) {
+                        change_author_simple(index, library, app, lang);
                     }
                 }
             }
@@ -167,32 +111,13 @@ fn change_author(
 
 #[allow(dead_code)]
 #[deprecated(note = "Used before, requires input. Consider using action_books() instead")]
-fn change_pages(
-    book_system: &mut BookSystem,
-    reader_base: &mut ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+fn change_pages(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = app::channel();
     let mut inp = Input3::<Input, Input, IntInput>::new(
-        match lang {
-            Lang::English => "Change amount of pages",
-            Lang::Russian => "Изменить Кол-во страниц",
-        },
-        match lang {
-            Lang::English => "Title",
-            Lang::Russian => "Название",
-        },
-        match lang {
-            Lang::English => "Author",
-            Lang::Russian => "Автор",
-        },
-        match lang {
-            Lang::English => "Amount of Pages",
-            Lang::Russian => "Кол-во страниц",
-        },
+        lang.tr("change-amount-of-pages"),
+        lang.tr("title"),
+        lang.tr("author"),
+        lang.tr("amount-of-pages"),
     );
 
     inp.show();
@@ -204,16 +129,8 @@ fn change_pages(
                 inp.hide();
 
                 if let Ok(book) = inp.set_input(lang) {
-                    if let Ok(index) = check_book(book_system, &book, lang) {
-                        change_pages_simple(
-                            index,
-                            book_system,
-                            reader_base,
-                            genres,
-                            caretaker,
-                            app,
-                            lang,
-                        );
+                    if let Ok(index) = check_book(library.book_system(), &book, lang) {
+                        change_pages_simple(index, library, app, lang);
                     }
                 }
             }
@@ -230,26 +147,9 @@ fn change_pages(
 /// program will let you know
 
 #[inline]
-pub fn change_location(
-    t_ind: usize,
-    book_system: &mut BookSystem,
-    reader_base: &mut ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+pub fn change_location(t_ind: usize, library: &mut Library, app: &App, lang: Lang) {
     let (s, r) = app::channel();
-    let mut inp2 = Input1::<IntInput>::new(
-        match lang {
-            Lang::English => "Book's number",
-            Lang::Russian => "Номер книги",
-        },
-        match lang {
-            Lang::English => "Book's number",
-            Lang::Russian => "Номер книги",
-        },
-    );
+    let mut inp2 = Input1::<IntInput>::new(lang.tr("books-number"), lang.tr("books-number"));
 
     inp2.show();
     (*inp2.ok).borrow_mut().emit(s, true);
@@ -261,26 +161,10 @@ pub fn change_location(
 
                 if let Ok(ind) = inp2.set_input(lang) {
                     match ind.first().unwrap().trim().parse::<usize>() {
-                        Err(_) => alert(
-                            500,
-                            500,
-                            match lang {
-                                Lang::English => "Incorrect book's number",
-                                Lang::Russian => "Некорректный номер книги",
-                            },
-                        ),
+                        Err(_) => alert(500, 500, lang.tr("incorrect-books-number")),
 
                         Ok(s_ind) => {
-                            change_location_simple(
-                                t_ind,
-                                s_ind,
-                                book_system,
-                                reader_base,
-                                genres,
-                                caretaker,
-                                app,
-                                lang,
-                            );
+                            change_location_simple(t_ind, s_ind, library, app, lang);
                         }
                     }
                 }
@@ -291,6 +175,41 @@ pub fn change_location(
     }
 }
 
+/// Function that changes type of simple book
+/// (e.g. reference copy, which isn't given).
+/// If you have mistakes in input,
+/// program will let you know
+
+#[inline]
+pub fn change_copy_type(t_ind: usize, library: &mut Library, app: &App, lang: Lang) {
+    let (s, r) = app::channel();
+    let mut inp2 = Input1::<IntInput>::new(lang.tr("books-number"), lang.tr("books-number"));
+
+    inp2.show();
+    (*inp2.ok).borrow_mut().emit(s, true);
+
+    while app.wait() {
+        if let Some(mes) = r.recv() {
+            if mes {
+                inp2.hide();
+
+                if let Ok(ind) = inp2.set_input(lang) {
+                    match ind.first().unwrap().trim().parse::<usize>() {
+                        Ok(s_ind) if s_ind > 0 => {
+                            change_copy_type_simple(t_ind, s_ind, library, lang);
+                        }
+
+                        _ => alert(500, 500, lang.tr("incorrect-books-number")),
+                    }
+                }
+            }
+            break;
+        } else if !inp2.shown() {
+            break;
+        }
+    }
+}
+
 /// **DEPRECATED**
 ///
 /// Used before, requires input.
@@ -303,32 +222,13 @@ pub fn change_location(
 
 #[allow(dead_code)]
 #[deprecated(note = "Used before, requires input. Consider using change_location() instead")]
-fn change_location_input(
-    book_system: &mut BookSystem,
-    reader_base: &mut ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+fn change_location_input(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = app::channel();
     let mut inp = Input3::<Input, Input, IntInput>::new(
-        match lang {
-            Lang::English => "Change book's location",
-            Lang::Russian => "Переместить книгу",
-        },
-        match lang {
-            Lang::English => "Title",
-            Lang::Russian => "Название",
-        },
-        match lang {
-            Lang::English => "Author",
-            Lang::Russian => "Автор",
-        },
-        match lang {
-            Lang::English => "Amount of Pages",
-            Lang::Russian => "Кол-во страниц",
-        },
+        lang.tr("change-books-location"),
+        lang.tr("title"),
+        lang.tr("author"),
+        lang.tr("amount-of-pages"),
     );
 
     inp.show();
@@ -340,17 +240,11 @@ fn change_location_input(
                 inp.hide();
 
                 if let Ok(book) = inp.set_input(lang) {
-                    if let Ok(t_ind) = check_book(book_system, &book, lang) {
+                    if let Ok(t_ind) = check_book(library.book_system(), &book, lang) {
                         let (s, r) = app::channel();
                         let mut inp2 = Input1::<IntInput>::new(
-                            match lang {
-                                Lang::English => "Book's number",
-                                Lang::Russian => "Номер книги",
-                            },
-                            match lang {
-                                Lang::English => "Book's number",
-                                Lang::Russian => "Номер книги",
-                            },
+                            lang.tr("books-number"),
+                            lang.tr("books-number"),
                         );
 
                         inp2.show();
@@ -363,25 +257,13 @@ fn change_location_input(
 
                                     if let Ok(ind) = inp2.set_input(lang) {
                                         match ind.first().unwrap().trim().parse::<usize>() {
-                                            Err(_) => alert(
-                                                500,
-                                                500,
-                                                match lang {
-                                                    Lang::English => "Incorrect book's number",
-                                                    Lang::Russian => "Некорректный номер книги",
-                                                },
-                                            ),
+                                            Err(_) => {
+                                                alert(500, 500, lang.tr("incorrect-books-number"))
+                                            }
 
                                             Ok(s_ind) => {
                                                 change_location_simple(
-                                                    t_ind,
-                                                    s_ind,
-                                                    book_system,
-                                                    reader_base,
-                                                    genres,
-                                                    caretaker,
-                                                    app,
-                                                    lang,
+                                                    t_ind, s_ind, library, app, lang,
                                                 );
                                             }
                                         }
@@ -1,10 +1,9 @@
 extern crate fltk;
 
 use crate::{
-    books::{book_sys::BookSystem, genres::Genres},
+    books::error::BookError,
     change::{input1::Input1, input2::Input2, Inputable},
-    reading::read_base::ReaderBase,
-    restore::caretaker::Caretaker,
+    library::Library,
     Lang,
 };
 
@@ -23,26 +22,12 @@ use fltk::{
 #[inline]
 pub(crate) fn change_title_simple(
     ind: usize,
-    book_system: &mut BookSystem,
-    reader_base: &mut ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     app: &App,
     lang: Lang,
 ) -> Option<String> {
     let (s3, r3) = app::channel();
-    let mut get_title = Input1::<Input>::new(
-        match lang {
-            Lang::English => "New Title",
-            Lang::Russian => "Новое название",
-        },
-        match lang {
-            Lang::English => "New Title",
-            Lang::Russian => "Новое название",
-        },
-    );
-
-    caretaker.add_memento(reader_base, book_system, genres);
+    let mut get_title = Input1::<Input>::new(lang.tr("new-title"), lang.tr("new-title"));
 
     get_title.show();
     (*get_title.ok).borrow_mut().emit(s3, true);
@@ -55,48 +40,22 @@ pub(crate) fn change_title_simple(
                 if let Ok(mut new_title) = get_title.set_input(lang) {
                     unsafe {
                         return if new_title.get_unchecked(0).is_empty() {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "'New title' is empty",
-                                    Lang::Russian => "'Новое название' пусто",
-                                },
-                            );
-                            caretaker.pop().unwrap();
+                            alert(500, 500, lang.tr("new-title-is-empty"));
                             None
                         } else {
-                            match book_system.change_title(ind, new_title.first().unwrap().clone())
-                            {
+                            match library.change_title(ind, new_title.first().unwrap().clone()) {
                                 Ok(_) => {
                                     fltk::dialog::message(
                                         500,
                                         500,
-                                        match lang {
-                                            Lang::English => "Successfully changed",
-                                            Lang::Russian => "Успешно изменено",
-                                        },
+                                        lang.tr("successfully-changed"),
                                     );
 
-                                    book_system.save();
-                                    reader_base.save();
                                     Some(new_title.pop().unwrap())
                                 }
 
-                                Err(_) => {
-                                    alert(
-                                        500,
-                                        500,
-                                        match lang {
-                                            Lang::English => {
-                                                "Book with same parameters already exists"
-                                            }
-                                            Lang::Russian => {
-                                                "Книга с предложенными параметрами уже сущетвует"
-                                            }
-                                        },
-                                    );
-                                    caretaker.pop().unwrap();
+                                Err(err) => {
+                                    alert(500, 500, err.message(lang));
                                     None
                                 }
                             }
@@ -106,7 +65,6 @@ pub(crate) fn change_title_simple(
             }
             break;
         } else if !get_title.shown() {
-            caretaker.pop().unwrap();
             return None;
         }
     }
@@ -121,26 +79,12 @@ pub(crate) fn change_title_simple(
 #[inline]
 pub(crate) fn change_author_simple(
     ind: usize,
-    book_system: &mut BookSystem,
-    reader_base: &mut ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     app: &App,
     lang: Lang,
 ) -> Option<String> {
     let (s3, r3) = app::channel();
-    let mut get_author = Input1::<Input>::new(
-        match lang {
-            Lang::English => "New Author",
-            Lang::Russian => "Новый автор",
-        },
-        match lang {
-            Lang::English => "New Author",
-            Lang::Russian => "Новый автор",
-        },
-    );
-
-    caretaker.add_memento(reader_base, book_system, genres);
+    let mut get_author = Input1::<Input>::new(lang.tr("new-author"), lang.tr("new-author"));
 
     get_author.show();
     (*get_author.ok).borrow_mut().emit(s3, true);
@@ -153,49 +97,22 @@ pub(crate) fn change_author_simple(
                 if let Ok(mut new_author) = get_author.set_input(lang) {
                     unsafe {
                         return if new_author.get_unchecked(0).is_empty() {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "'New author' is empty",
-                                    Lang::Russian => "'Новый автор' пусто",
-                                },
-                            );
-                            caretaker.pop().unwrap();
+                            alert(500, 500, lang.tr("new-author-is-empty"));
                             None
                         } else {
-                            match book_system
-                                .change_author(ind, new_author.first().unwrap().clone())
-                            {
+                            match library.change_author(ind, new_author.first().unwrap().clone()) {
                                 Ok(_) => {
                                     fltk::dialog::message(
                                         500,
                                         500,
-                                        match lang {
-                                            Lang::English => "Successfully changed",
-                                            Lang::Russian => "Успешно изменено",
-                                        },
+                                        lang.tr("successfully-changed"),
                                     );
 
-                                    book_system.save();
-                                    reader_base.save();
                                     Some(new_author.pop().unwrap())
                                 }
 
-                                Err(_) => {
-                                    alert(
-                                        500,
-                                        500,
-                                        match lang {
-                                            Lang::English => {
-                                                "Book with same parameters already exists"
-                                            }
-                                            Lang::Russian => {
-                                                "Книга с предложенными параметрами уже существует"
-                                            }
-                                        },
-                                    );
-                                    caretaker.pop().unwrap();
+                                Err(err) => {
+                                    alert(500, 500, err.message(lang));
                                     None
                                 }
                             }
@@ -205,7 +122,6 @@ pub(crate) fn change_author_simple(
             }
             break;
         } else if !get_author.shown() {
-            caretaker.pop().unwrap();
             return None;
         }
     }
@@ -220,27 +136,16 @@ pub(crate) fn change_author_simple(
 #[inline]
 pub(crate) fn change_pages_simple(
     ind: usize,
-    book_system: &mut BookSystem,
-    reader_base: &mut ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     app: &App,
     lang: Lang,
 ) -> Option<String> {
     let (s3, r3) = app::channel();
     let mut get_pages = Input1::<IntInput>::new(
-        match lang {
-            Lang::English => "New Amount of Pages",
-            Lang::Russian => "Новое Кол-во страниц",
-        },
-        match lang {
-            Lang::English => "New Amount of Pages",
-            Lang::Russian => "Новое Кол-во Страниц",
-        },
+        lang.tr("new-amount-of-pages"),
+        lang.tr("new-amount-of-pages-2"),
     );
 
-    caretaker.add_memento(reader_base, book_system, genres);
-
     get_pages.show();
     (*get_pages.ok).borrow_mut().emit(s3, true);
 
@@ -252,63 +157,29 @@ pub(crate) fn change_pages_simple(
                 if let Ok(mut new_pages) = get_pages.set_input(lang) {
                     unsafe {
                         return if new_pages.get_unchecked(0).is_empty() {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "'New amount of pages' is empty",
-                                    Lang::Russian => "'Новое Кол-во страниц' пусто",
-                                },
-                            );
-                            caretaker.pop().unwrap();
+                            alert(500, 500, lang.tr("new-amount-of-pages-is-empty"));
                             None
                         } else {
-                            match book_system.change_pages(ind, new_pages.first().unwrap().clone())
-                            {
-                                Ok(_) => {
-                                    fltk::dialog::message(
-                                        500,
-                                        500,
-                                        match lang {
-                                            Lang::English => "Successfully changed",
-                                            Lang::Russian => "Успешно изменено",
-                                        },
-                                    );
-
-                                    book_system.save();
-                                    reader_base.save();
-                                    Some(new_pages.pop().unwrap())
-                                }
-
-                                Err(0) => {
-                                    alert(
-                                        500,
-                                        500,
-                                        match lang {
-                                            Lang::English => "'New amount of pages' input error",
-                                            Lang::Russian => {
-                                                "Некорректный ввод для 'Нового количества странциц'"
-                                            }
-                                        },
-                                    );
-                                    caretaker.pop().unwrap();
-                                    None
-                                }
+                            match new_pages.first().unwrap().trim().parse::<u16>() {
+                                Ok(pages) => match library.change_pages(ind, pages) {
+                                    Ok(_) => {
+                                        fltk::dialog::message(
+                                            500,
+                                            500,
+                                            lang.tr("successfully-changed"),
+                                        );
+
+                                        Some(new_pages.pop().unwrap())
+                                    }
+
+                                    Err(err) => {
+                                        alert(500, 500, err.message(lang));
+                                        None
+                                    }
+                                },
 
                                 Err(_) => {
-                                    alert(
-                                        500,
-                                        500,
-                                        match lang {
-                                            Lang::English => {
-                                                "Book with same parameters already exists"
-                                            }
-                                            Lang::Russian => {
-                                                "Книга с предложенными параметрами уже существует"
-                                            }
-                                        },
-                                    );
-                                    caretaker.pop().unwrap();
+                                    alert(500, 500, lang.tr("new-amount-of-pages-input-error"));
                                     None
                                 }
                             }
@@ -317,7 +188,6 @@ pub(crate) fn change_pages_simple(
                 }
             }
         } else if !get_pages.shown() {
-            caretaker.pop();
             return None;
         }
     }
@@ -334,31 +204,17 @@ pub(crate) fn change_pages_simple(
 pub(crate) fn change_location_simple(
     t_ind: usize,
     s_ind: usize,
-    book_system: &mut BookSystem,
-    reader_base: &ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     app: &App,
     lang: Lang,
 ) -> Option<(u16, u8)> {
     let (s3, r3) = app::channel();
     let mut get_loc = Input2::<IntInput, IntInput>::new(
-        match lang {
-            Lang::English => "New Location",
-            Lang::Russian => "Перемещение Книги",
-        },
-        match lang {
-            Lang::English => "New Cabinet",
-            Lang::Russian => "Новый Шкаф",
-        },
-        match lang {
-            Lang::English => "New Shelf",
-            Lang::Russian => "Новая Полка",
-        },
+        lang.tr("new-location"),
+        lang.tr("new-cabinet"),
+        lang.tr("new-shelf"),
     );
 
-    caretaker.add_memento(reader_base, book_system, genres);
-
     get_loc.show();
     (*get_loc.ok).borrow_mut().emit(s3, true);
 
@@ -367,101 +223,46 @@ pub(crate) fn change_location_simple(
             if msg {
                 get_loc.hide();
 
-                if let Ok(mut new_loc) = get_loc.set_input(lang) {
+                if let Ok(new_loc) = get_loc.set_input(lang) {
                     unsafe {
                         return if new_loc.get_unchecked(0).is_empty() {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "'New cabinet' is empty",
-                                    Lang::Russian => "'Новый шкаф' пусто",
-                                },
-                            );
-                            caretaker.pop().unwrap();
+                            alert(500, 500, lang.tr("new-cabinet-is-empty"));
                             None
                         } else if new_loc.get_unchecked(1).is_empty() {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "'New shelf' is empty",
-                                    Lang::Russian => "'Новая полка' пусто",
-                                },
-                            );
-                            caretaker.pop().unwrap();
+                            alert(500, 500, lang.tr("new-shelf-is-empty"));
                             None
                         } else {
-                            match book_system.change_location(
-                                t_ind,
-                                s_ind,
-                                new_loc.swap_remove(0),
-                                new_loc.pop().unwrap(),
+                            match (
+                                new_loc.get_unchecked(0).trim().parse::<u16>(),
+                                new_loc.get_unchecked(1).trim().parse::<u8>(),
                             ) {
-                                Ok(_) => {
-                                    fltk::dialog::message(
-                                        500,
-                                        500,
-                                        match lang {
-                                            Lang::English => "Successfully changed",
-                                            Lang::Russian => "Успешно изменено",
-                                        },
-                                    );
-
-                                    book_system.save();
-
-                                    Some((
-                                        (**(**book_system.books.get_unchecked(t_ind))
-                                            .borrow()
-                                            .books
-                                            .get_unchecked(s_ind - 1))
-                                        .borrow()
-                                        .cabinet,
-                                        (**(**book_system.books.get_unchecked(t_ind))
-                                            .borrow()
-                                            .books
-                                            .get_unchecked(s_ind - 1))
-                                        .borrow()
-                                        .shelf,
-                                    ))
-                                }
-
-                                Err(0) => {
-                                    alert(
-                                        500,
-                                        500,
-                                        match lang {
-                                            Lang::English => "'New cabinet' input error",
-                                            Lang::Russian => "Ошибка ввода 'Нового шкафа'",
-                                        },
-                                    );
-                                    caretaker.pop().unwrap();
-                                    None
+                                (Ok(cabinet), Ok(shelf)) => {
+                                    match library.change_location(t_ind, s_ind - 1, cabinet, shelf)
+                                    {
+                                        Ok(_) => {
+                                            fltk::dialog::message(
+                                                500,
+                                                500,
+                                                lang.tr("successfully-changed"),
+                                            );
+
+                                            Some((cabinet, shelf))
+                                        }
+
+                                        Err(err) => {
+                                            alert(500, 500, err.message(lang));
+                                            None
+                                        }
+                                    }
                                 }
 
-                                Err(1) => {
-                                    alert(
-                                        500,
-                                        500,
-                                        match lang {
-                                            Lang::English => "'New shelf' input error",
-                                            Lang::Russian => "Ошибка ввода 'Новой полки'",
-                                        },
-                                    );
-                                    caretaker.pop().unwrap();
+                                (Err(_), _) => {
+                                    alert(500, 500, lang.tr("new-cabinet-input-error"));
                                     None
                                 }
 
-                                Err(_) => {
-                                    alert(
-                                        500,
-                                        500,
-                                        match lang {
-                                            Lang::English => "Book's number is incorrect",
-                                            Lang::Russian => "Номер книги не корректен",
-                                        },
-                                    );
-                                    caretaker.pop().unwrap();
+                                (_, Err(_)) => {
+                                    alert(500, 500, lang.tr("new-shelf-input-error"));
                                     None
                                 }
                             }
@@ -471,9 +272,49 @@ pub(crate) fn change_location_simple(
             }
             break;
         } else if !get_loc.shown() {
-            caretaker.pop().unwrap();
             return None;
         }
     }
     None
 }
+
+/// Changing type of already known simple book
+/// (number of book starts from 1).
+/// Empty type makes it ordinary copy
+
+#[inline]
+pub(crate) fn change_copy_type_simple(
+    t_ind: usize,
+    s_ind: usize,
+    library: &mut Library,
+    lang: Lang,
+) -> Option<String> {
+    let current = match library
+        .book_system()
+        .books
+        .get(t_ind)
+        .and_then(|the_book| (**the_book).borrow().books.get(s_ind - 1).cloned())
+    {
+        Some(book) => (*book).borrow().copy_type.clone(),
+
+        None => {
+            alert(500, 500, BookError::SimpleBookNotFound.message(lang));
+            return None;
+        }
+    };
+
+    let new_copy_type = fltk::dialog::input(500, 500, lang.tr("new-copy-type"), current.as_str())?;
+
+    match library.change_copy_type(t_ind, s_ind - 1, new_copy_type.clone()) {
+        Ok(_) => {
+            fltk::dialog::message(500, 500, lang.tr("successfully-changed"));
+
+            Some(new_copy_type.trim().to_string())
+        }
+
+        Err(err) => {
+            alert(500, 500, err.message(lang));
+            None
+        }
+    }
+}
@@ -5,10 +5,9 @@ use crate::{
         book::{info::simple::*, utils::check_book},
         tables::{cell_book, draw_data, draw_header},
     },
-    books::{book_sys::BookSystem, genres::Genres},
+    books::book_sys::BookSystem,
     change::{input3::Input3, Inputable},
-    reading::read_base::ReaderBase,
-    restore::caretaker::Caretaker,
+    library::Library,
     Lang,
 };
 
@@ -16,12 +15,12 @@ use fltk::{
     app,
     app::App,
     draw,
+    enums::Font,
     input::{Input, IntInput},
     prelude::*,
     table::Table,
     tree::Tree,
     window::SingleWindow,
-    enums::Font
 };
 
 use std::{cell::RefCell, cmp::max, collections::BTreeMap, rc::Rc};
@@ -38,33 +37,13 @@ use std::{cell::RefCell, cmp::max, collections::BTreeMap, rc::Rc};
 
 #[allow(dead_code)]
 #[deprecated(note = "Used before, requires input. Consider using action_books() instead")]
-fn the_book_info(
-    book_system: Rc<RefCell<BookSystem>>,
-    reader_base: Rc<RefCell<ReaderBase>>,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-    main_table: &mut Table,
-) {
+fn the_book_info(library: Rc<RefCell<Library>>, app: &App, lang: Lang, main_table: &mut Table) {
     let (s2, r2) = app::channel();
     let mut inp = Input3::<Input, Input, IntInput>::new(
-        match lang {
-            Lang::English => "Find book",
-            Lang::Russian => "Найти книгу",
-        },
-        match lang {
-            Lang::English => "Title",
-            Lang::Russian => "Название",
-        },
-        match lang {
-            Lang::English => "Author",
-            Lang::Russian => "Автор",
-        },
-        match lang {
-            Lang::English => "Amount of Pages",
-            Lang::Russian => "Кол-во страниц",
-        },
+        lang.tr("find-book"),
+        lang.tr("title"),
+        lang.tr("author"),
+        lang.tr("amount-of-pages"),
     );
 
     inp.show();
@@ -77,19 +56,10 @@ fn the_book_info(
 
                 if let Ok(the_book) = inp.set_input(lang) {
                     if let Ok(index) = {
-                        let check = check_book(&(*book_system).borrow(), &the_book, lang);
+                        let check = check_book(&(*library).borrow().book_system(), &the_book, lang);
                         check
                     } {
-                        the_book_info_simple(
-                            index,
-                            book_system.clone(),
-                            &mut *(*reader_base).borrow_mut(),
-                            genres,
-                            caretaker,
-                            app,
-                            lang,
-                            main_table,
-                        )
+                        the_book_info_simple(index, library.clone(), app, lang, main_table)
                     }
                 }
             }
@@ -112,32 +82,13 @@ fn the_book_info(
 
 #[allow(dead_code)]
 #[deprecated(note = "Used before, requires input. Consider using action_books() instead")]
-fn book_info(
-    book_system: Rc<RefCell<BookSystem>>,
-    reader_base: &ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+fn book_info(library: Rc<RefCell<Library>>, app: &App, lang: Lang) {
     let (s2, r2) = app::channel();
     let mut inp = Input3::<Input, Input, IntInput>::new(
-        match lang {
-            Lang::English => "Find book",
-            Lang::Russian => "Найти книгу",
-        },
-        match lang {
-            Lang::English => "Title",
-            Lang::Russian => "Название",
-        },
-        match lang {
-            Lang::English => "Author",
-            Lang::Russian => "Автор",
-        },
-        match lang {
-            Lang::English => "Amount of Pages",
-            Lang::Russian => "Кол-во страниц",
-        },
+        lang.tr("find-book"),
+        lang.tr("title"),
+        lang.tr("author"),
+        lang.tr("amount-of-pages"),
     );
 
     inp.show();
@@ -149,16 +100,10 @@ fn book_info(
                 inp.hide();
 
                 if let Ok(the_book) = inp.set_input(lang) {
-                    if let Ok(index) = check_book(&*(*book_system).borrow(), &the_book, lang) {
-                        book_info_simple2(
-                            index,
-                            book_system.clone(),
-                            reader_base,
-                            genres,
-                            caretaker,
-                            app,
-                            lang,
-                        )
+                    if let Ok(index) =
+                        check_book((*library).borrow().book_system(), &the_book, lang)
+                    {
+                        book_info_simple2(index, library.clone(), app, lang)
                     }
                 }
             }
@@ -176,31 +121,28 @@ fn book_info(
 
 #[inline]
 pub fn show_all_books(
-    book_system: Rc<RefCell<BookSystem>>,
-    reader_base: &mut ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: Rc<RefCell<Library>>,
     app: &App,
     lang: Lang,
     main_table: &mut Table,
 ) {
     let mut wind = SingleWindow::default()
-        .with_label(match lang {
-            Lang::English => "All Books",
-            Lang::Russian => "Все книги",
-        })
+        .with_label(lang.tr("all-books"))
         .with_size(820, 550)
         .center_screen();
 
     let mut table = Table::new(10, 10, 800, 540, "");
-    table.set_rows(max(20, (*book_system).borrow().books.len() as i32));
+    table.set_rows(max(
+        20,
+        (*library).borrow().book_system().books.len() as i32,
+    ));
     table.set_row_header(true);
     table.set_cols(4);
     table.set_col_header(true);
     table.set_col_width_all(190);
     table.end();
 
-    let bs = book_system.clone();
+    let lib = library.clone();
 
     table.draw_cell(move |t, ctx, row, col, x, y, w, h| match ctx {
         fltk::table::TableContext::StartPage => draw::set_font(Font::Helvetica, 14),
@@ -209,25 +151,13 @@ pub fn show_all_books(
             &format!(
                 "{}",
                 match col {
-                    0 => match lang {
-                        Lang::English => "Title",
-                        Lang::Russian => "Название",
-                    },
-
-                    1 => match lang {
-                        Lang::English => "Author",
-                        Lang::Russian => "Автор",
-                    },
-
-                    2 => match lang {
-                        Lang::English => "Amount of Pages",
-                        Lang::Russian => "Кол-во страниц",
-                    },
-
-                    _ => match lang {
-                        Lang::English => "Amount of available books",
-                        Lang::Russian => "Кол-во доступных книг",
-                    },
+                    0 => lang.tr("title"),
+
+                    1 => lang.tr("author"),
+
+                    2 => lang.tr("amount-of-pages"),
+
+                    _ => lang.tr("amount-of-available-books"),
                 }
             ),
             x,
@@ -239,7 +169,7 @@ pub fn show_all_books(
         fltk::table::TableContext::RowHeader => draw_header(&format!("{}", row + 1), x, y, w, h),
 
         fltk::table::TableContext::Cell => draw_data(
-            &format!("{}", cell_book(col, row, &*(*bs).borrow())),
+            &format!("{}", cell_book(col, row, (*lib).borrow().book_system())),
             x,
             y,
             w,
@@ -255,7 +185,7 @@ pub fn show_all_books(
     wind.show();
 
     while app.wait() {
-        let len = (*book_system).borrow().books.len();
+        let len = (*library).borrow().book_system().books.len();
 
         (0..len).for_each(|i| {
             if table.is_selected(i as i32, 0)
@@ -263,16 +193,7 @@ pub fn show_all_books(
                 || table.is_selected(i as i32, 2)
                 || table.is_selected(i as i32, 3)
             {
-                the_book_info_simple(
-                    i,
-                    book_system.clone(),
-                    reader_base,
-                    genres,
-                    caretaker,
-                    app,
-                    lang,
-                    main_table,
-                );
+                the_book_info_simple(i, library.clone(), app, lang, main_table);
 
                 table.unset_selection();
                 return;
@@ -291,22 +212,10 @@ pub fn show_all_books(
 
 #[inline]
 pub fn show_all_authors(book_system: &BookSystem, lang: Lang) {
-    let mut wind = SingleWindow::new(
-        500,
-        500,
-        300,
-        400,
-        match lang {
-            Lang::English => "All Books by Authors",
-            Lang::Russian => "Все Книги по Авторам",
-        },
-    );
+    let mut wind = SingleWindow::new(500, 500, 300, 400, lang.tr("all-books-by-authors"));
 
     let mut tree = Tree::new(0, 0, 300, 400, "");
-    tree.set_root_label(match lang {
-        Lang::English => "Authors",
-        Lang::Russian => "Авторы",
-    });
+    tree.set_root_label(lang.tr("authors"));
 
     let mut authors = BTreeMap::new();
 
@@ -318,10 +227,7 @@ pub fn show_all_authors(book_system: &BookSystem, lang: Lang) {
                 "{}, {} {}",
                 (**b).borrow().title,
                 (**b).borrow().pages,
-                match lang {
-                    Lang::English => "pages",
-                    Lang::Russian => "страниц",
-                }
+                lang.tr("pages")
             ));
     });
 
@@ -3,14 +3,14 @@ extern crate fltk;
 use crate::{
     actions::{
         book::{add_rem::simple::*, change::simple::*},
+        fit_menu,
         genres::full::customize_book_genre,
         read::utils::get_book_ind,
         tables::*,
     },
-    books::{book::Book, book_sys::BookSystem, genres::Genres},
+    books::book::Book,
     change::{input1::Input1, Inputable},
-    reading::read_base::ReaderBase,
-    restore::caretaker::Caretaker,
+    library::Library,
     Lang,
 };
 
@@ -57,16 +57,14 @@ enum MessageTheBook {
 
 pub fn book_info_simple(
     book: &Weak<RefCell<Book>>,
-    book_system: Rc<RefCell<BookSystem>>,
-    reader_base: &ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: Rc<RefCell<Library>>,
     app: &App,
     lang: Lang,
 ) {
     let (t_ind, s_ind) = {
-        let t_ind = (*book_system)
+        let t_ind = (*library)
             .borrow()
+            .book_system()
             .find_book(
                 &(*book.upgrade().unwrap()).borrow().title(),
                 &(*book.upgrade().unwrap()).borrow().author(),
@@ -75,8 +73,9 @@ pub fn book_info_simple(
             .unwrap();
 
         (t_ind, unsafe {
-            (*book_system)
+            (*library)
                 .borrow()
+                .book_system()
                 .books
                 .get_unchecked(t_ind)
                 .borrow()
@@ -90,17 +89,17 @@ pub fn book_info_simple(
     let label = format!(
         "{} {} {}",
         *unsafe {
-            &(**(*book_system).borrow().books.get_unchecked(t_ind))
+            &(**(*library).borrow().book_system().books.get_unchecked(t_ind))
                 .borrow()
                 .title
         },
         *unsafe {
-            &(**(*book_system).borrow().books.get_unchecked(t_ind))
+            &(**(*library).borrow().book_system().books.get_unchecked(t_ind))
                 .borrow()
                 .author
         },
         *unsafe {
-            &(**(*book_system).borrow().books.get_unchecked(t_ind))
+            &(**(*library).borrow().book_system().books.get_unchecked(t_ind))
                 .borrow()
                 .pages
         },
@@ -113,59 +112,35 @@ pub fn book_info_simple(
     let mut table1 = VGrid::new(0, 0, 908, 170, "");
     table1.set_params(6, 1, 1);
 
-    let label = format!(
-        "{}: {}",
-        match lang {
-            Lang::English => "Title",
-            Lang::Russian => "Название",
-        },
-        *unsafe {
-            &(**(*book_system).borrow().books.get_unchecked(t_ind))
-                .borrow()
-                .title
-        }
-    );
+    let label = format!("{}: {}", lang.tr("title"), *unsafe {
+        &(**(*library).borrow().book_system().books.get_unchecked(t_ind))
+            .borrow()
+            .title
+    });
 
     table1.add(&Frame::new(10, 50, 100, 30, None).with_label(label.as_str()));
 
-    let label = format!(
-        "{}: {}",
-        match lang {
-            Lang::English => "Author",
-            Lang::Russian => "Автор",
-        },
-        *unsafe {
-            &(**(*book_system).borrow().books.get_unchecked(t_ind))
-                .borrow()
-                .author
-        }
-    );
+    let label = format!("{}: {}", lang.tr("author"), *unsafe {
+        &(**(*library).borrow().book_system().books.get_unchecked(t_ind))
+            .borrow()
+            .author
+    });
 
     table1.add(&Frame::new(30, 50, 100, 30, None).with_label(label.as_str()));
 
-    let label = format!(
-        "{}: {}",
-        match lang {
-            Lang::English => "Amount of Pages",
-            Lang::Russian => "Кол-во страниц",
-        },
-        *unsafe {
-            &(**(*book_system).borrow().books.get_unchecked(t_ind))
-                .borrow()
-                .pages
-        },
-    );
+    let label = format!("{}: {}", lang.tr("amount-of-pages"), *unsafe {
+        &(**(*library).borrow().book_system().books.get_unchecked(t_ind))
+            .borrow()
+            .pages
+    },);
 
     table1.add(&Frame::new(50, 50, 100, 30, None).with_label(label.as_str()));
 
     let label = format!(
         "{}: {}",
-        match lang {
-            Lang::English => "Order Number",
-            Lang::Russian => "Порядковый номер",
-        },
-        get_book_ind(&*(*book_system).borrow(), unsafe {
-            (**(*book_system).borrow().books.get_unchecked(t_ind))
+        lang.tr("order-number"),
+        get_book_ind(&*(*library).borrow().book_system(), unsafe {
+            (**(*library).borrow().book_system().books.get_unchecked(t_ind))
                 .borrow()
                 .books
                 .get_unchecked(s_ind)
@@ -177,12 +152,9 @@ pub fn book_info_simple(
 
     let label = format!(
         "{}: {}",
-        match lang {
-            Lang::English => "Now is Read By",
-            Lang::Russian => "В данный момент читается",
-        },
+        lang.tr("now-is-read-by"),
         if unsafe {
-            (**(**(*book_system).borrow().books.get_unchecked(t_ind))
+            (**(**(*library).borrow().book_system().books.get_unchecked(t_ind))
                 .borrow()
                 .books
                 .get_unchecked(s_ind))
@@ -190,7 +162,7 @@ pub fn book_info_simple(
             .is_using
         } {
             unsafe {
-                (**(**(*book_system).borrow().books.get_unchecked(t_ind))
+                (**(**(*library).borrow().book_system().books.get_unchecked(t_ind))
                     .borrow()
                     .books
                     .get_unchecked(s_ind))
@@ -205,7 +177,7 @@ pub fn book_info_simple(
                 .name
                 .clone()
                     + " "
-                    + (*(**(**(*book_system).borrow().books.get_unchecked(t_ind))
+                    + (*(**(**(*library).borrow().book_system().books.get_unchecked(t_ind))
                         .borrow()
                         .books
                         .get_unchecked(s_ind))
@@ -220,7 +192,7 @@ pub fn book_info_simple(
                     .family
                     .as_str()
                     + " "
-                    + (*(**(**(*book_system).borrow().books.get_unchecked(t_ind))
+                    + (*(**(**(*library).borrow().book_system().books.get_unchecked(t_ind))
                         .borrow()
                         .books
                         .get_unchecked(s_ind))
@@ -235,7 +207,7 @@ pub fn book_info_simple(
                     .father
                     .as_str()
                     + " "
-                    + (*(**(**(*book_system).borrow().books.get_unchecked(t_ind))
+                    + (*(**(**(*library).borrow().book_system().books.get_unchecked(t_ind))
                         .borrow()
                         .books
                         .get_unchecked(s_ind))
@@ -252,62 +224,38 @@ pub fn book_info_simple(
                     .as_str()
             }
         } else {
-            match lang {
-                Lang::English => "None",
-                Lang::Russian => "Никем",
-            }
-            .to_string()
+            lang.tr("none").to_string()
         }
     );
 
     table1.add(&Frame::new(90, 50, 100, 30, None).with_label(label.as_str()));
 
-    let label = format!(
-        "{}: {}",
-        match lang {
-            Lang::English => "Cabinet",
-            Lang::Russian => "Шкаф",
-        },
-        unsafe {
-            (**(**(*book_system).borrow().books.get_unchecked(t_ind))
-                .borrow()
-                .books
-                .get_unchecked(s_ind))
+    let label = format!("{}: {}", lang.tr("cabinet"), unsafe {
+        (**(**(*library).borrow().book_system().books.get_unchecked(t_ind))
             .borrow()
-            .cabinet
-        },
-    );
+            .books
+            .get_unchecked(s_ind))
+        .borrow()
+        .cabinet
+    },);
 
     let mut cab_frame = Frame::new(110, 50, 100, 30, None).with_label(label.as_str());
 
-    let label = format!(
-        "{}: {}",
-        match lang {
-            Lang::English => "Shelf",
-            Lang::Russian => "Полка",
-        },
-        unsafe {
-            (**(**(*book_system).borrow().books.get_unchecked(t_ind))
-                .borrow()
-                .books
-                .get_unchecked(s_ind))
+    let label = format!("{}: {}", lang.tr("shelf"), unsafe {
+        (**(**(*library).borrow().book_system().books.get_unchecked(t_ind))
             .borrow()
-            .shelf
-        }
-    );
+            .books
+            .get_unchecked(s_ind))
+        .borrow()
+        .shelf
+    });
 
     let mut shelf_frame = Frame::new(130, 50, 100, 30, None).with_label(label.as_str());
 
     table1.add(&cab_frame);
     table1.add(&shelf_frame);
 
-    let label = format!(
-        "{}:",
-        match lang {
-            Lang::English => "All Readers",
-            Lang::Russian => "Все читатели",
-        }
-    );
+    let label = format!("{}:", lang.tr("all-readers"));
 
     table1.add(&Frame::new(150, 50, 100, 30, None).with_label(label.as_str()));
 
@@ -316,7 +264,7 @@ pub fn book_info_simple(
     let mut table2 = Table::new(0, 127, 848, 600, None);
 
     table2.set_rows(max(30, unsafe {
-        (**(**(*book_system).borrow().books.get_unchecked(t_ind))
+        (**(**(*library).borrow().book_system().books.get_unchecked(t_ind))
             .borrow()
             .books
             .get_unchecked(s_ind))
@@ -334,7 +282,7 @@ pub fn book_info_simple(
     wind.end();
     wind.show();
 
-    let bs = book_system.clone();
+    let lib = library.clone();
 
     table2.draw_cell(move |t, ctx, row, col, x, y, w, h| match ctx {
         table::TableContext::StartPage => draw::set_font(Font::Helvetica, 14),
@@ -343,35 +291,17 @@ pub fn book_info_simple(
             &format!(
                 "{}",
                 match col {
-                    0 => match lang {
-                        Lang::English => "1-st Name",
-                        Lang::Russian => "Имя",
-                    },
-
-                    1 => match lang {
-                        Lang::English => "2-nd Name",
-                        Lang::Russian => "Фамилия",
-                    },
-
-                    2 => match lang {
-                        Lang::English => "Middle Name",
-                        Lang::Russian => "Отчество",
-                    },
-
-                    3 => match lang {
-                        Lang::English => "Age",
-                        Lang::Russian => "Возраст",
-                    },
-
-                    4 => match lang {
-                        Lang::English => "Start Date",
-                        Lang::Russian => "Дата начала",
-                    },
-
-                    _ => match lang {
-                        Lang::English => "Finish Date",
-                        Lang::Russian => "Срок Сдачи",
-                    },
+                    0 => lang.tr("1-st-name"),
+
+                    1 => lang.tr("2-nd-name"),
+
+                    2 => lang.tr("middle-name"),
+
+                    3 => lang.tr("age"),
+
+                    4 => lang.tr("start-date"),
+
+                    _ => lang.tr("finish-date"),
                 }
             ),
             x,
@@ -389,12 +319,13 @@ pub fn book_info_simple(
                     col,
                     row,
                     Rc::downgrade(&unsafe {
-                        (*(**(*bs).borrow().books.get_unchecked(t_ind))
+                        (*(**(*lib).borrow().book_system().books.get_unchecked(t_ind))
                             .borrow()
                             .books
                             .get_unchecked(s_ind))
                         .clone()
                     }),
+                    lang,
                 )
             ),
             x,
@@ -408,32 +339,21 @@ pub fn book_info_simple(
         _ => (),
     });
 
-    let mut menu = MenuBar::new(
-        0,
-        0,
-        130 + match lang {
-            Lang::English => 0,
-            Lang::Russian => 50,
-        },
-        30,
-        "",
-    );
+    let mut menu = MenuBar::new(0, 0, 130, 30, "");
 
     wind.add(&menu);
 
     let (s, r) = app::channel();
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Change location\t",
-            Lang::Russian => "&Изменить расположение\t",
-        },
+        lang.tr("change-location"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
         true,
     );
 
+    fit_menu(&mut menu);
     wind.show();
 
     while app.wait() {
@@ -442,36 +362,13 @@ pub fn book_info_simple(
                 if let Some((cab, shelf)) = change_location_simple(
                     t_ind,
                     s_ind + 1,
-                    &mut *(*book_system).borrow_mut(),
-                    reader_base,
-                    genres,
-                    caretaker,
+                    &mut *(*library).borrow_mut(),
                     app,
                     lang,
                 ) {
-                    cab_frame.set_label(
-                        format!(
-                            "{}: {}",
-                            match lang {
-                                Lang::English => "Cabinet",
-                                Lang::Russian => "Шкаф",
-                            },
-                            cab
-                        )
-                        .as_str(),
-                    );
+                    cab_frame.set_label(format!("{}: {}", lang.tr("cabinet"), cab).as_str());
 
-                    shelf_frame.set_label(
-                        format!(
-                            "{}: {}",
-                            match lang {
-                                Lang::English => "Shelf",
-                                Lang::Russian => "Полка",
-                            },
-                            shelf
-                        )
-                        .as_str(),
-                    );
+                    shelf_frame.set_label(format!("{}: {}", lang.tr("shelf"), shelf).as_str());
 
                     cab_frame.redraw();
                     shelf_frame.redraw();
@@ -489,24 +386,12 @@ pub fn book_info_simple(
 #[inline]
 pub(crate) fn book_info_simple2(
     index: usize,
-    book_system: Rc<RefCell<BookSystem>>,
-    reader_base: &ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: Rc<RefCell<Library>>,
     app: &App,
     lang: Lang,
 ) {
     let (s, r) = app::channel();
-    let mut inp2 = Input1::<IntInput>::new(
-        match lang {
-            Lang::English => "Number of Book",
-            Lang::Russian => "Номер книги",
-        },
-        match lang {
-            Lang::English => "Number of Book",
-            Lang::Russian => "Номер книги",
-        },
-    );
+    let mut inp2 = Input1::<IntInput>::new(lang.tr("number-of-book"), lang.tr("number-of-book"));
 
     inp2.show();
     (*inp2.ok).borrow_mut().emit(s, true);
@@ -521,35 +406,25 @@ pub(crate) fn book_info_simple2(
 
                     if bind
                         > unsafe {
-                            (**(*book_system).borrow().books.get_unchecked(index))
+                            (**(*library).borrow().book_system().books.get_unchecked(index))
                                 .borrow()
                                 .books
                                 .len()
                         }
                         || bind == 0
                     {
-                        alert(
-                            500,
-                            500,
-                            match lang {
-                                Lang::English => "Incorrect number of book",
-                                Lang::Russian => "Некорректный номер книги",
-                            },
-                        );
+                        alert(500, 500, lang.tr("incorrect-number-of-book"));
                         return;
                     }
 
                     book_info_simple(
                         &Rc::downgrade(unsafe {
-                            (**(*book_system).borrow().books.get_unchecked(index))
+                            (**(*library).borrow().book_system().books.get_unchecked(index))
                                 .borrow()
                                 .books
                                 .get_unchecked(bind - 1)
                         }),
-                        book_system.clone(),
-                        reader_base,
-                        genres,
-                        caretaker,
+                        library.clone(),
                         app,
                         lang,
                     );
@@ -567,10 +442,7 @@ pub(crate) fn book_info_simple2(
 
 pub fn the_book_info_simple(
     ind: usize,
-    book_system: Rc<RefCell<BookSystem>>,
-    reader_base: &mut ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: Rc<RefCell<Library>>,
     app: &App,
     lang: Lang,
     main_table: &mut Table,
@@ -578,12 +450,12 @@ pub fn the_book_info_simple(
     let label = format!(
         "{} {}",
         *unsafe {
-            &(**(*book_system).borrow().books.get_unchecked(ind))
+            &(**(*library).borrow().book_system().books.get_unchecked(ind))
                 .borrow()
                 .title
         },
         *unsafe {
-            &(**(*book_system).borrow().books.get_unchecked(ind))
+            &(**(*library).borrow().book_system().books.get_unchecked(ind))
                 .borrow()
                 .author
         }
@@ -594,64 +466,36 @@ pub fn the_book_info_simple(
     let mut table = VGrid::new(0, 30, 520, 180, "");
     table.set_params(5, 1, 1);
 
-    let label = format!(
-        "{}: {}",
-        match lang {
-            Lang::English => "Title",
-            Lang::Russian => "Название",
-        },
-        *unsafe {
-            &(**(*book_system).borrow().books.get_unchecked(ind))
-                .borrow()
-                .title
-        },
-    );
+    let label = format!("{}: {}", lang.tr("title"), *unsafe {
+        &(**(*library).borrow().book_system().books.get_unchecked(ind))
+            .borrow()
+            .title
+    },);
 
     let mut title_frame = Frame::new(30, 50, 420, 30, None).with_label(label.as_str());
 
-    let label = format!(
-        "{}: {}",
-        match lang {
-            Lang::English => "Author",
-            Lang::Russian => "Автор",
-        },
-        *unsafe {
-            &(**(*book_system).borrow().books.get_unchecked(ind))
-                .borrow()
-                .author
-        }
-    );
+    let label = format!("{}: {}", lang.tr("author"), *unsafe {
+        &(**(*library).borrow().book_system().books.get_unchecked(ind))
+            .borrow()
+            .author
+    });
 
     let mut author_frame = Frame::new(50, 50, 420, 30, None).with_label(label.as_str());
 
-    let label = format!(
-        "{}: {}",
-        match lang {
-            Lang::English => "Amount of Pages",
-            Lang::Russian => "Кол-во страниц",
-        },
-        *unsafe {
-            &(**(*book_system).borrow().books.get_unchecked(ind))
-                .borrow()
-                .pages
-        }
-    );
+    let label = format!("{}: {}", lang.tr("amount-of-pages"), *unsafe {
+        &(**(*library).borrow().book_system().books.get_unchecked(ind))
+            .borrow()
+            .pages
+    });
 
     let mut pages_frame = Frame::new(70, 50, 420, 30, None).with_label(label.as_str());
 
-    let label = format!(
-        "{}: {}",
-        match lang {
-            Lang::English => "Amount of books",
-            Lang::Russian => "Кол-во книг",
-        },
-        unsafe {
-            (**(*book_system).borrow().books.get_unchecked(ind))
-                .borrow()
-                .books
-                .len()
-        }
-    );
+    let label = format!("{}: {}", lang.tr("amount-of-books-2"), unsafe {
+        (**(*library).borrow().book_system().books.get_unchecked(ind))
+            .borrow()
+            .books
+            .len()
+    });
 
     let mut amount_frame = Frame::new(90, 50, 320, 30, None).with_label(label.as_str());
 
@@ -660,13 +504,7 @@ pub fn the_book_info_simple(
     table.add(&pages_frame);
     table.add(&amount_frame);
 
-    let label = format!(
-        "{}:",
-        match lang {
-            Lang::English => "Genres",
-            Lang::Russian => "Жанры",
-        },
-    );
+    let label = format!("{}:", lang.tr("genres"),);
 
     table.add(&Frame::new(90, 50, 320, 30, None).with_label(label.as_str()));
     table.auto_layout();
@@ -675,7 +513,7 @@ pub fn the_book_info_simple(
 
     genre_table.set_rows(
         if let Some(g) = unsafe {
-            &(**(*book_system).borrow().books.get_unchecked(ind))
+            &(**(*library).borrow().book_system().books.get_unchecked(ind))
                 .borrow()
                 .genres
         } {
@@ -689,7 +527,14 @@ pub fn the_book_info_simple(
     genre_table.set_col_width_all(500);
     genre_table.end();
 
-    let b = unsafe { (*book_system).borrow().books.get_unchecked(ind).clone() };
+    let b = unsafe {
+        (*library)
+            .borrow()
+            .book_system()
+            .books
+            .get_unchecked(ind)
+            .clone()
+    };
 
     genre_table.draw_cell(move |t, ctx, row, col, x, y, w, h| match ctx {
         table::TableContext::StartPage => draw::set_font(Font::Helvetica, 14),
@@ -712,25 +557,13 @@ pub fn the_book_info_simple(
 
     wind.end();
 
-    let mut menu = MenuBar::new(
-        0,
-        0,
-        420 + match lang {
-            Lang::English => 0,
-            Lang::Russian => 90,
-        },
-        30,
-        "",
-    );
+    let mut menu = MenuBar::new(0, 0, 420, 30, "");
     wind.add(&menu);
 
     let (s, r) = app::channel();
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Change/Change title\t",
-            Lang::Russian => "&Изменить/Изменить название\t",
-        },
+        lang.tr("change-change-title"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -738,10 +571,7 @@ pub fn the_book_info_simple(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Change/Change author\t",
-            Lang::Russian => "&Изменить/Изменить автора\t",
-        },
+        lang.tr("change-change-author"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -749,10 +579,7 @@ pub fn the_book_info_simple(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Change/Change amount of pages\t",
-            Lang::Russian => "&Изменить/Изменить Кол-во страниц\t",
-        },
+        lang.tr("change-change-amount-of-pages"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -760,10 +587,7 @@ pub fn the_book_info_simple(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Change/Customize book genres\t",
-            Lang::Russian => "&Изменить/Изменить жанры книги\t",
-        },
+        lang.tr("change-customize-book-genres"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -771,10 +595,7 @@ pub fn the_book_info_simple(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Remove/Remove all books\t",
-            Lang::Russian => "&Удалить/Удалить все книги\t",
-        },
+        lang.tr("remove-remove-all-books"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -782,10 +603,7 @@ pub fn the_book_info_simple(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Remove/Remove one book\t",
-            Lang::Russian => "&Удалить/Удалить одну книгу\t",
-        },
+        lang.tr("remove-remove-one-book"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -793,10 +611,7 @@ pub fn the_book_info_simple(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Add books",
-            Lang::Russian => "&Добавить книги",
-        },
+        lang.tr("add-books-menu"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -804,93 +619,47 @@ pub fn the_book_info_simple(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Get current book's information",
-            Lang::Russian => "&Информация о конкретной книгe",
-        },
+        lang.tr("get-current-books-information"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
         MessageTheBook::Info,
     );
 
+    fit_menu(&mut menu);
     wind.show();
 
     while app.wait() {
         if let Some(msg) = r.recv() {
             match msg {
                 MessageTheBook::ChangeTitle => {
-                    if let Some(new_title) = change_title_simple(
-                        ind,
-                        &mut *(*book_system).borrow_mut(),
-                        reader_base,
-                        genres,
-                        caretaker,
-                        app,
-                        lang,
-                    ) {
-                        title_frame.set_label(
-                            format!(
-                                "{}: {}",
-                                match lang {
-                                    Lang::English => "Title",
-                                    Lang::Russian => "Название",
-                                },
-                                new_title
-                            )
-                            .as_str(),
-                        );
+                    if let Some(new_title) =
+                        change_title_simple(ind, &mut *(*library).borrow_mut(), app, lang)
+                    {
+                        title_frame
+                            .set_label(format!("{}: {}", lang.tr("title"), new_title).as_str());
                         title_frame.redraw();
                         main_table.redraw();
                     }
                 }
 
                 MessageTheBook::ChangeAuthor => {
-                    if let Some(new_author) = change_author_simple(
-                        ind,
-                        &mut *(*book_system).borrow_mut(),
-                        reader_base,
-                        genres,
-                        caretaker,
-                        app,
-                        lang,
-                    ) {
-                        author_frame.set_label(
-                            format!(
-                                "{}: {}",
-                                match lang {
-                                    Lang::English => "Author",
-                                    Lang::Russian => "Автор",
-                                },
-                                new_author
-                            )
-                            .as_str(),
-                        );
+                    if let Some(new_author) =
+                        change_author_simple(ind, &mut *(*library).borrow_mut(), app, lang)
+                    {
+                        author_frame
+                            .set_label(format!("{}: {}", lang.tr("author"), new_author).as_str());
                         author_frame.redraw();
                         main_table.redraw();
                     }
                 }
 
                 MessageTheBook::ChangePages => {
-                    if let Some(new_pages) = change_pages_simple(
-                        ind,
-                        &mut *(*book_system).borrow_mut(),
-                        reader_base,
-                        genres,
-                        caretaker,
-                        app,
-                        lang,
-                    ) {
+                    if let Some(new_pages) =
+                        change_pages_simple(ind, &mut *(*library).borrow_mut(), app, lang)
+                    {
                         pages_frame.set_label(
-                            format!(
-                                "{}: {}",
-                                match lang {
-                                    Lang::English => "Amount of pages",
-                                    Lang::Russian => "Кол-во страниц",
-                                },
-                                new_pages
-                            )
-                            .as_str(),
+                            format!("{}: {}", lang.tr("amount-of-pages-2"), new_pages).as_str(),
                         );
                         pages_frame.redraw();
                         main_table.redraw();
@@ -898,29 +667,14 @@ pub fn the_book_info_simple(
                 }
 
                 MessageTheBook::RemoveSimple => {
-                    if remove_book_simple(
-                        ind,
-                        &mut *(*book_system).borrow_mut(),
-                        reader_base,
-                        genres,
-                        caretaker,
-                        app,
-                        lang,
-                    ) {
+                    if remove_book_simple(ind, &mut *(*library).borrow_mut(), app, lang) {
                         amount_frame.set_label(
-                            format!(
-                                "{}: {}",
-                                match lang {
-                                    Lang::English => "Amount of pages",
-                                    Lang::Russian => "Кол-во страниц",
-                                },
-                                unsafe {
-                                    (**(*book_system).borrow().books.get_unchecked(ind))
-                                        .borrow()
-                                        .books
-                                        .len()
-                                }
-                            )
+                            format!("{}: {}", lang.tr("amount-of-pages-2"), unsafe {
+                                (**(*library).borrow().book_system().books.get_unchecked(ind))
+                                    .borrow()
+                                    .books
+                                    .len()
+                            })
                             .as_str(),
                         );
                         amount_frame.redraw();
@@ -929,29 +683,14 @@ pub fn the_book_info_simple(
                 }
 
                 MessageTheBook::AddSimple => {
-                    if add_books_simple(
-                        ind,
-                        &mut *(*book_system).borrow_mut(),
-                        reader_base,
-                        genres,
-                        caretaker,
-                        app,
-                        lang,
-                    ) {
+                    if add_books_simple(ind, &mut *(*library).borrow_mut(), app, lang) {
                         amount_frame.set_label(
-                            format!(
-                                "{}: {}",
-                                match lang {
-                                    Lang::English => "Amount of books",
-                                    Lang::Russian => "Кол-во книг",
-                                },
-                                unsafe {
-                                    (**(*book_system).borrow().books.get_unchecked(ind))
-                                        .borrow()
-                                        .books
-                                        .len()
-                                }
-                            )
+                            format!("{}: {}", lang.tr("amount-of-books-2"), unsafe {
+                                (**(*library).borrow().book_system().books.get_unchecked(ind))
+                                    .borrow()
+                                    .books
+                                    .len()
+                            })
                             .as_str(),
                         );
                         amount_frame.redraw();
@@ -959,39 +698,17 @@ pub fn the_book_info_simple(
                 }
 
                 MessageTheBook::RemoveThis => {
-                    remove_the_book_simple(
-                        ind,
-                        &mut *(*book_system).borrow_mut(),
-                        reader_base,
-                        genres,
-                        caretaker,
-                        lang,
-                    );
+                    remove_the_book_simple(ind, &mut *(*library).borrow_mut(), lang);
                     return;
                 }
 
                 MessageTheBook::CustomizeBookGenre => {
-                    customize_book_genre(
-                        genres,
-                        &mut *(*book_system).borrow_mut(),
-                        reader_base,
-                        caretaker,
-                        app,
-                        lang,
-                    );
+                    customize_book_genre(&mut *(*library).borrow_mut(), app, lang);
                     genre_table.redraw();
                     main_table.redraw();
                 }
 
-                MessageTheBook::Info => book_info_simple2(
-                    ind,
-                    book_system.clone(),
-                    reader_base,
-                    genres,
-                    caretaker,
-                    app,
-                    lang,
-                ),
+                MessageTheBook::Info => book_info_simple2(ind, library.clone(), app, lang),
             }
         } else if !wind.shown() {
             return;
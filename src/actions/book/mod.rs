@@ -4,16 +4,16 @@ use crate::{
     actions::{
         book::{
             add_rem::simple::{remove_book_simple, remove_the_book_simple},
-            change::{full::change_location, simple::*},
+            change::{
+                full::{change_copy_type, change_location},
+                simple::*,
+            },
             info::simple::{book_info_simple2, the_book_info_simple},
             utils::check_book,
         },
         genres::full::all_genres,
     },
-    books::book_sys::BookSystem,
-    books::genres::Genres,
-    reading::read_base::ReaderBase,
-    restore::caretaker::Caretaker,
+    library::Library,
     Lang, Message,
 };
 
@@ -27,30 +27,25 @@ pub(crate) mod utils;
 
 #[inline]
 pub fn action_books(
-    book_system: Rc<RefCell<BookSystem>>,
-    reader_base: &mut ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: Rc<RefCell<Library>>,
     app: &App,
     lang: Lang,
     main_table: &mut Table,
     action: Message,
 ) {
-    if (*book_system).borrow().books.is_empty() {
-        alert(
-            500,
-            500,
-            match lang {
-                Lang::English => "Book System is empty",
-                Lang::Russian => "Книг нет",
-            },
-        );
+    if (*library).borrow().book_system().books.is_empty() {
+        alert(500, 500, lang.tr("book-system-is-empty"));
 
         return;
     }
 
     match {
-        let check = all_genres(genres, &*(*book_system).borrow(), app, lang);
+        let check = all_genres(
+            (*library).borrow().genres(),
+            (*library).borrow().book_system(),
+            app,
+            lang,
+        );
         check
     } {
         Some(book) => {
@@ -63,116 +58,49 @@ pub fn action_books(
                 .collect::<Vec<_>>();
 
             if book.len() != 3 {
-                alert(
-                    500,
-                    500,
-                    match lang {
-                        Lang::English => "Book isn't selected",
-                        Lang::Russian => "Книга не выбрана",
-                    },
-                );
+                alert(500, 500, lang.tr("book-isnt-selected"));
                 return;
             }
 
             if let Ok(index) = {
-                let check = check_book(&*(*book_system).borrow(), &book, lang);
+                let check = check_book((*library).borrow().book_system(), &book, lang);
                 check
             } {
                 match action {
                     Message::RemoveBook => {
-                        remove_book_simple(
-                            index,
-                            &mut *(*book_system).borrow_mut(),
-                            reader_base,
-                            genres,
-                            caretaker,
-                            app,
-                            lang,
-                        );
+                        remove_book_simple(index, &mut *(*library).borrow_mut(), app, lang);
                     }
 
                     Message::RemoveTheBook => {
-                        remove_the_book_simple(
-                            index,
-                            &mut *(*book_system).borrow_mut(),
-                            reader_base,
-                            genres,
-                            caretaker,
-                            lang,
-                        );
+                        remove_the_book_simple(index, &mut *(*library).borrow_mut(), lang);
                     }
 
                     Message::ChangeTitle => {
-                        change_title_simple(
-                            index,
-                            &mut *(*book_system).borrow_mut(),
-                            reader_base,
-                            genres,
-                            caretaker,
-                            app,
-                            lang,
-                        );
+                        change_title_simple(index, &mut *(*library).borrow_mut(), app, lang);
                     }
 
                     Message::ChangeAuthor => {
-                        change_author_simple(
-                            index,
-                            &mut *(*book_system).borrow_mut(),
-                            reader_base,
-                            genres,
-                            caretaker,
-                            app,
-                            lang,
-                        );
+                        change_author_simple(index, &mut *(*library).borrow_mut(), app, lang);
                     }
 
                     Message::ChangePages => {
-                        change_pages_simple(
-                            index,
-                            &mut *(*book_system).borrow_mut(),
-                            reader_base,
-                            genres,
-                            caretaker,
-                            app,
-                            lang,
-                        );
+                        change_pages_simple(index, &mut *(*library).borrow_mut(), app, lang);
                     }
 
                     Message::ChangeLocation => {
-                        change_location(
-                            index,
-                            &mut *(*book_system).borrow_mut(),
-                            reader_base,
-                            genres,
-                            caretaker,
-                            app,
-                            lang,
-                        );
+                        change_location(index, &mut *(*library).borrow_mut(), app, lang);
+                    }
+
+                    Message::ChangeCopyType => {
+                        change_copy_type(index, &mut *(*library).borrow_mut(), app, lang);
                     }
 
                     Message::InfoTheBook => {
-                        the_book_info_simple(
-                            index,
-                            book_system.clone(),
-                            reader_base,
-                            genres,
-                            caretaker,
-                            app,
-                            lang,
-                            main_table,
-                        );
+                        the_book_info_simple(index, library.clone(), app, lang, main_table);
                     }
 
                     Message::InfoBook => {
-                        book_info_simple2(
-                            index,
-                            book_system.clone(),
-                            reader_base,
-                            genres,
-                            caretaker,
-                            app,
-                            lang,
-                        );
+                        book_info_simple2(index, library.clone(), app, lang);
                     }
 
                     Message::GetBook => {}
@@ -183,14 +111,7 @@ pub fn action_books(
         }
 
         None => {
-            alert(
-                500,
-                500,
-                match lang {
-                    Lang::English => "Book isn't selected",
-                    Lang::Russian => "Книга не выбрана",
-                },
-            );
+            alert(500, 500, lang.tr("book-isnt-selected"));
             return;
         }
     }
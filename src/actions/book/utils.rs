@@ -1,7 +1,12 @@
 extern crate fltk;
 
-use crate::{books::book_sys::BookSystem, Lang};
-use fltk::dialog::alert;
+use crate::{
+    books::book_sys::BookSystem,
+    change::{input2::Input2, Inputable},
+    Lang,
+};
+
+use fltk::{app, app::App, dialog::alert, input::IntInput, prelude::*};
 
 /// Function that checks
 /// if input of book was empty
@@ -9,34 +14,13 @@ use fltk::dialog::alert;
 #[inline]
 pub(crate) fn empty_inp_book(inp: &Vec<String>, lang: Lang) -> bool {
     return if unsafe { inp.get_unchecked(0).is_empty() } {
-        alert(
-            500,
-            500,
-            match lang {
-                Lang::English => "'Title' is empty",
-                Lang::Russian => "'Название' пусто",
-            },
-        );
+        alert(500, 500, lang.tr("title-is-empty"));
         true
     } else if unsafe { inp.get_unchecked(1).is_empty() } {
-        alert(
-            500,
-            500,
-            match lang {
-                Lang::English => "'Author' is empty",
-                Lang::Russian => "'Автор' пусто",
-            },
-        );
+        alert(500, 500, lang.tr("author-is-empty"));
         true
     } else if unsafe { inp.get_unchecked(2).is_empty() } {
-        alert(
-            500,
-            500,
-            match lang {
-                Lang::English => "'Amount of pages' is empty",
-                Lang::Russian => "'Кол-во страниц' пусто",
-            },
-        );
+        alert(500, 500, lang.tr("amount-of-pages-is-empty"));
         true
     } else {
         false
@@ -64,29 +48,73 @@ pub(crate) fn check_book(
                 Some(i) => Ok(i),
 
                 None => {
-                    alert(
-                        500,
-                        500,
-                        match lang {
-                            Lang::English => "Book isn't found",
-                            Lang::Russian => "Книга не найдена",
-                        },
-                    );
+                    alert(500, 500, lang.tr("book-isnt-found"));
                     Err(())
                 }
             },
 
             Err(_) => {
-                alert(
-                    500,
-                    500,
-                    match lang {
-                        Lang::English => "Amount of Pages input error",
-                        Lang::Russian => "Ошибка ввода количества страниц",
-                    },
-                );
+                alert(500, 500, lang.tr("amount-of-pages-input-error"));
                 Err(())
             }
         },
     };
 }
+
+/// Asks for location of one simple book
+/// (number of cabinet and it's shelf).
+/// Returns None if window was closed
+/// or input was incorrect
+
+#[inline]
+pub(crate) fn ask_location(app: &App, lang: Lang) -> Option<(u16, u8)> {
+    let (s2, r2) = app::channel();
+    let mut inp = Input2::<IntInput, IntInput>::new(
+        lang.tr("location"),
+        lang.tr("cabinets-number"),
+        lang.tr("shelfs-number"),
+    );
+
+    inp.show();
+    (*inp.ok).borrow_mut().emit(s2, true);
+
+    while app.wait() {
+        if let Some(message) = r2.recv() {
+            if message {
+                inp.hide();
+
+                if let Ok(location) = inp.set_input(lang) {
+                    return match (
+                        location.first().unwrap().trim().parse::<u16>(),
+                        location.last().unwrap().trim().parse::<u8>(),
+                    ) {
+                        (Ok(cabinet), Ok(shelf)) => Some((cabinet, shelf)),
+
+                        (Err(_), _) => {
+                            alert(500, 500, lang.tr("cabinets-number-input-error"));
+                            None
+                        }
+
+                        (_, Err(_)) => {
+                            alert(500, 500, lang.tr("shelfs-number-input-error"));
+                            None
+                        }
+                    };
+                }
+            }
+            return None;
+        } else if !inp.shown() {
+            return None;
+        }
+    }
+
+    None
+}
+
+/// Asks for locations of several simple books.
+/// Stops on the first cancelled or incorrect input
+
+#[inline]
+pub(crate) fn ask_locations(amount: usize, app: &App, lang: Lang) -> Option<Vec<(u16, u8)>> {
+    (0..amount).map(|_| ask_location(app, lang)).collect()
+}
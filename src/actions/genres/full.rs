@@ -8,8 +8,7 @@ use crate::{
     },
     books::{book_sys::BookSystem, genres::Genres},
     change::{input1::Input1, input3::Input3, Inputable},
-    reading::read_base::ReaderBase,
-    restore::caretaker::Caretaker,
+    library::Library,
     Lang,
 };
 
@@ -18,13 +17,13 @@ use fltk::{
     app::App,
     dialog::alert,
     draw,
+    enums::Font,
     input::{Input, IntInput},
     prelude::*,
     table,
     table::Table,
     tree::{Tree, TreeItem},
     window::SingleWindow,
-    enums::Font
 };
 
 use std::cmp::max;
@@ -34,27 +33,9 @@ use std::cmp::max;
 /// program will let you know
 
 #[inline]
-pub fn add_genre(
-    genres: &mut Genres,
-    reader_base: &ReaderBase,
-    book_system: &BookSystem,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+pub fn add_genre(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = app::channel();
-    let mut inp = Input1::<Input>::new(
-        match lang {
-            Lang::English => "Add Genre",
-            Lang::Russian => "Добавить жанр",
-        },
-        match lang {
-            Lang::English => "New Genre",
-            Lang::Russian => "Новый жанр",
-        },
-    );
-
-    caretaker.add_memento(reader_base, book_system, genres);
+    let mut inp = Input1::<Input>::new(lang.tr("add-genre"), lang.tr("new-genre"));
 
     inp.show();
     (*inp.ok).borrow_mut().emit(s2, true);
@@ -66,27 +47,14 @@ pub fn add_genre(
 
                 if let Ok(genre) = inp.set_input(lang) {
                     if genre.first().unwrap().is_empty() {
-                        alert(
-                            500,
-                            500,
-                            match lang {
-                                Lang::English => "'New genre' is empty",
-                                Lang::Russian => "'Новый жанр' пусто",
-                            },
-                        );
-                        caretaker.pop().unwrap();
+                        alert(500, 500, lang.tr("new-genre-is-empty"));
                         return;
                     } else {
-                        genres.add(genre.first().unwrap().clone());
-                        fltk::dialog::message(
-                            500,
-                            500,
-                            match lang {
-                                Lang::English => "Successfully added",
-                                Lang::Russian => "Успешно добавлено",
-                            },
-                        );
-                        genres.save();
+                        match library.add_genre(genre.first().unwrap().clone()) {
+                            Ok(_) => fltk::dialog::message(500, 500, lang.tr("successfully-added")),
+
+                            Err(err) => alert(500, 500, err.message(lang)),
+                        }
                     }
                 }
             }
@@ -102,27 +70,9 @@ pub fn add_genre(
 /// program will let you know
 
 #[inline]
-pub fn remove_genre(
-    genres: &mut Genres,
-    reader_base: &ReaderBase,
-    book_system: &mut BookSystem,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+pub fn remove_genre(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = app::channel();
-    let mut inp = Input1::<Input>::new(
-        match lang {
-            Lang::English => "Remove Genre",
-            Lang::Russian => "Удалить жанр",
-        },
-        match lang {
-            Lang::English => "Genre's title",
-            Lang::Russian => "Название жанра",
-        },
-    );
-
-    caretaker.add_memento(reader_base, book_system, genres);
+    let mut inp = Input1::<Input>::new(lang.tr("remove-genre"), lang.tr("genres-title"));
 
     inp.show();
     (*inp.ok).borrow_mut().emit(s2, true);
@@ -134,49 +84,16 @@ pub fn remove_genre(
 
                 if let Ok(genre) = inp.set_input(lang) {
                     if genre.first().unwrap().is_empty() {
-                        alert(
-                            500,
-                            500,
-                            match lang {
-                                Lang::English => "'Genre's title' is empty",
-                                Lang::Russian => "'Название жанра' пусто",
-                            },
-                        );
-                        caretaker.pop().unwrap();
+                        alert(500, 500, lang.tr("genres-title-is-empty"));
                         return;
                     } else {
-                        genres.remove(genre.first().unwrap());
-
-                        book_system.iter_mut().for_each(|b| {
-                            if {
-                                let check = (**b).borrow().genres.is_some();
-                                check
-                            } {
-                                (**b)
-                                    .borrow_mut()
-                                    .genres
-                                    .as_mut()
-                                    .unwrap()
-                                    .remove(genre.first().unwrap());
-
-                                if {
-                                    let check = (**b).borrow().genres.as_ref().unwrap().is_empty();
-                                    check
-                                } {
-                                    (**b).borrow_mut().genres = None
-                                }
+                        match library.remove_genre(genre.first().unwrap()) {
+                            Ok(_) => {
+                                fltk::dialog::message(500, 500, lang.tr("successfully-removed"))
                             }
-                        });
-
-                        fltk::dialog::message(
-                            500,
-                            500,
-                            match lang {
-                                Lang::English => "Successfully removed",
-                                Lang::Russian => "Успешно удалено",
-                            },
-                        );
-                        genres.save();
+
+                            Err(err) => alert(500, 500, err.message(lang)),
+                        }
                     }
                 }
             }
@@ -193,36 +110,15 @@ pub fn remove_genre(
 /// genres as you need
 
 #[inline]
-pub fn customize_book_genre(
-    genres: &Genres,
-    book_system: &mut BookSystem,
-    reader_base: &ReaderBase,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+pub fn customize_book_genre(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = app::channel();
     let mut inp = Input3::<Input, Input, IntInput>::new(
-        match lang {
-            Lang::English => "Customize book's genres",
-            Lang::Russian => "Изменить жанры книги",
-        },
-        match lang {
-            Lang::English => "Title",
-            Lang::Russian => "Название",
-        },
-        match lang {
-            Lang::English => "Author",
-            Lang::Russian => "Автор",
-        },
-        match lang {
-            Lang::English => "Amount of Pages",
-            Lang::Russian => "Кол-во страниц",
-        },
+        lang.tr("customize-books-genres"),
+        lang.tr("title"),
+        lang.tr("author"),
+        lang.tr("amount-of-pages"),
     );
 
-    caretaker.add_memento(reader_base, book_system, genres);
-
     inp.show();
     (*inp.ok).borrow_mut().emit(s2, true);
 
@@ -232,21 +128,13 @@ pub fn customize_book_genre(
                 inp.hide();
 
                 if let Ok(book) = inp.set_input(lang) {
-                    if let Ok(index) = check_book(book_system, &book, lang) {
-                        customize_book_genre_simple(
-                            index,
-                            genres,
-                            book_system,
-                            caretaker,
-                            app,
-                            lang,
-                        )
+                    if let Ok(index) = check_book(library.book_system(), &book, lang) {
+                        customize_book_genre_simple(index, library, app, lang)
                     }
                 }
             }
             break;
         } else if !inp.shown() {
-            caretaker.pop().unwrap();
             return;
         }
     }
@@ -265,16 +153,7 @@ pub fn customize_book_genre(
 #[deprecated(note = "Used before. Consider using all_genres() instead, it's more representable")]
 fn find_by_genre(book_system: &BookSystem, app: &App, lang: Lang) {
     let (s, r) = app::channel();
-    let mut inp = Input1::<Input>::new(
-        match lang {
-            Lang::English => "Input Genre",
-            Lang::Russian => "Ввод Жанра",
-        },
-        match lang {
-            Lang::English => "Input Genre",
-            Lang::Russian => "Введите Жанр",
-        },
-    );
+    let mut inp = Input1::<Input>::new(lang.tr("input-genre"), lang.tr("input-genre-2"));
 
     inp.show();
     (*inp.ok).borrow_mut().emit(s, true);
@@ -285,16 +164,8 @@ fn find_by_genre(book_system: &BookSystem, app: &App, lang: Lang) {
                 inp.hide();
 
                 if let Ok(genre) = inp.set_input(lang) {
-                    let mut wind = SingleWindow::new(
-                        500,
-                        500,
-                        300,
-                        400,
-                        match lang {
-                            Lang::English => "Books with spec genre",
-                            Lang::Russian => "Книги с искомым жанром",
-                        },
-                    );
+                    let mut wind =
+                        SingleWindow::new(500, 500, 300, 400, lang.tr("books-with-spec-genre"));
 
                     let mut book_table = Table::new(0, 0, 300, 400, "");
                     let mut find = vec![];
@@ -362,43 +233,18 @@ pub fn all_genres(
     app: &App,
     lang: Lang,
 ) -> Option<TreeItem> {
-    let mut wind = SingleWindow::new(
-        500,
-        500,
-        300,
-        400,
-        match lang {
-            Lang::English => "All Books with Genres",
-            Lang::Russian => "Все Книги с Жанрами",
-        },
-    );
+    let mut wind = SingleWindow::new(500, 500, 300, 400, lang.tr("all-books-with-genres"));
 
     let mut tree = Tree::new(0, 0, 300, 400, "");
-    tree.set_root_label(match lang {
-        Lang::English => "Genres",
-        Lang::Russian => "Жанры",
-    });
+    tree.set_root_label(lang.tr("genres"));
 
     genres.iter().for_each(|g| {
         tree.add(g.as_str()).unwrap();
         find_by_genre_simple(g, book_system)
             .into_iter()
             .for_each(|b| {
-                tree.add(
-                    format!(
-                        "{}/{} {} {} {}",
-                        g,
-                        b.0,
-                        b.1,
-                        b.2,
-                        match lang {
-                            Lang::English => "pages",
-                            Lang::Russian => "страниц",
-                        }
-                    )
-                    .as_str(),
-                )
-                .unwrap();
+                tree.add(format!("{}/{} {} {} {}", g, b.0, b.1, b.2, lang.tr("pages")).as_str())
+                    .unwrap();
             })
     });
 
@@ -411,34 +257,17 @@ pub fn all_genres(
                 (**b).borrow().title,
                 (**b).borrow().author,
                 (**b).borrow().pages,
-                match lang {
-                    Lang::English => "pages",
-                    Lang::Russian => "страниц",
-                }
+                lang.tr("pages")
             )
         })
         .collect::<Vec<_>>();
 
     if !no_genre.is_empty() {
-        tree.add(match lang {
-            Lang::English => "Without Genres",
-            Lang::Russian => "Без Жанров",
-        })
-        .unwrap();
+        tree.add(lang.tr("without-genres")).unwrap();
 
         no_genre.into_iter().for_each(|b| {
-            tree.add(
-                format!(
-                    "{}/{}",
-                    match lang {
-                        Lang::English => "Without Genres",
-                        Lang::Russian => "Без Жанров",
-                    },
-                    b
-                )
-                .as_str(),
-            )
-            .unwrap();
+            tree.add(format!("{}/{}", lang.tr("without-genres"), b).as_str())
+                .unwrap();
         });
     }
 
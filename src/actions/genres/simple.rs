@@ -2,11 +2,7 @@ extern crate fltk;
 
 use fltk::{app::App, browser::CheckBrowser, prelude::*, window::SingleWindow};
 
-use crate::{
-    books::{book_sys::BookSystem, genres::Genres},
-    restore::caretaker::Caretaker,
-    Lang,
-};
+use crate::{books::book_sys::BookSystem, library::Library, Lang};
 
 use std::collections::HashSet;
 
@@ -19,9 +15,7 @@ use std::collections::HashSet;
 #[inline]
 pub(crate) fn customize_book_genre_simple(
     index: usize,
-    genres: &Genres,
-    book_system: &mut BookSystem,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     app: &App,
     lang: Lang,
 ) {
@@ -29,20 +23,18 @@ pub(crate) fn customize_book_genre_simple(
         500,
         100,
         300,
-        50 * genres.len() as i32,
-        match lang {
-            Lang::English => "Select Genres",
-            Lang::Russian => "Выбрать жанры",
-        },
+        50 * library.genres().len() as i32,
+        lang.tr("select-genres"),
     );
 
-    let mut genre_choice = CheckBrowser::new(0, 0, 300, 50 * genres.len() as i32 + 30, "");
+    let mut genre_choice =
+        CheckBrowser::new(0, 0, 300, 50 * library.genres().len() as i32 + 30, "");
 
-    genres.iter().for_each(|g| {
+    library.genres().iter().for_each(|g| {
         genre_choice.add(
             g.as_str(),
             if let Some(gen) = unsafe {
-                &(**book_system.books.get_unchecked(index))
+                &(**library.book_system().books.get_unchecked(index))
                     .borrow_mut()
                     .genres
             } {
@@ -61,70 +53,27 @@ pub(crate) fn customize_book_genre_simple(
     wind.show();
 
     while app.wait() {
-        (0..genres.len()).for_each(|i| {
-            if genre_choice.checked(i as i32 + 1) {
-                unsafe {
-                    if (**book_system.books.get_unchecked(index))
-                        .borrow_mut()
-                        .genres
-                        .is_some()
-                    {
-                        (**book_system.books.get_unchecked(index))
-                            .borrow_mut()
-                            .genres
-                            .as_mut()
-                            .unwrap()
-                            .insert(genres.iter().skip(i).next().unwrap().clone());
-                    } else {
-                        (**book_system.books.get_unchecked(index))
-                            .borrow_mut()
-                            .genres = Some(HashSet::new());
-
-                        (**book_system.books.get_unchecked(index))
-                            .borrow_mut()
-                            .genres
-                            .as_mut()
-                            .unwrap()
-                            .insert(genres.iter().skip(i).next().unwrap().clone());
-                    }
-                }
-
-                book_system.save();
-            } else {
-                unsafe {
-                    if (**book_system.books.get_unchecked(index))
-                        .borrow_mut()
-                        .genres
-                        .is_some()
-                    {
-                        (**book_system.books.get_unchecked(index))
-                            .borrow_mut()
-                            .genres
-                            .as_mut()
-                            .unwrap()
-                            .remove(genres.iter().skip(i).next().unwrap());
-
-                        if (**book_system.books.get_unchecked(index))
-                            .borrow()
-                            .genres
-                            .as_ref()
-                            .unwrap()
-                            .len()
-                            == 0
-                        {
-                            (**book_system.books.get_unchecked(index))
-                                .borrow_mut()
-                                .genres = None;
-                        }
-                    }
-                }
-            }
+        let checked = library
+            .genres()
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| genre_choice.checked(*i as i32 + 1))
+            .map(|(_, g)| g.clone())
+            .collect::<HashSet<_>>();
+
+        let current = unsafe {
+            (**library.book_system().books.get_unchecked(index))
+                .borrow()
+                .genres
+                .clone()
+                .unwrap_or_default()
+        };
 
-            book_system.save();
-        });
+        if checked != current {
+            let _ = library.set_book_genres(index, checked);
+        }
 
         if !wind.shown() {
-            caretaker.pop().unwrap();
             return;
         }
     }
@@ -139,24 +88,17 @@ pub fn find_by_genre_simple(
     genre: &String,
     book_system: &BookSystem,
 ) -> Vec<(String, String, u16)> {
-    let mut find = vec![];
-
-    book_system.iter().for_each(|x| {
-        if (**x).borrow().genres.is_some()
-            && (**x)
-                .borrow()
-                .genres
-                .as_ref()
-                .unwrap()
-                .contains(genre.as_str())
-        {
-            find.push((
-                (**x).borrow().title.clone(),
-                (**x).borrow().author.clone(),
-                (**x).borrow().pages.clone(),
-            ))
-        }
-    });
-
-    find
+    book_system
+        .find_by_genre(genre)
+        .into_iter()
+        .map(|ind| {
+            let the_book = unsafe { (**book_system.books.get_unchecked(ind)).borrow() };
+
+            (
+                the_book.title.clone(),
+                the_book.author.clone(),
+                the_book.pages,
+            )
+        })
+        .collect()
 }
@@ -2,10 +2,8 @@ extern crate fltk;
 
 use crate::{
     actions::{giveaway::simple::*, read::utils::check_reader},
-    books::{book_sys::BookSystem, genres::Genres},
     change::{input3::Input3, Inputable},
-    reading::read_base::ReaderBase,
-    restore::caretaker::Caretaker,
+    library::Library,
     Lang,
 };
 
@@ -15,32 +13,13 @@ use fltk::{app::App, input::*, prelude::WidgetExt};
 /// return date for the book
 
 #[inline]
-pub fn change_return_date(
-    book_system: &mut BookSystem,
-    reader_base: &ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+pub fn change_return_date(library: &mut Library, app: &App, lang: Lang) {
     let (s, r) = fltk::app::channel();
     let mut inp2 = Input3::<Input, Input, Input>::new(
-        match lang {
-            Lang::English => "Find Reader",
-            Lang::Russian => "Поиск Читателя",
-        },
-        match lang {
-            Lang::English => "First Name",
-            Lang::Russian => "Имя",
-        },
-        match lang {
-            Lang::English => "Second Name",
-            Lang::Russian => "Фамилия",
-        },
-        match lang {
-            Lang::English => "Middle Name",
-            Lang::Russian => "Отчество",
-        },
+        lang.tr("find-reader"),
+        lang.tr("first-name"),
+        lang.tr("second-name"),
+        lang.tr("middle-name"),
     );
 
     inp2.show();
@@ -52,28 +31,18 @@ pub fn change_return_date(
                 inp2.hide();
 
                 if let Ok(reader) = inp2.set_input(lang) {
-                    match check_reader(reader_base, &reader, app, lang) {
+                    match check_reader(library.reader_base(), &reader, app, lang) {
                         Some(rind) => {
-                            change_return_date_simple(
-                                rind,
-                                book_system,
-                                reader_base,
-                                genres,
-                                caretaker,
-                                app,
-                                lang,
-                            );
+                            change_return_date_simple(rind, library, app, lang);
                         }
 
                         None => {
-                            caretaker.pop().unwrap();
                             return;
                         }
                     }
                 }
             }
         } else if !inp2.shown() {
-            caretaker.pop().unwrap();
             return;
         }
     }
@@ -86,36 +55,15 @@ pub fn change_return_date(
 /// program will let you know
 
 #[inline]
-pub fn give_book(
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+pub fn give_book(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = fltk::app::channel();
     let mut inp = Input3::<Input, Input, Input>::new(
-        match lang {
-            Lang::English => "Find Reader",
-            Lang::Russian => "Поиск Читателя",
-        },
-        match lang {
-            Lang::English => "First Name",
-            Lang::Russian => "Имя",
-        },
-        match lang {
-            Lang::English => "Second Name",
-            Lang::Russian => "Фамилия",
-        },
-        match lang {
-            Lang::English => "Middle Name",
-            Lang::Russian => "Отчество",
-        },
+        lang.tr("find-reader"),
+        lang.tr("first-name"),
+        lang.tr("second-name"),
+        lang.tr("middle-name"),
     );
 
-    caretaker.add_memento(reader_base, book_system, genres);
-
     inp.show();
     (*inp.ok).borrow_mut().emit(s2, true);
 
@@ -125,21 +73,12 @@ pub fn give_book(
                 inp.hide();
 
                 if let Ok(reader) = inp.set_input(lang) {
-                    match check_reader(reader_base, &reader, app, lang) {
+                    match check_reader(library.reader_base(), &reader, app, lang) {
                         Some(x) => {
-                            give_book_known_reader(
-                                x,
-                                reader_base,
-                                book_system,
-                                genres,
-                                caretaker,
-                                app,
-                                lang,
-                            );
+                            give_book_known_reader(x, library, app, lang);
                         }
 
                         None => {
-                            caretaker.pop().unwrap();
                             return;
                         }
                     }
@@ -147,7 +86,6 @@ pub fn give_book(
             }
             break;
         } else if !inp.shown() {
-            caretaker.pop().unwrap();
             break;
         }
     }
@@ -158,36 +96,15 @@ pub fn give_book(
 /// program will let you know
 
 #[inline]
-pub fn get_book(
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+pub fn get_book(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = fltk::app::channel();
     let mut inp = Input3::<Input, Input, Input>::new(
-        match lang {
-            Lang::English => "Find Reader",
-            Lang::Russian => "Поиск Читателя",
-        },
-        match lang {
-            Lang::English => "First Name",
-            Lang::Russian => "Имя",
-        },
-        match lang {
-            Lang::English => "Second Name",
-            Lang::Russian => "Фамилия",
-        },
-        match lang {
-            Lang::English => "Middle Name",
-            Lang::Russian => "Отчество",
-        },
+        lang.tr("find-reader"),
+        lang.tr("first-name"),
+        lang.tr("second-name"),
+        lang.tr("middle-name"),
     );
 
-    caretaker.add_memento(reader_base, book_system, genres);
-
     inp.show();
     (*inp.ok).borrow_mut().emit(s2, true);
 
@@ -197,21 +114,12 @@ pub fn get_book(
                 inp.hide();
 
                 if let Ok(reader) = inp.set_input(lang) {
-                    match check_reader(reader_base, &reader, app, lang) {
+                    match check_reader(library.reader_base(), &reader, app, lang) {
                         Some(x) => {
-                            get_book_known_reader(
-                                x,
-                                reader_base,
-                                book_system,
-                                genres,
-                                caretaker,
-                                app,
-                                lang,
-                            );
+                            get_book_known_reader(x, library, app, lang);
                         }
 
                         None => {
-                            caretaker.pop().unwrap();
                             return;
                         }
                     }
@@ -219,7 +127,6 @@ pub fn get_book(
             }
             break;
         } else if !inp.shown() {
-            caretaker.pop().unwrap();
             break;
         }
     }
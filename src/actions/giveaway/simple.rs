@@ -1,45 +1,37 @@
-extern crate chrono;
 extern crate fltk;
 extern crate fltk_calendar;
 
 use crate::{
     actions::{book::utils::check_book, genres::full::all_genres},
-    books::{book::Book, book_sys::BookSystem, date::Date, genres::Genres},
-    change::{input3::Input3, Inputable},
-    reading::read_base::ReaderBase,
-    restore::caretaker::Caretaker,
+    auth::Permission,
+    books::{book::Book, date::Date},
+    change::{input1::Input1, input3::Input3, Inputable},
+    library::Library,
+    policy::Refusal,
     Lang,
 };
 
 use fltk::{
-    app,
     app::App,
     dialog::alert,
     input::{Input, IntInput},
     prelude::*,
 };
 
-use chrono::{Datelike, Local};
 use fltk_calendar::calendar::Calendar;
 
-use std::{
-    cell::RefCell,
-    rc::{Rc, Weak},
-};
+use std::{cell::RefCell, rc::Weak};
 
 /// Function that gives
 /// book to known reader
 
 pub(crate) fn give_book_known_reader(
     rind: usize,
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     app: &App,
     lang: Lang,
 ) -> Option<String> {
-    match all_genres(genres, book_system, app, lang) {
+    match all_genres(library.genres(), library.book_system(), app, lang) {
         Some(book) => {
             let label = book.label().unwrap();
             let book = label
@@ -50,235 +42,147 @@ pub(crate) fn give_book_known_reader(
                 .collect::<Vec<_>>();
 
             if book.len() != 3 {
-                alert(
-                    500,
-                    500,
-                    match lang {
-                        Lang::English => "Book isn't selected",
-                        Lang::Russian => "Книга не выбрана",
-                    },
-                );
+                alert(500, 500, lang.tr("book-isnt-selected"));
                 return None;
             }
 
             let mut book = book.into_iter();
 
-            match book_system.find_book(
+            match library.book_system().find_book(
                 &book.next().unwrap(),
                 &book.next().unwrap(),
                 book.next().unwrap().parse().unwrap(),
             ) {
                 Some(bind) => {
-                    caretaker.add_memento(reader_base, book_system, genres);
-
-                    let mut win = fltk::window::SingleWindow::new(
-                        800,
-                        500,
-                        200,
-                        100,
-                        match lang {
-                            Lang::English => "Choose finish date",
-                            Lang::Russian => "Выберите срок сдачи",
-                        },
-                    );
+                    let over_limits = match library.loan_refusals(rind, bind) {
+                        Ok(refusals) if refusals.is_empty() => false,
+                        Ok(refusals) => allow_over_limits(&refusals, library, lang)?,
 
-                    let _ = fltk::frame::Frame::new(
-                        30,
-                        10,
-                        150,
-                        50,
-                        match lang {
-                            Lang::English => "Choose finish date",
-                            Lang::Russian => "Выберите срок сдачи",
-                        },
-                    );
+                        Err(err) => {
+                            alert(500, 500, err.message(lang));
+                            return None;
+                        }
+                    };
 
-                    let mut but = fltk::button::Button::new(
-                        80,
-                        60,
-                        60,
-                        20,
-                        match lang {
-                            Lang::English => "OK",
-                            Lang::Russian => "ОК",
-                        },
-                    );
+                    let proposed = match library.propose_due_date(rind, bind) {
+                        Ok(date) => date,
 
-                    win.end();
-                    win.show();
+                        Err(err) => {
+                            alert(500, 500, err.message(lang));
+                            return None;
+                        }
+                    };
 
-                    let (sd, rd) = app::channel();
-                    but.emit(sd, true);
+                    // Other due date is chosen only explicitly
 
-                    while app.wait() {
-                        if let Some(msg) = rd.recv() {
-                            if msg {
-                                win.hide();
+                    let finish = match fltk::dialog::choice2(
+                        500,
+                        500,
+                        lang.format("give-book-until", &[("date", &proposed.localized(lang))])
+                            .as_str(),
+                        lang.tr("give"),
+                        lang.tr("other-date"),
+                        lang.tr("cancel"),
+                    ) {
+                        Some(0) => proposed,
+
+                        Some(1) => {
+                            match Calendar::default().get_date().and_then(Date::from_naive) {
+                                Some(date) => date,
 
-                                return match Calendar::default().get_date() {
-                                    Some(date) => {
-                                        let date = Date::from(date);
+                                None => {
+                                    alert(500, 500, lang.tr("date-wasnt-selected"));
+                                    return None;
+                                }
+                            }
+                        }
 
-                                        if date < Date::from(chrono::Local::now()) {
-                                            alert(
-                                                500,
-                                                500,
-                                                match lang {
-                                                    Lang::English => {
-                                                        "The deadline must be no later than the day of issue"
-                                                    }
-                                                    Lang::Russian => {
-                                                        "Срок Сдачи должен быть не позднее дня выдачи"
-                                                    }
-                                                },
-                                            );
-                                            return None;
-                                        }
+                        _ => return None,
+                    };
 
-                                        let simple_book = unsafe {
-                                            (*book_system.books.get_unchecked(bind))
-                                                .borrow_mut()
-                                                .get_unused()
-                                        };
+                    let given = match over_limits {
+                        true => library.give_book_over_limits(rind, bind, finish),
+                        false => library.give_book(rind, bind, finish),
+                    };
 
-                                        match simple_book {
-                                            None => {
-                                                alert(
-                                                    500,
-                                                    500,
-                                                    match lang {
-                                                        Lang::English => "There are no free books",
-                                                        Lang::Russian => {
-                                                            "Свободных книг не осталось"
-                                                        }
-                                                    },
-                                                );
-                                                caretaker.pop().unwrap();
-                                                None
-                                            }
+                    match given {
+                        Ok(s_ind) => {
+                            fltk::dialog::message(
+                                500,
+                                500,
+                                moved_due_date(
+                                    "book-successfully-given-to-reader",
+                                    finish,
+                                    library,
+                                    lang,
+                                )
+                                .as_str(),
+                            );
 
-                                            Some(sim) => {
-                                                return if let Err(_) = unsafe {
-                                                    (*(*reader_base.readers.get_unchecked(rind))
-                                                        .as_ptr())
-                                                    .start_reading(
-                                                        (*(*book_system.books.get_unchecked(bind))
-                                                            .as_ptr())
-                                                        .books
-                                                        .get_unchecked(sim),
-                                                        &date,
-                                                    )
-                                                } {
-                                                    alert(
-                                                        500,
-                                                        500,
-                                                        match lang {
-                                                            Lang::English => "Reader is already reading book with same params",
-                                                            Lang::Russian => "Читатель уже читает книгу с такими параметрами",
-                                                        },
-                                                    );
-                                                    caretaker.pop().unwrap();
-                                                    None
-                                                } else {
-                                                    unsafe {
-                                                        (*book_system.books.get_unchecked(bind))
-                                                            .borrow_mut()
-                                                            .books
-                                                            .get_unchecked(sim)
-                                                    }
-                                                    .borrow_mut()
-                                                    .start_reading(
-                                                        unsafe {
-                                                            reader_base.readers.get_unchecked(rind)
-                                                        },
-                                                        date,
-                                                    );
-
-                                                    fltk::dialog::message(
-                                                        500,
-                                                        500,
-                                                        match lang {
-                                                            Lang::English => {
-                                                                "Book successfully given to reader"
-                                                            }
-                                                            Lang::Russian => {
-                                                                "Книга успешно выдана читателю"
-                                                            }
-                                                        },
-                                                    );
-
-                                                    book_system.save();
-                                                    reader_base.save();
-
-                                                    Some(
-                                                        (*unsafe {
-                                                            reader_base.readers.get_unchecked(rind)
-                                                        }
-                                                        .borrow()
-                                                        .reading
-                                                        .as_ref()
-                                                        .unwrap()
-                                                        .first()
-                                                        .unwrap()
-                                                        .upgrade()
-                                                        .unwrap())
-                                                        .borrow()
-                                                        .to_string(book_system),
-                                                    )
-                                                };
-                                            }
-                                        }
-                                    }
+                            Some(given_book(bind, s_ind, library))
+                        }
 
-                                    None => {
-                                        alert(
-                                            500,
-                                            500,
-                                            match lang {
-                                                Lang::English => "Date wasn't selected",
-                                                Lang::Russian => "Дата не была выбрана",
-                                            },
-                                        );
-                                        caretaker.pop().unwrap();
-                                        None
-                                    }
-                                };
-                            }
-                        } else if !win.shown() {
-                            caretaker.pop().unwrap();
-                            return None;
+                        Err(err) => {
+                            alert(500, 500, err.message(lang));
+                            None
                         }
                     }
                 }
 
                 None => {
-                    alert(
-                        500,
-                        500,
-                        match lang {
-                            Lang::English => "Book isn't selected",
-                            Lang::Russian => "Книга не выбрана",
-                        },
-                    );
-                    return None;
+                    alert(500, 500, lang.tr("book-isnt-selected"));
+                    None
                 }
             }
         }
 
         None => {
-            alert(
-                500,
-                500,
-                match lang {
-                    Lang::English => "Book isn't selected",
-                    Lang::Russian => "Книга не выбрана",
-                },
-            );
-            return None;
+            alert(500, 500, lang.tr("book-isnt-selected"));
+            None
         }
     }
+}
 
-    None
+/// Description of simple book which is just given:
+/// simple book with chosen index of The Book with chosen index
+
+#[inline]
+fn given_book(bind: usize, s_ind: usize, library: &Library) -> String {
+    let book = (*library.book_system().books[bind]).borrow().books[s_ind].clone();
+    let description = (*book).borrow().to_string(library.book_system());
+    description
+}
+
+/// Shows why loan policy refuses to give book.
+/// User who can override limits is asked
+/// if book should be given anyway.
+/// Returns None if book isn't given
+
+fn allow_over_limits(refusals: &[Refusal], library: &Library, lang: Lang) -> Option<bool> {
+    let reasons = refusals
+        .iter()
+        .map(|refusal| refusal.to_string(lang))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let text = format!("{}\n{}", lang.tr("loan-is-refused"), reasons);
+
+    if !library.allows(Permission::OverrideLimits) {
+        alert(500, 500, text.as_str());
+        return None;
+    }
+
+    match fltk::dialog::choice2(
+        500,
+        500,
+        format!("{}\n\n{}", text, lang.tr("give-over-limits")).as_str(),
+        lang.tr("yes"),
+        lang.tr("no"),
+        "",
+    ) {
+        Some(0) => Some(true),
+        _ => None,
+    }
 }
 
 /// **DEPRECATED**
@@ -292,35 +196,18 @@ pub(crate) fn give_book_known_reader(
 #[deprecated(note = "Used before. Requires input. Consider using give_book_known_reader() instead")]
 fn give_book_known_reader_input(
     rind: usize,
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     app: &App,
     lang: Lang,
 ) -> Option<String> {
     let (s3, r3) = fltk::app::channel();
     let mut inp2 = Input3::<Input, Input, IntInput>::new(
-        match lang {
-            Lang::English => "Find Book",
-            Lang::Russian => "Поиск Книги",
-        },
-        match lang {
-            Lang::English => "Title",
-            Lang::Russian => "Название",
-        },
-        match lang {
-            Lang::English => "Author",
-            Lang::Russian => "Автор",
-        },
-        match lang {
-            Lang::English => "Amount of Pages",
-            Lang::Russian => "Кол-во страниц",
-        },
+        lang.tr("find-book-2"),
+        lang.tr("title"),
+        lang.tr("author"),
+        lang.tr("amount-of-pages"),
     );
 
-    caretaker.add_memento(reader_base, book_system, genres);
-
     inp2.show();
     (*inp2.ok).borrow_mut().emit(s3, true);
 
@@ -332,29 +219,15 @@ fn give_book_known_reader_input(
                 if let Ok(book) = inp2.set_input(lang) {
                     let bind;
 
-                    match check_book(book_system, &book, lang) {
+                    match check_book(library.book_system(), &book, lang) {
                         Ok(x) => bind = x,
                         Err(_) => return None,
                     }
 
                     let (s4, r4) = fltk::app::channel();
-                    let mut inp3 = Input3::<IntInput, IntInput, IntInput>::new(
-                        match lang {
-                            Lang::English => "Set Return Date",
-                            Lang::Russian => "Срок Сдачи",
-                        },
-                        match lang {
-                            Lang::English => "Day (number)",
-                            Lang::Russian => "День (номер)",
-                        },
-                        match lang {
-                            Lang::English => "Month (number)",
-                            Lang::Russian => "Месяц (номер)",
-                        },
-                        match lang {
-                            Lang::English => "Year",
-                            Lang::Russian => "Год",
-                        },
+                    let mut inp3 = Input1::<Input>::new(
+                        lang.tr("set-return-date"),
+                        Date::format_hint(lang.date_format()).as_str(),
                     );
 
                     inp3.show();
@@ -364,238 +237,42 @@ fn give_book_known_reader_input(
                         if let Some(mes) = r4.recv() {
                             if mes {
                                 if let Ok(dat) = inp3.set_input(lang) {
-                                    return match unsafe {
-                                        dat.get_unchecked(0).trim().parse::<u8>()
-                                    } {
-                                        Ok(day) => {
-                                            match unsafe {
-                                                dat.get_unchecked(1).trim().parse::<u8>()
-                                            } {
-                                                Ok(month) => {
-                                                    match unsafe {
-                                                        dat.get_unchecked(2).trim().parse::<u16>()
-                                                    } {
-                                                        Ok(year) => {
-                                                            match Date::new(day, month, year) {
-                                                                Err(_) => {
-                                                                    alert(
-                                                                        500,
-                                                                        500,
-                                                                        match lang {
-                                                                            Lang::English => "Incorrect return date",
-                                                                            Lang::Russian => "Некорректная дата возврата",
-                                                                        },
-                                                                    );
-                                                                    caretaker.pop().unwrap();
-                                                                    None
-                                                                }
-
-                                                                Ok(date) => {
-                                                                    if date
-                                                                        < Date::from(
-                                                                            chrono::Local::now(),
-                                                                        )
-                                                                    {
-                                                                        alert(
-                                                                            500,
-                                                                            500,
-                                                                            match lang {
-                                                                                Lang::English =>
-                                                                                    concat!("The deadline must be no later",
-                                                                                    " than the day of issue"),
-                                                                                Lang::Russian =>
-                                                                                    concat!("Срок Сдачи должен быть не",
-                                                                                    " позднее дня выдачи"),
-                                                                            });
-                                                                        return None;
-                                                                    }
-
-                                                                    let simple_book = unsafe {
-                                                                        (*book_system
-                                                                            .books
-                                                                            .get_unchecked(bind))
-                                                                        .borrow_mut()
-                                                                        .get_unused()
-                                                                    };
-
-                                                                    match simple_book {
-                                                                        None => {
-                                                                            alert(
-                                                                                500,
-                                                                                500,
-                                                                                match lang {
-                                                                                    Lang::English => "There are no free books",
-                                                                                    Lang::Russian =>
-                                                                                        "Свободных книг не осталось",
-                                                                                },
-                                                                            );
-                                                                            caretaker
-                                                                                .pop()
-                                                                                .unwrap();
-                                                                            None
-                                                                        }
-
-                                                                        Some(sim) => {
-                                                                            if unsafe {
-                                                                                (**reader_base
-                                                                                    .readers
-                                                                                    .get_unchecked(
-                                                                                        rind,
-                                                                                    ))
-                                                                                .borrow()
-                                                                                .reading
-                                                                                .is_some()
-                                                                            } {
-                                                                                alert(
-                                                                                    500,
-                                                                                    500,
-                                                                                    match lang {
-                                                                                        Lang::English => concat!(
-                                                                                        "This reader is already",
-                                                                                        " reading book"),
-                                                                                        Lang::Russian => concat!("Этот читатель",
-                                                                                        " уже читает книгу"),
-                                                                                    },
-                                                                                );
-
-                                                                                caretaker
-                                                                                    .pop()
-                                                                                    .unwrap();
-                                                                                return None;
-                                                                            }
-
-                                                                            unsafe {
-                                                                                if (*reader_base.readers.get_unchecked(rind))
-                                                                                    .borrow_mut()
-                                                                                    .start_reading(
-                                                                                        (*book_system.books
-                                                                                                     .get_unchecked(bind))
-                                                                                            .borrow_mut()
-                                                                                            .books
-                                                                                            .get_unchecked(sim),
-                                                                                        &date
-                                                                                    ).is_err() {
-                                                                                    alert(
-                                                                                        500,
-                                                                                        500,
-                                                                                        match lang {
-                                                                                            Lang::English =>
-                                                                                                concat!("Reader is already reading",
-                                                                                                " book with same params"),
-                                                                                            Lang::Russian =>
-                                                                                                concat!("Читатель уже читает книгу с",
-                                                                                                " такими параметрами"),
-                                                                                        },
-                                                                                    );
-                                                                                    caretaker.pop().unwrap();
-                                                                                    return None;
-                                                                                }
-
-                                                                                (*(*book_system.books.get_unchecked(bind))
-                                                                                    .borrow_mut()
-                                                                                    .books
-                                                                                    .get_unchecked(sim))
-                                                                                    .borrow_mut()
-                                                                                    .start_reading(
-                                                                                        reader_base.readers
-                                                                                                   .get_unchecked(rind),
-                                                                                        date,
-                                                                                    );
-                                                                            }
-
-                                                                            fltk::dialog::message(
-                                                                                500,
-                                                                                500,
-                                                                                match lang {
-                                                                                    Lang::English =>
-                                                                                        "Book successfully given to reader",
-                                                                                    Lang::Russian =>
-                                                                                        "Книга успешно выдана читателю",
-                                                                                },
-                                                                            );
-
-                                                                            book_system.save();
-                                                                            reader_base.save();
-
-                                                                            Some(unsafe {
-                                                                                (*reader_base
-                                                                                    .readers
-                                                                                    .get_unchecked(
-                                                                                        rind,
-                                                                                    )
-                                                                                    .borrow()
-                                                                                    .reading
-                                                                                    .as_ref()
-                                                                                    .unwrap()
-                                                                                    .first()
-                                                                                    .unwrap()
-                                                                                    .upgrade()
-                                                                                    .unwrap())
-                                                                                .borrow()
-                                                                                .to_string(
-                                                                                    book_system,
-                                                                                )
-                                                                            })
-                                                                        }
-                                                                    }
-                                                                }
-                                                            }
-                                                        }
-
-                                                        Err(_) => {
-                                                            alert(
-                                                                500,
-                                                                500,
-                                                                match lang {
-                                                                    Lang::English => {
-                                                                        "'Year' input error"
-                                                                    }
-                                                                    Lang::Russian => {
-                                                                        "Ошибка ввода 'Года'"
-                                                                    }
-                                                                },
-                                                            );
-                                                            caretaker.pop().unwrap();
-                                                            None
-                                                        }
-                                                    }
-                                                }
-
-                                                Err(_) => {
-                                                    alert(
-                                                        500,
-                                                        500,
-                                                        match lang {
-                                                            Lang::English => "'Month' input error",
-                                                            Lang::Russian => {
-                                                                "Ошибка ввода 'Месяца'"
-                                                            }
-                                                        },
-                                                    );
-                                                    caretaker.pop().unwrap();
-                                                    None
-                                                }
-                                            }
-                                        }
-
+                                    return match Date::parse_localized(
+                                        unsafe { dat.get_unchecked(0) },
+                                        lang,
+                                    ) {
                                         Err(_) => {
-                                            alert(
-                                                500,
-                                                500,
-                                                match lang {
-                                                    Lang::English => "'Day' input error",
-                                                    Lang::Russian => "Ошибка ввода 'Дня'",
-                                                },
-                                            );
-                                            caretaker.pop().unwrap();
+                                            alert(500, 500, lang.tr("incorrect-return-date"));
                                             None
                                         }
+
+                                        Ok(date) => match library.give_book(rind, bind, date) {
+                                            Ok(s_ind) => {
+                                                fltk::dialog::message(
+                                                    500,
+                                                    500,
+                                                    moved_due_date(
+                                                        "book-successfully-given-to-reader",
+                                                        date,
+                                                        library,
+                                                        lang,
+                                                    )
+                                                    .as_str(),
+                                                );
+
+                                                Some(given_book(bind, s_ind, library))
+                                            }
+
+                                            Err(err) => {
+                                                alert(500, 500, err.message(lang));
+                                                None
+                                            }
+                                        },
                                     };
                                 }
                             }
                             break;
                         } else if !inp3.shown() {
-                            caretaker.pop().unwrap();
                             return None;
                         }
                     }
@@ -603,7 +280,6 @@ fn give_book_known_reader_input(
             }
             break;
         } else if !inp2.shown() {
-            caretaker.pop().unwrap();
             return None;
         }
     }
@@ -628,16 +304,11 @@ pub(crate) fn get_book_known_reader_no_input(
     title: &String,
     author: &String,
     pages: u16,
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     lang: Lang,
 ) -> bool {
-    return match reader_base.get_book(rind) {
+    return match library.reader_base().get_book(rind) {
         Some(books) => {
-            caretaker.add_memento(reader_base, book_system, genres);
-
             let book = {
                 match books.iter().position(|b| {
                     (*b.upgrade().unwrap()).borrow().title() == *title
@@ -648,10 +319,7 @@ pub(crate) fn get_book_known_reader_no_input(
                         alert(
                             500,
                             500,
-                            match lang {
-                                Lang::English => "This reader wasn't reading searching book",
-                                Lang::Russian => "Этот читатель не читает искомую книгу",
-                            },
+                            lang.tr("this-reader-wasnt-reading-searching-book"),
                         );
 
                         return false;
@@ -661,7 +329,8 @@ pub(crate) fn get_book_known_reader_no_input(
                 }
             };
 
-            let bind = book_system
+            let bind = library
+                .book_system()
                 .iter()
                 .position(|b| {
                     &*(**b).borrow() as *const _
@@ -676,93 +345,64 @@ pub(crate) fn get_book_known_reader_no_input(
                 })
                 .unwrap();
 
-            let sim;
-
-            unsafe {
-                sim = (*book_system.books.get_unchecked(bind))
-                    .borrow_mut()
-                    .find_by_reader(reader_base.readers.get_unchecked(rind))
-                    .unwrap();
-
-                (*reader_base.readers.get_unchecked_mut(rind))
-                    .borrow_mut()
-                    .finish_reading(&Rc::downgrade(
-                        &(*(*book_system.books.get_unchecked(bind))
-                            .borrow_mut()
-                            .books
-                            .get_unchecked(sim)),
-                    ));
-
-                match (*(*book_system.books.get_unchecked(bind))
-                    .borrow_mut()
-                    .books
-                    .get_unchecked(sim))
-                .borrow_mut()
-                .finish_reading()
-                {
-                    Ok(_) => fltk::dialog::message(
-                        500,
-                        500,
-                        match lang {
-                            Lang::English => "Book is returned",
-                            Lang::Russian => "Книга возвращена",
-                        },
-                    ),
+            return_book_simple(rind, bind, library, lang)
+        }
 
-                    Err(_) => fltk::dialog::message(
-                        500,
-                        500,
-                        match lang {
-                            Lang::English => "Book is returned after deadline",
-                            Lang::Russian => "Книга возвращена после Срок Сдачиа",
-                        },
-                    ),
-                }
-            }
+        None => {
+            alert(
+                500,
+                500,
+                lang.tr("this-reader-wasnt-reading-searching-book"),
+            );
 
-            let cab = unsafe {
-                (*(*book_system.books.get_unchecked(bind))
-                    .borrow()
-                    .books
-                    .get_unchecked(sim))
-                .borrow()
-                .cabinet
-            };
+            false
+        }
+    };
+}
 
-            let shelf = unsafe {
-                (*(*book_system.books.get_unchecked(bind))
-                    .borrow()
-                    .books
-                    .get_unchecked(sim))
-                .borrow()
-                .shelf
-            };
+/// Function that returns book
+/// of the book from known reader
+/// and shows where it must be placed
+
+#[inline]
+fn return_book_simple(rind: usize, bind: usize, library: &mut Library, lang: Lang) -> bool {
+    let location = unsafe {
+        let the_book = (**library.book_system().books.get_unchecked(bind)).borrow();
+
+        the_book
+            .find_by_reader(library.reader_base().readers.get_unchecked(rind))
+            .map(|sim| {
+                let book = (**the_book.books.get_unchecked(sim)).borrow();
+                (book.cabinet, book.shelf)
+            })
+    };
 
+    return match library.return_book(rind, bind) {
+        Ok(is_late) => {
             fltk::dialog::message(
                 500,
                 500,
-                match lang {
-                    Lang::English => format!("Book location: cabinet {}, shelf {}", cab, shelf),
-                    Lang::Russian => format!("Расположение книги: шкаф {}, полка {}", cab, shelf),
-                }
-                .as_str(),
+                if is_late {
+                    lang.tr("book-is-returned-after-deadline")
+                } else {
+                    lang.tr("book-is-returned")
+                },
             );
 
-            book_system.save();
-            reader_base.save();
-            true
-        }
+            let (cab, shelf) = location.unwrap();
 
-        None => {
-            alert(
+            fltk::dialog::message(
                 500,
                 500,
-                match lang {
-                    Lang::English => "This reader wasn't reading searching book",
-                    Lang::Russian => "Этот читатель не читает искомую книгу",
-                },
+                lang.format("book-location", &[("cabinet", &cab), ("shelf", &shelf)])
+                    .as_str(),
             );
 
+            true
+        }
+
+        Err(err) => {
+            alert(500, 500, err.message(lang));
             false
         }
     };
@@ -772,14 +412,11 @@ pub(crate) fn get_book_known_reader_no_input(
 
 pub(crate) fn get_book_known_reader(
     rind: usize,
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     app: &App,
     lang: Lang,
 ) -> bool {
-    match all_genres(genres, book_system, app, lang) {
+    match all_genres(library.genres(), library.book_system(), app, lang) {
         Some(book) => {
             let label = book.label().unwrap();
             let book = label
@@ -790,148 +427,28 @@ pub(crate) fn get_book_known_reader(
                 .collect::<Vec<_>>();
 
             if book.len() != 3 {
-                alert(
-                    500,
-                    500,
-                    match lang {
-                        Lang::English => "Book isn't selected",
-                        Lang::Russian => "Книга не выбрана",
-                    },
-                );
+                alert(500, 500, lang.tr("book-isnt-selected"));
                 return false;
             }
 
             let mut book = book.into_iter();
 
-            match book_system.find_book(
+            match library.book_system().find_book(
                 &book.next().unwrap(),
                 &book.next().unwrap(),
                 book.next().unwrap().parse().unwrap(),
             ) {
-                Some(bind) => {
-                    caretaker.add_memento(reader_base, book_system, genres);
-
-                    match unsafe {
-                        let check = (*book_system.books.get_unchecked(bind))
-                            .borrow_mut()
-                            .find_by_reader(reader_base.readers.get_unchecked(rind));
-                        check
-                    } {
-                        None => {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "This reader wasn't reading searching book",
-
-                                    Lang::Russian => "Этот читатель не читает искомую книгу",
-                                },
-                            );
-                            caretaker.pop().unwrap();
-                            false
-                        }
-
-                        Some(sim) => {
-                            unsafe {
-                                (*reader_base.readers.get_unchecked_mut(rind))
-                                    .borrow_mut()
-                                    .finish_reading(&Rc::downgrade(
-                                        &(*(*book_system.books.get_unchecked(bind))
-                                            .borrow()
-                                            .books
-                                            .get_unchecked(sim)),
-                                    ));
-                            }
-
-                            match unsafe {
-                                (*(*book_system.books.get_unchecked(bind))
-                                    .borrow()
-                                    .books
-                                    .get_unchecked(sim))
-                                .borrow_mut()
-                                .finish_reading()
-                            } {
-                                Ok(_) => fltk::dialog::message(
-                                    500,
-                                    500,
-                                    match lang {
-                                        Lang::English => "Book is returned",
-                                        Lang::Russian => "Книга возвращена",
-                                    },
-                                ),
-
-                                Err(_) => fltk::dialog::message(
-                                    500,
-                                    500,
-                                    match lang {
-                                        Lang::English => "Book is returned after deadline",
-                                        Lang::Russian => "Книга возвращена после срока сдачи",
-                                    },
-                                ),
-                            }
-
-                            let cab = unsafe {
-                                (*(*book_system.books.get_unchecked(bind))
-                                    .borrow()
-                                    .books
-                                    .get_unchecked(sim))
-                                .borrow()
-                                .cabinet
-                            };
-
-                            let shelf = unsafe {
-                                (*(*book_system.books.get_unchecked(bind))
-                                    .borrow()
-                                    .books
-                                    .get_unchecked(sim))
-                                .borrow()
-                                .shelf
-                            };
-
-                            fltk::dialog::message(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => {
-                                        format!("Book location: cabinet {}, shelf {}", cab, shelf)
-                                    }
-                                    Lang::Russian => {
-                                        format!("Расположение книги: шкаф {}, полка {}", cab, shelf)
-                                    }
-                                }
-                                .as_str(),
-                            );
-
-                            book_system.save();
-                            reader_base.save();
-                            true
-                        }
-                    }
-                }
+                Some(bind) => return_book_simple(rind, bind, library, lang),
 
                 None => {
-                    alert(
-                        500,
-                        500,
-                        match lang {
-                            Lang::English => "Book isn't selected",
-                            Lang::Russian => "Книга не выбрана",
-                        },
-                    );
+                    alert(500, 500, lang.tr("book-isnt-selected"));
                     return false;
                 }
             }
         }
 
         None => {
-            alert(
-                500,
-                500,
-                match lang {
-                    Lang::English => "Book isn't selected",
-                    Lang::Russian => "Книга не выбрана",
-                },
-            );
+            alert(500, 500, lang.tr("book-isnt-selected"));
             return false;
         }
     }
@@ -948,35 +465,18 @@ pub(crate) fn get_book_known_reader(
 #[deprecated(note = "Used before, requires input. Consider using action_books() instead")]
 pub(crate) fn get_book_known_reader_input(
     rind: usize,
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     app: &App,
     lang: Lang,
 ) -> bool {
     let (s3, r3) = fltk::app::channel();
     let mut inp2 = Input3::<Input, Input, IntInput>::new(
-        match lang {
-            Lang::English => "Find Book",
-            Lang::Russian => "Поиск Книги",
-        },
-        match lang {
-            Lang::English => "Title",
-            Lang::Russian => "Название книги",
-        },
-        match lang {
-            Lang::English => "Author",
-            Lang::Russian => "Автор",
-        },
-        match lang {
-            Lang::English => "Amount of Pages",
-            Lang::Russian => "Кол-во страниц",
-        },
+        lang.tr("find-book-2"),
+        lang.tr("title-2"),
+        lang.tr("author"),
+        lang.tr("amount-of-pages"),
     );
 
-    caretaker.add_memento(reader_base, book_system, genres);
-
     inp2.show();
     (*inp2.ok).borrow_mut().emit(s3, true);
 
@@ -986,120 +486,14 @@ pub(crate) fn get_book_known_reader_input(
                 inp2.hide();
 
                 if let Ok(book) = inp2.set_input(lang) {
-                    return match check_book(book_system, &book, lang) {
-                        Ok(bind) => {
-                            match unsafe {
-                                let check = (*book_system.books.get_unchecked(bind))
-                                    .borrow_mut()
-                                    .find_by_reader(reader_base.readers.get_unchecked(rind));
-                                check
-                            } {
-                                None => {
-                                    alert(
-                                        500,
-                                        500,
-                                        match lang {
-                                            Lang::English => {
-                                                "This reader wasn't reading searching book"
-                                            }
-
-                                            Lang::Russian => {
-                                                "Этот читатель не читает искомую книгу"
-                                            }
-                                        },
-                                    );
-                                    caretaker.pop().unwrap();
-                                    false
-                                }
-
-                                Some(sim) => {
-                                    unsafe {
-                                        (*reader_base.readers.get_unchecked_mut(rind))
-                                            .borrow_mut()
-                                            .finish_reading(&Rc::downgrade(
-                                                &(*(*book_system.books.get_unchecked(bind))
-                                                    .borrow()
-                                                    .books
-                                                    .get_unchecked(sim)),
-                                            ));
-                                    }
-
-                                    match unsafe {
-                                        (*(*book_system.books.get_unchecked(bind))
-                                            .borrow()
-                                            .books
-                                            .get_unchecked(sim))
-                                        .borrow_mut()
-                                        .finish_reading()
-                                    } {
-                                        Ok(_) => fltk::dialog::message(
-                                            500,
-                                            500,
-                                            match lang {
-                                                Lang::English => "Book is returned",
-                                                Lang::Russian => "Книга возвращена",
-                                            },
-                                        ),
-
-                                        Err(_) => fltk::dialog::message(
-                                            500,
-                                            500,
-                                            match lang {
-                                                Lang::English => "Book is returned after deadline",
-                                                Lang::Russian => {
-                                                    "Книга возвращена после срока сдачи"
-                                                }
-                                            },
-                                        ),
-                                    }
-
-                                    let cab = unsafe {
-                                        (*(*book_system.books.get_unchecked(bind))
-                                            .borrow()
-                                            .books
-                                            .get_unchecked(sim))
-                                        .borrow()
-                                        .cabinet
-                                    };
-
-                                    let shelf = unsafe {
-                                        (*(*book_system.books.get_unchecked(bind))
-                                            .borrow()
-                                            .books
-                                            .get_unchecked(sim))
-                                        .borrow()
-                                        .shelf
-                                    };
-
-                                    fltk::dialog::message(
-                                        500,
-                                        500,
-                                        match lang {
-                                            Lang::English => format!(
-                                                "Book location: cabinet {}, shelf {}",
-                                                cab, shelf
-                                            ),
-                                            Lang::Russian => format!(
-                                                "Расположение книги: шкаф {}, полка {}",
-                                                cab, shelf
-                                            ),
-                                        }
-                                        .as_str(),
-                                    );
-
-                                    book_system.save();
-                                    reader_base.save();
-                                    true
-                                }
-                            }
-                        }
+                    return match check_book(library.book_system(), &book, lang) {
+                        Ok(bind) => return_book_simple(rind, bind, library, lang),
                         Err(_) => false,
                     };
                 }
             }
             break;
         } else if !inp2.shown() {
-            caretaker.pop().unwrap();
             return false;
         }
     }
@@ -1114,35 +508,18 @@ pub(crate) fn get_book_known_reader_input(
 #[inline]
 pub fn change_return_date_simple(
     rind: usize,
-    book_system: &mut BookSystem,
-    reader_base: &ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     app: &App,
     lang: Lang,
 ) -> bool {
     let (s, r) = fltk::app::channel();
     let mut inp2 = Input3::<Input, Input, IntInput>::new(
-        match lang {
-            Lang::English => "Find Book",
-            Lang::Russian => "Поиск Книги",
-        },
-        match lang {
-            Lang::English => "Title",
-            Lang::Russian => "Название",
-        },
-        match lang {
-            Lang::English => "Author",
-            Lang::Russian => "Автор",
-        },
-        match lang {
-            Lang::English => "Amount of Pages",
-            Lang::Russian => "Кол-во страниц",
-        },
+        lang.tr("find-book-2"),
+        lang.tr("title"),
+        lang.tr("author"),
+        lang.tr("amount-of-pages"),
     );
 
-    caretaker.add_memento(reader_base, book_system, genres);
-
     inp2.show();
     (*inp2.ok).borrow_mut().emit(s, true);
 
@@ -1154,72 +531,45 @@ pub fn change_return_date_simple(
                 if let Ok(book) = inp2.set_input(lang) {
                     return match unsafe { book.get_unchecked(2).trim().parse::<u16>() } {
                         Err(_) => {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "'Amount of Pages' input error",
-                                    Lang::Russian => "Этот читатель ничего не читает",
-                                },
-                            );
+                            alert(500, 500, lang.tr("amount-of-pages-input-error-2"));
 
-                            caretaker.pop().unwrap();
                             false
                         }
 
                         Ok(pages) => {
-                            match unsafe {
-                                &(**reader_base.readers.get_unchecked(rind)).borrow().reading
-                            } {
+                            let book_op = unsafe {
+                                (**library.reader_base().readers.get_unchecked(rind))
+                                    .borrow()
+                                    .reading
+                                    .as_ref()
+                                    .map(|books| {
+                                        books
+                                            .iter()
+                                            .find(|b| {
+                                                (*b.upgrade().unwrap()).borrow().title()
+                                                    == book.get_unchecked(0).trim()
+                                                    && (*b.upgrade().unwrap()).borrow().author()
+                                                        == book.get_unchecked(1).trim()
+                                                    && (*b.upgrade().unwrap()).borrow().pages()
+                                                        == pages
+                                            })
+                                            .cloned()
+                                    })
+                            };
+
+                            match book_op {
                                 None => {
-                                    alert(
-                                        500,
-                                        500,
-                                        match lang {
-                                            Lang::English => "This reader isn't reading anything",
-                                            Lang::Russian => "Этот читатель ничего не читает",
-                                        },
-                                    );
-                                    caretaker.pop().unwrap();
+                                    alert(500, 500, lang.tr("this-reader-isnt-reading-anything"));
                                     false
                                 }
 
-                                Some(books) => {
-                                    match books.iter().position(|b| {
-                                        (*b.upgrade().unwrap()).borrow().title()
-                                            == *unsafe { book.get_unchecked(0).trim().to_string() }
-                                            && (*b.upgrade().unwrap()).borrow().author()
-                                                == *unsafe {
-                                                    book.get_unchecked(1).trim().to_string()
-                                                }
-                                            && (*b.upgrade().unwrap()).borrow().pages() == pages
-                                    }) {
-                                        None => {
-                                            alert(
-                                                500,
-                                                500,
-                                                match lang {
-                                                    Lang::English => {
-                                                        "This reader isn't reading this book"
-                                                    }
-                                                    Lang::Russian => {
-                                                        "Этот читатель не читает искомую книгу"
-                                                    }
-                                                },
-                                            );
-                                            caretaker.pop().unwrap();
-                                            false
-                                        }
+                                Some(None) => {
+                                    alert(500, 500, lang.tr("this-reader-isnt-reading-this-book"));
+                                    false
+                                }
 
-                                        Some(ind) => change_return_date_known_book(
-                                            unsafe { books.get_unchecked(ind) },
-                                            book_system,
-                                            reader_base,
-                                            genres,
-                                            caretaker,
-                                            lang,
-                                        ),
-                                    }
+                                Some(Some(book)) => {
+                                    change_return_date_known_book(&book, library, lang)
                                 }
                             }
                         }
@@ -1227,7 +577,6 @@ pub fn change_return_date_simple(
                 }
             }
         } else if !inp2.shown() {
-            caretaker.pop().unwrap();
             return false;
         }
     }
@@ -1237,41 +586,27 @@ pub fn change_return_date_simple(
 
 /// Changes return date for
 /// first book to return for reader
-/// (for main table)
+/// (the one with the earliest return date, for main table)
 
 #[inline]
-pub fn change_return_date_first_book(
-    rind: usize,
-    book_system: &mut BookSystem,
-    reader_base: &ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    lang: Lang,
-) -> bool {
-    unsafe {
-        if (**reader_base.readers.get_unchecked(rind))
+pub fn change_return_date_first_book(rind: usize, library: &mut Library, lang: Lang) -> bool {
+    let book_op = unsafe {
+        (**library.reader_base().readers.get_unchecked(rind))
             .borrow()
             .reading
-            .is_some()
-        {
-            change_return_date_known_book(
-                (**reader_base.readers.get_unchecked(rind))
-                    .borrow()
-                    .reading
-                    .as_ref()
-                    .unwrap()
-                    .first()
-                    .unwrap(),
-                book_system,
-                reader_base,
-                genres,
-                caretaker,
-                lang,
-            )
-        } else {
-            false
-        }
-    }
+            .as_ref()
+            .and_then(|books| {
+                books
+                    .iter()
+                    .min_by_key(|b| ((*b.upgrade().unwrap()).borrow().readers.last().unwrap().1).1)
+                    .cloned()
+            })
+    };
+
+    return match book_op {
+        Some(book) => change_return_date_known_book(&book, library, lang),
+        None => false,
+    };
 }
 
 /// Function that changes
@@ -1280,112 +615,38 @@ pub fn change_return_date_first_book(
 #[inline]
 pub(crate) fn change_return_date_known_book(
     book_op: &Weak<RefCell<Book>>,
-    book_system: &mut BookSystem,
-    reader_base: &ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     lang: Lang,
 ) -> bool {
-    caretaker.add_memento(reader_base, book_system, genres);
-
-    return match Calendar::default().get_date() {
+    return match Calendar::default().get_date().and_then(Date::from_naive) {
         None => {
-            alert(
-                500,
-                500,
-                match lang {
-                    Lang::English => "Date wasn't selected",
-                    Lang::Russian => "Дата не была выбрана",
-                },
-            );
+            alert(500, 500, lang.tr("date-wasnt-selected"));
             false
         }
 
         Some(date) => {
-            let new_date = Date {
-                day: date.day() as u8,
-                month: date.month() as u8,
-                year: date.year() as u16,
-            };
+            let (ind, s_ind) = library
+                .book_system()
+                .find_simple_book(&book_op.upgrade().unwrap())
+                .unwrap();
 
-            let start = ((*(*book_op.upgrade().unwrap())
-                .borrow_mut()
-                .readers
-                .last_mut()
-                .unwrap())
-            .1)
-                .0;
-
-            if new_date >= start && new_date >= Date::from(Local::now()) {
-                ((*(*book_op.upgrade().unwrap())
-                    .borrow_mut()
-                    .readers
-                    .last_mut()
-                    .unwrap())
-                .1)
-                    .1 = new_date;
-
-                unsafe {
-                    (*(*((*book_op.upgrade().unwrap())
-                        .borrow_mut()
-                        .readers
-                        .last_mut()
-                        .unwrap()
-                        .0)
-                        .upgrade()
-                        .unwrap())
-                    .as_ptr())
-                    .reading
-                    .as_mut()
-                    .unwrap()
-                    .sort_unstable_by(|f, s| {
-                        ((*f.upgrade().unwrap()).borrow().readers.last().unwrap().1)
-                            .1
-                            .cmp(
-                                &((*(*s.upgrade().unwrap()).as_ptr())
-                                    .readers
-                                    .last()
-                                    .unwrap()
-                                    .1)
-                                    .1,
-                            )
-                    });
-                }
+            match library.change_return_date(ind, s_ind, date) {
+                Ok(_) => {
+                    fltk::dialog::message(
+                        500,
+                        500,
+                        moved_due_date("date-is-successfully-changed", date, library, lang)
+                            .as_str(),
+                    );
 
-                fltk::dialog::message(
-                    500,
-                    500,
-                    match lang {
-                        Lang::English => "Date is successfully changed",
-                        Lang::Russian => "Дата успешно изменена",
-                    },
-                );
-
-                book_system.save();
-                true
-            } else {
-                alert(
-                    500,
-                    500,
-                    match lang {
-                        Lang::English => {
-                            concat!(
-                                "Date can only be not earlier than deadline",
-                                " and not earlier than today"
-                            )
-                        }
+                    true
+                }
 
-                        Lang::Russian => {
-                            concat!(
-                                "Дата обязана быть не позже Срок Сдачиа",
-                                " и не позже сегоднешней даты"
-                            )
-                        }
-                    },
-                );
+                Err(err) => {
+                    alert(500, 500, err.message(lang));
 
-                caretaker.pop().unwrap();
-                false
+                    false
+                }
             }
         }
     };
@@ -1405,49 +666,22 @@ pub(crate) fn change_return_date_known_book(
 )]
 fn change_return_date_simple_input(
     book_op: &Option<Weak<RefCell<Book>>>,
-    book_system: &mut BookSystem,
-    reader_base: &ReaderBase,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     app: &App,
     lang: Lang,
 ) -> bool {
-    caretaker.add_memento(reader_base, book_system, genres);
-
     return match book_op {
         None => {
-            alert(
-                500,
-                500,
-                match lang {
-                    Lang::English => "This reader isn't reading anything",
-                    Lang::Russian => "Этот читатель ни читает книгу",
-                },
-            );
+            alert(500, 500, lang.tr("this-reader-isnt-reading-anything"));
 
-            caretaker.pop().unwrap();
             false
         }
 
         Some(book) => {
             let (s2, r2) = fltk::app::channel();
-            let mut inp = Input3::<IntInput, IntInput, IntInput>::new(
-                match lang {
-                    Lang::English => "Set New Date",
-                    Lang::Russian => "Изменить дату",
-                },
-                match lang {
-                    Lang::English => "Day (number)",
-                    Lang::Russian => "День (номер)",
-                },
-                match lang {
-                    Lang::English => "Month (number)",
-                    Lang::Russian => "Месяц (номер)",
-                },
-                match lang {
-                    Lang::English => "Year",
-                    Lang::Russian => "Год",
-                },
+            let mut inp = Input1::<Input>::new(
+                lang.tr("set-new-date"),
+                Date::format_hint(lang.date_format()).as_str(),
             );
 
             inp.show();
@@ -1459,132 +693,43 @@ fn change_return_date_simple_input(
                         inp.hide();
 
                         if let Ok(date) = inp.set_input(lang) {
-                            return match unsafe { date.get_unchecked(0).trim().parse::<u8>() } {
-                                Ok(day) => match unsafe {
-                                    date.get_unchecked(1).trim().parse::<u8>()
-                                } {
-                                    Ok(month) => {
-                                        match unsafe { date.get_unchecked(2).trim().parse::<u16>() }
-                                        {
-                                            Ok(year) => {
-                                                match Date::new(day, month, year) {
-                                                    Ok(new_date) => {
-                                                        let start = ((*(*book.upgrade().unwrap())
-                                                            .borrow_mut()
-                                                            .readers
-                                                            .last_mut()
-                                                            .unwrap())
-                                                        .1)
-                                                            .0;
-
-                                                        if new_date >= start
-                                                            && new_date >= Date::from(Local::now())
-                                                        {
-                                                            ((*(*book.upgrade().unwrap())
-                                                                .borrow_mut()
-                                                                .readers
-                                                                .last_mut()
-                                                                .unwrap())
-                                                            .1)
-                                                                .1 = new_date;
-
-                                                            fltk::dialog::message(
-                                                                500,
-                                                                500,
-                                                                match lang {
-                                                                    Lang::English => "Date is successfully changed",
-                                                                    Lang::Russian => "Дата успешно изменена",
-                                                                }
-                                                            );
-
-                                                            book_system.save();
-                                                            true
-                                                        } else {
-                                                            alert(
-                                                                500,
-                                                                500,
-                                                                match lang {
-                                                                    Lang::English => {
-                                                                        concat!(
-                                                                        "Date can only be not earlier than deadline",
-                                                                        " and not earlier than today"
-                                                                        )
-                                                                    }
-
-                                                                    Lang::Russian => {
-                                                                        concat!(
-                                                                        "Дата обязана быть не позже Срок Сдачиа",
-                                                                        " и не позже сегоднешней даты"
-                                                                        )
-                                                                    }
-                                                                },
-                                                            );
-
-                                                            caretaker.pop().unwrap();
-                                                            false
-                                                        }
-                                                    }
-
-                                                    Err(_) => {
-                                                        alert(
-                                                            500,
-                                                            500,
-                                                            match lang {
-                                                                Lang::English => "Incorrect date",
-                                                                Lang::Russian => {
-                                                                    "Некорректная дата"
-                                                                }
-                                                            },
-                                                        );
-
-                                                        caretaker.pop().unwrap();
-                                                        false
-                                                    }
-                                                }
-                                            }
-
-                                            Err(_) => {
-                                                alert(
-                                                    500,
-                                                    500,
-                                                    match lang {
-                                                        Lang::English => "Incorrect 'Year' input",
-                                                        Lang::Russian => "Некорректный ввод 'Года'",
-                                                    },
-                                                );
+                            return match Date::parse_localized(
+                                unsafe { date.get_unchecked(0) },
+                                lang,
+                            ) {
+                                Ok(new_date) => {
+                                    let (ind, s_ind) = library
+                                        .book_system()
+                                        .find_simple_book(&book.upgrade().unwrap())
+                                        .unwrap();
+
+                                    match library.change_return_date(ind, s_ind, new_date) {
+                                        Ok(_) => {
+                                            fltk::dialog::message(
+                                                500,
+                                                500,
+                                                moved_due_date(
+                                                    "date-is-successfully-changed",
+                                                    new_date,
+                                                    library,
+                                                    lang,
+                                                )
+                                                .as_str(),
+                                            );
 
-                                                caretaker.pop().unwrap();
-                                                false
-                                            }
+                                            true
                                         }
-                                    }
 
-                                    Err(_) => {
-                                        alert(
-                                            500,
-                                            500,
-                                            match lang {
-                                                Lang::English => "Incorrect 'Month' input",
-                                                Lang::Russian => "Некорректный ввод 'Месяца'",
-                                            },
-                                        );
-
-                                        caretaker.pop().unwrap();
-                                        false
+                                        Err(err) => {
+                                            alert(500, 500, err.message(lang));
+                                            false
+                                        }
                                     }
-                                },
+                                }
 
                                 Err(_) => {
-                                    alert(
-                                        500,
-                                        500,
-                                        match lang {
-                                            Lang::English => "Incorrect 'Day' input",
-                                            Lang::Russian => "Некорректный ввод 'Дня'",
-                                        },
-                                    );
+                                    alert(500, 500, lang.tr("incorrect-date"));
 
-                                    caretaker.pop().unwrap();
                                     false
                                 }
                             };
@@ -1592,7 +737,6 @@ fn change_return_date_simple_input(
                     }
                     break;
                 } else if !inp.shown() {
-                    caretaker.pop().unwrap();
                     return false;
                 }
             }
@@ -1601,3 +745,22 @@ fn change_return_date_simple_input(
         }
     };
 }
+
+/// Message about successful giving or changing of return date.
+/// If library is closed on chosen date,
+/// user is told that it's moved to the next open day
+
+#[inline]
+fn moved_due_date(message: &'static str, date: Date, library: &Library, lang: Lang) -> String {
+    let due = library.calendar().due_date(date);
+
+    match due == date {
+        true => lang.tr(message).to_string(),
+
+        false => format!(
+            "{}\n{}",
+            lang.tr(message),
+            lang.format("due-date-is-moved", &[("date", &due.localized(lang))])
+        ),
+    }
+}
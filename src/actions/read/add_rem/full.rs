@@ -11,10 +11,9 @@ use fltk::{
 
 use crate::{
     actions::read::{add_rem::simple::*, utils::*},
-    books::{book_sys::BookSystem, date::Date, genres::Genres},
+    books::date::Date,
     change::{input3::Input3, input4::Input4, Inputable},
-    reading::read_base::ReaderBase,
-    restore::caretaker::Caretaker,
+    library::Library,
     Lang,
 };
 
@@ -26,41 +25,17 @@ use std::cmp::min;
 /// program will let you know
 
 #[inline]
-pub fn add_reader(
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+pub fn add_reader(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = app::channel();
 
     let mut inp = Input4::<Input, Input, Input, MultilineInput>::new(
-        match lang {
-            Lang::English => "Add Reader",
-            Lang::Russian => "Добавить читателя",
-        },
-        match lang {
-            Lang::English => "First Name",
-            Lang::Russian => "Имя",
-        },
-        match lang {
-            Lang::English => "Second Names",
-            Lang::Russian => "Фамилия",
-        },
-        match lang {
-            Lang::English => "Middle Name",
-            Lang::Russian => "Отчество",
-        },
-        match lang {
-            Lang::English => "Info (< 50 symb.)",
-            Lang::Russian => "Инфо (< 50 симв.)",
-        },
+        lang.tr("add-reader"),
+        lang.tr("first-name"),
+        lang.tr("second-names"),
+        lang.tr("middle-name"),
+        lang.tr("info-50-symb"),
     );
 
-    caretaker.add_memento(reader_base, book_system, genres);
-
     inp.show();
     (*inp.ok).borrow_mut().emit(s2, true);
 
@@ -71,7 +46,6 @@ pub fn add_reader(
 
                 if let Ok(reader) = inp.set_input(lang) {
                     if empty_inp_reader(&reader, lang) {
-                        caretaker.pop().unwrap();
                         return;
                     }
 
@@ -80,33 +54,12 @@ pub fn add_reader(
                         500,
                         200,
                         100,
-                        match lang {
-                            Lang::English => "Choose birth date",
-                            Lang::Russian => "Выберите дату рождения",
-                        },
+                        lang.tr("choose-birth-date"),
                     );
 
-                    let _ = fltk::frame::Frame::new(
-                        30,
-                        10,
-                        150,
-                        50,
-                        match lang {
-                            Lang::English => "Choose birth date",
-                            Lang::Russian => "Выберите дату рождения",
-                        },
-                    );
+                    let _ = fltk::frame::Frame::new(30, 10, 150, 50, lang.tr("choose-birth-date"));
 
-                    let mut but = fltk::button::Button::new(
-                        80,
-                        60,
-                        60,
-                        20,
-                        match lang {
-                            Lang::English => "OK",
-                            Lang::Russian => "ОК",
-                        },
-                    );
+                    let mut but = fltk::button::Button::new(80, 60, 60, 20, lang.tr("ok"));
 
                     win.end();
                     win.show();
@@ -119,9 +72,9 @@ pub fn add_reader(
                             if msg {
                                 win.hide();
 
-                                match Calendar::default().get_date() {
+                                match Calendar::default().get_date().and_then(Date::from_naive) {
                                     Some(date) => {
-                                        match reader_base.add_reader(
+                                        match library.add_reader(
                                             unsafe { reader.get_unchecked(0).trim().to_string() },
                                             unsafe { reader.get_unchecked(1).trim().to_string() },
                                             unsafe { reader.get_unchecked(2).trim().to_string() },
@@ -133,52 +86,31 @@ pub fn add_reader(
                                                     .into_iter()
                                                     .fold("".to_string(), |acc, s| acc + " " + s)
                                             },
-                                            Date::from(date),
+                                            date,
                                         ) {
                                             Ok(_) => {
                                                 fltk::dialog::message(
                                                     500,
                                                     500,
-                                                    match lang {
-                                                        Lang::English => "Successfully added",
-                                                        Lang::Russian => "Успешно добавлено",
-                                                    },
+                                                    lang.tr("successfully-added"),
                                                 );
-                                                reader_base.save();
                                             }
 
-                                            Err(_) => {
-                                                alert(
-                                                    500,
-                                                    500,
-                                                    match lang {
-                                                        Lang::English => "Reader with same parameters already exists",
-                                                        Lang::Russian => "Читатель с предложенными парамтрами уже существует",
-                                                    }
-                                                );
-                                                caretaker.pop().unwrap();
+                                            Err(err) => {
+                                                alert(500, 500, err.message(lang));
                                                 return;
                                             }
                                         }
                                     }
 
                                     None => {
-                                        alert(
-                                            500,
-                                            500,
-                                            match lang {
-                                                Lang::English => "Date wasn't selected",
-                                                Lang::Russian => "Дата не была выбрана",
-                                            },
-                                        );
-                                        caretaker.pop().unwrap();
+                                        alert(500, 500, lang.tr("date-wasnt-selected"));
                                         return;
                                     }
                                 }
                             }
                             break;
                         } else if !win.shown() {
-                            caretaker.pop().unwrap();
                             return;
                         }
                     }
@@ -186,7 +118,6 @@ pub fn add_reader(
             }
             break;
         } else if !inp.shown() {
-            caretaker.pop().unwrap();
             return;
         }
     }
@@ -197,33 +128,14 @@ pub fn add_reader(
 /// program will let you know
 
 #[inline]
-pub fn remove_reader(
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+pub fn remove_reader(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = app::channel();
 
     let mut inp = Input3::<Input, Input, Input>::new(
-        match lang {
-            Lang::English => "Remove Reader",
-            Lang::Russian => "Удалить читателя",
-        },
-        match lang {
-            Lang::English => "First Name",
-            Lang::Russian => "Имя",
-        },
-        match lang {
-            Lang::English => "Second Names",
-            Lang::Russian => "Фамилия",
-        },
-        match lang {
-            Lang::English => "Middle Name",
-            Lang::Russian => "Отчество",
-        },
+        lang.tr("remove-reader"),
+        lang.tr("first-name"),
+        lang.tr("second-names"),
+        lang.tr("middle-name"),
     );
 
     inp.show();
@@ -235,15 +147,8 @@ pub fn remove_reader(
                 inp.hide();
 
                 if let Ok(reader) = inp.set_input(lang) {
-                    if let Some(rind) = check_reader(reader_base, &reader, app, lang) {
-                        remove_reader_simple(
-                            rind,
-                            reader_base,
-                            book_system,
-                            genres,
-                            caretaker,
-                            lang,
-                        )
+                    if let Some(rind) = check_reader(library.reader_base(), &reader, app, lang) {
+                        remove_reader_simple(rind, library, lang)
                     }
                 }
             }
@@ -1,11 +1,6 @@
 extern crate fltk;
 
-use crate::{
-    books::{book_sys::BookSystem, genres::Genres},
-    reading::read_base::ReaderBase,
-    restore::caretaker::Caretaker,
-    Lang,
-};
+use crate::{library::Library, Lang};
 
 use fltk::dialog::alert;
 
@@ -13,42 +8,14 @@ use fltk::dialog::alert;
 /// already known reader
 
 #[inline]
-pub(crate) fn remove_reader_simple(
-    ind: usize,
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    lang: Lang,
-) {
-    caretaker.add_memento(reader_base, book_system, genres);
-
-    match reader_base.remove_reader(ind) {
+pub(crate) fn remove_reader_simple(ind: usize, library: &mut Library, lang: Lang) {
+    match library.remove_reader(ind) {
         Ok(_) => {
-            fltk::dialog::message(
-                500,
-                500,
-                match lang {
-                    Lang::English => "Successfully removed",
-                    Lang::Russian => "Успешно удалён",
-                },
-            );
-
-            reader_base.save();
-            book_system.save();
+            fltk::dialog::message(500, 500, lang.tr("successfully-removed-2"));
         }
 
-        Err(_) => {
-            alert(
-                500,
-                500,
-                match lang {
-                    Lang::English => "Reader is not found",
-                    Lang::Russian => "Читатель не найден",
-                },
-            );
-            caretaker.pop().unwrap();
-            return;
+        Err(err) => {
+            alert(500, 500, err.message(lang));
         }
     }
 }
@@ -2,10 +2,8 @@ extern crate fltk;
 
 use crate::{
     actions::read::{change::simple::*, utils::check_reader},
-    books::{book_sys::BookSystem, genres::Genres},
     change::{input3::Input3, Inputable},
-    reading::read_base::ReaderBase,
-    restore::caretaker::Caretaker,
+    library::Library,
     Lang,
 };
 
@@ -16,33 +14,14 @@ use fltk::{app, app::App, input::Input, prelude::*};
 /// program will let you know
 
 #[inline]
-pub fn change_name(
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+pub fn change_name(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = app::channel();
 
     let mut inp = Input3::<Input, Input, Input>::new(
-        match lang {
-            Lang::English => "Change Name",
-            Lang::Russian => "Изменить имя",
-        },
-        match lang {
-            Lang::English => "First Name",
-            Lang::Russian => "Имя",
-        },
-        match lang {
-            Lang::English => "Second Names",
-            Lang::Russian => "Фамилия",
-        },
-        match lang {
-            Lang::English => "Middle Name",
-            Lang::Russian => "Отчество",
-        },
+        lang.tr("change-name"),
+        lang.tr("first-name"),
+        lang.tr("second-names"),
+        lang.tr("middle-name"),
     );
 
     inp.show();
@@ -54,16 +33,8 @@ pub fn change_name(
                 inp.hide();
 
                 if let Ok(reader) = inp.set_input(lang) {
-                    if let Some(rind) = check_reader(reader_base, &reader, app, lang) {
-                        change_name_simple(
-                            rind,
-                            reader_base,
-                            book_system,
-                            genres,
-                            caretaker,
-                            app,
-                            lang,
-                        );
+                    if let Some(rind) = check_reader(library.reader_base(), &reader, app, lang) {
+                        change_name_simple(rind, library, app, lang);
                     }
                 }
             }
@@ -79,33 +50,14 @@ pub fn change_name(
 /// program will let you know
 
 #[inline]
-pub fn change_family(
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+pub fn change_family(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = app::channel();
 
     let mut inp = Input3::<Input, Input, Input>::new(
-        match lang {
-            Lang::English => "Change 2-nd Name",
-            Lang::Russian => "Изменить фамилию",
-        },
-        match lang {
-            Lang::English => "First Name",
-            Lang::Russian => "Имя",
-        },
-        match lang {
-            Lang::English => "Second Names",
-            Lang::Russian => "Фамилия",
-        },
-        match lang {
-            Lang::English => "Middle Name",
-            Lang::Russian => "Отчество",
-        },
+        lang.tr("change-2-nd-name"),
+        lang.tr("first-name"),
+        lang.tr("second-names"),
+        lang.tr("middle-name"),
     );
 
     inp.show();
@@ -117,16 +69,8 @@ pub fn change_family(
                 inp.hide();
 
                 if let Ok(reader) = inp.set_input(lang) {
-                    if let Some(rind) = check_reader(reader_base, &reader, app, lang) {
-                        change_family_simple(
-                            rind,
-                            reader_base,
-                            book_system,
-                            genres,
-                            caretaker,
-                            app,
-                            lang,
-                        );
+                    if let Some(rind) = check_reader(library.reader_base(), &reader, app, lang) {
+                        change_family_simple(rind, library, app, lang);
                     }
                 }
             }
@@ -142,33 +86,14 @@ pub fn change_family(
 /// program will let you know
 
 #[inline]
-pub fn change_father(
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+pub fn change_father(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = app::channel();
 
     let mut inp = Input3::<Input, Input, Input>::new(
-        match lang {
-            Lang::English => "Change Middle Name",
-            Lang::Russian => "Изменить Отчество",
-        },
-        match lang {
-            Lang::English => "First Name",
-            Lang::Russian => "Имя",
-        },
-        match lang {
-            Lang::English => "Second Names",
-            Lang::Russian => "Фамилия",
-        },
-        match lang {
-            Lang::English => "Middle Name",
-            Lang::Russian => "Отчество",
-        },
+        lang.tr("change-middle-name"),
+        lang.tr("first-name"),
+        lang.tr("second-names"),
+        lang.tr("middle-name"),
     );
 
     inp.show();
@@ -180,16 +105,8 @@ pub fn change_father(
                 inp.hide();
 
                 if let Ok(reader) = inp.set_input(lang) {
-                    if let Some(rind) = check_reader(reader_base, &reader, app, lang) {
-                        change_father_simple(
-                            rind,
-                            reader_base,
-                            book_system,
-                            genres,
-                            caretaker,
-                            app,
-                            lang,
-                        );
+                    if let Some(rind) = check_reader(library.reader_base(), &reader, app, lang) {
+                        change_father_simple(rind, library, app, lang);
                     }
                 }
             }
@@ -205,33 +122,14 @@ pub fn change_father(
 /// program will let you know
 
 #[inline]
-pub fn change_info(
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+pub fn change_info(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = app::channel();
 
     let mut inp = Input3::<Input, Input, Input>::new(
-        match lang {
-            Lang::English => "Change info",
-            Lang::Russian => "Изменить информацию",
-        },
-        match lang {
-            Lang::English => "First Name",
-            Lang::Russian => "Имя",
-        },
-        match lang {
-            Lang::English => "Second Names",
-            Lang::Russian => "Фамилия",
-        },
-        match lang {
-            Lang::English => "Middle Name",
-            Lang::Russian => "Отчество",
-        },
+        lang.tr("change-info"),
+        lang.tr("first-name"),
+        lang.tr("second-names"),
+        lang.tr("middle-name"),
     );
 
     inp.show();
@@ -243,16 +141,45 @@ pub fn change_info(
                 inp.hide();
 
                 if let Ok(reader) = inp.set_input(lang) {
-                    if let Some(rind) = check_reader(reader_base, &reader, app, lang) {
-                        change_info_simple(
-                            rind,
-                            reader_base,
-                            book_system,
-                            genres,
-                            caretaker,
-                            app,
-                            lang,
-                        );
+                    if let Some(rind) = check_reader(library.reader_base(), &reader, app, lang) {
+                        change_info_simple(rind, library, app, lang);
+                    }
+                }
+            }
+            break;
+        } else if !inp.shown() {
+            break;
+        }
+    }
+}
+
+/// Function that changes reader's category.
+/// Category chooses loan periods of reader.
+/// If you have mistakes in input,
+/// program will let you know
+
+#[inline]
+pub fn change_category(library: &mut Library, app: &App, lang: Lang) {
+    let (s2, r2) = app::channel();
+
+    let mut inp = Input3::<Input, Input, Input>::new(
+        lang.tr("change-category"),
+        lang.tr("first-name"),
+        lang.tr("second-names"),
+        lang.tr("middle-name"),
+    );
+
+    inp.show();
+    (*inp.ok).borrow_mut().emit(s2, true);
+
+    while app.wait() {
+        if let Some(message) = r2.recv() {
+            if message {
+                inp.hide();
+
+                if let Ok(reader) = inp.set_input(lang) {
+                    if let Some(rind) = check_reader(library.reader_base(), &reader, app, lang) {
+                        change_category_simple(rind, library, lang);
                     }
                 }
             }
@@ -268,33 +195,14 @@ pub fn change_info(
 /// program will let you know
 
 #[inline]
-pub fn change_age(
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
-    app: &App,
-    lang: Lang,
-) {
+pub fn change_age(library: &mut Library, app: &App, lang: Lang) {
     let (s2, r2) = app::channel();
 
     let mut inp = Input3::<Input, Input, Input>::new(
-        match lang {
-            Lang::English => "Change Age",
-            Lang::Russian => "Изменить Возраст",
-        },
-        match lang {
-            Lang::English => "First Name",
-            Lang::Russian => "Имя",
-        },
-        match lang {
-            Lang::English => "Second Names",
-            Lang::Russian => "Фамилия",
-        },
-        match lang {
-            Lang::English => "Middle Name",
-            Lang::Russian => "Отчество",
-        },
+        lang.tr("change-age"),
+        lang.tr("first-name"),
+        lang.tr("second-names"),
+        lang.tr("middle-name"),
     );
 
     inp.show();
@@ -306,16 +214,8 @@ pub fn change_age(
                 inp.hide();
 
                 if let Ok(reader) = inp.set_input(lang) {
-                    if let Some(rind) = check_reader(reader_base, &reader, app, lang) {
-                        change_age_simple(
-                            rind,
-                            reader_base,
-                            book_system,
-                            genres,
-                            caretaker,
-                            app,
-                            lang,
-                        );
+                    if let Some(rind) = check_reader(library.reader_base(), &reader, app, lang) {
+                        change_age_simple(rind, library, app, lang);
                     }
                 }
             }
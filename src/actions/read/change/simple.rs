@@ -2,10 +2,9 @@ extern crate fltk;
 extern crate fltk_calendar;
 
 use crate::{
-    books::{book_sys::BookSystem, date::Date, genres::Genres},
+    books::date::Date,
     change::{input1::Input1, Inputable},
-    reading::read_base::ReaderBase,
-    restore::caretaker::Caretaker,
+    library::Library,
     Lang,
 };
 
@@ -18,26 +17,12 @@ use fltk_calendar::calendar::Calendar;
 #[inline]
 pub(crate) fn change_name_simple(
     ind: usize,
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     app: &App,
     lang: Lang,
 ) -> Option<String> {
     let (s3, r3) = app::channel();
-    let mut get_name = Input1::<Input>::new(
-        match lang {
-            Lang::English => "New Name",
-            Lang::Russian => "Новое Имя",
-        },
-        match lang {
-            Lang::English => "New Name",
-            Lang::Russian => "Новое Имя",
-        },
-    );
-
-    caretaker.add_memento(reader_base, book_system, genres);
+    let mut get_name = Input1::<Input>::new(lang.tr("new-name"), lang.tr("new-name"));
 
     get_name.show();
     (*get_name.ok).borrow_mut().emit(s3, true);
@@ -48,62 +33,17 @@ pub(crate) fn change_name_simple(
                 get_name.hide();
 
                 if let Ok(new_name) = get_name.set_input(lang) {
-                    return match reader_base
+                    return match library
                         .change_name(ind, unsafe { new_name.get_unchecked(0).clone() })
                     {
                         Ok(_) => {
-                            fltk::dialog::message(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "Successfully changed",
-                                    Lang::Russian => "Успешно изменено",
-                                },
-                            );
-
-                            reader_base.save();
-                            book_system.save();
-                            Some(unsafe { new_name.get_unchecked(0).clone() })
-                        }
-
-                        Err(0) => {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "Reader is not found",
-                                    Lang::Russian => "Читатель не найден",
-                                },
-                            );
-                            caretaker.pop().unwrap();
-                            None
-                        }
+                            fltk::dialog::message(500, 500, lang.tr("successfully-changed"));
 
-                        Err(1) => {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "Reader with same parameters already exists",
-                                    Lang::Russian => {
-                                        "Читатель с предложенными параметрами уже существует"
-                                    }
-                                },
-                            );
-                            caretaker.pop().unwrap();
-                            None
+                            Some(unsafe { new_name.get_unchecked(0).clone() })
                         }
 
-                        Err(_) => {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "'New name' is empty",
-                                    Lang::Russian => "'Новое имя' пусто",
-                                },
-                            );
-                            caretaker.pop().unwrap();
+                        Err(err) => {
+                            alert(500, 500, err.message(lang));
                             None
                         }
                     };
@@ -111,7 +51,6 @@ pub(crate) fn change_name_simple(
             }
             break;
         } else if !get_name.shown() {
-            caretaker.pop().unwrap();
             return None;
         }
     }
@@ -125,26 +64,12 @@ pub(crate) fn change_name_simple(
 #[inline]
 pub(crate) fn change_family_simple(
     ind: usize,
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     app: &App,
     lang: Lang,
 ) -> Option<String> {
     let (s3, r3) = app::channel();
-    let mut get_family = Input1::<Input>::new(
-        match lang {
-            Lang::English => "'New 2-nd Name",
-            Lang::Russian => "Новая Фамилия",
-        },
-        match lang {
-            Lang::English => "'New 2-nd Name",
-            Lang::Russian => "Новая Фамилия",
-        },
-    );
-
-    caretaker.add_memento(reader_base, book_system, genres);
+    let mut get_family = Input1::<Input>::new(lang.tr("new-2-nd-name"), lang.tr("new-2-nd-name"));
 
     get_family.show();
     (*get_family.ok).borrow_mut().emit(s3, true);
@@ -155,63 +80,17 @@ pub(crate) fn change_family_simple(
                 get_family.hide();
 
                 if let Ok(new_family) = get_family.set_input(lang) {
-                    return match reader_base
+                    return match library
                         .change_family(ind, unsafe { new_family.get_unchecked(0).clone() })
                     {
                         Ok(_) => {
-                            fltk::dialog::message(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "Successfully changed",
-                                    Lang::Russian => "Успешно изменено",
-                                },
-                            );
-
-                            reader_base.save();
-                            book_system.save();
+                            fltk::dialog::message(500, 500, lang.tr("successfully-changed"));
 
                             Some(unsafe { new_family.get_unchecked(0).clone() })
                         }
 
-                        Err(0) => {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "Reader is not found",
-                                    Lang::Russian => "Читатель не найден",
-                                },
-                            );
-                            caretaker.pop().unwrap();
-                            None
-                        }
-
-                        Err(1) => {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "Reader with same parameters already exists",
-                                    Lang::Russian => {
-                                        "Читатель с предложенными параметрами уже существует"
-                                    }
-                                },
-                            );
-                            caretaker.pop().unwrap();
-                            None
-                        }
-
-                        Err(_) => {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "'New 2-nd Name' is empty",
-                                    Lang::Russian => "'Новая Фамилия' пусто",
-                                },
-                            );
-                            caretaker.pop().unwrap();
+                        Err(err) => {
+                            alert(500, 500, err.message(lang));
                             None
                         }
                     };
@@ -219,7 +98,6 @@ pub(crate) fn change_family_simple(
             }
             break;
         } else if !get_family.shown() {
-            caretaker.pop().unwrap();
             return None;
         }
     }
@@ -232,26 +110,13 @@ pub(crate) fn change_family_simple(
 #[inline]
 pub(crate) fn change_father_simple(
     ind: usize,
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     app: &App,
     lang: Lang,
 ) -> Option<String> {
     let (s3, r3) = app::channel();
-    let mut get_father = Input1::<Input>::new(
-        match lang {
-            Lang::English => "New Middle Name",
-            Lang::Russian => "Новое Отчество",
-        },
-        match lang {
-            Lang::English => "New Middle Name",
-            Lang::Russian => "Новое Отчество",
-        },
-    );
-
-    caretaker.add_memento(reader_base, book_system, genres);
+    let mut get_father =
+        Input1::<Input>::new(lang.tr("new-middle-name"), lang.tr("new-middle-name"));
 
     get_father.show();
     (*get_father.ok).borrow_mut().emit(s3, true);
@@ -262,62 +127,17 @@ pub(crate) fn change_father_simple(
                 get_father.hide();
 
                 if let Ok(new_father) = get_father.set_input(lang) {
-                    return match reader_base
+                    return match library
                         .change_father(ind, unsafe { new_father.get_unchecked(0).clone() })
                     {
                         Ok(_) => {
-                            fltk::dialog::message(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "Successfully changed",
-                                    Lang::Russian => "Успешно изменено",
-                                },
-                            );
-
-                            reader_base.save();
-                            book_system.save();
-                            Some(unsafe { new_father.get_unchecked(0).clone() })
-                        }
+                            fltk::dialog::message(500, 500, lang.tr("successfully-changed"));
 
-                        Err(0) => {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "Reader is not found",
-                                    Lang::Russian => "Читатель не найден",
-                                },
-                            );
-                            caretaker.pop().unwrap();
-                            None
-                        }
-
-                        Err(1) => {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "Reader with same parameters already exists",
-                                    Lang::Russian => {
-                                        "Читатель с предложенными параметрами уже существует"
-                                    }
-                                },
-                            );
-                            caretaker.pop().unwrap();
-                            None
+                            Some(unsafe { new_father.get_unchecked(0).clone() })
                         }
 
-                        Err(_) => {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "'New Mid. Name' is empty",
-                                    Lang::Russian => "'Новое Отчество' пусто",
-                                },
-                            );
-                            caretaker.pop().unwrap();
+                        Err(err) => {
+                            alert(500, 500, err.message(lang));
                             None
                         }
                     };
@@ -325,7 +145,6 @@ pub(crate) fn change_father_simple(
             }
             break;
         } else if !get_father.shown() {
-            caretaker.pop().unwrap();
             return None;
         }
     }
@@ -338,26 +157,13 @@ pub(crate) fn change_father_simple(
 #[inline]
 pub(crate) fn change_info_simple(
     ind: usize,
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     app: &App,
     lang: Lang,
 ) -> Option<String> {
     let (s3, r3) = app::channel();
-    let mut get_info = Input1::<Input>::new(
-        match lang {
-            Lang::English => "New Info (< 50 symb)",
-            Lang::Russian => "Инфо (< 50 симв.)",
-        },
-        match lang {
-            Lang::English => "New Info (< 50 symb)",
-            Lang::Russian => "Инфо (< 50 симв.)",
-        },
-    );
-
-    caretaker.add_memento(reader_base, book_system, genres);
+    let mut get_info =
+        Input1::<Input>::new(lang.tr("new-info-50-symb"), lang.tr("new-info-50-symb"));
 
     get_info.show();
     (*get_info.ok).borrow_mut().emit(s3, true);
@@ -368,47 +174,17 @@ pub(crate) fn change_info_simple(
                 get_info.hide();
 
                 if let Ok(new_info) = get_info.set_input(lang) {
-                    return match reader_base
+                    return match library
                         .change_info(ind, unsafe { new_info.get_unchecked(0).clone() })
                     {
                         Ok(_) => {
-                            fltk::dialog::message(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "Successfully changed",
-                                    Lang::Russian => "Успешно изменено",
-                                },
-                            );
-
-                            reader_base.save();
-                            book_system.save();
-                            Some(unsafe { new_info.get_unchecked(0).clone() })
-                        }
+                            fltk::dialog::message(500, 500, lang.tr("successfully-changed"));
 
-                        Err(0) => {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "Reader is not found",
-                                    Lang::Russian => "Читатель не найден",
-                                },
-                            );
-                            caretaker.pop().unwrap();
-                            None
+                            Some(unsafe { new_info.get_unchecked(0).clone() })
                         }
 
-                        Err(_) => {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "'New Info' is empty",
-                                    Lang::Russian => "'Инфо' пусто",
-                                },
-                            );
-                            caretaker.pop().unwrap();
+                        Err(err) => {
+                            alert(500, 500, err.message(lang));
                             None
                         }
                     };
@@ -416,7 +192,6 @@ pub(crate) fn change_info_simple(
             }
             break;
         } else if !get_info.shown() {
-            caretaker.pop().unwrap();
             return None;
         }
     }
@@ -424,44 +199,52 @@ pub(crate) fn change_info_simple(
     None
 }
 
+/// Change category of already known reader.
+/// Empty category removes it
+
+#[inline]
+pub(crate) fn change_category_simple(
+    ind: usize,
+    library: &mut Library,
+    lang: Lang,
+) -> Option<String> {
+    let current = unsafe { library.reader_base().readers.get_unchecked(ind) }
+        .borrow()
+        .category
+        .clone();
+
+    let new_category = fltk::dialog::input(500, 500, lang.tr("new-category"), current.as_str())?;
+
+    match library.change_category(ind, new_category.clone()) {
+        Ok(_) => {
+            fltk::dialog::message(500, 500, lang.tr("successfully-changed"));
+
+            Some(new_category.trim().to_string())
+        }
+
+        Err(err) => {
+            alert(500, 500, err.message(lang));
+            None
+        }
+    }
+}
+
 /// Function that changes
 /// age of already known reader
 
 #[inline]
 pub(crate) fn change_age_simple(
     ind: usize,
-    reader_base: &mut ReaderBase,
-    book_system: &mut BookSystem,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: &mut Library,
     app: &App,
     lang: Lang,
 ) -> Option<u16> {
-    caretaker.add_memento(reader_base, book_system, genres);
-
-    let mut win = fltk::window::SingleWindow::new(800, 500, 250, 100, "Choose new birth date");
-
-    let _ = fltk::frame::Frame::new(
-        50,
-        10,
-        150,
-        50,
-        match lang {
-            Lang::English => "Choose new birth date",
-            Lang::Russian => "Выберите новую дату рождения",
-        },
-    );
-
-    let mut but = fltk::button::Button::new(
-        80,
-        60,
-        60,
-        20,
-        match lang {
-            Lang::English => "OK",
-            Lang::Russian => "ОК",
-        },
-    );
+    let mut win =
+        fltk::window::SingleWindow::new(800, 500, 250, 100, lang.tr("choose-new-birth-date"));
+
+    let _ = fltk::frame::Frame::new(50, 10, 150, 50, lang.tr("choose-new-birth-date"));
+
+    let mut but = fltk::button::Button::new(80, 60, 60, 20, lang.tr("ok"));
 
     win.end();
     win.show();
@@ -474,64 +257,28 @@ pub(crate) fn change_age_simple(
             if msg {
                 win.hide();
 
-                return match Calendar::default().get_date() {
-                    Some(date) => match reader_base.change_age(ind, Date::from(date)) {
+                return match Calendar::default().get_date().and_then(Date::from_naive) {
+                    Some(date) => match library.change_age(ind, date) {
                         Ok(_) => {
-                            fltk::dialog::message(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "Successfully changed",
-                                    Lang::Russian => "Успешно изменено",
-                                },
-                            );
-
-                            reader_base.save();
-                            book_system.save();
+                            fltk::dialog::message(500, 500, lang.tr("successfully-changed"));
 
                             unsafe {
-                                Some((**reader_base.readers.get_unchecked(ind)).borrow().age())
+                                Some(
+                                    (**library.reader_base().readers.get_unchecked(ind))
+                                        .borrow()
+                                        .age(),
+                                )
                             }
                         }
 
-                        Err(0) => {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "Reader isn't found",
-                                    Lang::Russian => "Читатель не найден",
-                                },
-                            );
-                            caretaker.pop().unwrap();
-                            None
-                        }
-
-                        Err(_) => {
-                            alert(
-                                500,
-                                500,
-                                match lang {
-                                    Lang::English => "Reader with same parameters already exists",
-                                    Lang::Russian => {
-                                        "Читатель с предложенными параметрами уже существует"
-                                    }
-                                },
-                            );
-                            caretaker.pop().unwrap();
+                        Err(err) => {
+                            alert(500, 500, err.message(lang));
                             None
                         }
                     },
 
                     None => {
-                        alert(
-                            500,
-                            500,
-                            match lang {
-                                Lang::English => "Date wasn't selected",
-                                Lang::Russian => "Дата не была выбрана",
-                            },
-                        );
+                        alert(500, 500, lang.tr("date-wasnt-selected"));
                         None
                     }
                 };
@@ -4,10 +4,8 @@ use fltk::{app, app::App, input::Input, prelude::*, table::Table};
 
 use crate::{
     actions::read::{info::simple::*, utils::check_reader},
-    books::{book_sys::BookSystem, genres::Genres},
     change::{input3::Input3, Inputable},
-    reading::read_base::ReaderBase,
-    restore::caretaker::Caretaker,
+    library::Library,
     Lang,
 };
 
@@ -21,32 +19,17 @@ use std::{cell::RefCell, rc::Rc};
 
 #[inline]
 pub fn reader_info_reading(
-    reader_base: Rc<RefCell<ReaderBase>>,
-    book_system: Rc<RefCell<BookSystem>>,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: Rc<RefCell<Library>>,
     app: &App,
     lang: Lang,
     main_table: &mut Table,
 ) {
     let (s2, r2) = app::channel();
     let mut inp = Input3::<Input, Input, Input>::new(
-        match lang {
-            Lang::English => "Find Reader",
-            Lang::Russian => "Поиск Читателя",
-        },
-        match lang {
-            Lang::English => "First Name",
-            Lang::Russian => "Имя",
-        },
-        match lang {
-            Lang::English => "Second Names",
-            Lang::Russian => "Фамилия",
-        },
-        match lang {
-            Lang::English => "Middle Name",
-            Lang::Russian => "Отчество",
-        },
+        lang.tr("find-reader"),
+        lang.tr("first-name"),
+        lang.tr("second-names"),
+        lang.tr("middle-name"),
     );
 
     inp.show();
@@ -59,19 +42,11 @@ pub fn reader_info_reading(
 
                 if let Ok(reader) = inp.set_input(lang) {
                     if let Some(ind) = {
-                        let check = check_reader(&*(*reader_base).borrow(), &reader, app, lang);
+                        let check =
+                            check_reader((*library).borrow().reader_base(), &reader, app, lang);
                         check
                     } {
-                        reader_info_simple_reading(
-                            ind,
-                            reader_base.clone(),
-                            book_system.clone(),
-                            genres,
-                            caretaker,
-                            app,
-                            lang,
-                            main_table,
-                        )
+                        reader_info_simple_reading(ind, library.clone(), app, lang, main_table)
                     }
                 }
             }
@@ -89,32 +64,17 @@ pub fn reader_info_reading(
 
 #[inline]
 pub fn reader_info_all_books(
-    reader_base: Rc<RefCell<ReaderBase>>,
-    book_system: Rc<RefCell<BookSystem>>,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: Rc<RefCell<Library>>,
     app: &App,
     lang: Lang,
     main_table: &mut Table,
 ) {
     let (s2, r2) = app::channel();
     let mut inp = Input3::<Input, Input, Input>::new(
-        match lang {
-            Lang::English => "Find Reader",
-            Lang::Russian => "Поиск Читателя",
-        },
-        match lang {
-            Lang::English => "First Name",
-            Lang::Russian => "Имя",
-        },
-        match lang {
-            Lang::English => "Second Names",
-            Lang::Russian => "Фамилия",
-        },
-        match lang {
-            Lang::English => "Middle Name",
-            Lang::Russian => "Отчество",
-        },
+        lang.tr("find-reader"),
+        lang.tr("first-name"),
+        lang.tr("second-names"),
+        lang.tr("middle-name"),
     );
 
     inp.show();
@@ -127,19 +87,11 @@ pub fn reader_info_all_books(
 
                 if let Ok(reader) = inp.set_input(lang) {
                     if let Some(ind) = {
-                        let check = check_reader(&*(*reader_base).borrow(), &reader, app, lang);
+                        let check =
+                            check_reader((*library).borrow().reader_base(), &reader, app, lang);
                         check
                     } {
-                        reader_info_simple_all_books(
-                            ind,
-                            reader_base.clone(),
-                            book_system.clone(),
-                            genres,
-                            caretaker,
-                            app,
-                            lang,
-                            main_table,
-                        )
+                        reader_info_simple_all_books(ind, library.clone(), app, lang, main_table)
                     }
                 }
             }
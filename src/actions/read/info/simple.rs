@@ -3,13 +3,12 @@ extern crate fltk;
 use crate::{
     actions::{
         book::info::simple::book_info_simple,
+        fit_menu,
         giveaway::simple::{get_book_known_reader, give_book_known_reader},
         read::{add_rem::simple::*, change::simple::*},
         tables::{cell_book2, draw_data, draw_header},
     },
-    books::{book_sys::BookSystem, genres::Genres},
-    reading::read_base::ReaderBase,
-    restore::caretaker::Caretaker,
+    library::Library,
     Lang,
 };
 
@@ -49,10 +48,7 @@ enum MessageReader {
 
 pub fn reader_info_simple_reading(
     ind: usize,
-    reader_base: Rc<RefCell<ReaderBase>>,
-    book_system: Rc<RefCell<BookSystem>>,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: Rc<RefCell<Library>>,
     app: &App,
     lang: Lang,
     main_table: &mut Table,
@@ -60,17 +56,17 @@ pub fn reader_info_simple_reading(
     let label = format!(
         "{} {} {}",
         *unsafe {
-            &(*(*reader_base).borrow().readers.get_unchecked(ind))
+            &(*(*library).borrow().reader_base().readers.get_unchecked(ind))
                 .borrow()
                 .name
         },
         *unsafe {
-            &(*(*reader_base).borrow().readers.get_unchecked(ind))
+            &(*(*library).borrow().reader_base().readers.get_unchecked(ind))
                 .borrow()
                 .family
         },
         *unsafe {
-            &(*(*reader_base).borrow().readers.get_unchecked(ind))
+            &(*(*library).borrow().reader_base().readers.get_unchecked(ind))
                 .borrow()
                 .father
         },
@@ -83,78 +79,43 @@ pub fn reader_info_simple_reading(
     let mut table1 = VGrid::new(0, 0, 670, 200, "");
     table1.set_params(6, 1, 1);
 
-    let label = format!(
-        "{}: {}",
-        match lang {
-            Lang::English => "First Name",
-            Lang::Russian => "\t\tИмя",
-        },
-        *unsafe {
-            &(*(*reader_base).borrow().readers.get_unchecked(ind))
-                .borrow()
-                .name
-        }
-    );
+    let label = format!("{}: {}", lang.tr("first-name-tabbed"), *unsafe {
+        &(*(*library).borrow().reader_base().readers.get_unchecked(ind))
+            .borrow()
+            .name
+    });
 
     let mut name_frame = Frame::new(10, 50, 100, 30, None).with_label(label.as_str());
 
-    let label = format!(
-        "{}: {}",
-        match lang {
-            Lang::English => "Second Name",
-            Lang::Russian => "\t\tФамилия",
-        },
-        *unsafe {
-            &(*(*reader_base).borrow().readers.get_unchecked(ind))
-                .borrow()
-                .family
-        }
-    );
+    let label = format!("{}: {}", lang.tr("second-name-tabbed"), *unsafe {
+        &(*(*library).borrow().reader_base().readers.get_unchecked(ind))
+            .borrow()
+            .family
+    });
 
     let mut family_frame = Frame::new(30, 50, 100, 30, None).with_label(label.as_str());
 
-    let label = format!(
-        "{}: {}",
-        match lang {
-            Lang::English => "Middle Name",
-            Lang::Russian => "\t\tОтчество",
-        },
-        *unsafe {
-            &(*(*reader_base).borrow().readers.get_unchecked(ind))
-                .borrow()
-                .father
-        }
-    );
+    let label = format!("{}: {}", lang.tr("middle-name-tabbed"), *unsafe {
+        &(*(*library).borrow().reader_base().readers.get_unchecked(ind))
+            .borrow()
+            .father
+    });
 
     let mut father_frame = Frame::new(50, 50, 100, 30, None).with_label(label.as_str());
 
-    let label = format!(
-        "{}: {}",
-        match lang {
-            Lang::English => "Age",
-            Lang::Russian => "\t\tВозраст",
-        },
-        *unsafe {
-            &(*(*reader_base).borrow().readers.get_unchecked(ind))
-                .borrow()
-                .age()
-        }
-    );
+    let label = format!("{}: {}", lang.tr("age-tabbed"), *unsafe {
+        &(*(*library).borrow().reader_base().readers.get_unchecked(ind))
+            .borrow()
+            .age()
+    });
 
     let mut age_frame = Frame::new(70, 50, 100, 30, None).with_label(label.as_str());
 
-    let label = format!(
-        "{}: {}",
-        match lang {
-            Lang::English => "Additional Info",
-            Lang::Russian => "Дополнительная Информация",
-        },
-        *unsafe {
-            &(*(*reader_base).borrow().readers.get_unchecked(ind))
-                .borrow()
-                .info
-        }
-    );
+    let label = format!("{}: {}", lang.tr("additional-info"), *unsafe {
+        &(*(*library).borrow().reader_base().readers.get_unchecked(ind))
+            .borrow()
+            .info
+    });
 
     let mut info_frame = Frame::new(200, 50, 100, 30, None).with_label(label.as_str());
 
@@ -164,20 +125,14 @@ pub fn reader_info_simple_reading(
     table1.add(&age_frame);
     table1.add(&info_frame);
 
-    let label = format!(
-        "{}:",
-        match lang {
-            Lang::English => "Books read by reader now",
-            Lang::Russian => "Книги, читаемые сейчас",
-        }
-    );
+    let label = format!("{}:", lang.tr("books-read-by-reader-now"));
 
     table1.add(&Frame::new(200, 50, 100, 30, None).with_label(label.as_str()));
     table1.auto_layout();
 
     let mut table2 = Table::new(0, 230, 670, 600, None);
     table2.set_rows(max(30, unsafe {
-        (**(*reader_base).borrow().readers.get_unchecked(ind))
+        (**(*library).borrow().reader_base().readers.get_unchecked(ind))
             .borrow()
             .books
             .len() as i32
@@ -190,25 +145,13 @@ pub fn reader_info_simple_reading(
 
     wind.end();
 
-    let mut menu = MenuBar::new(
-        0,
-        0,
-        255 + match lang {
-            Lang::English => 0,
-            Lang::Russian => 40,
-        },
-        30,
-        "",
-    );
+    let mut menu = MenuBar::new(0, 0, 255, 30, "");
     wind.add(&menu);
 
     let (s, r) = app::channel();
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Change/Change name\t",
-            Lang::Russian => "&Изменить/Изменить имя\t",
-        },
+        lang.tr("change-change-name"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -216,10 +159,7 @@ pub fn reader_info_simple_reading(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Change/Change 2-nd name\t",
-            Lang::Russian => "&Изменить/Изменить фамилию\t",
-        },
+        lang.tr("change-change-2-nd-name"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -227,10 +167,7 @@ pub fn reader_info_simple_reading(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Change/Change mid name\t",
-            Lang::Russian => "&Изменить/Изменить отчество\t",
-        },
+        lang.tr("change-change-mid-name"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -238,10 +175,7 @@ pub fn reader_info_simple_reading(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Change/Change age\t",
-            Lang::Russian => "&Изменить/Изменить возраст\t",
-        },
+        lang.tr("change-change-age"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -249,10 +183,7 @@ pub fn reader_info_simple_reading(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Change/Change additional information",
-            Lang::Russian => "&Изменить/Изменить дополнительную информацию",
-        },
+        lang.tr("change-change-additional-information"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -260,10 +191,7 @@ pub fn reader_info_simple_reading(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Giveaway/Give book\t",
-            Lang::Russian => "&Выдача/Выдать книгу\t",
-        },
+        lang.tr("giveaway-give-book"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -271,10 +199,7 @@ pub fn reader_info_simple_reading(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Giveaway/Get book\t",
-            Lang::Russian => "&Выдача/Вернуть книгу\t",
-        },
+        lang.tr("giveaway-get-book"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -282,20 +207,17 @@ pub fn reader_info_simple_reading(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Remove reader",
-            Lang::Russian => "&Удалить читателя",
-        },
+        lang.tr("remove-reader-menu"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
         MessageReader::RemoveThis,
     );
 
+    fit_menu(&mut menu);
     wind.show();
 
-    let rb = reader_base.clone();
-    let bs = book_system.clone();
+    let lib = library.clone();
 
     table2.draw_cell(move |t, ctx, row, col, x, y, w, h| match ctx {
         fltk::table::TableContext::StartPage => draw::set_font(Font::Helvetica, 14),
@@ -304,25 +226,13 @@ pub fn reader_info_simple_reading(
             &format!(
                 "{}",
                 match col {
-                    0 => match lang {
-                        Lang::English => "Title",
-                        Lang::Russian => "Название",
-                    },
-
-                    1 => match lang {
-                        Lang::English => "Author",
-                        Lang::Russian => "Автор",
-                    },
-
-                    2 => match lang {
-                        Lang::English => "Amount of Pages",
-                        Lang::Russian => "Кол-во страниц",
-                    },
-
-                    _ => match lang {
-                        Lang::English => "Number of book",
-                        Lang::Russian => "Номер книги",
-                    },
+                    0 => lang.tr("title"),
+
+                    1 => lang.tr("author"),
+
+                    2 => lang.tr("amount-of-pages"),
+
+                    _ => lang.tr("number-of-book-2"),
                 }
             ),
             x,
@@ -336,7 +246,13 @@ pub fn reader_info_simple_reading(
         fltk::table::TableContext::Cell => draw_data(
             &format!(
                 "{}",
-                cell_book2(col, row, ind, &*(*rb).borrow(), &*(*bs).borrow())
+                cell_book2(
+                    col,
+                    row,
+                    ind,
+                    (*lib).borrow().reader_base(),
+                    (*lib).borrow().book_system()
+                )
             ),
             x,
             y,
@@ -353,25 +269,11 @@ pub fn reader_info_simple_reading(
         if let Some(msg) = r.recv() {
             match msg {
                 MessageReader::ChangeName => {
-                    if let Some(new_name) = change_name_simple(
-                        ind,
-                        &mut *(*reader_base).borrow_mut(),
-                        &mut *(*book_system).borrow_mut(),
-                        genres,
-                        caretaker,
-                        app,
-                        lang,
-                    ) {
+                    if let Some(new_name) =
+                        change_name_simple(ind, &mut *(*library).borrow_mut(), app, lang)
+                    {
                         name_frame.set_label(
-                            format!(
-                                "{}: {}",
-                                match lang {
-                                    Lang::English => "First Name",
-                                    Lang::Russian => "\t\tИмя",
-                                },
-                                new_name
-                            )
-                            .as_str(),
+                            format!("{}: {}", lang.tr("first-name-tabbed"), new_name).as_str(),
                         );
                         name_frame.redraw();
                         main_table.redraw();
@@ -379,25 +281,11 @@ pub fn reader_info_simple_reading(
                 }
 
                 MessageReader::ChangeFamily => {
-                    if let Some(new_family) = change_family_simple(
-                        ind,
-                        &mut *(*reader_base).borrow_mut(),
-                        &mut *(*book_system).borrow_mut(),
-                        genres,
-                        caretaker,
-                        app,
-                        lang,
-                    ) {
+                    if let Some(new_family) =
+                        change_family_simple(ind, &mut *(*library).borrow_mut(), app, lang)
+                    {
                         family_frame.set_label(
-                            format!(
-                                "{}: {}",
-                                match lang {
-                                    Lang::English => "Second Name",
-                                    Lang::Russian => "\t\tФамилия",
-                                },
-                                new_family
-                            )
-                            .as_str(),
+                            format!("{}: {}", lang.tr("second-name-tabbed"), new_family).as_str(),
                         );
                         family_frame.redraw();
                         main_table.redraw();
@@ -405,25 +293,11 @@ pub fn reader_info_simple_reading(
                 }
 
                 MessageReader::ChangeFather => {
-                    if let Some(new_father) = change_father_simple(
-                        ind,
-                        &mut *(*reader_base).borrow_mut(),
-                        &mut *(*book_system).borrow_mut(),
-                        genres,
-                        caretaker,
-                        app,
-                        lang,
-                    ) {
+                    if let Some(new_father) =
+                        change_father_simple(ind, &mut *(*library).borrow_mut(), app, lang)
+                    {
                         father_frame.set_label(
-                            format!(
-                                "{}: {}",
-                                match lang {
-                                    Lang::English => "Middle Name",
-                                    Lang::Russian => "\t\tОтчество",
-                                },
-                                new_father
-                            )
-                            .as_str(),
+                            format!("{}: {}", lang.tr("middle-name-tabbed"), new_father).as_str(),
                         );
                         father_frame.redraw();
                         main_table.redraw();
@@ -431,94 +305,42 @@ pub fn reader_info_simple_reading(
                 }
 
                 MessageReader::ChangeAge => {
-                    if let Some(new_age) = change_age_simple(
-                        ind,
-                        &mut *(*reader_base).borrow_mut(),
-                        &mut *(*book_system).borrow_mut(),
-                        genres,
-                        caretaker,
-                        app,
-                        lang,
-                    ) {
-                        age_frame.set_label(
-                            format!(
-                                "{}: {}",
-                                match lang {
-                                    Lang::English => "Age",
-                                    Lang::Russian => "\t\tВозраст",
-                                },
-                                new_age,
-                            )
-                            .as_str(),
-                        );
+                    if let Some(new_age) =
+                        change_age_simple(ind, &mut *(*library).borrow_mut(), app, lang)
+                    {
+                        age_frame
+                            .set_label(format!("{}: {}", lang.tr("age-tabbed"), new_age,).as_str());
                         age_frame.redraw();
                         main_table.redraw();
                     }
                 }
 
                 MessageReader::ChangeInfo => {
-                    if let Some(new_info) = change_info_simple(
-                        ind,
-                        &mut *(*reader_base).borrow_mut(),
-                        &mut *(*book_system).borrow_mut(),
-                        genres,
-                        caretaker,
-                        app,
-                        lang,
-                    ) {
+                    if let Some(new_info) =
+                        change_info_simple(ind, &mut *(*library).borrow_mut(), app, lang)
+                    {
                         info_frame.set_label(
-                            format!(
-                                "{}: {}",
-                                match lang {
-                                    Lang::English => "Additional Info",
-                                    Lang::Russian => "Дополнительная Информация",
-                                },
-                                new_info
-                            )
-                            .as_str(),
+                            format!("{}: {}", lang.tr("additional-info"), new_info).as_str(),
                         );
                         info_frame.redraw();
                     }
                 }
 
                 MessageReader::GiveBook => {
-                    give_book_known_reader(
-                        ind,
-                        &mut *(*reader_base).borrow_mut(),
-                        &mut *(*book_system).borrow_mut(),
-                        genres,
-                        caretaker,
-                        app,
-                        lang,
-                    );
+                    give_book_known_reader(ind, &mut *(*library).borrow_mut(), app, lang);
                     table2.redraw();
                     main_table.redraw();
                 }
 
                 MessageReader::GetBook => {
-                    if get_book_known_reader(
-                        ind,
-                        &mut *(*reader_base).borrow_mut(),
-                        &mut *(*book_system).borrow_mut(),
-                        genres,
-                        caretaker,
-                        app,
-                        lang,
-                    ) {
+                    if get_book_known_reader(ind, &mut *(*library).borrow_mut(), app, lang) {
                         table2.redraw();
                         main_table.redraw();
                     }
                 }
 
                 MessageReader::RemoveThis => {
-                    remove_reader_simple(
-                        ind,
-                        &mut *(*reader_base).borrow_mut(),
-                        &mut *(*book_system).borrow_mut(),
-                        genres,
-                        caretaker,
-                        lang,
-                    );
+                    remove_reader_simple(ind, &mut *(*library).borrow_mut(), lang);
                     main_table.redraw();
                     return;
                 }
@@ -528,7 +350,7 @@ pub fn reader_info_simple_reading(
         }
 
         let len = unsafe {
-            (**(*reader_base).borrow().readers.get_unchecked(ind))
+            (**(*library).borrow().reader_base().readers.get_unchecked(ind))
                 .borrow()
                 .books
                 .len()
@@ -542,15 +364,12 @@ pub fn reader_info_simple_reading(
             {
                 unsafe {
                     book_info_simple(
-                        &(**(*reader_base).borrow().readers.get_unchecked(ind))
+                        &(**(*library).borrow().reader_base().readers.get_unchecked(ind))
                             .borrow()
                             .books
                             .get_unchecked(i)
                             .clone(),
-                        book_system.clone(),
-                        &(*reader_base).borrow(),
-                        genres,
-                        caretaker,
+                        library.clone(),
                         app,
                         lang,
                     );
@@ -569,10 +388,7 @@ pub fn reader_info_simple_reading(
 
 pub(crate) fn reader_info_simple_all_books(
     ind: usize,
-    reader_base: Rc<RefCell<ReaderBase>>,
-    book_system: Rc<RefCell<BookSystem>>,
-    genres: &Genres,
-    caretaker: &mut Caretaker,
+    library: Rc<RefCell<Library>>,
     app: &App,
     lang: Lang,
     main_table: &mut Table,
@@ -580,17 +396,17 @@ pub(crate) fn reader_info_simple_all_books(
     let label = format!(
         "{} {} {}",
         *unsafe {
-            &(*(*reader_base).borrow().readers.get_unchecked(ind))
+            &(*(*library).borrow().reader_base().readers.get_unchecked(ind))
                 .borrow()
                 .name
         },
         *unsafe {
-            &(*(*reader_base).borrow().readers.get_unchecked(ind))
+            &(*(*library).borrow().reader_base().readers.get_unchecked(ind))
                 .borrow()
                 .family
         },
         *unsafe {
-            &(*(*reader_base).borrow().readers.get_unchecked(ind))
+            &(*(*library).borrow().reader_base().readers.get_unchecked(ind))
                 .borrow()
                 .father
         },
@@ -603,80 +419,49 @@ pub(crate) fn reader_info_simple_all_books(
     let mut table1 = VGrid::new(0, 0, 670, 200, None);
     table1.set_params(6, 1, 1);
 
-    let label = format!(
-        "{}: {}",
-        match lang {
-            Lang::English => "First Name",
-            Lang::Russian => "\t\tИмя",
-        },
-        *unsafe {
-            &(*(*reader_base).borrow().readers.get_unchecked(ind))
-                .borrow()
-                .name
-        }
-    );
+    let label = format!("{}: {}", lang.tr("first-name-tabbed"), *unsafe {
+        &(*(*library).borrow().reader_base().readers.get_unchecked(ind))
+            .borrow()
+            .name
+    });
 
     let mut name_frame = Frame::new(10, 50, 100, 30, None).with_label(label.as_str());
 
-    let label = format!(
-        "{}: {}",
-        match lang {
-            Lang::English => "Second Name",
-            Lang::Russian => "\t\tФамилия",
-        },
-        *unsafe {
-            &(*(*reader_base).borrow().readers.get_unchecked(ind))
-                .borrow()
-                .family
-        }
-    );
+    let label = format!("{}: {}", lang.tr("second-name-tabbed"), *unsafe {
+        &(*(*library).borrow().reader_base().readers.get_unchecked(ind))
+            .borrow()
+            .family
+    });
 
     let mut family_frame = Frame::new(30, 50, 100, 30, None).with_label(label.as_str());
 
-    let label = format!(
-        "{}: {}",
-        match lang {
-            Lang::English => "Middle Name",
-            Lang::Russian => "\t\tОтчество",
-        },
-        *unsafe {
-            &(*(*reader_base).borrow().readers.get_unchecked(ind))
-                .borrow()
-                .father
-        }
-    );
+    let label = format!("{}: {}", lang.tr("middle-name-tabbed"), *unsafe {
+        &(*(*library).borrow().reader_base().readers.get_unchecked(ind))
+            .borrow()
+            .father
+    });
 
     let mut father_frame = Frame::new(50, 50, 100, 30, None).with_label(label.as_str());
 
-    let label = format!(
-        "{}: {}",
-        match lang {
-            Lang::English => "Age",
-            Lang::Russian => "\t\tВозраст",
-        },
-        *unsafe {
-            &(*(*reader_base).borrow().readers.get_unchecked(ind))
-                .borrow()
-                .age()
-        }
-    );
+    let label = format!("{}: {}", lang.tr("age-tabbed"), *unsafe {
+        &(*(*library).borrow().reader_base().readers.get_unchecked(ind))
+            .borrow()
+            .age()
+    });
 
     let mut age_frame = Frame::new(70, 50, 100, 30, None).with_label(label.as_str());
 
     let label = format!(
         "{}: {}",
-        match lang {
-            Lang::English => "Nearest to return deadline",
-            Lang::Russian => "Ближайщее к сдаче",
-        },
+        lang.tr("nearest-to-return-deadline"),
         if unsafe {
-            (**(*reader_base).borrow().readers.get_unchecked(ind))
+            (**(*library).borrow().reader_base().readers.get_unchecked(ind))
                 .borrow()
                 .reading
                 .is_some()
         } {
             unsafe {
-                (*(**(*reader_base).borrow().readers.get_unchecked(ind))
+                (*(**(*library).borrow().reader_base().readers.get_unchecked(ind))
                     .borrow()
                     .reading
                     .as_ref()
@@ -686,31 +471,20 @@ pub(crate) fn reader_info_simple_all_books(
                     .upgrade()
                     .unwrap())
                 .borrow()
-                .to_string(&*(*book_system).borrow())
+                .to_string(&*(*library).borrow().book_system())
             }
         } else {
-            match lang {
-                Lang::English => "None",
-                Lang::Russian => "Ничего",
-            }
-            .to_string()
+            lang.tr("none-2").to_string()
         }
     );
 
     let mut reading_frame = Frame::new(90, 50, 100, 30, None).with_label(label.as_str());
 
-    let label = format!(
-        "{}: {}",
-        match lang {
-            Lang::English => "Additional information",
-            Lang::Russian => "Дополнительная информация",
-        },
-        *unsafe {
-            &(*(*reader_base).borrow().readers.get_unchecked(ind))
-                .borrow()
-                .info
-        }
-    );
+    let label = format!("{}: {}", lang.tr("additional-information"), *unsafe {
+        &(*(*library).borrow().reader_base().readers.get_unchecked(ind))
+            .borrow()
+            .info
+    });
 
     let mut info_frame = Frame::new(100, 50, 100, 50, None).with_label(label.as_str());
 
@@ -721,20 +495,14 @@ pub(crate) fn reader_info_simple_all_books(
     table1.add(&reading_frame);
     table1.add(&info_frame);
 
-    let label = format!(
-        "{}:",
-        match lang {
-            Lang::English => "Books read by reader",
-            Lang::Russian => "Прочитанные книги",
-        }
-    );
+    let label = format!("{}:", lang.tr("books-read-by-reader"));
 
     table1.add(&Frame::new(160, 50, 100, 30, None).with_label(label.as_str()));
     table1.auto_layout();
 
     let mut table2 = Table::new(0, 230, 570, 600, "");
     table2.set_rows(max(30, unsafe {
-        (**(*reader_base).borrow().readers.get_unchecked(ind))
+        (**(*library).borrow().reader_base().readers.get_unchecked(ind))
             .borrow()
             .books
             .len() as i32
@@ -747,25 +515,13 @@ pub(crate) fn reader_info_simple_all_books(
 
     wind.end();
 
-    let mut menu = MenuBar::new(
-        0,
-        0,
-        255 + match lang {
-            Lang::English => 0,
-            Lang::Russian => 40,
-        },
-        30,
-        "",
-    );
+    let mut menu = MenuBar::new(0, 0, 255, 30, "");
     wind.add(&menu);
 
     let (s, r) = app::channel();
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Change/Change name\t",
-            Lang::Russian => "&Изменить/Изменить имя\t",
-        },
+        lang.tr("change-change-name"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -773,10 +529,7 @@ pub(crate) fn reader_info_simple_all_books(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Change/Change 2-nd name\t",
-            Lang::Russian => "&Изменить/Изменить фамилию\t",
-        },
+        lang.tr("change-change-2-nd-name"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -784,10 +537,7 @@ pub(crate) fn reader_info_simple_all_books(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Change/Change mid name\t",
-            Lang::Russian => "&Изменить/Изменить отчество\t",
-        },
+        lang.tr("change-change-mid-name"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -795,10 +545,7 @@ pub(crate) fn reader_info_simple_all_books(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Change/Change age\t",
-            Lang::Russian => "&Изменить/Изменить возраст\t",
-        },
+        lang.tr("change-change-age"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -806,10 +553,7 @@ pub(crate) fn reader_info_simple_all_books(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Change/Change additional information",
-            Lang::Russian => "&Изменить/Изменить дополнительную информацию",
-        },
+        lang.tr("change-change-additional-information"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -817,10 +561,7 @@ pub(crate) fn reader_info_simple_all_books(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Giveaway/Give book\t",
-            Lang::Russian => "&Выдача/Выдать книгу\t",
-        },
+        lang.tr("giveaway-give-book"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -828,10 +569,7 @@ pub(crate) fn reader_info_simple_all_books(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Giveaway/Get book\t",
-            Lang::Russian => "&Выдача/Вернуть книгу\t",
-        },
+        lang.tr("giveaway-get-book"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
@@ -839,20 +577,17 @@ pub(crate) fn reader_info_simple_all_books(
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Remove reader",
-            Lang::Russian => "&Удалить читателя",
-        },
+        lang.tr("remove-reader-menu"),
         Shortcut::empty(),
         MenuFlag::Normal,
         s,
         MessageReader::RemoveThis,
     );
 
+    fit_menu(&mut menu);
     wind.show();
 
-    let rb = reader_base.clone();
-    let bs = book_system.clone();
+    let lib = library.clone();
 
     table2.draw_cell(move |t, ctx, row, col, x, y, w, h| match ctx {
         fltk::table::TableContext::StartPage => draw::set_font(Font::Helvetica, 14),
@@ -861,25 +596,13 @@ pub(crate) fn reader_info_simple_all_books(
             &format!(
                 "{}",
                 match col {
-                    0 => match lang {
-                        Lang::English => "Title",
-                        Lang::Russian => "Название",
-                    },
-
-                    1 => match lang {
-                        Lang::English => "Author",
-                        Lang::Russian => "Автор",
-                    },
-
-                    2 => match lang {
-                        Lang::English => "Amount of Pages",
-                        Lang::Russian => "Кол-во страниц",
-                    },
-
-                    _ => match lang {
-                        Lang::English => "Number of book",
-                        Lang::Russian => "Номер книги",
-                    },
+                    0 => lang.tr("title"),
+
+                    1 => lang.tr("author"),
+
+                    2 => lang.tr("amount-of-pages"),
+
+                    _ => lang.tr("number-of-book-2"),
                 }
             ),
             x,
@@ -893,7 +616,13 @@ pub(crate) fn reader_info_simple_all_books(
         fltk::table::TableContext::Cell => draw_data(
             &format!(
                 "{}",
-                cell_book2(col, row, ind, &*(*rb).borrow(), &*(*bs).borrow())
+                cell_book2(
+                    col,
+                    row,
+                    ind,
+                    (*lib).borrow().reader_base(),
+                    (*lib).borrow().book_system()
+                )
             ),
             x,
             y,
@@ -910,25 +639,11 @@ pub(crate) fn reader_info_simple_all_books(
         if let Some(msg) = r.recv() {
             match msg {
                 MessageReader::ChangeName => {
-                    if let Some(new_name) = change_name_simple(
-                        ind,
-                        &mut *(*reader_base).borrow_mut(),
-                        &mut *(*book_system).borrow_mut(),
-                        genres,
-                        caretaker,
-                        app,
-                        lang,
-                    ) {
+                    if let Some(new_name) =
+                        change_name_simple(ind, &mut *(*library).borrow_mut(), app, lang)
+                    {
                         name_frame.set_label(
-                            format!(
-                                "{}: {}",
-                                match lang {
-                                    Lang::English => "First Name",
-                                    Lang::Russian => "\t\tИмя",
-                                },
-                                new_name
-                            )
-                            .as_str(),
+                            format!("{}: {}", lang.tr("first-name-tabbed"), new_name).as_str(),
                         );
                         name_frame.redraw();
                         main_table.redraw();
@@ -936,25 +651,11 @@ pub(crate) fn reader_info_simple_all_books(
                 }
 
                 MessageReader::ChangeFamily => {
-                    if let Some(new_family) = change_family_simple(
-                        ind,
-                        &mut *(*reader_base).borrow_mut(),
-                        &mut *(*book_system).borrow_mut(),
-                        genres,
-                        caretaker,
-                        app,
-                        lang,
-                    ) {
+                    if let Some(new_family) =
+                        change_family_simple(ind, &mut *(*library).borrow_mut(), app, lang)
+                    {
                         family_frame.set_label(
-                            format!(
-                                "{}: {}",
-                                match lang {
-                                    Lang::English => "Second Name",
-                                    Lang::Russian => "\t\tФамилия",
-                                },
-                                new_family
-                            )
-                            .as_str(),
+                            format!("{}: {}", lang.tr("second-name-tabbed"), new_family).as_str(),
                         );
                         family_frame.redraw();
                         main_table.redraw();
@@ -962,25 +663,11 @@ pub(crate) fn reader_info_simple_all_books(
                 }
 
                 MessageReader::ChangeFather => {
-                    if let Some(new_father) = change_father_simple(
-                        ind,
-                        &mut *(*reader_base).borrow_mut(),
-                        &mut *(*book_system).borrow_mut(),
-                        genres,
-                        caretaker,
-                        app,
-                        lang,
-                    ) {
+                    if let Some(new_father) =
+                        change_father_simple(ind, &mut *(*library).borrow_mut(), app, lang)
+                    {
                         father_frame.set_label(
-                            format!(
-                                "{}: {}",
-                                match lang {
-                                    Lang::English => "Middle Name",
-                                    Lang::Russian => "\t\tОтчество",
-                                },
-                                new_father
-                            )
-                            .as_str(),
+                            format!("{}: {}", lang.tr("middle-name-tabbed"), new_father).as_str(),
                         );
                         father_frame.redraw();
                         main_table.redraw();
@@ -988,77 +675,33 @@ pub(crate) fn reader_info_simple_all_books(
                 }
 
                 MessageReader::ChangeAge => {
-                    if let Some(new_age) = change_age_simple(
-                        ind,
-                        &mut *(*reader_base).borrow_mut(),
-                        &mut *(*book_system).borrow_mut(),
-                        genres,
-                        caretaker,
-                        app,
-                        lang,
-                    ) {
-                        age_frame.set_label(
-                            format!(
-                                "{}: {}",
-                                match lang {
-                                    Lang::English => "Age",
-                                    Lang::Russian => "\t\tВозраст",
-                                },
-                                new_age,
-                            )
-                            .as_str(),
-                        );
+                    if let Some(new_age) =
+                        change_age_simple(ind, &mut *(*library).borrow_mut(), app, lang)
+                    {
+                        age_frame
+                            .set_label(format!("{}: {}", lang.tr("age-tabbed"), new_age,).as_str());
                         age_frame.redraw();
                         main_table.redraw();
                     }
                 }
 
                 MessageReader::ChangeInfo => {
-                    if let Some(new_info) = change_info_simple(
-                        ind,
-                        &mut *(*reader_base).borrow_mut(),
-                        &mut *(*book_system).borrow_mut(),
-                        genres,
-                        caretaker,
-                        app,
-                        lang,
-                    ) {
+                    if let Some(new_info) =
+                        change_info_simple(ind, &mut *(*library).borrow_mut(), app, lang)
+                    {
                         info_frame.set_label(
-                            format!(
-                                "{}: {}",
-                                match lang {
-                                    Lang::English => "Additional Info",
-                                    Lang::Russian => "Дополнительная Информация",
-                                },
-                                new_info
-                            )
-                            .as_str(),
+                            format!("{}: {}", lang.tr("additional-info"), new_info).as_str(),
                         );
                         info_frame.redraw();
                     }
                 }
 
                 MessageReader::GiveBook => {
-                    if let Some(book) = give_book_known_reader(
-                        ind,
-                        &mut *(*reader_base).borrow_mut(),
-                        &mut *(*book_system).borrow_mut(),
-                        genres,
-                        caretaker,
-                        app,
-                        lang,
-                    ) {
-                        reading_frame.set_label(
-                            format!(
-                                "{}: {}",
-                                match lang {
-                                    Lang::English => "Reading now",
-                                    Lang::Russian => "Читается сейчас",
-                                },
-                                book
-                            )
-                            .as_str(),
-                        );
+                    if let Some(book) =
+                        give_book_known_reader(ind, &mut *(*library).borrow_mut(), app, lang)
+                    {
+                        reading_frame
+                            .set_label(format!("{}: {}", lang.tr("reading-now"), book).as_str());
                         reading_frame.redraw();
                     }
                     table2.redraw();
@@ -1066,28 +709,9 @@ pub(crate) fn reader_info_simple_all_books(
                 }
 
                 MessageReader::GetBook => {
-                    if get_book_known_reader(
-                        ind,
-                        &mut *(*reader_base).borrow_mut(),
-                        &mut *(*book_system).borrow_mut(),
-                        genres,
-                        caretaker,
-                        app,
-                        lang,
-                    ) {
+                    if get_book_known_reader(ind, &mut *(*library).borrow_mut(), app, lang) {
                         reading_frame.set_label(
-                            format!(
-                                "{}: {}",
-                                match lang {
-                                    Lang::English => "Reading now",
-                                    Lang::Russian => "Читается сейчас",
-                                },
-                                match lang {
-                                    Lang::English => "None",
-                                    Lang::Russian => "Ничего",
-                                },
-                            )
-                            .as_str(),
+                            format!("{}: {}", lang.tr("reading-now"), lang.tr("none-2"),).as_str(),
                         );
                         reading_frame.redraw();
                     }
@@ -1096,14 +720,7 @@ pub(crate) fn reader_info_simple_all_books(
                 }
 
                 MessageReader::RemoveThis => {
-                    remove_reader_simple(
-                        ind,
-                        &mut *(*reader_base).borrow_mut(),
-                        &mut *(*book_system).borrow_mut(),
-                        genres,
-                        caretaker,
-                        lang,
-                    );
+                    remove_reader_simple(ind, &mut *(*library).borrow_mut(), lang);
                     return;
                 }
             }
@@ -1112,7 +729,7 @@ pub(crate) fn reader_info_simple_all_books(
         }
 
         let len = unsafe {
-            (**(*reader_base).borrow().readers.get_unchecked(ind))
+            (**(*library).borrow().reader_base().readers.get_unchecked(ind))
                 .borrow()
                 .books
                 .len()
@@ -1126,15 +743,12 @@ pub(crate) fn reader_info_simple_all_books(
             {
                 unsafe {
                     book_info_simple(
-                        &(**(*reader_base).borrow().readers.get_unchecked(ind))
+                        &(**(*library).borrow().reader_base().readers.get_unchecked(ind))
                             .borrow()
                             .books
                             .get_unchecked(i)
                             .clone(),
-                        book_system.clone(),
-                        &(*reader_base).borrow(),
-                        genres,
-                        caretaker,
+                        library.clone(),
                         app,
                         lang,
                     );
@@ -17,34 +17,13 @@ use crate::{
 #[inline]
 pub(crate) fn empty_inp_reader(inp: &Vec<String>, lang: Lang) -> bool {
     return if unsafe { inp.get_unchecked(0).is_empty() } {
-        alert(
-            500,
-            500,
-            match lang {
-                Lang::English => "'Name' is empty",
-                Lang::Russian => "'Имя' пусто",
-            },
-        );
+        alert(500, 500, lang.tr("name-is-empty"));
         true
     } else if unsafe { inp.get_unchecked(1).is_empty() } {
-        alert(
-            500,
-            500,
-            match lang {
-                Lang::English => "'2-nd Name' is empty",
-                Lang::Russian => "'Фамилия' пусто",
-            },
-        );
+        alert(500, 500, lang.tr("2-nd-name-is-empty"));
         true
     } else if unsafe { inp.get_unchecked(2).is_empty() } {
-        alert(
-            500,
-            500,
-            match lang {
-                Lang::English => "'Mid. Name' is empty",
-                Lang::Russian => "'Фамилия' пусто",
-            },
-        );
+        alert(500, 500, lang.tr("mid-name-is-empty"));
         true
     } else {
         false
@@ -66,38 +45,12 @@ pub(crate) fn check_reader(
         true => None,
 
         false => {
-            let mut win = fltk::window::SingleWindow::new(
-                800,
-                500,
-                200,
-                100,
-                match lang {
-                    Lang::English => "Choose birth date",
-                    Lang::Russian => "Выберите дату рождения",
-                },
-            );
-
-            let _ = fltk::frame::Frame::new(
-                30,
-                10,
-                150,
-                50,
-                match lang {
-                    Lang::English => "Choose birth date",
-                    Lang::Russian => "Выберите дату рождения",
-                },
-            );
-
-            let mut but = fltk::button::Button::new(
-                80,
-                60,
-                60,
-                20,
-                match lang {
-                    Lang::English => "OK",
-                    Lang::Russian => "ОК",
-                },
-            );
+            let mut win =
+                fltk::window::SingleWindow::new(800, 500, 200, 100, lang.tr("choose-birth-date"));
+
+            let _ = fltk::frame::Frame::new(30, 10, 150, 50, lang.tr("choose-birth-date"));
+
+            let mut but = fltk::button::Button::new(80, 60, 60, 20, lang.tr("ok"));
 
             win.end();
             win.show();
@@ -110,22 +63,15 @@ pub(crate) fn check_reader(
                     if msg {
                         win.hide();
 
-                        return match Calendar::default().get_date() {
+                        return match Calendar::default().get_date().and_then(Date::from_naive) {
                             Some(date) => match reader_base.find_reader(
                                 unsafe { &reader.get_unchecked(0) },
                                 unsafe { &reader.get_unchecked(1) },
                                 unsafe { &reader.get_unchecked(2) },
-                                Date::from(date),
+                                date,
                             ) {
                                 None => {
-                                    alert(
-                                        500,
-                                        500,
-                                        match lang {
-                                            Lang::English => "Reader isn't found",
-                                            Lang::Russian => "Читатель не найден",
-                                        },
-                                    );
+                                    alert(500, 500, lang.tr("reader-isnt-found"));
                                     None
                                 }
 
@@ -133,14 +79,7 @@ pub(crate) fn check_reader(
                             },
 
                             None => {
-                                alert(
-                                    500,
-                                    500,
-                                    match lang {
-                                        Lang::English => "Date wasn't selected",
-                                        Lang::Russian => "Дата не была выбрана",
-                                    },
-                                );
+                                alert(500, 500, lang.tr("date-wasnt-selected"));
                                 None
                             }
                         };
@@ -166,11 +105,10 @@ pub(crate) fn get_book_ind(book_system: &BookSystem, book: *const Book) -> usize
     }
 
     unsafe {
-        match book_system.find_book(
-            &(*book).title().to_string(),
-            &(*book).author().to_string(),
-            (*book).pages(),
-        ) {
+        let the_book = (*book).the_book.as_ref().unwrap().upgrade().unwrap();
+        let id = (*the_book).borrow().id;
+
+        match book_system.find_book_by_id(id) {
             None => panic!("Index out of range"),
             Some(ind) => {
                 (*(**book_system.books.get_unchecked(ind)).borrow().books)
@@ -4,13 +4,16 @@ extern crate fltk;
 use crate::{
     actions::read::utils::get_book_ind,
     books::{book::Book, book_sys::BookSystem, date::Date, genres::Genres, the_book::TheBook},
+    calendar::OpeningCalendar,
     reading::read_base::ReaderBase,
     Lang,
 };
 
-use chrono::Datelike;
-
-use fltk::{draw, enums::Color, enums::{Align, FrameType}};
+use fltk::{
+    draw,
+    enums::Color,
+    enums::{Align, FrameType},
+};
 
 use std::{
     cell::RefCell,
@@ -32,15 +35,7 @@ pub fn draw_header(txt: &str, x: i32, y: i32, w: i32, h: i32) {
 /// Function that draws cells in table
 
 #[inline]
-pub fn draw_data(
-    txt: &str,
-    x: i32,
-    y: i32,
-    w: i32,
-    h: i32,
-    selected: bool,
-    color: Option<Color>,
-) {
+pub fn draw_data(txt: &str, x: i32, y: i32, w: i32, h: i32, selected: bool, color: Option<Color>) {
     draw::push_clip(x, y, w, h);
 
     draw::set_draw_color(if color.is_some() {
@@ -67,8 +62,8 @@ pub fn draw_data(
 /// if column is 1, it' ll return books' params (or none)
 /// if column is 2, it' ll return start date's params (or none)
 /// if column is 2, it' ll return finish date's params (or none).
-/// if reader is late, it' ll return some color,
-/// else none
+/// if reader is late (closed days of calendar are skipped),
+/// it' ll return some color, else none
 
 #[inline]
 pub fn cell_reader(
@@ -76,6 +71,7 @@ pub fn cell_reader(
     y: i32,
     reader_base: &ReaderBase,
     book_system: &BookSystem,
+    calendar: &OpeningCalendar,
     lang: Lang,
 ) -> (String, Option<Color>) {
     return if y < reader_base.len() as i32 {
@@ -83,7 +79,7 @@ pub fn cell_reader(
             match RefCell::borrow(unsafe { &(**reader_base.readers.get_unchecked(y as usize)) })
                 .reading
             {
-                None => Date::from(chrono::Local::now()),
+                None => Date::today(),
                 Some(_) => Date::new(
                     ((*RefCell::borrow(unsafe {
                         &(**reader_base.readers.get_unchecked(y as usize))
@@ -141,9 +137,9 @@ pub fn cell_reader(
             };
 
         let color = {
-            let cur_date = Date::from(chrono::Local::now());
+            let cur_date = Date::today();
 
-            if cur_date > reader_date {
+            if calendar.is_overdue(reader_date, cur_date) {
                 Some(Color::Red)
             } else {
                 None
@@ -162,6 +158,7 @@ pub fn cell_reader(
                         .father,
                     RefCell::borrow(unsafe { &(**reader_base.readers.get_unchecked(y as usize)) })
                         .birth
+                        .localized(lang)
                 ),
                 color,
             )
@@ -209,10 +206,7 @@ pub fn cell_reader(
                         .unwrap())
                         .borrow()
                         .pages(),
-                        match lang {
-                            Lang::English => "pages",
-                            Lang::Russian => "страниц",
-                        },
+                        lang.tr("pages"),
                         get_book_ind(
                             book_system,
                             (*RefCell::borrow(unsafe {
@@ -232,80 +226,30 @@ pub fn cell_reader(
                 ),
 
                 2 => (
-                    format!(
-                        "{}/{}/{}",
-                        ((*RefCell::borrow(unsafe {
-                            &(**reader_base.readers.get_unchecked(y as usize))
-                        })
-                        .reading
-                        .as_ref()
-                        .unwrap()
-                        .first()
-                        .unwrap()
-                        .upgrade()
-                        .unwrap())
-                        .borrow()
-                        .readers
-                        .last()
-                        .unwrap()
-                        .1)
-                            .0
-                            .day,
-                        ((*RefCell::borrow(unsafe {
-                            &(**reader_base.readers.get_unchecked(y as usize))
-                        })
-                        .reading
-                        .as_ref()
-                        .unwrap()
-                        .first()
-                        .unwrap()
-                        .upgrade()
-                        .unwrap())
-                        .borrow()
-                        .readers
-                        .last()
-                        .unwrap()
-                        .1)
-                            .0
-                            .month,
-                        ((*RefCell::borrow(unsafe {
-                            &(**reader_base.readers.get_unchecked(y as usize))
-                        })
-                        .reading
-                        .as_ref()
-                        .unwrap()
-                        .first()
-                        .unwrap()
-                        .upgrade()
-                        .unwrap())
-                        .borrow()
-                        .readers
-                        .last()
-                        .unwrap()
-                        .1)
-                            .0
-                            .year,
-                    ),
+                    ((*RefCell::borrow(unsafe {
+                        &(**reader_base.readers.get_unchecked(y as usize))
+                    })
+                    .reading
+                    .as_ref()
+                    .unwrap()
+                    .first()
+                    .unwrap()
+                    .upgrade()
+                    .unwrap())
+                    .borrow()
+                    .readers
+                    .last()
+                    .unwrap()
+                    .1)
+                        .0
+                        .localized(lang),
                     color,
                 ),
 
-                _ => (
-                    format!(
-                        "{}/{}/{}",
-                        reader_date.day, reader_date.month, reader_date.year,
-                    ),
-                    color,
-                ),
+                _ => (reader_date.localized(lang), color),
             }
         } else {
-            (
-                match lang {
-                    Lang::English => "None",
-                    Lang::Russian => "Ничего",
-                }
-                .to_string(),
-                None,
-            )
+            (lang.tr("none-2").to_string(), None)
         }
     } else {
         ("".to_string(), None)
@@ -321,7 +265,7 @@ pub fn cell_reader(
 /// else none
 
 #[inline]
-pub fn cell_reader2(x: i32, y: i32, book: Weak<RefCell<Book>>) -> String {
+pub fn cell_reader2(x: i32, y: i32, book: Weak<RefCell<Book>>, lang: Lang) -> String {
     return if y < (*book.upgrade().unwrap()).borrow().readers.len() as i32 {
         match x {
             0 => unsafe {
@@ -383,7 +327,7 @@ pub fn cell_reader2(x: i32, y: i32, book: Weak<RefCell<Book>>) -> String {
                     .get_unchecked(y as usize)
                     .1)
                     .0
-                    .to_string()
+                    .localized(lang)
             },
 
             _ => unsafe {
@@ -393,7 +337,7 @@ pub fn cell_reader2(x: i32, y: i32, book: Weak<RefCell<Book>>) -> String {
                     .get_unchecked(y as usize)
                     .1)
                     .1
-                    .to_string()
+                    .localized(lang)
             },
         }
     } else {
@@ -597,10 +541,7 @@ pub fn cell_book3(y: i32, books: &Vec<(String, String, u16)>, lang: Lang) -> Str
         {
             if books.is_empty() {
                 if y == 0 {
-                    match lang {
-                        Lang::English => "None",
-                        Lang::Russian => "Ничего",
-                    }
+                    lang.tr("none-2")
                 } else {
                     ""
                 }
@@ -623,18 +564,28 @@ pub fn cell_book3(y: i32, books: &Vec<(String, String, u16)>, lang: Lang) -> Str
     );
 }
 
-/// Function that returns date and time as string.
+/// Function that returns field of current date as string.
+/// Fields are ordered as in language's date format
 
 #[inline]
-pub fn cell_date_time(x: i32) -> String {
-    return format!(
-        "{}",
-        match x {
-            0 => chrono::Local::now().day().to_string(),
-            1 => chrono::Local::now().month().to_string(),
-            _ => chrono::Local::now().year().to_string(),
-        }
-    );
+pub fn cell_date_time(x: i32, lang: Lang) -> String {
+    return match Date::format_fields(lang.date_format()).get(x as usize) {
+        Some(field) => Date::today().format(field),
+        None => "".to_string(),
+    };
+}
+
+/// Function that returns name of current date's field.
+/// Fields are ordered as in language's date format
+
+#[inline]
+pub fn date_time_header(x: i32, lang: Lang) -> &'static str {
+    return match Date::format_fields(lang.date_format()).get(x as usize) {
+        Some(&"%d") => lang.tr("day"),
+        Some(&"%m") => lang.tr("month"),
+        Some(_) => lang.tr("year"),
+        None => "",
+    };
 }
 
 /// Function that returns book's genre as string.
@@ -648,10 +599,7 @@ pub(crate) fn cell_genre(x: i32, book: &Rc<RefCell<TheBook>>, lang: Lang) -> Str
                 if let Some(g) = unsafe { &(*(*book).as_ptr()).genres } {
                     g.iter().next().unwrap().as_str()
                 } else {
-                    match lang {
-                        Lang::English => "None",
-                        Lang::Russian => "Ничего",
-                    }
+                    lang.tr("none-2")
                 }
             } else {
                 if let Some(g) = unsafe { &(*(*book).as_ptr()).genres } {
@@ -0,0 +1,143 @@
+extern crate chrono;
+
+use crate::{books::date::Date, restore::command::Command, Lang};
+
+use chrono::NaiveDateTime;
+
+pub mod viewer;
+
+/// What was done with command
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AuditAction {
+    /// Operation was made
+    Done,
+
+    /// Operation was undone
+    Undone,
+
+    /// Undone operation was made again
+    Redone,
+}
+
+impl AuditAction {
+    /// All actions
+
+    pub const ALL: [AuditAction; 3] = [AuditAction::Done, AuditAction::Undone, AuditAction::Redone];
+
+    /// Name of action as it's stored
+
+    #[inline]
+    pub fn name(self) -> String {
+        format!("{:?}", self)
+    }
+
+    /// Searches action by its stored name
+
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Self> {
+        AuditAction::ALL.iter().copied().find(|a| a.name() == name)
+    }
+
+    /// Localized name of action
+
+    #[inline]
+    pub fn message(self, lang: Lang) -> &'static str {
+        lang.tr(match self {
+            AuditAction::Done => "audit-action-done",
+            AuditAction::Undone => "audit-action-undone",
+            AuditAction::Redone => "audit-action-redone",
+        })
+    }
+}
+
+/// One entry of audit log.
+/// Command keeps time of action and all changed records
+/// before and after it (for undone operation it's inverted)
+
+#[derive(Clone, Debug)]
+pub struct AuditEntry {
+    pub(crate) user: String,
+    pub(crate) action: AuditAction,
+    pub(crate) command: Command,
+}
+
+impl AuditEntry {
+    /// Login of user who made action
+
+    #[inline]
+    pub fn user(&self) -> &str {
+        self.user.as_str()
+    }
+
+    /// What was done with command
+
+    #[inline]
+    pub fn action(&self) -> AuditAction {
+        self.action
+    }
+
+    /// Operation with changed records
+
+    #[inline]
+    pub fn command(&self) -> &Command {
+        &self.command
+    }
+
+    /// Time of action
+
+    #[inline]
+    pub fn time(&self) -> NaiveDateTime {
+        self.command.time()
+    }
+}
+
+/// Filter of audit log.
+/// Empty conditions match every entry
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AuditFilter {
+    /// First day of actions
+    pub from: Option<Date>,
+
+    /// Last day of actions
+    pub to: Option<Date>,
+
+    /// Login of user
+    pub user: Option<String>,
+
+    /// Part of name of changed reader, book or genre
+    /// (case insensitive)
+    pub record: Option<String>,
+}
+
+impl AuditFilter {
+    /// Checks if entry satisfies all conditions
+
+    pub fn matches(&self, entry: &AuditEntry) -> bool {
+        let day = match Date::from_naive(entry.time().date()) {
+            Some(day) => day,
+            None => return false,
+        };
+
+        self.from.map_or(true, |from| day >= from)
+            && self.to.map_or(true, |to| day <= to)
+            && self.user.as_ref().map_or(true, |user| entry.user == *user)
+            && self.record.as_ref().map_or(true, |record| {
+                let record = record.to_lowercase();
+
+                entry
+                    .command
+                    .subjects()
+                    .iter()
+                    .any(|s| s.to_lowercase().contains(&record))
+            })
+    }
+
+    /// Entries which satisfy all conditions
+
+    #[inline]
+    pub fn apply<'a>(&self, entries: &'a [AuditEntry]) -> Vec<&'a AuditEntry> {
+        entries.iter().filter(|e| self.matches(e)).collect()
+    }
+}
@@ -0,0 +1,319 @@
+extern crate fltk;
+extern crate fltk_calendar;
+
+use crate::{
+    audit::{AuditEntry, AuditFilter},
+    books::date::Date,
+    library::Library,
+    restore::{
+        command::Change,
+        snapshot::{describe_reader, describe_title},
+    },
+    storage::{BookRecord, ReaderRecord},
+    Lang,
+};
+
+use fltk::{
+    app,
+    app::App,
+    browser::HoldBrowser,
+    button::Button,
+    dialog::alert,
+    input::Input,
+    prelude::*,
+    text::{TextBuffer, TextDisplay},
+    window::SingleWindow,
+};
+
+use fltk_calendar::calendar::Calendar;
+
+/// Format of action's time in audit log
+
+const TIME_FORMAT: &str = "%d.%m.%Y %H:%M:%S";
+
+/// Buttons of audit log's window
+
+#[derive(Clone, Copy)]
+enum Event {
+    From,
+    To,
+    Filter,
+    Reset,
+}
+
+/// Function that shows audit log
+/// from newest to oldest entry.
+/// Entries can be filtered by dates, user and record.
+/// Chosen entry is shown with all changed records
+/// before and after action
+
+pub fn audit(library: &Library, app: &App, lang: Lang) {
+    let entries = match library.audit() {
+        Ok(entries) => entries,
+
+        Err(err) => {
+            alert(
+                500,
+                500,
+                format!("{}\n{}", lang.tr("audit-log-is-damaged"), err).as_str(),
+            );
+            return;
+        }
+    };
+
+    let (s, r) = app::channel();
+
+    let mut wind = SingleWindow::new(300, 100, 900, 600, lang.tr("audit-log"));
+
+    let mut from = Button::new(10, 10, 120, 25, "");
+    let mut to = Button::new(140, 10, 120, 25, "");
+    let mut user = Input::new(330, 10, 120, 25, "");
+    let mut record = Input::new(530, 10, 150, 25, "");
+
+    user.set_label(lang.tr("user"));
+
+    record.set_label(lang.tr("record"));
+
+    let mut filter_button = Button::new(690, 10, 95, 25, lang.tr("filter"));
+
+    let mut reset = Button::new(795, 10, 95, 25, lang.tr("reset"));
+
+    let mut browser = HoldBrowser::new(0, 45, 900, 300, "");
+    let mut details = TextDisplay::new(0, 350, 900, 250, "");
+    details.set_buffer(TextBuffer::default());
+
+    from.emit(s, Event::From);
+    to.emit(s, Event::To);
+    filter_button.emit(s, Event::Filter);
+    reset.emit(s, Event::Reset);
+
+    wind.end();
+    wind.show();
+
+    let mut filter = AuditFilter::default();
+    let mut shown = filter.apply(&entries);
+    let mut selected = 0;
+
+    set_date_label(&mut from, filter.from, true, lang);
+    set_date_label(&mut to, filter.to, false, lang);
+    fill_audit(&mut browser, &shown, lang);
+
+    while app.wait() {
+        if let Some(event) = r.recv() {
+            match event {
+                Event::From => {
+                    filter.from = Calendar::default().get_date().and_then(Date::from_naive);
+                    set_date_label(&mut from, filter.from, true, lang);
+                }
+
+                Event::To => {
+                    filter.to = Calendar::default().get_date().and_then(Date::from_naive);
+                    set_date_label(&mut to, filter.to, false, lang);
+                }
+
+                Event::Filter => {
+                    filter.user = Some(user.value().trim().to_string()).filter(|u| !u.is_empty());
+                    filter.record =
+                        Some(record.value().trim().to_string()).filter(|r| !r.is_empty());
+                }
+
+                Event::Reset => {
+                    filter = AuditFilter::default();
+                    user.set_value("");
+                    record.set_value("");
+                    set_date_label(&mut from, filter.from, true, lang);
+                    set_date_label(&mut to, filter.to, false, lang);
+                }
+            }
+
+            shown = filter.apply(&entries);
+            fill_audit(&mut browser, &shown, lang);
+        }
+
+        if browser.value() != selected {
+            selected = browser.value();
+
+            details.buffer().unwrap().set_text(
+                match selected {
+                    ind if ind > 0 => describe_entry(shown[shown.len() - ind as usize], lang),
+                    _ => String::new(),
+                }
+                .as_str(),
+            );
+        }
+
+        if !wind.shown() {
+            return;
+        }
+    }
+}
+
+/// Shows chosen date on button.
+/// If date isn't chosen, there is no limit
+
+fn set_date_label(button: &mut Button, date: Option<Date>, from: bool, lang: Lang) {
+    button.set_label(
+        match (date, from) {
+            (Some(date), true) => lang.format("date-from", &[("date", &date.localized(lang))]),
+            (Some(date), false) => lang.format("date-to", &[("date", &date.localized(lang))]),
+            (None, true) => lang.tr("date-from-any").to_string(),
+            (None, false) => lang.tr("date-to-any").to_string(),
+        }
+        .as_str(),
+    );
+}
+
+/// Lists entries from newest to oldest
+
+fn fill_audit(browser: &mut HoldBrowser, entries: &[&AuditEntry], lang: Lang) {
+    browser.clear();
+
+    entries.iter().rev().for_each(|entry| {
+        browser.add(
+            format!(
+                "{}  {}  {}  {}",
+                entry.time().format(TIME_FORMAT),
+                entry.user(),
+                entry.action().message(lang),
+                entry.command().message(lang)
+            )
+            .as_str(),
+        )
+    });
+}
+
+/// Action with all records before and after it
+
+fn describe_entry(entry: &AuditEntry, lang: Lang) -> String {
+    let command = entry.command();
+
+    let mut text = format!(
+        "{}  {}  {}: {}\n",
+        entry.time().format(TIME_FORMAT),
+        entry.user(),
+        entry.action().message(lang),
+        command.operation().message(lang)
+    );
+
+    command.readers.iter().for_each(|change| {
+        describe_change(
+            &mut text,
+            change,
+            lang.tr("reader"),
+            |reader| describe_reader_full(reader, lang),
+            lang,
+        )
+    });
+
+    command.books.iter().for_each(|change| {
+        describe_change(
+            &mut text,
+            change,
+            lang.tr("book"),
+            |book| describe_book(book, lang),
+            lang,
+        )
+    });
+
+    command
+        .added_genres
+        .iter()
+        .for_each(|genre| text.push_str(&format!("\n+ {}: {}\n", lang.tr("genre"), genre)));
+
+    command
+        .removed_genres
+        .iter()
+        .for_each(|genre| text.push_str(&format!("\n- {}: {}\n", lang.tr("genre"), genre)));
+
+    text
+}
+
+/// Writes record before and after change.
+/// Added record has only state after,
+/// removed one has only state before
+
+fn describe_change<T>(
+    text: &mut String,
+    change: &Change<T>,
+    kind: &str,
+    describe: impl Fn(&T) -> String,
+    lang: Lang,
+) {
+    let (before, after) = (lang.tr("before"), lang.tr("after"));
+
+    let item = match change {
+        Change::Added(_, record) => format!("+ {}\n  {}: {}", kind, after, describe(record)),
+        Change::Removed(_, record) => format!("- {}\n  {}: {}", kind, before, describe(record)),
+
+        Change::Changed(old, new) => format!(
+            "~ {}\n  {}: {}\n  {}: {}",
+            kind,
+            before,
+            describe(old),
+            after,
+            describe(new)
+        ),
+    };
+
+    text.push('\n');
+    text.push_str(&item);
+    text.push('\n');
+}
+
+/// Reader with books he is reading
+
+#[inline]
+fn describe_reader_full(reader: &ReaderRecord, lang: Lang) -> String {
+    match reader.reading.is_empty() {
+        true => describe_reader(reader, lang),
+        false => format!(
+            "{} [{}]",
+            describe_reader(reader, lang),
+            reader.reading.join("; ")
+        ),
+    }
+}
+
+/// The Book with all simple books and their givings
+
+fn describe_book(book: &BookRecord, lang: Lang) -> String {
+    let copies = book
+        .copies
+        .iter()
+        .map(|copy| {
+            let loans = copy
+                .loans
+                .iter()
+                .map(|loan| {
+                    format!(
+                        "{} ({} - {})",
+                        loan.reader,
+                        loan.start.localized(lang),
+                        loan.finish.localized(lang)
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            format!(
+                "{}{}",
+                lang.format(
+                    "copy-location",
+                    &[
+                        ("id", &copy.id),
+                        ("cabinet", &copy.cabinet),
+                        ("shelf", &copy.shelf)
+                    ]
+                ),
+                match loans.is_empty() {
+                    true => String::new(),
+                    false => lang.format("given-to", &[("readers", &loans.join(", "))]),
+                }
+            )
+        })
+        .collect::<Vec<_>>();
+
+    match copies.is_empty() {
+        true => describe_title(book),
+        false => format!("{}\n    {}", describe_title(book), copies.join("\n    ")),
+    }
+}
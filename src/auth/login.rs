@@ -0,0 +1,143 @@
+extern crate fltk;
+
+use crate::{
+    auth::{normalize, AuthError, Role, Users},
+    change::{input2::Input2, input3::Input3, Inputable},
+    library::Library,
+    Lang,
+};
+
+use fltk::{
+    app,
+    app::App,
+    dialog::{alert, message},
+    input::{Input, SecretInput},
+    prelude::*,
+};
+
+/// Function that asks login and password
+/// until they are correct. If session is locked,
+/// only its user can unlock it.
+/// If window is closed, it will return None,
+/// else it will return login and role of user
+
+pub fn authorize(
+    users: &mut Users,
+    session: Option<&str>,
+    app: &App,
+    lang: Lang,
+) -> Option<(String, Role)> {
+    let (s, r) = app::channel();
+
+    loop {
+        let mut password = Input2::<Input, SecretInput>::new(
+            lang.tr(match session {
+                None => "authorization",
+                Some(_) => "session-is-locked",
+            }),
+            lang.tr("login"),
+            lang.tr("password"),
+        );
+
+        password.show();
+        (*password.ok).borrow_mut().emit(s, true);
+
+        let mut answered = false;
+
+        while app.wait() {
+            if let Some(true) = r.recv() {
+                answered = true;
+                break;
+            } else if !password.shown() {
+                return None;
+            }
+        }
+
+        if !answered {
+            return None;
+        }
+
+        let input = password.set_input(lang);
+        password.hide();
+
+        let data = match input {
+            Ok(data) => data,
+            Err(_) => continue,
+        };
+
+        let login = normalize(data.first().unwrap());
+
+        let result = match session {
+            Some(user) if user != login => Err(AuthError::WrongCredentials),
+            _ => users.authorize(&login, data.last().unwrap()),
+        };
+
+        match result {
+            Ok(role) => return Some((login, role)),
+            Err(AuthError::Locked) => alert_locked(users, &login, lang),
+            Err(err) => alert(500, 500, err.message(lang)),
+        }
+    }
+}
+
+/// Function that changes login and password
+/// of current user. Current password is asked
+/// and checked as on authorization
+
+pub fn change_credentials(users: &mut Users, library: &mut Library, app: &App, lang: Lang) {
+    let (s, r) = app::channel();
+
+    let mut inp = Input3::<SecretInput, Input, SecretInput>::new(
+        lang.tr("change-credentials"),
+        lang.tr("current-password"),
+        lang.tr("new-login"),
+        lang.tr("new-password"),
+    );
+
+    inp.show();
+    (*inp.ok).borrow_mut().emit(s, true);
+
+    while app.wait() {
+        if let Some(msg) = r.recv() {
+            if msg {
+                inp.hide();
+
+                if let Ok(data) = inp.set_input(lang) {
+                    let new_login = normalize(data[1].trim());
+
+                    match users.change_credentials(library.user(), &data[0], &new_login, &data[2]) {
+                        Ok(_) => {
+                            library.set_user(&new_login);
+
+                            message(500, 500, lang.tr("new-login-and-password-are-saved"));
+                        }
+
+                        Err(AuthError::Locked) => alert_locked(users, library.user(), lang),
+
+                        Err(err) => alert(500, 500, err.message(lang)),
+                    }
+                }
+            }
+            return;
+        } else if !inp.shown() {
+            return;
+        }
+    }
+}
+
+/// Tells how long login stays locked
+
+#[inline]
+fn alert_locked(users: &Users, login: &str, lang: Lang) {
+    alert(
+        500,
+        500,
+        format!(
+            "{}\n{}: {}",
+            AuthError::Locked.message(lang),
+            lang.tr("seconds-left"),
+            users.locked_for(login).map_or(0, |left| left.as_secs() + 1)
+        )
+        .as_str(),
+    );
+}
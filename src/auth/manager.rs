@@ -0,0 +1,171 @@
+extern crate fltk;
+
+use crate::{
+    auth::{Role, Users},
+    change::{input2::Input2, Inputable},
+    Lang,
+};
+
+use fltk::{
+    app,
+    app::App,
+    browser::HoldBrowser,
+    button::Button,
+    dialog::{alert, choice2},
+    input::{Input, SecretInput},
+    prelude::*,
+    window::SingleWindow,
+};
+
+/// Buttons of users' window
+
+#[derive(Clone, Copy)]
+enum Event {
+    Add,
+    Role,
+    Remove,
+}
+
+/// Function that shows all staff accounts with their roles.
+/// Administrator can add new accounts,
+/// change their roles and remove them
+
+pub fn users(users: &mut Users, app: &App, lang: Lang) {
+    let (s, r) = app::channel();
+
+    let mut wind = SingleWindow::new(500, 100, 400, 400, lang.tr("users"));
+
+    let mut browser = HoldBrowser::new(0, 0, 400, 360, "");
+
+    let mut add = Button::new(110, 370, 90, 25, lang.tr("add"));
+
+    let mut role = Button::new(210, 370, 90, 25, lang.tr("role"));
+
+    let mut remove = Button::new(310, 370, 80, 25, lang.tr("remove"));
+
+    add.emit(s, Event::Add);
+    role.emit(s, Event::Role);
+    remove.emit(s, Event::Remove);
+
+    wind.end();
+    wind.show();
+
+    fill_users(&mut browser, users, lang);
+
+    while app.wait() {
+        if let Some(event) = r.recv() {
+            let result = match event {
+                Event::Add => match input_user(app, lang) {
+                    Some((login, password)) => match choose_role(lang) {
+                        Some(role) => users.add(login.trim(), &password, role),
+                        None => continue,
+                    },
+
+                    None => continue,
+                },
+
+                Event::Role | Event::Remove => {
+                    let ind = browser.value();
+
+                    if ind <= 0 {
+                        alert(500, 500, lang.tr("user-isnt-selected"));
+                        continue;
+                    }
+
+                    let login = users.accounts()[ind as usize - 1].login.clone();
+
+                    match event {
+                        Event::Role => match choose_role(lang) {
+                            Some(role) => users.set_role(&login, role),
+                            None => continue,
+                        },
+
+                        _ => users.remove(&login),
+                    }
+                }
+            };
+
+            if let Err(err) = result {
+                alert(500, 500, err.message(lang));
+            }
+
+            fill_users(&mut browser, users, lang);
+        }
+
+        if !wind.shown() {
+            return;
+        }
+    }
+}
+
+/// Lists all accounts with their roles
+
+fn fill_users(browser: &mut HoldBrowser, users: &Users, lang: Lang) {
+    browser.clear();
+
+    users.accounts().iter().for_each(|account| {
+        browser.add(format!("{}  ({})", account.login(), account.role().message(lang)).as_str())
+    });
+}
+
+/// Asks login and password of new account.
+/// If window is closed, it will return None
+
+fn input_user(app: &App, lang: Lang) -> Option<(String, String)> {
+    let (s, r) = app::channel();
+
+    let mut inp = Input2::<Input, SecretInput>::new(
+        lang.tr("new-user"),
+        lang.tr("login"),
+        lang.tr("password"),
+    );
+
+    inp.show();
+    (*inp.ok).borrow_mut().emit(s, true);
+
+    while app.wait() {
+        if let Some(true) = r.recv() {
+            inp.hide();
+
+            return inp
+                .set_input(lang)
+                .ok()
+                .map(|data| (data.first().unwrap().clone(), data.last().unwrap().clone()));
+        } else if !inp.shown() {
+            return None;
+        }
+    }
+
+    None
+}
+
+/// Asks role of account.
+/// Librarian is also asked if they can override loan limits.
+/// If dialog is closed, it will return None
+
+#[inline]
+fn choose_role(lang: Lang) -> Option<Role> {
+    match choice2(
+        500,
+        500,
+        lang.tr("choose-role"),
+        Role::Administrator.message(lang),
+        Role::Librarian.message(lang),
+        Role::ReadOnly.message(lang),
+    )? {
+        0 => Some(Role::Administrator),
+        2 => Some(Role::ReadOnly),
+
+        _ => match choice2(
+            500,
+            500,
+            lang.tr("can-override-limits"),
+            lang.tr("yes"),
+            lang.tr("no"),
+            "",
+        )? {
+            0 => Some(Role::SeniorLibrarian),
+            _ => Some(Role::Librarian),
+        },
+    }
+}
@@ -0,0 +1,984 @@
+extern crate argon2;
+extern crate chrono;
+extern crate rand_core;
+extern crate unicode_normalization;
+extern crate yaml_rust;
+
+use crate::{
+    data::{
+        self,
+        error::{LoadError, LoadErrorKind, Record},
+        DataDir,
+    },
+    storage::{migration, yaml::emit},
+    Lang,
+};
+
+use std::{
+    collections::HashMap,
+    env,
+    error::Error,
+    fmt::{Display, Formatter},
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use argon2::{
+    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+
+use chrono::{Local, NaiveDateTime};
+
+use rand_core::OsRng;
+
+use unicode_normalization::UnicodeNormalization;
+
+use yaml_rust::{yaml::Hash, Yaml};
+
+pub mod login;
+pub mod manager;
+
+/// Separates login and password in admin file
+
+const SEPARATOR: char = '\0';
+
+/// Version of users' file
+
+const USERS_VERSION: i64 = 1;
+
+/// Key of accounts in users' file
+
+const USERS_KEY: &str = "Users";
+
+/// Key of account's failed attempts in users' file
+
+const FAILURES_KEY: &str = "Failures";
+
+/// Key of account's lockout end in users' file
+
+const LOCKED_KEY: &str = "Locked Until";
+
+/// Key of failed attempts with logins of no account in users' file
+
+const FAILED_LOGINS_KEY: &str = "Failed Logins";
+
+/// Format of lockout's end in users' file
+
+const TIME_FORMAT: &str = "%d.%m.%Y %H:%M:%S";
+
+/// How many failed attempts are allowed before lockout
+
+pub const FREE_ATTEMPTS: u32 = 3;
+
+/// Lockout after the first extra failed attempt.
+/// Every next failure doubles it
+
+pub const LOCKOUT_SECS: i64 = 30;
+
+/// Longest lockout
+
+pub const MAX_LOCKOUT_SECS: i64 = 60 * 60;
+
+/// How many logins with failed attempts are remembered.
+/// Logins which are locked for shorter time are forgotten first
+
+pub const MAX_LOCKOUTS: usize = 100;
+
+/// Command line flag to choose idle time (in minutes)
+/// after which main window is locked.
+/// Can be used as `--idle-timeout <n>` or `--idle-timeout=<n>`
+
+pub const IDLE_TIMEOUT_FLAG: &str = "--idle-timeout";
+
+/// Environment variable to choose idle time (in minutes)
+
+pub const IDLE_TIMEOUT_ENV: &str = "LIBRS_IDLE_TIMEOUT";
+
+/// Idle time (in minutes) by default
+
+pub const DEFAULT_IDLE_TIMEOUT: u64 = 15;
+
+/// Chooses idle time in the next order:
+/// command line flag, environment variable, default time.
+/// Incorrect values are ignored.
+/// If it's 0, window is never locked
+
+#[inline]
+pub fn idle_timeout_from_args<I: IntoIterator<Item = String>>(args: I) -> Option<Duration> {
+    let minutes = data::parse_flag(args, IDLE_TIMEOUT_FLAG)
+        .and_then(|t| t.parse().ok())
+        .or_else(|| env::var(IDLE_TIMEOUT_ENV).ok()?.parse().ok())
+        .unwrap_or(DEFAULT_IDLE_TIMEOUT);
+
+    Some(Duration::from_secs(minutes * 60)).filter(|t| !t.is_zero())
+}
+
+/// Chooses idle time for current process
+
+#[inline]
+pub fn idle_timeout_from_env() -> Option<Duration> {
+    idle_timeout_from_args(env::args().skip(1))
+}
+
+/// Brings login or password to Unicode NFC form,
+/// so the same text typed with composed
+/// or decomposed letters (like **й** or **и** + **̆**)
+/// gives the same credentials
+
+#[inline]
+pub fn normalize(text: &str) -> String {
+    text.nfc().collect()
+}
+
+/// Hashes password with Argon2id and random salt.
+/// Password is normalized to NFC before hashing.
+/// Result is PHC string with algorithm,
+/// its parameters, salt and hash
+/// (like **$argon2id$v=19$m=19456,t=2,p=1$...**)
+
+#[inline]
+pub fn hash_password(password: &str) -> io::Result<String> {
+    Argon2::default()
+        .hash_password(
+            normalize(password).as_bytes(),
+            &SaltString::generate(&mut OsRng),
+        )
+        .map(|hash| hash.to_string())
+        .map_err(|err| io::Error::new(ErrorKind::Other, err.to_string()))
+}
+
+/// Checks password (normalized to NFC) with PHC string.
+/// Parameters and salt are taken from string,
+/// so they can be changed without breaking old hashes
+
+#[inline]
+pub fn verify_password(password: &str, hash: &str) -> bool {
+    PasswordHash::new(hash).map_or(false, |hash| {
+        Argon2::default()
+            .verify_password(normalize(password).as_bytes(), &hash)
+            .is_ok()
+    })
+}
+
+/// Unsalted polynomial hash of old versions.
+/// It's only used to check old admin files
+/// before they are migrated
+
+#[inline]
+fn legacy_hash(str: &str, p: u128, module: u128) -> String {
+    let mut hash = 0;
+
+    str.bytes()
+        .enumerate()
+        .map(|(i, byte)| {
+            hash = match i {
+                0 => byte as u128,
+                _ => (hash.overflowing_mul(p).0.overflowing_add(byte as u128).0) % module,
+            };
+
+            hash as u8 as char
+        })
+        .collect()
+}
+
+/// What user is allowed to do
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Permission {
+    /// Search and view readers, books and genres
+    View,
+
+    /// Change readers, books, genres and give books
+    Edit,
+
+    /// Manage users and restore data
+    Administer,
+
+    /// Give books despite limits of loan policy
+    OverrideLimits,
+}
+
+/// Role of staff account
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// Manages users and restores data
+    Administrator,
+
+    /// Works with readers, books and givings
+    Librarian,
+
+    /// Librarian who can give books despite limits of loan policy
+    SeniorLibrarian,
+
+    /// Only searches and views data (volunteer)
+    ReadOnly,
+}
+
+impl Role {
+    /// All roles
+
+    pub const ALL: [Role; 4] = [
+        Role::Administrator,
+        Role::Librarian,
+        Role::SeniorLibrarian,
+        Role::ReadOnly,
+    ];
+
+    /// Name of role as it's stored.
+    /// Senior librarian was added without new version
+    /// of users' file, files without it are read as before
+
+    #[inline]
+    pub fn name(self) -> String {
+        format!("{:?}", self)
+    }
+
+    /// Searches role by its stored name
+
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Role::ALL.iter().copied().find(|r| r.name() == name)
+    }
+
+    /// Checks if role has permission
+
+    #[inline]
+    pub fn allows(self, permission: Permission) -> bool {
+        match permission {
+            Permission::View => true,
+            Permission::Edit => self != Role::ReadOnly,
+            Permission::Administer => self == Role::Administrator,
+
+            Permission::OverrideLimits => {
+                self == Role::Administrator || self == Role::SeniorLibrarian
+            }
+        }
+    }
+
+    /// Localized name of role
+
+    #[inline]
+    pub fn message(self, lang: Lang) -> &'static str {
+        lang.tr(match self {
+            Role::Administrator => "role-administrator",
+            Role::Librarian => "role-librarian",
+            Role::SeniorLibrarian => "role-senior-librarian",
+            Role::ReadOnly => "role-read-only",
+        })
+    }
+}
+
+/// Error of operation which isn't allowed for user's role
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AccessDenied;
+
+impl AccessDenied {
+    /// Localized description of error
+
+    #[inline]
+    pub fn message(&self, lang: Lang) -> &'static str {
+        lang.tr("access-denied")
+    }
+}
+
+impl Display for AccessDenied {
+    /// Describes error in english
+
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message(Lang::default()))
+    }
+}
+
+impl Error for AccessDenied {}
+
+/// Error of operation with accounts
+
+#[derive(Debug)]
+pub enum AuthError {
+    /// Login is empty
+    EmptyLogin,
+
+    /// Password is empty
+    EmptyPassword,
+
+    /// Account with same login exists
+    AlreadyExists,
+
+    /// Account with such login isn't found
+    NotFound,
+
+    /// There must be at least one administrator
+    LastAdministrator,
+
+    /// Login or password is wrong
+    WrongCredentials,
+
+    /// Too many failed attempts, login is locked for some time
+    Locked,
+
+    /// Users' file can't be written
+    Io(io::Error),
+}
+
+impl AuthError {
+    /// Localized description of error
+
+    #[inline]
+    pub fn message(&self, lang: Lang) -> &'static str {
+        lang.tr(match self {
+            AuthError::EmptyLogin => "auth-error-empty-login",
+            AuthError::EmptyPassword => "auth-error-empty-password",
+            AuthError::AlreadyExists => "auth-error-already-exists",
+            AuthError::NotFound => "auth-error-not-found",
+            AuthError::LastAdministrator => "auth-error-last-administrator",
+            AuthError::WrongCredentials => "auth-error-wrong-credentials",
+            AuthError::Locked => "auth-error-locked",
+            AuthError::Io(_) => "auth-error-io",
+        })
+    }
+}
+
+impl Display for AuthError {
+    /// Describes error in english
+
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message(Lang::default()))
+    }
+}
+
+impl Error for AuthError {
+    /// Underlying io error
+
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AuthError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AuthError {
+    /// Wraps io error
+
+    #[inline]
+    fn from(err: io::Error) -> Self {
+        AuthError::Io(err)
+    }
+}
+
+/// Staff account with role.
+/// Password is kept as PHC string
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Account {
+    pub(crate) login: String,
+    pub(crate) role: Role,
+    pub(crate) password: String,
+}
+
+impl Account {
+    /// Login of account
+
+    #[inline]
+    pub fn login(&self) -> &str {
+        self.login.as_str()
+    }
+
+    /// Role of account
+
+    #[inline]
+    pub fn role(&self) -> Role {
+        self.role
+    }
+}
+
+/// Login and password as they are stored in admin file
+/// of old versions (before several accounts)
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Stored {
+    /// Login and PHC string of password
+    Hashed { login: String, password: String },
+
+    /// Unsalted hashes of login and password
+    Legacy { login: String, password: String },
+}
+
+impl Stored {
+    /// Parses content of admin file.
+    /// If file is empty, it will return None
+
+    #[inline]
+    fn parse(content: &str) -> Option<Self> {
+        if content.is_empty() {
+            return None;
+        }
+
+        let (login, password) = content
+            .split_once(SEPARATOR)
+            .unwrap_or((content, Default::default()));
+
+        let login = login.to_string();
+        let password = password.to_string();
+
+        Some(match PasswordHash::new(&password) {
+            Ok(_) => Stored::Hashed { login, password },
+            Err(_) => Stored::Legacy { login, password },
+        })
+    }
+
+    /// Checks login and password
+
+    #[inline]
+    fn verify(&self, login: &str, password: &str) -> bool {
+        match self {
+            Stored::Hashed {
+                login: stored_login,
+                password: hash,
+            } => stored_login == login && verify_password(password, hash),
+
+            Stored::Legacy {
+                login: login_hash,
+                password: password_hash,
+            } => {
+                legacy_hash(login, 97, 1e9 as u128 + 7) == *login_hash
+                    && legacy_hash(password, 101, 1e9 as u128 + 7) == *password_hash
+            }
+        }
+    }
+}
+
+/// Failed attempts to log in.
+/// After several failures login is locked,
+/// every next failure makes lockout longer
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Lockout {
+    failures: u32,
+    until: Option<NaiveDateTime>,
+}
+
+impl Lockout {
+    /// Remembers failed attempt
+
+    #[inline]
+    fn fail(&mut self, now: NaiveDateTime) {
+        self.failures += 1;
+
+        if self.failures > FREE_ATTEMPTS {
+            let secs = LOCKOUT_SECS
+                .checked_shl(self.failures - FREE_ATTEMPTS - 1)
+                .filter(|&secs| secs > 0 && secs < MAX_LOCKOUT_SECS)
+                .unwrap_or(MAX_LOCKOUT_SECS);
+
+            self.until = Some(now + chrono::Duration::seconds(secs));
+        }
+    }
+
+    /// Checks if lockout ended longer than the longest lockout ago,
+    /// so its failed attempts are forgotten
+
+    #[inline]
+    fn is_expired(&self, now: NaiveDateTime) -> bool {
+        self.until
+            .is_some_and(|until| now - until > chrono::Duration::seconds(MAX_LOCKOUT_SECS))
+    }
+}
+
+/// Staff accounts with their roles.
+/// Accounts are stored in users' file of data directory
+/// together with their failed attempts to log in.
+/// Every login is locked separately, so failures
+/// with one login don't lock the others.
+/// Failures with logins of no account are kept the same way,
+/// so lockout doesn't show which accounts exist.
+/// Login from admin file of old versions becomes administrator
+/// after the first successful login, then admin file is cleared
+
+#[derive(Clone, Debug)]
+pub struct Users {
+    path: PathBuf,
+    admin: PathBuf,
+    accounts: Vec<Account>,
+    lockouts: HashMap<String, Lockout>,
+    legacy: Option<Stored>,
+}
+
+impl Users {
+    /// Reads accounts from data directory
+
+    pub fn load(data_dir: &DataDir) -> Result<Self, LoadError> {
+        let path = data_dir.users();
+        let admin = data_dir.admin();
+
+        let (accounts, lockouts) = match path.exists() {
+            true => match data::read_yaml(&path)? {
+                Some(doc) => users_from_yaml(&path, &doc)?,
+                None => Default::default(),
+            },
+
+            false => Default::default(),
+        };
+
+        let legacy = match accounts.is_empty() {
+            false => None,
+
+            true => match fs::read(&admin) {
+                Ok(content) => Stored::parse(&String::from_utf8_lossy(&content)),
+                Err(err) if err.kind() == ErrorKind::NotFound => None,
+                Err(err) => return Err(LoadError::file(&admin, LoadErrorKind::Io(err))),
+            },
+        };
+
+        Ok(Users {
+            path,
+            admin,
+            accounts,
+            lockouts,
+            legacy,
+        })
+    }
+
+    /// Checks if there are no accounts yet
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty() && self.legacy.is_none()
+    }
+
+    /// All accounts
+
+    #[inline]
+    pub fn accounts(&self) -> &[Account] {
+        &self.accounts
+    }
+
+    /// Account with chosen login.
+    /// Login is compared in NFC form
+
+    #[inline]
+    pub fn find(&self, login: &str) -> Option<&Account> {
+        let login = normalize(login);
+        self.accounts.iter().find(|a| a.login == login)
+    }
+
+    /// How long login stays locked after failed attempts.
+    /// If it isn't locked, it will return None
+
+    #[inline]
+    pub fn locked_for(&self, login: &str) -> Option<Duration> {
+        self.lockouts
+            .get(&normalize(login))
+            .and_then(|lockout| lockout.until)
+            .and_then(|until| (until - Local::now().naive_local()).to_std().ok())
+            .filter(|left| !left.is_zero())
+    }
+
+    /// Checks login and password and returns role of account.
+    /// After several failures login is locked for some time.
+    /// If login is from admin file of old versions,
+    /// it's migrated to administrator on success
+
+    pub fn authorize(&mut self, login: &str, password: &str) -> Result<Role, AuthError> {
+        let role = self.check(login, password)?;
+
+        if self.find(login).is_none() {
+            self.add(login, password, Role::Administrator)?;
+            data::write_atomic(&self.admin, &[])?;
+            self.legacy = None;
+        }
+
+        Ok(role)
+    }
+
+    /// Changes login and password of account.
+    /// Current password is checked as on authorization
+
+    pub fn change_credentials(
+        &mut self,
+        login: &str,
+        password: &str,
+        new_login: &str,
+        new_password: &str,
+    ) -> Result<(), AuthError> {
+        if new_login.is_empty() {
+            return Err(AuthError::EmptyLogin);
+        }
+
+        if new_password.is_empty() {
+            return Err(AuthError::EmptyPassword);
+        }
+
+        let ind = self.position(login)?;
+        let new_login = normalize(new_login);
+
+        if new_login != self.accounts[ind].login && self.find(&new_login).is_some() {
+            return Err(AuthError::AlreadyExists);
+        }
+
+        self.check(login, password)?;
+
+        let old = self.accounts[ind].clone();
+        self.accounts[ind].login = new_login;
+        self.accounts[ind].password = hash_password(new_password)?;
+
+        self.save().map_err(|err| {
+            self.accounts[ind] = old;
+            AuthError::Io(err)
+        })
+    }
+
+    /// Checks login and password with lockout of this login.
+    /// Failed attempts of all logins are remembered in users' file,
+    /// so restart doesn't reset lockout
+
+    fn check(&mut self, login: &str, password: &str) -> Result<Role, AuthError> {
+        if self.locked_for(login).is_some() {
+            return Err(AuthError::Locked);
+        }
+
+        let role = match (self.find(login), &self.legacy) {
+            (Some(account), _) => {
+                Some(account.role).filter(|_| verify_password(password, &account.password))
+            }
+
+            (None, Some(stored)) => {
+                Some(Role::Administrator).filter(|_| stored.verify(login, password))
+            }
+
+            (None, None) => None,
+        };
+
+        match role {
+            Some(role) => {
+                if self.lockouts.remove(&normalize(login)).is_some() {
+                    self.save()?;
+                }
+
+                Ok(role)
+            }
+
+            None => {
+                let login = normalize(login);
+                let now = Local::now().naive_local();
+
+                self.lockouts.entry(login.clone()).or_default().fail(now);
+                self.forget_failures(&login, now);
+
+                self.save()?;
+                Err(AuthError::WrongCredentials)
+            }
+        }
+    }
+
+    /// Forgets expired lockouts, then the shortest ones
+    /// until there are no more than `MAX_LOCKOUTS` logins.
+    /// Failures of chosen login are kept
+
+    fn forget_failures(&mut self, login: &str, now: NaiveDateTime) {
+        self.lockouts.retain(|_, lockout| !lockout.is_expired(now));
+
+        while self.lockouts.len() > MAX_LOCKOUTS {
+            let shortest = self
+                .lockouts
+                .iter()
+                .filter(|(other, _)| *other != login)
+                .min_by_key(|(_, lockout)| (lockout.until, lockout.failures))
+                .map(|(other, _)| other.clone());
+
+            match shortest {
+                Some(other) => self.lockouts.remove(&other),
+                None => break,
+            };
+        }
+    }
+
+    /// Adds new account.
+    /// Login must be unique, it's kept in NFC form
+
+    pub fn add(&mut self, login: &str, password: &str, role: Role) -> Result<(), AuthError> {
+        if login.is_empty() {
+            return Err(AuthError::EmptyLogin);
+        }
+
+        if password.is_empty() {
+            return Err(AuthError::EmptyPassword);
+        }
+
+        if self.find(login).is_some() {
+            return Err(AuthError::AlreadyExists);
+        }
+
+        self.accounts.push(Account {
+            login: normalize(login),
+            role,
+            password: hash_password(password)?,
+        });
+
+        self.save().map_err(|err| {
+            self.accounts.pop();
+            AuthError::Io(err)
+        })
+    }
+
+    /// Removes account.
+    /// The last administrator can't be removed
+
+    pub fn remove(&mut self, login: &str) -> Result<(), AuthError> {
+        let ind = self.position(login)?;
+        self.check_administrators(ind, None)?;
+
+        let account = self.accounts.remove(ind);
+
+        self.save().map_err(|err| {
+            self.accounts.insert(ind, account);
+            AuthError::Io(err)
+        })
+    }
+
+    /// Changes role of account.
+    /// The last administrator can't lose his role
+
+    pub fn set_role(&mut self, login: &str, role: Role) -> Result<(), AuthError> {
+        let ind = self.position(login)?;
+        self.check_administrators(ind, Some(role))?;
+
+        let old = std::mem::replace(&mut self.accounts[ind].role, role);
+
+        self.save().map_err(|err| {
+            self.accounts[ind].role = old;
+            AuthError::Io(err)
+        })
+    }
+
+    /// Index of account with chosen login
+
+    #[inline]
+    fn position(&self, login: &str) -> Result<usize, AuthError> {
+        let login = normalize(login);
+
+        self.accounts
+            .iter()
+            .position(|a| a.login == login)
+            .ok_or(AuthError::NotFound)
+    }
+
+    /// Checks that some administrator stays
+    /// after account with chosen index gets new role
+    /// (or is removed if role is None)
+
+    #[inline]
+    fn check_administrators(&self, ind: usize, role: Option<Role>) -> Result<(), AuthError> {
+        let others = self
+            .accounts
+            .iter()
+            .enumerate()
+            .filter(|(i, a)| *i != ind && a.role == Role::Administrator)
+            .count();
+
+        match others == 0 && role != Some(Role::Administrator) {
+            true => Err(AuthError::LastAdministrator),
+            false => Ok(()),
+        }
+    }
+
+    /// Writes all accounts to users' file
+
+    #[inline]
+    fn save(&self) -> io::Result<()> {
+        data::write_atomic(
+            &self.path,
+            emit(&users_to_yaml(&self.accounts, &self.lockouts))?.as_bytes(),
+        )
+    }
+}
+
+/// Adds failed attempts and lockout's end (if it's locked)
+/// to yaml data of login
+
+#[inline]
+fn lockout_to_yaml(lockout: &Lockout, data: &mut Hash) {
+    data.insert(
+        Yaml::String(FAILURES_KEY.to_string()),
+        Yaml::Integer(lockout.failures as i64),
+    );
+
+    if let Some(until) = lockout.until {
+        data.insert(
+            Yaml::String(LOCKED_KEY.to_string()),
+            Yaml::String(until.format(TIME_FORMAT).to_string()),
+        );
+    }
+}
+
+/// Parses failed attempts and lockout's end of login.
+/// If they aren't written, there are none of them
+
+fn lockout_from_yaml(record: &Record, d: &Yaml) -> Result<Lockout, LoadError> {
+    let failures = match &d[FAILURES_KEY] {
+        Yaml::BadValue => 0,
+        failures => failures
+            .as_i64()
+            .and_then(|f| u32::try_from(f).ok())
+            .ok_or_else(|| record.error(FAILURES_KEY, LoadErrorKind::InvalidValue))?,
+    };
+
+    let until = match &d[LOCKED_KEY] {
+        Yaml::BadValue => None,
+        until => Some(
+            until
+                .as_str()
+                .and_then(|u| NaiveDateTime::parse_from_str(u, TIME_FORMAT).ok())
+                .ok_or_else(|| record.error(LOCKED_KEY, LoadErrorKind::InvalidValue))?,
+        ),
+    };
+
+    Ok(Lockout { failures, until })
+}
+
+/// Converts accounts with their failed attempts
+/// to yaml document with version header.
+/// Failed attempts with logins of no account
+/// are written separately (sorted by login)
+
+fn users_to_yaml(accounts: &[Account], lockouts: &HashMap<String, Lockout>) -> Yaml {
+    let users = accounts
+        .iter()
+        .map(|account| {
+            let mut data = Hash::new();
+
+            data.insert(
+                Yaml::String("Login".to_string()),
+                Yaml::String(account.login.clone()),
+            );
+
+            data.insert(
+                Yaml::String("Role".to_string()),
+                Yaml::String(account.role.name()),
+            );
+
+            data.insert(
+                Yaml::String("Password".to_string()),
+                Yaml::String(account.password.clone()),
+            );
+
+            if let Some(lockout) = lockouts.get(&account.login) {
+                lockout_to_yaml(lockout, &mut data);
+            }
+
+            Yaml::Hash(data)
+        })
+        .collect();
+
+    let mut failed_logins = lockouts
+        .iter()
+        .filter(|(login, _)| accounts.iter().all(|a| a.login != **login))
+        .collect::<Vec<_>>();
+
+    failed_logins.sort_by(|a, b| a.0.cmp(b.0));
+
+    let failed_logins = failed_logins
+        .into_iter()
+        .map(|(login, lockout)| {
+            let mut data = Hash::new();
+
+            data.insert(
+                Yaml::String("Login".to_string()),
+                Yaml::String(login.clone()),
+            );
+
+            lockout_to_yaml(lockout, &mut data);
+            Yaml::Hash(data)
+        })
+        .collect::<Vec<_>>();
+
+    let mut hash = Hash::new();
+
+    hash.insert(
+        Yaml::String(migration::VERSION_KEY.to_string()),
+        Yaml::Integer(USERS_VERSION),
+    );
+
+    hash.insert(Yaml::String(USERS_KEY.to_string()), Yaml::Array(users));
+
+    if !failed_logins.is_empty() {
+        hash.insert(
+            Yaml::String(FAILED_LOGINS_KEY.to_string()),
+            Yaml::Array(failed_logins),
+        );
+    }
+
+    Yaml::Hash(hash)
+}
+
+/// Parses accounts and failed attempts of all logins from yaml document.
+/// Accounts without failed attempts have none of them.
+/// Failed attempts shared by all accounts
+/// (as they were saved before) are ignored
+
+fn users_from_yaml(
+    path: &Path,
+    doc: &Yaml,
+) -> Result<(Vec<Account>, HashMap<String, Lockout>), LoadError> {
+    match migration::version(doc) {
+        Some(USERS_VERSION) => {}
+        Some(version) => {
+            return Err(LoadError::file(
+                path,
+                LoadErrorKind::UnsupportedVersion(version),
+            ))
+        }
+        None => return Err(LoadError::file(path, LoadErrorKind::WrongLayout)),
+    }
+
+    let mut lockouts = HashMap::new();
+
+    let accounts = doc[USERS_KEY]
+        .as_vec()
+        .ok_or_else(|| LoadError::file(path, LoadErrorKind::WrongLayout))?
+        .iter()
+        .enumerate()
+        .map(|(ind, d)| {
+            let record = Record::new(path, ind);
+
+            let account = Account {
+                login: record.str(d, "Login")?,
+                role: d["Role"]
+                    .as_str()
+                    .and_then(Role::from_name)
+                    .ok_or_else(|| record.error("Role", LoadErrorKind::InvalidValue))?,
+                password: record.str(d, "Password")?,
+            };
+
+            let lockout = lockout_from_yaml(&record, d)?;
+
+            if lockout != Lockout::default() {
+                lockouts.insert(account.login.clone(), lockout);
+            }
+
+            Ok(account)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if let Some(failed_logins) = doc[FAILED_LOGINS_KEY].as_vec() {
+        for (ind, d) in failed_logins.iter().enumerate() {
+            let record = Record::new(path, ind);
+            let login = record.str(d, "Login")?;
+            let lockout = lockout_from_yaml(&record, d)?;
+
+            if lockout != Lockout::default() {
+                lockouts.insert(login, lockout);
+            }
+        }
+    }
+
+    Ok((accounts, lockouts))
+}
@@ -1,18 +1,15 @@
 extern crate chrono;
-extern crate fltk;
 
 use crate::{
     actions::read::utils::get_book_ind,
-    books::{book_sys::BookSystem, date::Date, the_book::TheBook, ResultSelf},
-    change::{input2::Input2, Inputable},
+    books::{book_sys::BookSystem, date::Date, error::BookError, the_book::TheBook, ResultSelf},
+    calendar::OpeningCalendar,
+    id::Id,
     reading::reader::Reader,
-    Lang,
 };
 
 use chrono::Datelike;
 
-use fltk::{app, app::App, input::IntInput, prelude::*};
-
 use std::{
     cell::RefCell,
     fmt::{Debug, Formatter, Result},
@@ -21,15 +18,18 @@ use std::{
 
 /// Simple Book structure, which contains
 /// title, author, amount of pages, using status,
-/// location (number of cabinet and it's shelf)
+/// location (number of cabinet and it's shelf),
+/// type of copy (may be empty)
 /// and readers with start and finish dates
 
 #[derive(Default)]
 pub struct Book {
+    pub(crate) id: Id,
     pub(crate) the_book: Option<Weak<RefCell<TheBook>>>,
     pub(crate) is_using: bool,
     pub(crate) cabinet: u16,
     pub(crate) shelf: u8,
+    pub(crate) copy_type: String,
     pub(crate) readers: Vec<(Weak<RefCell<Reader>>, (Date, Date))>,
 }
 
@@ -59,6 +59,7 @@ impl Debug for Book {
             .field("is using", &self.is_using)
             .field("cabinet", &self.cabinet)
             .field("shelf", &self.shelf)
+            .field("copy type", &self.copy_type)
             .field(
                 "readers",
                 &self
@@ -105,54 +106,20 @@ impl PartialEq for Book {
 impl Eq for Book {}
 
 impl Book {
-    /// Constructs new simple book.
-    /// By default it has no readers and it isn't used.
-    /// If there are some input errors,
-    /// it'll return None
+    /// Constructs new simple book with chosen id and location.
+    /// By default it has no readers and it isn't used
 
     #[inline]
-    pub(crate) fn new(the_book: Rc<RefCell<TheBook>>, app: &App, lang: Lang) -> Option<Self> {
-        let (s2, r2) = app::channel();
-        let mut inp = Input2::<IntInput, IntInput>::new(
-            match lang {
-                Lang::English => "Location",
-                Lang::Russian => "Местонахождения",
-            },
-            match lang {
-                Lang::English => "Cabinet's number",
-                Lang::Russian => "Номер шкафа",
-            },
-            match lang {
-                Lang::English => "Shelf's number",
-                Lang::Russian => "Номер полки",
-            },
-        );
-
-        inp.show();
-        (*inp.ok).borrow_mut().emit(s2, true);
-
-        while app.wait() {
-            if let Some(message) = r2.recv() {
-                if message {
-                    inp.hide();
-
-                    if let Ok(location) = inp.set_input(lang) {
-                        return Some(Book {
-                            the_book: Some(Rc::downgrade(&the_book)),
-                            is_using: false,
-                            cabinet: location.first().unwrap().trim().parse().unwrap(),
-                            shelf: location.last().unwrap().trim().parse().unwrap(),
-                            readers: vec![],
-                        });
-                    }
-                }
-                return None;
-            } else if !inp.shown() {
-                return None;
-            }
+    pub(crate) fn new(id: Id, the_book: &Rc<RefCell<TheBook>>, cabinet: u16, shelf: u8) -> Self {
+        Book {
+            id,
+            the_book: Some(Rc::downgrade(the_book)),
+            is_using: false,
+            cabinet,
+            shelf,
+            copy_type: String::new(),
+            readers: vec![],
         }
-
-        None
     }
 
     /// Constructs book with known params.
@@ -160,16 +127,19 @@ impl Book {
     #[inline]
     #[allow(dead_code)]
     pub(crate) fn restore(
+        _id: Id,
         _the_book: Rc<RefCell<TheBook>>,
         _is_using: bool,
         _cabinet: u16,
         _shelf: u8,
     ) -> Self {
         Book {
+            id: _id,
             the_book: Some(Rc::downgrade(&_the_book)),
             is_using: _is_using,
             cabinet: _cabinet,
             shelf: _shelf,
+            copy_type: String::new(),
             readers: vec![],
         }
     }
@@ -203,6 +173,16 @@ impl Book {
             .pages
     }
 
+    /// Checks if both simple books are copies of the same The Book
+
+    #[inline]
+    pub(crate) fn is_copy_of_same(&self, other: &Book) -> bool {
+        match (&self.the_book, &other.the_book) {
+            (Some(first), Some(second)) => first.ptr_eq(second),
+            _ => false,
+        }
+    }
+
     /// Searches reader.
     /// If it isn't found, it' ll return the amount of all readers.
     /// else it will return reader index of the first occurrence
@@ -315,17 +295,22 @@ impl Book {
     }
 
     /// Function that uses after returning book from reader.
-    /// It changes book's status and finish date  
+    /// It changes book's status and finish date.
+    /// Book is late if it's returned after
+    /// the first open day since finish date
 
     #[inline]
-    pub(crate) fn finish_reading(&mut self) -> ResultSelf<Self> {
+    pub(crate) fn finish_reading(
+        &mut self,
+        calendar: &OpeningCalendar,
+    ) -> ResultSelf<Self, BookError> {
         self.is_using = false;
 
-        let now = Date::from(chrono::Local::now());
+        let now = Date::today();
         let was = ((*self.readers.last().unwrap()).1).1;
 
-        if now > was {
-            Err(1) // Reader is late
+        if calendar.is_overdue(was, now) {
+            Err(BookError::ReturnedLate)
         } else {
             ((*self.readers.last_mut().unwrap()).1).1 = now;
             Ok(self)
@@ -342,18 +327,13 @@ impl Book {
         self
     }
 
-    /// Clones simple book
-    /// with empty readers
+    /// Changes type of copy (e.g. "Reference").
+    /// Empty type means ordinary copy
 
     #[inline]
-    pub(crate) fn clone(&self, the_book: Rc<RefCell<TheBook>>) -> Self {
-        Book {
-            the_book: Some(Rc::downgrade(&the_book)),
-            is_using: self.is_using,
-            cabinet: self.cabinet,
-            shelf: self.shelf,
-            readers: vec![],
-        }
+    pub(crate) fn change_copy_type(&mut self, new_copy_type: String) -> &mut Self {
+        self.copy_type = new_copy_type;
+        self
     }
 
     /// Represent Book as String with next format:
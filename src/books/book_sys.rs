@@ -1,27 +1,20 @@
-extern crate fltk;
-extern crate yaml_rust;
-
 use crate::{
-    books::{book::Book, date::Date, the_book::TheBook, ResultSelf},
-    reading::{read_base::ReaderBase, reader::Reader},
-    Lang,
+    books::{
+        book::Book, date::Date, error::BookError, index::BookIndex, the_book::TheBook, ResultSelf,
+    },
+    data::error::{LoadError, LoadErrorKind, Record},
+    id::{Id, IdGen},
+    reading::read_base::ReaderBase,
+    storage::{BookRecord, Storage},
 };
 
 use std::{
     cell::RefCell,
     collections::HashSet,
     fmt::{Debug, Formatter},
-    fs::File,
-    io::{Read, Write},
+    io,
     iter::FromIterator,
-    rc::{Rc, Weak},
-};
-
-use fltk::app::App;
-
-use yaml_rust::{
-    yaml::{Array, Hash},
-    Yaml, YamlEmitter, YamlLoader,
+    rc::Rc,
 };
 
 /// Reader Base structure,
@@ -30,6 +23,9 @@ use yaml_rust::{
 #[derive(Default)]
 pub struct BookSystem {
     pub(crate) books: Vec<Rc<RefCell<TheBook>>>,
+    pub(crate) the_book_ids: IdGen,
+    pub(crate) book_ids: IdGen,
+    index: BookIndex,
 }
 
 impl Debug for BookSystem {
@@ -67,8 +63,27 @@ impl FromIterator<Rc<RefCell<TheBook>>> for BookSystem {
 
     #[inline]
     fn from_iter<T: IntoIterator<Item = Rc<RefCell<TheBook>>>>(iter: T) -> Self {
+        let books = iter.into_iter().collect::<Vec<_>>();
+        let mut the_book_ids = IdGen::new();
+        let mut book_ids = IdGen::new();
+
+        books.iter().for_each(|x| {
+            the_book_ids.observe((**x).borrow().id);
+
+            (**x)
+                .borrow()
+                .books
+                .iter()
+                .for_each(|b| book_ids.observe((**b).borrow().id));
+        });
+
+        let index = BookIndex::build(&books);
+
         BookSystem {
-            books: iter.into_iter().collect(),
+            books,
+            the_book_ids,
+            book_ids,
+            index,
         }
     }
 }
@@ -77,8 +92,13 @@ impl BookSystem {
     /// Constructs empty Book System
 
     #[inline]
-    pub const fn new() -> Self {
-        BookSystem { books: vec![] }
+    pub fn new() -> Self {
+        BookSystem {
+            books: vec![],
+            the_book_ids: IdGen::new(),
+            book_ids: IdGen::new(),
+            index: BookIndex::new(),
+        }
     }
 
     /// Creates immutable iterator
@@ -90,15 +110,6 @@ impl BookSystem {
         self.books.iter()
     }
 
-    /// Creates mutable iterate
-    /// on Book System with
-    /// smart pointers of The Book
-
-    #[inline]
-    pub(crate) fn iter_mut(&mut self) -> std::slice::IterMut<Rc<RefCell<TheBook>>> {
-        self.books.iter_mut()
-    }
-
     /// Amount of books
 
     #[inline]
@@ -111,63 +122,105 @@ impl BookSystem {
 
     #[inline]
     pub(crate) fn find_book(&self, title: &String, author: &String, pages: u16) -> Option<usize> {
-        self.iter().position(|x| {
-            (**x).borrow().title == *title
-                && (**x).borrow().author == *author
-                && (**x).borrow().pages == pages
-        })
+        self.index.find(title, author, pages)
+    }
+
+    /// Finds The Book by id
+
+    #[inline]
+    pub(crate) fn find_book_by_id(&self, id: Id) -> Option<usize> {
+        self.index.position(id)
+    }
+
+    /// Finds all The Books of author.
+    /// Returns indexes in ascending order
+
+    #[inline]
+    pub(crate) fn find_by_author(&self, author: &String) -> Vec<usize> {
+        self.index.find_by_author(author)
+    }
+
+    /// Finds all The Books with genre.
+    /// Returns indexes in ascending order
+
+    #[inline]
+    pub(crate) fn find_by_genre(&self, genre: &String) -> Vec<usize> {
+        self.index.find_by_genre(genre)
     }
 
-    /// Adds simple books without any checks
+    /// Finds simple book by it's pointer.
+    /// Returns index of The Book and index of simple book
+
+    #[inline]
+    pub(crate) fn find_simple_book(&self, book: &Rc<RefCell<Book>>) -> Option<(usize, usize)> {
+        let the_book = (**book).borrow().the_book.as_ref()?.upgrade()?;
+        let ind = self.find_book_by_id((*the_book).borrow().id)?;
+
+        if !Rc::ptr_eq(unsafe { self.books.get_unchecked(ind) }, &the_book) {
+            return None;
+        }
+
+        let s_ind = (*the_book)
+            .borrow()
+            .books
+            .iter()
+            .position(|b| Rc::ptr_eq(b, book))?;
+
+        Some((ind, s_ind))
+    }
+
+    /// Adds simple books to chosen locations
+    /// (cabinet and shelf) without any checks
 
     #[inline]
     pub(crate) unsafe fn add_books_unchecked(
         &mut self,
         ind: usize,
-        amount: usize,
-        app: &App,
-        lang: Lang,
+        locations: &[(u16, u8)],
     ) -> &mut Self {
-        (0..amount).for_each(|_| {
-            if let Some(simple) = Book::new(self.books.get_unchecked(ind).clone(), app, lang) {
-                (**self.books.get_unchecked_mut(ind))
-                    .borrow_mut()
-                    .books
-                    .push(Rc::new(RefCell::new(simple)));
-            }
+        locations.iter().for_each(|&(cabinet, shelf)| {
+            let simple = Book::new(
+                self.book_ids.next(),
+                self.books.get_unchecked(ind),
+                cabinet,
+                shelf,
+            );
+
+            (**self.books.get_unchecked_mut(ind))
+                .borrow_mut()
+                .books
+                .push(Rc::new(RefCell::new(simple)));
         });
         self
     }
 
-    /// Adds simple books with strong guarantee
+    /// Adds simple books to chosen locations
+    /// (cabinet and shelf) with strong guarantee
 
     #[inline]
     pub(crate) fn add_books(
         &mut self,
         ind: usize,
-        amount: usize,
-        app: &App,
-        lang: Lang,
-    ) -> ResultSelf<Self> {
+        locations: &[(u16, u8)],
+    ) -> ResultSelf<Self, BookError> {
         return if ind >= self.len() {
-            Err(1) // out of range
+            Err(BookError::NotFound)
         } else {
             unsafe {
                 let size = RefCell::borrow(&(**self.books.get_unchecked(ind)))
                     .books
                     .len() as u128;
 
-                if size + amount as u128 > usize::MAX as u128 {
-                    return Err(0); // too much books
+                if size + locations.len() as u128 > usize::MAX as u128 {
+                    return Err(BookError::TooManyBooks);
                 }
-                Ok(self.add_books_unchecked(ind, amount, app, lang))
+                Ok(self.add_books_unchecked(ind, locations))
             }
         };
     }
 
-    /// Adds new TheBook and **ONE** simple
-    /// (I think it's logical)
-    /// No checks provided
+    /// Adds new TheBook and its simple books
+    /// to chosen locations. No checks provided
 
     #[inline]
     pub(crate) unsafe fn add_book_unchecked(
@@ -175,19 +228,19 @@ impl BookSystem {
         new_title: String,
         new_author: String,
         new_pages: u16,
-        amount: usize,
-        app: &App,
-        lang: Lang,
+        locations: &[(u16, u8)],
     ) -> &mut Self {
-        self.books.push(Rc::new(RefCell::new(TheBook::new(
-            new_title, new_author, new_pages,
-        ))));
-        self.add_books_unchecked(self.books.len() - 1, amount, app, lang);
+        let id = self.the_book_ids.next();
+        let the_book = TheBook::new(id, new_title, new_author, new_pages);
+
+        self.index.insert(&the_book, self.books.len());
+        self.books.push(Rc::new(RefCell::new(the_book)));
+        self.add_books_unchecked(self.books.len() - 1, locations);
         self
     }
 
-    /// Adds new TheBook and **ONE** simple
-    /// (I think it's logical)
+    /// Adds new TheBook and its simple books
+    /// to chosen locations
 
     #[inline]
     pub(crate) fn add_book(
@@ -195,18 +248,14 @@ impl BookSystem {
         new_title: String,
         new_author: String,
         new_pages: u16,
-        amount: usize,
-        app: &App,
-        lang: Lang,
-    ) -> ResultSelf<Self> {
+        locations: &[(u16, u8)],
+    ) -> ResultSelf<Self, BookError> {
         return if !self.books.is_empty()
             && self.find_book(&new_title, &new_author, new_pages).is_some()
         {
-            Err(0) // already exists
+            Err(BookError::AlreadyExists)
         } else {
-            Ok(unsafe {
-                self.add_book_unchecked(new_title, new_author, new_pages, amount, app, lang)
-            })
+            Ok(unsafe { self.add_book_unchecked(new_title, new_author, new_pages, locations) })
         };
     }
 
@@ -220,16 +269,20 @@ impl BookSystem {
             .unwrap();
 
         if (**self.books.get_unchecked(ind)).borrow().books.is_empty() {
-            self.books.remove(ind);
+            self.index.remove(&self.books.remove(ind).borrow());
         }
     }
 
     /// Remove one simple book by index
 
     #[inline]
-    pub(crate) fn remove_one_book(&mut self, ind: usize, rind: usize) -> ResultSelf<Self> {
+    pub(crate) fn remove_one_book(
+        &mut self,
+        ind: usize,
+        rind: usize,
+    ) -> ResultSelf<Self, BookError> {
         return if ind >= self.len() {
-            Err(0) // search ind (TheBook) out of range
+            Err(BookError::NotFound)
         } else {
             unsafe {
                 if rind
@@ -237,7 +290,7 @@ impl BookSystem {
                         .books
                         .len()
                 {
-                    return Err(1); // remove ind (simple) out of range
+                    return Err(BookError::SimpleBookNotFound);
                 }
 
                 self.remove_one_book_unchecked(ind, rind);
@@ -254,16 +307,16 @@ impl BookSystem {
             .borrow_mut()
             .remove_all_books();
 
-        self.books.remove(ind);
+        self.index.remove(&self.books.remove(ind).borrow());
         self
     }
 
     /// Removes TheBook and all simple books
 
     #[inline]
-    pub(crate) fn remove_book(&mut self, ind: usize) -> ResultSelf<Self> {
+    pub(crate) fn remove_book(&mut self, ind: usize) -> ResultSelf<Self, BookError> {
         return if ind >= self.len() {
-            Err(0) // out of range
+            Err(BookError::NotFound)
         } else {
             Ok(unsafe { self.remove_book_unchecked(ind) })
         };
@@ -277,18 +330,24 @@ impl BookSystem {
         ind: usize,
         new_title: String,
     ) -> &mut Self {
-        (**self.books.get_unchecked_mut(ind))
-            .borrow_mut()
-            .change_title(new_title);
+        let mut the_book = (**self.books.get_unchecked(ind)).borrow_mut();
+        self.index.forget_key(&the_book);
+        the_book.change_title(new_title);
+        self.index.remember_key(&the_book);
+        drop(the_book);
         self
     }
 
     /// Changes TheBook's and all simple books' title
 
     #[inline]
-    pub(crate) fn change_title(&mut self, ind: usize, new_title: String) -> ResultSelf<Self> {
+    pub(crate) fn change_title(
+        &mut self,
+        ind: usize,
+        new_title: String,
+    ) -> ResultSelf<Self, BookError> {
         return if ind >= self.books.len() {
-            Err(0) // out of range
+            Err(BookError::NotFound)
         } else {
             unsafe {
                 if self
@@ -299,7 +358,7 @@ impl BookSystem {
                     )
                     .is_some()
                 {
-                    Err(1) // already exists
+                    Err(BookError::AlreadyExists)
                 } else {
                     Ok(self.change_title_unchecked(ind, new_title))
                 }
@@ -315,18 +374,24 @@ impl BookSystem {
         ind: usize,
         new_author: String,
     ) -> &mut Self {
-        (**self.books.get_unchecked_mut(ind))
-            .borrow_mut()
-            .change_author(new_author);
+        let mut the_book = (**self.books.get_unchecked(ind)).borrow_mut();
+        self.index.forget_key(&the_book);
+        the_book.change_author(new_author);
+        self.index.remember_key(&the_book);
+        drop(the_book);
         self
     }
 
     /// Changes TheBook's and all simple books' title
 
     #[inline]
-    pub(crate) fn change_author(&mut self, ind: usize, new_author: String) -> ResultSelf<Self> {
+    pub(crate) fn change_author(
+        &mut self,
+        ind: usize,
+        new_author: String,
+    ) -> ResultSelf<Self, BookError> {
         return if ind >= self.books.len() {
-            Err(0) // out of range
+            Err(BookError::NotFound)
         } else {
             unsafe {
                 if self
@@ -337,7 +402,7 @@ impl BookSystem {
                     )
                     .is_some()
                 {
-                    Err(1) // already exists
+                    Err(BookError::AlreadyExists)
                 } else {
                     Ok(self.change_author_unchecked(ind, new_author))
                 }
@@ -353,38 +418,39 @@ impl BookSystem {
         ind: usize,
         new_pages: u16,
     ) -> &mut Self {
-        (**self.books.get_unchecked_mut(ind))
-            .borrow_mut()
-            .change_pages(new_pages);
+        let mut the_book = (**self.books.get_unchecked(ind)).borrow_mut();
+        self.index.forget_key(&the_book);
+        the_book.change_pages(new_pages);
+        self.index.remember_key(&the_book);
+        drop(the_book);
         self
     }
 
-    /// Changes TheBook's and all simple books' title
+    /// Changes TheBook's and all simple books' amount of pages
 
     #[inline]
-    pub(crate) fn change_pages(&mut self, ind: usize, new_pages: String) -> ResultSelf<Self> {
-        return match new_pages.trim().parse::<u16>() {
-            Ok(new_pages_num) => {
-                if ind == self.len() {
-                    Err(1) // not found
+    pub(crate) fn change_pages(
+        &mut self,
+        ind: usize,
+        new_pages: u16,
+    ) -> ResultSelf<Self, BookError> {
+        return if ind >= self.len() {
+            Err(BookError::NotFound)
+        } else {
+            unsafe {
+                if self
+                    .find_book(
+                        &RefCell::borrow(&(**self.books.get_unchecked(ind))).title,
+                        &RefCell::borrow(&(**self.books.get_unchecked(ind))).author,
+                        new_pages,
+                    )
+                    .is_some()
+                {
+                    Err(BookError::AlreadyExists)
                 } else {
-                    unsafe {
-                        if self
-                            .find_book(
-                                &RefCell::borrow(&(**self.books.get_unchecked(ind))).title,
-                                &RefCell::borrow(&(**self.books.get_unchecked(ind))).author,
-                                new_pages_num,
-                            )
-                            .is_some()
-                        {
-                            Err(2) // already exists
-                        } else {
-                            Ok(self.change_pages_unchecked(ind, new_pages_num))
-                        }
-                    }
+                    Ok(self.change_pages_unchecked(ind, new_pages))
                 }
             }
-            Err(_) => Err(0), // parse error
         };
     }
 
@@ -414,35 +480,90 @@ impl BookSystem {
         &mut self,
         t_ind: usize,
         s_ind: usize,
-        new_cabinet: String,
-        new_shelf: String,
-    ) -> ResultSelf<Self> {
-        match new_cabinet.trim().parse::<u16>() {
-            Err(_) => {
-                return Err(0); // new cabinet err
-            }
+        new_cabinet: u16,
+        new_shelf: u8,
+    ) -> ResultSelf<Self, BookError> {
+        return if t_ind >= self.len() {
+            Err(BookError::NotFound)
+        } else if s_ind >= unsafe { (**self.books.get_unchecked(t_ind)).borrow().books.len() } {
+            Err(BookError::SimpleBookNotFound)
+        } else {
+            Ok(unsafe { self.change_location_unchecked(t_ind, s_ind, new_cabinet, new_shelf) })
+        };
+    }
 
-            Ok(cab) => {
-                match new_shelf.trim().parse::<u8>() {
-                    Err(_) => {
-                        return Err(1); // new shelf err
-                    }
+    /// Changes type of simple Book.
+    /// Type is trimmed, empty one means ordinary copy
 
-                    Ok(shelf) => {
-                        if s_ind
-                            > unsafe { (**self.books.get_unchecked(t_ind)).borrow().books.len() }
-                            || s_ind == 0
-                        {
-                            return Err(2); // index out of range (book)
-                        } else {
-                            Ok(unsafe {
-                                self.change_location_unchecked(t_ind, s_ind - 1, cab, shelf)
-                            })
-                        }
-                    }
-                }
+    #[inline]
+    pub(crate) fn change_copy_type(
+        &mut self,
+        t_ind: usize,
+        s_ind: usize,
+        new_copy_type: String,
+    ) -> ResultSelf<Self, BookError> {
+        return if t_ind >= self.len() {
+            Err(BookError::NotFound)
+        } else if s_ind >= unsafe { (**self.books.get_unchecked(t_ind)).borrow().books.len() } {
+            Err(BookError::SimpleBookNotFound)
+        } else {
+            unsafe {
+                (**(**self.books.get_unchecked_mut(t_ind))
+                    .borrow_mut()
+                    .books
+                    .get_unchecked_mut(s_ind))
+                .borrow_mut()
+                .change_copy_type(new_copy_type.trim().to_string());
             }
-        }
+
+            Ok(self)
+        };
+    }
+
+    /// Replaces genres of The Book without any checks
+
+    #[inline]
+    pub(crate) unsafe fn set_genres_unchecked(
+        &mut self,
+        ind: usize,
+        new_genres: Option<HashSet<String>>,
+    ) -> &mut Self {
+        let mut the_book = (**self.books.get_unchecked(ind)).borrow_mut();
+        self.index.forget_genres(&the_book);
+        the_book.genres = new_genres;
+        self.index.remember_genres(&the_book);
+        drop(the_book);
+        self
+    }
+
+    /// Replaces genres of The Book
+
+    #[inline]
+    pub(crate) fn set_genres(
+        &mut self,
+        ind: usize,
+        new_genres: Option<HashSet<String>>,
+    ) -> ResultSelf<Self, BookError> {
+        return if ind >= self.len() {
+            Err(BookError::NotFound)
+        } else {
+            Ok(unsafe { self.set_genres_unchecked(ind, new_genres) })
+        };
+    }
+
+    /// Removes genre from all The Books
+
+    #[inline]
+    pub(crate) fn remove_genre(&mut self, genre: &String) -> &mut Self {
+        self.find_by_genre(genre)
+            .into_iter()
+            .for_each(|ind| unsafe {
+                let mut the_book = (**self.books.get_unchecked(ind)).borrow_mut();
+                self.index.forget_genres(&the_book);
+                the_book.remove_genre(genre);
+                self.index.remember_genres(&the_book);
+            });
+        self
     }
 
     /// Deletes all books from current Book System
@@ -452,448 +573,381 @@ impl BookSystem {
     #[allow(dead_code)]
     pub(crate) fn clear(&mut self) -> &mut Self {
         self.books.clear();
+        self.index.clear();
         self
     }
 
-    /// Clones Book System
-    /// with new smart pointers for The Book
+    /// Id of The Book with chosen index
 
     #[inline]
-    pub(crate) fn clone(&self, reader_base: &ReaderBase) -> Self {
-        BookSystem {
-            books: self
-                .books
+    pub(crate) fn id(&self, ind: usize) -> Option<Id> {
+        self.books.get(ind).map(|b| (**b).borrow().id)
+    }
+
+    /// Ids of all readers who have ever taken
+    /// simple books of The Book
+
+    #[inline]
+    pub(crate) fn reader_ids(&self, ind: usize) -> Vec<Id> {
+        let mut ids = self
+            .books
+            .get(ind)
+            .map(|the_book| {
+                (**the_book)
+                    .borrow()
+                    .books
+                    .iter()
+                    .flat_map(|b| {
+                        (**b)
+                            .borrow()
+                            .readers
+                            .iter()
+                            .filter_map(|r| r.0.upgrade())
+                            .map(|r| (*r).borrow().id)
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    /// The Book with chosen id as it's stored
+    /// and its index in Book System
+
+    #[inline]
+    pub(crate) fn record(&self, id: Id) -> Option<(usize, BookRecord)> {
+        self.find_book_by_id(id).map(|ind| {
+            (
+                ind,
+                BookRecord::from(unsafe { &*(**self.books.get_unchecked(ind)).borrow() }),
+            )
+        })
+    }
+
+    /// Inserts stored The Book to chosen index
+    /// and links its simple books with readers.
+    /// All readers must be in Reader Base
+
+    #[inline]
+    pub(crate) fn insert_record(
+        &mut self,
+        ind: usize,
+        record: &BookRecord,
+        reader_base: &mut ReaderBase,
+    ) -> &mut Self {
+        let loaded = LoadedBook {
+            id: record.id,
+            title: record.title.clone(),
+            author: record.author.clone(),
+            pages: record.pages,
+            simple_books: record
+                .copies
                 .iter()
-                .map(|x| {
-                    let book = Rc::new(RefCell::new(TheBook {
-                        title: (**x).borrow().title.clone(),
-                        author: (**x).borrow().author.clone(),
-                        genres: (**x).borrow().genres.clone(),
-                        pages: (**x).borrow().pages,
-                        books: vec![],
-                    }));
-
-                    (*book).borrow_mut().books = (**x)
-                        .borrow()
-                        .books
+                .map(|copy| LoadedSimpleBook {
+                    id: copy.id,
+                    cabinet: copy.cabinet,
+                    shelf: copy.shelf,
+                    copy_type: copy.copy_type.clone(),
+                    is_using: copy.is_using,
+                    readers: copy
+                        .loans
                         .iter()
-                        .map(|s| {
-                            let sim_book =
-                                Rc::new(RefCell::new((**s).borrow().clone(book.clone())));
-
-                            (*sim_book).borrow_mut().readers = (**s)
-                                .borrow()
-                                .readers
-                                .iter()
-                                .map(|r| {
-                                    (
-                                        unsafe {
-                                            Rc::downgrade(
-                                                reader_base.readers.get_unchecked(
-                                                    reader_base
-                                                        .find_reader(
-                                                            &(*(r.0).upgrade().unwrap())
-                                                                .borrow()
-                                                                .name,
-                                                            &(*(r.0).upgrade().unwrap())
-                                                                .borrow()
-                                                                .family,
-                                                            &(*(r.0).upgrade().unwrap())
-                                                                .borrow()
-                                                                .father,
-                                                            (*(r.0).upgrade().unwrap())
-                                                                .borrow()
-                                                                .birth,
-                                                        )
-                                                        .unwrap(),
-                                                ),
-                                            )
-                                        },
-                                        (r.1).clone(),
-                                    )
-                                })
-                                .collect();
-
-                            unsafe { (*(*sim_book).as_ptr()).readers.iter_mut() }.for_each(|r| {
-                                if {
-                                    let check = (**s).borrow().is_using
-                                        && *(*(*(**s).borrow().readers.last().unwrap())
-                                            .0
-                                            .upgrade()
-                                            .unwrap())
-                                        .borrow()
-                                            == *(*(r.0).upgrade().unwrap()).borrow();
-                                    check
-                                } {
-                                    let rind = reader_base
-                                        .find_reader(
-                                            &(*(r.0).upgrade().unwrap()).borrow().name,
-                                            &(*(r.0).upgrade().unwrap()).borrow().family,
-                                            &(*(r.0).upgrade().unwrap()).borrow().father,
-                                            (*(r.0).upgrade().unwrap()).borrow().birth,
-                                        )
-                                        .unwrap();
-
-                                    unsafe {
-                                        (**reader_base.readers.get_unchecked(rind))
-                                            .borrow_mut()
-                                            .start_reading_unchecked(&sim_book, &(r.1).1);
-                                    }
-                                }
-                            });
-
-                            sim_book
+                        .map(|loan| {
+                            (
+                                reader_base.find_reader_by_id(loan.reader).unwrap(),
+                                loan.start,
+                                loan.finish,
+                            )
                         })
-                        .collect();
-                    book
+                        .collect(),
                 })
                 .collect(),
+            genres: record.genres.clone().map(HashSet::from_iter),
+        };
+
+        self.apply_loaded(loaded, reader_base, ind);
+        self
+    }
+
+    /// Replaces The Book with stored one in the same index.
+    /// The Book is found by record's id
+
+    #[inline]
+    pub(crate) fn replace_record(
+        &mut self,
+        record: &BookRecord,
+        reader_base: &mut ReaderBase,
+    ) -> &mut Self {
+        if let Some(ind) = self.find_book_by_id(record.id) {
+            unsafe { self.remove_book_unchecked(ind) };
+            self.insert_record(ind, record, reader_base);
         }
+
+        self
     }
 
-    /// Save to .yaml file
+    /// Removes The Book with chosen id
+    /// and all its simple books
 
-    pub(crate) fn save(&self) {
-        let mut array = Array::new();
+    #[inline]
+    pub(crate) fn remove_record(&mut self, id: Id) -> &mut Self {
+        if let Some(ind) = self.find_book_by_id(id) {
+            unsafe { self.remove_book_unchecked(ind) };
+        }
 
-        (0..self.books.len()).for_each(|book| {
-            let mut data = Hash::new();
+        self
+    }
 
-            unsafe {
-                data.insert(
-                    Yaml::String("№".to_string()),
-                    Yaml::Integer(book as i64 + 1),
-                );
-
-                data.insert(
-                    Yaml::String("Title".to_string()),
-                    Yaml::String(
-                        RefCell::borrow(&(**self.books.get_unchecked(book)))
-                            .title
-                            .clone(),
-                    ),
-                );
-
-                data.insert(
-                    Yaml::String("Author".to_string()),
-                    Yaml::String(
-                        RefCell::borrow(&(**self.books.get_unchecked(book)))
-                            .author
-                            .clone(),
-                    ),
-                );
-
-                data.insert(
-                    Yaml::String("Pages".to_string()),
-                    Yaml::Integer(
-                        RefCell::borrow(&(**self.books.get_unchecked(book))).pages as i64,
-                    ),
-                );
-
-                let mut book_arr = Array::new();
-
-                (**self.books.get_unchecked(book))
+    /// All books as they're stored
+
+    #[inline]
+    pub(crate) fn records(&self) -> Vec<BookRecord> {
+        self.books
+            .iter()
+            .map(|x| BookRecord::from(&*(**x).borrow()))
+            .collect()
+    }
+
+    /// Saves all books to storage
+
+    #[inline]
+    pub(crate) fn save(&self, storage: &dyn Storage) -> io::Result<()> {
+        storage.write_books(&self.records())
+    }
+
+    /// Loads all books from storage.
+    /// If any record is incorrect,
+    /// it will return error and Book System will stay unchanged
+
+    #[inline]
+    pub fn load(
+        &mut self,
+        reader_base: &mut ReaderBase,
+        storage: &dyn Storage,
+    ) -> Result<(), LoadError> {
+        self.load_records(reader_base, storage, false).map(|_| ())
+    }
+
+    /// Loads all books from storage
+    /// and skips incorrect books.
+    /// Returns errors of all skipped books
+
+    #[inline]
+    pub fn load_skip_bad(
+        &mut self,
+        reader_base: &mut ReaderBase,
+        storage: &dyn Storage,
+    ) -> Result<Vec<LoadError>, LoadError> {
+        self.load_records(reader_base, storage, true)
+    }
+
+    /// Loads books from storage.
+    /// All records are checked before anything is changed,
+    /// so skipped book leaves no links in readers
+
+    fn load_records(
+        &mut self,
+        reader_base: &mut ReaderBase,
+        storage: &dyn Storage,
+        skip_bad: bool,
+    ) -> Result<Vec<LoadError>, LoadError> {
+        let path = storage.books_path();
+        let mut skipped = vec![];
+        let mut loaded = vec![];
+        let mut used_the_books = self
+            .iter()
+            .map(|x| (**x).borrow().id)
+            .collect::<HashSet<_>>();
+
+        let mut used_books = self
+            .iter()
+            .flat_map(|x| {
+                (**x)
                     .borrow()
                     .books
                     .iter()
-                    .for_each(|simple| {
-                        let readers = (**simple)
-                            .borrow_mut()
-                            .readers
-                            .iter()
-                            .map(|x: &(Weak<RefCell<Reader>>, (Date, Date))| {
-                                let mut hash_reader = Hash::new();
-
-                                hash_reader.insert(
-                                    Yaml::String("Name".to_string()),
-                                    Yaml::String(
-                                        RefCell::borrow(&*((x.0).upgrade().unwrap())).name.clone(),
-                                    ),
-                                );
-
-                                hash_reader.insert(
-                                    Yaml::String("Family".to_string()),
-                                    Yaml::String(
-                                        RefCell::borrow(&*((x.0).upgrade().unwrap()))
-                                            .family
-                                            .clone(),
-                                    ),
-                                );
-
-                                hash_reader.insert(
-                                    Yaml::String("Father".to_string()),
-                                    Yaml::String(
-                                        RefCell::borrow(&*((x.0).upgrade().unwrap()))
-                                            .father
-                                            .clone(),
-                                    ),
-                                );
-
-                                hash_reader.insert(
-                                    Yaml::String("Day".to_string()),
-                                    Yaml::Integer(
-                                        RefCell::borrow(&*((x.0).upgrade().unwrap())).birth.day
-                                            as i64,
-                                    ),
-                                );
-
-                                hash_reader.insert(
-                                    Yaml::String("Month".to_string()),
-                                    Yaml::Integer(
-                                        RefCell::borrow(&*((x.0).upgrade().unwrap())).birth.month
-                                            as i64,
-                                    ),
-                                );
-
-                                hash_reader.insert(
-                                    Yaml::String("Year".to_string()),
-                                    Yaml::Integer(
-                                        RefCell::borrow(&*((x.0).upgrade().unwrap())).birth.year
-                                            as i64,
-                                    ),
-                                );
-
-                                hash_reader.insert(
-                                    Yaml::String("Start date".to_string()),
-                                    Yaml::Array(vec![
-                                        Yaml::Integer((x.1).0.day as i64),
-                                        Yaml::Integer((x.1).0.month as i64),
-                                        Yaml::Integer((x.1).0.year as i64),
-                                    ]),
-                                );
-
-                                hash_reader.insert(
-                                    Yaml::String("Finish date".to_string()),
-                                    Yaml::Array(vec![
-                                        Yaml::Integer((x.1).1.day as i64),
-                                        Yaml::Integer((x.1).1.month as i64),
-                                        Yaml::Integer((x.1).1.year as i64),
-                                    ]),
-                                );
-
-                                Yaml::Hash(hash_reader)
-                            })
-                            .collect::<Array>();
-
-                        let mut hash_simple = Hash::new();
-
-                        hash_simple.insert(
-                            Yaml::String("Cabinet".to_string()),
-                            Yaml::Integer(RefCell::borrow(&(**simple)).cabinet as i64),
-                        );
-
-                        hash_simple.insert(
-                            Yaml::String("Shelf".to_string()),
-                            Yaml::Integer(RefCell::borrow(&(**simple)).shelf as i64),
-                        );
-
-                        hash_simple.insert(
-                            Yaml::String("Using".to_string()),
-                            Yaml::Boolean(RefCell::borrow(&(**simple)).is_using),
-                        );
-
-                        hash_simple
-                            .insert(Yaml::String("Readers".to_string()), Yaml::Array(readers));
-                        book_arr.push(Yaml::Hash(hash_simple));
-                    });
-
-                data.insert(
-                    Yaml::String("Simple Books".to_string()),
-                    Yaml::Array(book_arr),
-                );
-
-                data.insert(
-                    Yaml::String("Genres".to_string()),
-                    if let Some(g) = &RefCell::borrow(&(**self.books.get_unchecked(book))).genres {
-                        Yaml::Array(Array::from_iter(
-                            g.iter()
-                                .map(|x| Yaml::String(x.clone()))
-                                .collect::<Vec<_>>(),
-                        ))
-                    } else {
-                        Yaml::Null
-                    },
-                );
+                    .map(|b| (**b).borrow().id)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<HashSet<_>>();
+
+        for (ind, record) in storage.read_books()?.into_iter().enumerate() {
+            let record_path = Record::new(&path, ind);
+
+            match record.and_then(|r| {
+                BookSystem::check_ids(&record_path, &r, &used_the_books, &used_books)?;
+                BookSystem::find_readers(&record_path, r, reader_base)
+            }) {
+                Ok(the_book) => {
+                    used_the_books.insert(the_book.id);
+                    used_books.extend(the_book.simple_books.iter().map(|b| b.id));
+                    loaded.push(the_book);
+                }
+
+                Err(err) if skip_bad => skipped.push(err),
+                Err(err) => return Err(err),
             }
-            array.push(Yaml::Hash(data));
-        });
+        }
 
-        let mut string = String::new();
-        let mut emitter = YamlEmitter::new(&mut string);
-        emitter.dump(&Yaml::Array(array)).unwrap();
+        loaded
+            .into_iter()
+            .for_each(|the_book| self.apply_loaded(the_book, reader_base, self.books.len()));
 
-        File::create("src/utils/books.yaml")
-            .unwrap()
-            .write_all(string.as_bytes())
-            .unwrap();
+        Ok(skipped)
     }
 
-    /// load from .yaml file
+    /// Checks that ids of The Book and its simple books
+    /// are not used by other books
+
+    fn check_ids(
+        record: &Record,
+        book: &BookRecord,
+        used_the_books: &HashSet<Id>,
+        used_books: &HashSet<Id>,
+    ) -> Result<(), LoadError> {
+        if used_the_books.contains(&book.id) {
+            return Err(record.error("Id", LoadErrorKind::DuplicateId));
+        }
 
-    #[inline]
-    pub fn load(&mut self, reader_base: &mut ReaderBase) {
-        let mut string = String::new();
+        let mut ids = HashSet::new();
 
-        File::open("src/utils/books.yaml")
-            .unwrap()
-            .read_to_string(&mut string)
-            .unwrap();
+        for (i, copy) in book.copies.iter().enumerate() {
+            if used_books.contains(&copy.id) || !ids.insert(copy.id) {
+                return Err(record
+                    .nested("Simple Books", i)
+                    .error("Id", LoadErrorKind::DuplicateId));
+            }
+        }
 
-        if !string.is_empty() {
-            let docs = YamlLoader::load_from_str(string.as_str()).unwrap();
-            let doc = docs.first().unwrap().clone().into_vec().unwrap();
-
-            doc.into_iter().for_each(|d| {
-                self.books.push(Rc::new(RefCell::new(TheBook {
-                    title: d["Title"].as_str().unwrap().to_string(),
-                    author: d["Author"].as_str().unwrap().to_string(),
-                    pages: d["Pages"].as_i64().unwrap() as u16,
-                    books: vec![],
-                    genres: None,
-                })));
-
-                d["Simple Books"]
-                    .as_vec()
-                    .unwrap()
-                    .iter()
-                    .for_each(|simple| {
-                        let the_book = unsafe {
-                            let last = self.books.len() - 1;
-                            self.books.get_unchecked(last)
-                        };
-
-                        let new_is_using = simple["Using"].as_bool().unwrap();
-
-                        let book = Rc::new(RefCell::new(Book {
-                            the_book: Some(Rc::downgrade(&the_book)),
-                            is_using: new_is_using,
-                            cabinet: simple["Cabinet"].as_i64().unwrap() as u16,
-                            shelf: simple["Shelf"].as_i64().unwrap() as u8,
-                            readers: vec![],
-                        }));
-
-                        (*(**self.books.last_mut().unwrap()).borrow_mut())
-                            .books
-                            .push(book);
-
-                        if new_is_using {
-                            if let Some(last_reader) = simple["Readers"].as_vec().unwrap().last() {
-                                unsafe {
-                                    let ind = reader_base
-                                        .find_reader(
-                                            &last_reader["Name"].as_str().unwrap().to_string(),
-                                            &last_reader["Family"].as_str().unwrap().to_string(),
-                                            &last_reader["Father"].as_str().unwrap().to_string(),
-                                            Date::new(
-                                                last_reader["Day"].as_i64().unwrap() as u8,
-                                                last_reader["Month"].as_i64().unwrap() as u8,
-                                                last_reader["Year"].as_i64().unwrap() as u16,
-                                            )
-                                            .unwrap(),
-                                        )
-                                        .unwrap();
-
-                                    (**reader_base.readers.get_unchecked_mut(ind))
-                                        .borrow_mut()
-                                        .start_reading_unchecked(
-                                            &(*(**self.books.last_mut().unwrap())
-                                                .borrow()
-                                                .books
-                                                .last()
-                                                .unwrap()),
-                                            &Date {
-                                                day: last_reader["Finish date"][0].as_i64().unwrap()
-                                                    as u8,
-                                                month: last_reader["Finish date"][1]
-                                                    .as_i64()
-                                                    .unwrap()
-                                                    as u8,
-                                                year: last_reader["Finish date"][2]
-                                                    .as_i64()
-                                                    .unwrap()
-                                                    as u16,
-                                            },
-                                        );
-                                }
-                            }
-                        }
-
-                        simple["Readers"]
-                            .as_vec()
-                            .unwrap()
-                            .iter()
-                            .for_each(|reader| {
-                                let ind = reader_base
-                                    .find_reader(
-                                        &reader["Name"].as_str().unwrap().to_string(),
-                                        &reader["Family"].as_str().unwrap().to_string(),
-                                        &reader["Father"].as_str().unwrap().to_string(),
-                                        Date::new(
-                                            reader["Day"].as_i64().unwrap() as u8,
-                                            reader["Month"].as_i64().unwrap() as u8,
-                                            reader["Year"].as_i64().unwrap() as u16,
-                                        )
-                                        .unwrap(),
-                                    )
-                                    .unwrap();
-
-                                (**(**self.books.last_mut().unwrap())
-                                    .borrow_mut()
-                                    .books
-                                    .last_mut()
-                                    .unwrap())
-                                .borrow_mut()
-                                .readers
-                                .push((
-                                    Rc::downgrade(unsafe {
-                                        reader_base.readers.get_unchecked(ind)
-                                    }),
-                                    (
-                                        Date::new(
-                                            reader["Start date"][0].as_i64().unwrap() as u8,
-                                            reader["Start date"][1].as_i64().unwrap() as u8,
-                                            reader["Start date"][2].as_i64().unwrap() as u16,
-                                        )
-                                        .unwrap(),
-                                        Date::new(
-                                            reader["Finish date"][0].as_i64().unwrap() as u8,
-                                            reader["Finish date"][1].as_i64().unwrap() as u8,
-                                            reader["Finish date"][2].as_i64().unwrap() as u16,
-                                        )
-                                        .unwrap(),
-                                    ),
-                                ));
-
-                                unsafe {
-                                    (**reader_base.readers.get_unchecked_mut(ind))
-                                        .borrow_mut()
-                                        .books
-                                        .push(Rc::downgrade(
-                                            &(*(**self.books.last_mut().unwrap())
-                                                .borrow_mut()
-                                                .books
-                                                .last_mut()
-                                                .unwrap()),
-                                        ));
-                                }
-                            });
-                    });
-
-                if d["Genres"].is_null() {
-                    (**self.books.last_mut().unwrap()).borrow_mut().genres = None;
-                } else {
-                    (**self.books.last_mut().unwrap()).borrow_mut().genres = Some(HashSet::new());
+        Ok(())
+    }
+
+    /// Finds readers of all simple books in Reader Base.
+    /// If reader isn't found, it will return error
+
+    fn find_readers(
+        record: &Record,
+        book: BookRecord,
+        reader_base: &ReaderBase,
+    ) -> Result<LoadedBook, LoadError> {
+        let mut simple_books = vec![];
+
+        for (i, copy) in book.copies.into_iter().enumerate() {
+            let simple_record = record.nested("Simple Books", i);
+            let mut readers = vec![];
+
+            for (j, loan) in copy.loans.into_iter().enumerate() {
+                let ind = reader_base.find_reader_by_id(loan.reader).ok_or_else(|| {
+                    simple_record
+                        .nested("Readers", j)
+                        .error("Reader", LoadErrorKind::UnknownReader)
+                })?;
+
+                readers.push((ind, loan.start, loan.finish));
+            }
+
+            simple_books.push(LoadedSimpleBook {
+                id: copy.id,
+                cabinet: copy.cabinet,
+                shelf: copy.shelf,
+                copy_type: copy.copy_type,
+                is_using: copy.is_using,
+                readers,
+            });
+        }
+
+        Ok(LoadedBook {
+            id: book.id,
+            title: book.title,
+            author: book.author,
+            pages: book.pages,
+            simple_books,
+            genres: book.genres.map(HashSet::from_iter),
+        })
+    }
 
-                    d["Genres"].as_vec().unwrap().iter().for_each(|genres| {
-                        (**self.books.last_mut().unwrap())
+    /// Inserts parsed type of book to chosen index
+    /// and links its books with readers
+
+    fn apply_loaded(&mut self, loaded: LoadedBook, reader_base: &mut ReaderBase, ind: usize) {
+        self.the_book_ids.observe(loaded.id);
+
+        let the_book = Rc::new(RefCell::new(TheBook {
+            id: loaded.id,
+            title: loaded.title,
+            author: loaded.author,
+            pages: loaded.pages,
+            books: vec![],
+            genres: loaded.genres,
+        }));
+
+        self.index.insert(&(*the_book).borrow(), ind);
+        self.books.insert(ind, the_book.clone());
+
+        loaded.simple_books.into_iter().for_each(|simple| {
+            self.book_ids.observe(simple.id);
+
+            let book = Rc::new(RefCell::new(Book {
+                id: simple.id,
+                the_book: Some(Rc::downgrade(&the_book)),
+                is_using: simple.is_using,
+                cabinet: simple.cabinet,
+                shelf: simple.shelf,
+                copy_type: simple.copy_type,
+                readers: vec![],
+            }));
+
+            (*the_book).borrow_mut().books.push(book.clone());
+
+            if simple.is_using {
+                if let Some((ind, _, finish)) = simple.readers.last() {
+                    unsafe {
+                        (**reader_base.readers.get_unchecked_mut(*ind))
                             .borrow_mut()
-                            .genres
-                            .as_mut()
-                            .unwrap()
-                            .insert(genres.as_str().unwrap().to_string());
-                    });
+                            .start_reading_unchecked(&book, finish);
+                    }
                 }
+            }
+
+            simple.readers.into_iter().for_each(|(ind, start, finish)| {
+                let reader = unsafe { reader_base.readers.get_unchecked(ind) };
+
+                (*book)
+                    .borrow_mut()
+                    .readers
+                    .push((Rc::downgrade(reader), (start, finish)));
+
+                (**reader).borrow_mut().books.push(Rc::downgrade(&book));
             });
-        }
+        });
     }
 }
+
+/// Type of book read from storage,
+/// which isn't added to Book System yet
+
+struct LoadedBook {
+    id: Id,
+    title: String,
+    author: String,
+    pages: u16,
+    simple_books: Vec<LoadedSimpleBook>,
+    genres: Option<HashSet<String>>,
+}
+
+/// Simple book read from storage.
+/// Readers are stored as indexes in Reader Base
+/// with start and finish dates
+
+struct LoadedSimpleBook {
+    id: Id,
+    cabinet: u16,
+    shelf: u8,
+    copy_type: String,
+    is_using: bool,
+    readers: Vec<(usize, Date, Date)>,
+}
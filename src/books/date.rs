@@ -1,18 +1,25 @@
 extern crate chrono;
 
-use chrono::{DateTime, Datelike, Local, NaiveDate};
+use crate::Lang;
+use chrono::{Datelike, Days, Local, Months, NaiveDate, Weekday};
 use std::{
     cmp::*,
     fmt::{Display, Formatter},
     ops::Sub,
 };
 
+/// Format of ISO 8601 dates (Y-M-D).
+/// It's used by languages without their own format
+/// and always accepted on parsing
+
+pub const ISO_FORMAT: &str = "%Y-%m-%d";
+
 /// Date structure, which contains day, month and year.
 /// It's a copyable type like i32 (no move).
 /// You can clone, debug and compare as == / !=
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub(crate) struct Date {
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Date {
     pub(crate) day: u8,
     pub(crate) month: u8,
     pub(crate) year: u16,
@@ -54,7 +61,8 @@ impl Sub for Date {
     /// years
     type Output = u16;
 
-    /// Difference between two dates in years (>= 0)
+    /// Difference between two dates in years (>= 0).
+    /// For difference in days use days_until()
 
     #[inline]
     fn sub(self, rhs: Self) -> Self::Output {
@@ -75,29 +83,13 @@ impl Sub for Date {
     }
 }
 
-impl From<DateTime<Local>> for Date {
-    /// Constructor from chrono's library dates
+impl From<Date> for NaiveDate {
+    /// Converts date to chrono's library dates.
+    /// Date is always correct, so it never fails
 
     #[inline]
-    fn from(date: DateTime<Local>) -> Self {
-        Date {
-            day: date.day() as u8,
-            month: date.month() as u8,
-            year: date.year() as u16,
-        }
-    }
-}
-
-impl From<NaiveDate> for Date {
-    /// Constructor from chrono's library dates
-
-    #[inline]
-    fn from(date: NaiveDate) -> Self {
-        Date {
-            day: date.day() as u8,
-            month: date.month() as u8,
-            year: date.year() as u16,
-        }
+    fn from(date: Date) -> Self {
+        NaiveDate::from_ymd_opt(date.year as i32, date.month as u32, date.day as u32).unwrap()
     }
 }
 
@@ -111,13 +103,13 @@ impl From<Date> for String {
 }
 
 impl Display for Date {
-    /// Displays date as string.
-    ///
-    /// D/M/Y
+    /// Displays date as string
+    /// in ISO 8601 format (Y-M-D).
+    /// For user use localized()
 
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.to_string())
+        write!(f, "{}", self.format(ISO_FORMAT))
     }
 }
 
@@ -126,7 +118,7 @@ impl Date {
     /// It will return Err.
 
     #[inline]
-    pub(crate) fn new(new_day: u8, new_month: u8, new_year: u16) -> std::result::Result<Self, ()> {
+    pub fn new(new_day: u8, new_month: u8, new_year: u16) -> std::result::Result<Self, ()> {
         let date = Date {
             day: new_day,
             month: new_month,
@@ -156,10 +148,175 @@ impl Date {
         };
     }
 
-    /// Converts date to string
+    /// Constructs date from chrono's library date.
+    /// If year is negative or too big, it will return None
+
+    #[inline]
+    pub fn from_naive(date: NaiveDate) -> Option<Self> {
+        Some(Date {
+            day: date.day() as u8,
+            month: date.month() as u8,
+            year: u16::try_from(date.year()).ok()?,
+        })
+    }
+
+    /// Current local date
+
+    #[inline]
+    pub fn today() -> Self {
+        Date::from_naive(Local::now().date_naive()).expect("current year is out of range")
+    }
+
+    /// Converts date to chrono's library date.
+    /// Every correct date is supported by chrono
+
+    #[inline]
+    pub fn to_naive(self) -> NaiveDate {
+        NaiveDate::from(self)
+    }
+
+    /// Date after chosen amount of days
+    /// (before if amount is negative).
+    /// If date is out of range, it will return None
+
+    #[inline]
+    pub fn add_days(self, days: i64) -> Option<Self> {
+        let date = self.to_naive();
+
+        match days >= 0 {
+            true => date.checked_add_days(Days::new(days as u64)),
+            false => date.checked_sub_days(Days::new(days.unsigned_abs())),
+        }
+        .and_then(Date::from_naive)
+    }
+
+    /// Date before chosen amount of days
+    /// (after if amount is negative).
+    /// If date is out of range, it will return None
+
+    #[inline]
+    pub fn sub_days(self, days: i64) -> Option<Self> {
+        self.add_days(days.checked_neg()?)
+    }
+
+    /// Date after chosen amount of weeks
+    /// (before if amount is negative).
+    /// If date is out of range, it will return None
+
+    #[inline]
+    pub fn add_weeks(self, weeks: i64) -> Option<Self> {
+        self.add_days(weeks.checked_mul(7)?)
+    }
+
+    /// Date before chosen amount of weeks
+    /// (after if amount is negative).
+    /// If date is out of range, it will return None
+
+    #[inline]
+    pub fn sub_weeks(self, weeks: i64) -> Option<Self> {
+        self.sub_days(weeks.checked_mul(7)?)
+    }
+
+    /// Date after chosen amount of months
+    /// (before if amount is negative).
+    /// If there is no such day in month,
+    /// it will be the last day of month
+    /// (31.01 + 1 month = 29.02 in leap year).
+    /// If date is out of range, it will return None
+
+    #[inline]
+    pub fn add_months(self, months: i32) -> Option<Self> {
+        let date = self.to_naive();
+
+        match months >= 0 {
+            true => date.checked_add_months(Months::new(months as u32)),
+            false => date.checked_sub_months(Months::new(months.unsigned_abs())),
+        }
+        .and_then(Date::from_naive)
+    }
+
+    /// Date before chosen amount of months
+    /// (after if amount is negative).
+    /// If there is no such day in month,
+    /// it will be the last day of month.
+    /// If date is out of range, it will return None
+
+    #[inline]
+    pub fn sub_months(self, months: i32) -> Option<Self> {
+        self.add_months(months.checked_neg()?)
+    }
+
+    /// Amount of days from this date to other one.
+    /// It's negative if other date is earlier
+    /// (e.g. finish.days_until(today) is how long book is overdue)
+
+    #[inline]
+    pub fn days_until(self, other: Date) -> i64 {
+        (other.to_naive() - self.to_naive()).num_days()
+    }
+
+    /// Day of week
+
+    #[inline]
+    pub fn weekday(self) -> Weekday {
+        self.to_naive().weekday()
+    }
+
+    /// Formats date with chrono's format
+    /// (e.g. "%d.%m.%Y")
+
+    #[inline]
+    pub fn format(&self, format: &str) -> String {
+        NaiveDate::from(*self).format(format).to_string()
+    }
+
+    /// Parses date with chrono's format.
+    /// If text doesn't match format or
+    /// date is wrong, it will return Err
+
+    #[inline]
+    pub fn parse(text: &str, format: &str) -> std::result::Result<Self, ()> {
+        NaiveDate::parse_from_str(text.trim(), format)
+            .ok()
+            .and_then(Date::from_naive)
+            .ok_or(())
+    }
+
+    /// Formats date according to language
+    /// (e.g. 31.12.2024 for russian, 12/31/2024 for english)
+
+    #[inline]
+    pub fn localized(&self, lang: Lang) -> String {
+        self.format(lang.date_format())
+    }
+
+    /// Parses date according to language.
+    /// ISO 8601 dates are accepted in any language
+
+    #[inline]
+    pub fn parse_localized(text: &str, lang: Lang) -> std::result::Result<Self, ()> {
+        Date::parse(text, lang.date_format()).or_else(|_| Date::parse(text, ISO_FORMAT))
+    }
+
+    /// Fields of chosen format in their order
+    /// (e.g. ["%d", "%m", "%Y"] for "%d.%m.%Y")
+
+    #[inline]
+    pub fn format_fields(format: &str) -> Vec<&str> {
+        format
+            .match_indices('%')
+            .filter_map(|(ind, _)| format.get(ind..ind + 2))
+            .collect()
+    }
+
+    /// Hint for user how to input date in chosen format
+    /// (e.g. DD.MM.YYYY)
 
     #[inline]
-    pub(crate) fn to_string(&self) -> String {
-        format!("{}/{}/{}", self.day, self.month, self.year)
+    pub fn format_hint(format: &str) -> String {
+        format
+            .replace("%d", "DD")
+            .replace("%m", "MM")
+            .replace("%Y", "YYYY")
     }
 }
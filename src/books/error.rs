@@ -0,0 +1,255 @@
+use crate::{auth::AccessDenied, policy::Refusal, reading::error::ReaderError, Lang};
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    io,
+};
+
+/// Error of operation with book
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BookError {
+    /// The Book with such index isn't found
+    NotFound,
+
+    /// Simple book with such index isn't found
+    SimpleBookNotFound,
+
+    /// Book with same title, author and amount of pages exists
+    AlreadyExists,
+
+    /// The Book can't contain more simple books
+    TooManyBooks,
+
+    /// All simple books are used
+    NoFreeBooks,
+
+    /// Simple book isn't used now
+    NotUsed,
+
+    /// Reader has returned book after deadline
+    ReturnedLate,
+
+    /// Genre isn't in genres' list
+    UnknownGenre,
+
+    /// User's role doesn't allow operation
+    NoAccess,
+
+    /// Change can't be saved to storage
+    Io(io::ErrorKind),
+}
+
+impl BookError {
+    /// Localized description of error
+
+    #[inline]
+    pub fn message(&self, lang: Lang) -> &'static str {
+        lang.tr(match self {
+            BookError::NotFound => "book-error-not-found",
+            BookError::SimpleBookNotFound => "book-error-simple-book-not-found",
+            BookError::AlreadyExists => "book-error-already-exists",
+            BookError::TooManyBooks => "book-error-too-many-books",
+            BookError::NoFreeBooks => "book-error-no-free-books",
+            BookError::NotUsed => "book-error-not-used",
+            BookError::ReturnedLate => "book-error-returned-late",
+            BookError::UnknownGenre => "book-error-unknown-genre",
+            BookError::NoAccess => "access-denied",
+            BookError::Io(_) => "book-error-io",
+        })
+    }
+}
+
+impl From<AccessDenied> for BookError {
+    /// Operation isn't allowed for user's role
+
+    #[inline]
+    fn from(_: AccessDenied) -> Self {
+        BookError::NoAccess
+    }
+}
+
+impl From<io::Error> for BookError {
+    /// Change isn't saved
+
+    #[inline]
+    fn from(err: io::Error) -> Self {
+        BookError::Io(err.kind())
+    }
+}
+
+impl Display for BookError {
+    /// Describes error in english
+
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message(Lang::default()))
+    }
+}
+
+impl Error for BookError {}
+
+/// Error of operation with genres
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GenreError {
+    /// Genre is already in list
+    AlreadyExists,
+
+    /// Genre isn't in list
+    NotFound,
+
+    /// User's role doesn't allow operation
+    NoAccess,
+
+    /// Change can't be saved to storage
+    Io(io::ErrorKind),
+}
+
+impl GenreError {
+    /// Localized description of error
+
+    #[inline]
+    pub fn message(&self, lang: Lang) -> &'static str {
+        lang.tr(match self {
+            GenreError::AlreadyExists => "genre-error-already-exists",
+            GenreError::NotFound => "genre-error-not-found",
+            GenreError::NoAccess => "access-denied",
+            GenreError::Io(_) => "genre-error-io",
+        })
+    }
+}
+
+impl From<AccessDenied> for GenreError {
+    /// Operation isn't allowed for user's role
+
+    #[inline]
+    fn from(_: AccessDenied) -> Self {
+        GenreError::NoAccess
+    }
+}
+
+impl From<io::Error> for GenreError {
+    /// Change isn't saved
+
+    #[inline]
+    fn from(err: io::Error) -> Self {
+        GenreError::Io(err.kind())
+    }
+}
+
+impl Display for GenreError {
+    /// Describes error in english
+
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message(Lang::default()))
+    }
+}
+
+impl Error for GenreError {}
+
+/// Error of giving or returning book.
+/// It's caused by reader, by book,
+/// by wrong return date or by loan policy
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LoanError {
+    /// Reader isn't found or can't take book
+    Reader(ReaderError),
+
+    /// Book isn't found or can't be given
+    Book(BookError),
+
+    /// Return date is earlier than today or than day of giving
+    WrongDate,
+
+    /// Loan policy doesn't allow to give book
+    Refused(Refusal),
+
+    /// User's role doesn't allow operation
+    NoAccess,
+
+    /// Change can't be saved to storage
+    Io(io::ErrorKind),
+}
+
+impl LoanError {
+    /// Localized description of error
+
+    #[inline]
+    pub fn message(&self, lang: Lang) -> &'static str {
+        match self {
+            LoanError::Reader(err) => err.message(lang),
+            LoanError::Book(err) => err.message(lang),
+
+            LoanError::WrongDate => lang.tr("return-date-cant-be-earlier-than-today"),
+            LoanError::Refused(refusal) => refusal.message(lang),
+
+            LoanError::NoAccess => AccessDenied.message(lang),
+            LoanError::Io(_) => lang.tr("loan-error-io"),
+        }
+    }
+}
+
+impl Display for LoanError {
+    /// Describes error in english
+
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message(Lang::default()))
+    }
+}
+
+impl Error for LoanError {
+    /// Reader's or book's error
+
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LoanError::Reader(err) => Some(err),
+            LoanError::Book(err) => Some(err),
+            LoanError::WrongDate
+            | LoanError::Refused(_)
+            | LoanError::NoAccess
+            | LoanError::Io(_) => None,
+        }
+    }
+}
+
+impl From<ReaderError> for LoanError {
+    /// Wraps reader's error
+
+    #[inline]
+    fn from(err: ReaderError) -> Self {
+        LoanError::Reader(err)
+    }
+}
+
+impl From<BookError> for LoanError {
+    /// Wraps book's error
+
+    #[inline]
+    fn from(err: BookError) -> Self {
+        LoanError::Book(err)
+    }
+}
+
+impl From<AccessDenied> for LoanError {
+    /// Operation isn't allowed for user's role
+
+    #[inline]
+    fn from(_: AccessDenied) -> Self {
+        LoanError::NoAccess
+    }
+}
+
+impl From<io::Error> for LoanError {
+    /// Change isn't saved
+
+    #[inline]
+    fn from(err: io::Error) -> Self {
+        LoanError::Io(err.kind())
+    }
+}
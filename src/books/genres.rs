@@ -1,14 +1,11 @@
-extern crate yaml_rust;
+use crate::{data::error::LoadError, storage::Storage};
 
 use std::{
     collections::{hash_set::Iter, BTreeSet, HashSet},
-    fs::File,
-    io::{Read, Write},
+    io,
     iter::FromIterator,
 };
 
-use yaml_rust::{yaml::Array, Yaml, YamlEmitter, YamlLoader};
-
 /// All existing genres.yaml of books
 
 #[derive(Debug, Default, Clone)]
@@ -144,59 +141,63 @@ impl Genres {
         self
     }
 
-    /// Saves all genres to yaml file
+    /// All genres as they're stored.
+    /// Genres are sorted, so output doesn't depend on hashing
 
     #[inline]
-    pub(crate) fn save(&self) {
-        let mut array = Array::new();
+    pub(crate) fn records(&self) -> Vec<String> {
+        let mut genres = self.genres.iter().cloned().collect::<Vec<_>>();
+        genres.sort_unstable();
+        genres
+    }
 
-        if self.genres.is_empty() {
-            array.push(Yaml::String("None".to_string()));
-        } else {
-            self.genres
-                .iter()
-                .for_each(|g| array.push(Yaml::String(g.clone())));
-        }
+    /// Saves all genres to storage
 
-        let mut string = String::new();
-        let mut emitter = YamlEmitter::new(&mut string);
+    #[inline]
+    pub(crate) fn save(&self, storage: &dyn Storage) -> io::Result<()> {
+        storage.write_genres(&self.records())
+    }
 
-        let mut hash = yaml_rust::yaml::Hash::new();
-        hash.insert(Yaml::String("Genres".to_string()), Yaml::Array(array));
-        emitter.dump(&Yaml::Hash(hash)).unwrap();
+    /// Loads all genres from storage.
+    /// If any genre is incorrect,
+    /// it will return error and genres will stay unchanged
 
-        File::create("src/utils/genres.yaml")
-            .unwrap()
-            .write_all(string.as_bytes())
-            .unwrap();
+    #[inline]
+    pub fn load(&mut self, storage: &dyn Storage) -> Result<(), LoadError> {
+        self.load_records(storage, false).map(|_| ())
     }
 
-    /// Loads from yaml file
+    /// Loads all genres from storage and skips incorrect ones.
+    /// Returns errors of all skipped genres
 
     #[inline]
-    pub fn load(&mut self) {
-        let mut string = String::new();
+    pub fn load_skip_bad(&mut self, storage: &dyn Storage) -> Result<Vec<LoadError>, LoadError> {
+        self.load_records(storage, true)
+    }
 
-        File::open("src/utils/genres.yaml")
-            .unwrap()
-            .read_to_string(&mut string)
-            .unwrap();
+    /// Loads genres from storage.
+    /// Errors in records are either skipped or returned
 
-        if !string.is_empty() {
-            let doc = YamlLoader::load_from_str(string.as_str())
-                .unwrap()
-                .first()
-                .unwrap()["Genres"]
-                .as_vec()
-                .unwrap()
-                .clone();
+    fn load_records(
+        &mut self,
+        storage: &dyn Storage,
+        skip_bad: bool,
+    ) -> Result<Vec<LoadError>, LoadError> {
+        let mut skipped = vec![];
+        let mut genres = HashSet::new();
 
-            if doc.first().unwrap().as_str().unwrap().to_string() != "None" {
-                self.genres = doc
-                    .iter()
-                    .map(|x| x.as_str().unwrap().to_string())
-                    .collect();
+        for record in storage.read_genres()? {
+            match record {
+                Ok(genre) => {
+                    genres.insert(genre);
+                }
+
+                Err(err) if skip_bad => skipped.push(err),
+                Err(err) => return Err(err),
             }
         }
+
+        self.genres.extend(genres);
+        Ok(skipped)
     }
 }
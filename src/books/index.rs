@@ -0,0 +1,188 @@
+use crate::{
+    books::the_book::TheBook,
+    id::{Id, Positions},
+};
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+/// Identity of The Book:
+/// title, author and amount of pages
+
+type BookKey = (String, String, u16);
+
+/// Hash indexes of Book System.
+/// Finds The Books by id, by identity,
+/// by author and by genre
+/// without scanning all books
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct BookIndex {
+    positions: Positions,
+    keys: HashMap<BookKey, Id>,
+    authors: HashMap<String, HashSet<Id>>,
+    genres: HashMap<String, HashSet<Id>>,
+}
+
+impl BookIndex {
+    /// Creates empty index
+
+    #[inline]
+    pub(crate) fn new() -> Self {
+        BookIndex::default()
+    }
+
+    /// Builds index of all The Books
+
+    #[inline]
+    pub(crate) fn build(books: &[Rc<RefCell<TheBook>>]) -> Self {
+        let mut index = BookIndex::new();
+
+        books
+            .iter()
+            .enumerate()
+            .for_each(|(pos, book)| index.insert(&(**book).borrow(), pos));
+
+        index
+    }
+
+    /// Identity of The Book
+
+    #[inline]
+    fn key(book: &TheBook) -> BookKey {
+        (book.title.clone(), book.author.clone(), book.pages)
+    }
+
+    /// Position of The Book with chosen id
+
+    #[inline]
+    pub(crate) fn position(&self, id: Id) -> Option<usize> {
+        self.positions.get(id)
+    }
+
+    /// Position of The Book with chosen params
+
+    #[inline]
+    pub(crate) fn find(&self, title: &String, author: &String, pages: u16) -> Option<usize> {
+        self.keys
+            .get(&(title.clone(), author.clone(), pages))
+            .and_then(|&id| self.position(id))
+    }
+
+    /// Sorted positions of The Books with chosen ids
+
+    #[inline]
+    fn positions_of(&self, ids: Option<&HashSet<Id>>) -> Vec<usize> {
+        let mut found = ids
+            .into_iter()
+            .flatten()
+            .filter_map(|&id| self.position(id))
+            .collect::<Vec<_>>();
+
+        found.sort_unstable();
+        found
+    }
+
+    /// Sorted positions of all The Books of author
+
+    #[inline]
+    pub(crate) fn find_by_author(&self, author: &String) -> Vec<usize> {
+        self.positions_of(self.authors.get(author))
+    }
+
+    /// Sorted positions of all The Books with genre
+
+    #[inline]
+    pub(crate) fn find_by_genre(&self, genre: &String) -> Vec<usize> {
+        self.positions_of(self.genres.get(genre))
+    }
+
+    /// Remembers The Book inserted at chosen position
+
+    #[inline]
+    pub(crate) fn insert(&mut self, book: &TheBook, pos: usize) {
+        self.positions.insert(book.id, pos);
+        self.remember_key(book);
+        self.remember_genres(book);
+    }
+
+    /// Forgets removed The Book
+
+    #[inline]
+    pub(crate) fn remove(&mut self, book: &TheBook) {
+        self.positions.remove(book.id);
+        self.forget_key(book);
+        self.forget_genres(book);
+    }
+
+    /// Forgets identity and author of The Book before they're changed
+
+    #[inline]
+    pub(crate) fn forget_key(&mut self, book: &TheBook) {
+        let key = BookIndex::key(book);
+
+        if self.keys.get(&key) == Some(&book.id) {
+            self.keys.remove(&key);
+        }
+
+        BookIndex::forget_in(&mut self.authors, &book.author, book.id);
+    }
+
+    /// Remembers identity and author of The Book after they're changed
+
+    #[inline]
+    pub(crate) fn remember_key(&mut self, book: &TheBook) {
+        self.keys.entry(BookIndex::key(book)).or_insert(book.id);
+
+        self.authors
+            .entry(book.author.clone())
+            .or_default()
+            .insert(book.id);
+    }
+
+    /// Forgets genres of The Book before they're changed
+
+    #[inline]
+    pub(crate) fn forget_genres(&mut self, book: &TheBook) {
+        book.genres
+            .iter()
+            .flatten()
+            .for_each(|g| BookIndex::forget_in(&mut self.genres, g, book.id));
+    }
+
+    /// Remembers genres of The Book after they're changed
+
+    #[inline]
+    pub(crate) fn remember_genres(&mut self, book: &TheBook) {
+        book.genres.iter().flatten().for_each(|g| {
+            self.genres.entry(g.clone()).or_default().insert(book.id);
+        });
+    }
+
+    /// Removes id from secondary index.
+    /// Empty sets are removed too
+
+    #[inline]
+    fn forget_in(index: &mut HashMap<String, HashSet<Id>>, key: &String, id: Id) {
+        if let Some(ids) = index.get_mut(key) {
+            ids.remove(&id);
+
+            if ids.is_empty() {
+                index.remove(key);
+            }
+        }
+    }
+
+    /// Forgets all The Books
+
+    #[inline]
+    pub(crate) fn clear(&mut self) {
+        self.positions.clear();
+        self.keys.clear();
+        self.authors.clear();
+        self.genres.clear();
+    }
+}
@@ -1,15 +1,17 @@
 pub(crate) mod book;
 pub mod book_sys;
-pub(crate) mod date;
+pub mod date;
+pub mod error;
 pub mod genres;
+pub(crate) mod index;
 pub(crate) mod the_book;
 
 /// Error-handling type.
 /// If everything is ok, it should return ref on self (but it's not necessary),
-/// else it will return err with code
-/// (that's will help you to correctly understand error)
+/// else it will return error of chosen domain
+/// (reader's, book's or genre's)
 
-pub(crate) type ResultSelf<'a, T> = std::result::Result<&'a mut T, u8>;
+pub(crate) type ResultSelf<'a, T, E> = std::result::Result<&'a mut T, E>;
 
 /// **DEPRECATED**
 ///
@@ -1,5 +1,6 @@
 use crate::{
-    books::{book::Book, ResultSelf},
+    books::{book::Book, error::BookError, ResultSelf},
+    id::Id,
     reading::reader::Reader,
 };
 
@@ -14,6 +15,7 @@ use std::{
 /// title, author, amount of pages, simple books and genres.yaml
 
 pub struct TheBook {
+    pub(crate) id: Id,
     pub(crate) title: String,
     pub(crate) author: String,
     pub(crate) pages: u16,
@@ -73,11 +75,12 @@ impl Debug for TheBook {
 }
 
 impl TheBook {
-    /// Constructs TheBook
+    /// Constructs TheBook with chosen id
 
     #[inline]
-    pub(crate) const fn new(new_title: String, new_author: String, new_pages: u16) -> Self {
+    pub(crate) const fn new(id: Id, new_title: String, new_author: String, new_pages: u16) -> Self {
         TheBook {
+            id,
             title: new_title,
             author: new_author,
             pages: new_pages,
@@ -114,6 +117,7 @@ impl TheBook {
     /// If all are used, it will return amount of books
 
     #[inline]
+    #[allow(dead_code)]
     pub(crate) fn get_unused(&self) -> Option<usize> {
         self.books.iter().position(|x| !(**x).borrow().is_using)
     }
@@ -134,9 +138,9 @@ impl TheBook {
     /// If index is incorrect, it will return Err
 
     #[inline]
-    pub(crate) fn remove_book(&mut self, ind: usize) -> ResultSelf<Self> {
+    pub(crate) fn remove_book(&mut self, ind: usize) -> ResultSelf<Self, BookError> {
         return if ind == self.books.len() {
-            Err(0)
+            Err(BookError::SimpleBookNotFound)
         } else {
             unsafe {
                 (**self.books.get_unchecked_mut(ind))
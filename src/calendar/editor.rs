@@ -0,0 +1,234 @@
+extern crate chrono;
+extern crate fltk;
+extern crate fltk_calendar;
+
+use crate::{
+    books::date::Date,
+    calendar::{Holiday, WEEK},
+    change::{input1::Input1, Inputable},
+    library::Library,
+    Lang,
+};
+
+use chrono::Weekday;
+
+use fltk::{
+    app,
+    app::App,
+    browser::HoldBrowser,
+    button::{Button, CheckButton},
+    dialog::{alert, choice2, file_chooser, message},
+    frame::Frame,
+    input::Input,
+    prelude::*,
+    window::SingleWindow,
+};
+
+use fltk_calendar::calendar::Calendar;
+
+use std::fs;
+
+/// Buttons of calendar's window
+
+#[derive(Clone, Copy)]
+enum Event {
+    Closed,
+    Add,
+    Remove,
+    Import,
+}
+
+/// Function that shows opening calendar of library.
+/// Administrator can choose weekly closed days,
+/// add and remove holidays and import them
+/// from iCalendar file. Every change is saved at once
+
+pub fn opening_calendar(library: &mut Library, app: &App, lang: Lang) {
+    let (s, r) = app::channel();
+
+    let mut wind = SingleWindow::new(500, 100, 600, 400, lang.tr("opening-calendar"));
+
+    let _ = Frame::new(10, 5, 150, 25, lang.tr("closed-days"));
+
+    let mut days = WEEK
+        .iter()
+        .enumerate()
+        .map(|(ind, &day)| {
+            let mut check =
+                CheckButton::new(20, 35 + ind as i32 * 30, 140, 25, weekday_name(day, lang));
+
+            check.emit(s, Event::Closed);
+            check
+        })
+        .collect::<Vec<_>>();
+
+    let mut browser = HoldBrowser::new(170, 0, 430, 360, "");
+
+    let mut add = Button::new(180, 370, 90, 25, lang.tr("add"));
+
+    let mut remove = Button::new(280, 370, 90, 25, lang.tr("remove"));
+
+    let mut import = Button::new(380, 370, 210, 25, lang.tr("import-icalendar"));
+
+    add.emit(s, Event::Add);
+    remove.emit(s, Event::Remove);
+    import.emit(s, Event::Import);
+
+    wind.end();
+    wind.show();
+
+    fill_calendar(&mut days, &mut browser, library, lang);
+
+    while app.wait() {
+        if let Some(event) = r.recv() {
+            let mut calendar = library.calendar().clone();
+            let mut imported = None;
+
+            let result = match event {
+                Event::Closed => {
+                    let closed = WEEK
+                        .iter()
+                        .zip(days.iter())
+                        .filter(|(_, check)| check.is_checked())
+                        .map(|(&day, _)| day)
+                        .collect::<Vec<_>>();
+
+                    calendar.set_closed_days(&closed).map(|_| ())
+                }
+
+                Event::Add => match input_holiday(app, lang) {
+                    Some(holiday) => calendar.add_holiday(holiday).map(|_| ()),
+                    None => continue,
+                },
+
+                Event::Remove => {
+                    let ind = browser.value();
+
+                    if ind <= 0 {
+                        alert(500, 500, lang.tr("holiday-isnt-selected"));
+                        continue;
+                    }
+
+                    calendar.remove_holiday(ind as usize - 1).map(|_| ())
+                }
+
+                Event::Import => {
+                    match file_chooser(lang.tr("choose-icalendar-file"), "*.ics", ".", false) {
+                        Some(path) => fs::read_to_string(path)
+                            .map_err(|err| err.into())
+                            .and_then(|text| calendar.import_ical(&text))
+                            .map(|result| imported = Some(result)),
+
+                        None => continue,
+                    }
+                }
+            };
+
+            match result.and_then(|_| library.set_calendar(calendar)) {
+                Ok(_) => {
+                    if let Some((added, skipped)) = imported {
+                        let mut text = format!("{}: {}", lang.tr("imported-holidays"), added);
+
+                        if skipped > 0 {
+                            text.push_str(
+                                format!("\n{}: {}", lang.tr("skipped-events"), skipped).as_str(),
+                            );
+                        }
+
+                        message(500, 500, text.as_str());
+                    }
+                }
+
+                Err(err) => alert(500, 500, err.message(lang)),
+            }
+
+            fill_calendar(&mut days, &mut browser, library, lang);
+        }
+
+        if !wind.shown() {
+            return;
+        }
+    }
+}
+
+/// Shows closed days and lists all holidays
+
+fn fill_calendar(
+    days: &mut [CheckButton],
+    browser: &mut HoldBrowser,
+    library: &Library,
+    lang: Lang,
+) {
+    WEEK.iter()
+        .zip(days.iter_mut())
+        .for_each(|(day, check)| check.set_checked(library.calendar().closed_days().contains(day)));
+
+    browser.clear();
+
+    library
+        .calendar()
+        .holidays()
+        .iter()
+        .for_each(|holiday| browser.add(holiday.to_string(lang).as_str()));
+}
+
+/// Asks date, name of holiday and if it's repeated every year.
+/// If window is closed, it will return None
+
+fn input_holiday(app: &App, lang: Lang) -> Option<Holiday> {
+    let date = match Calendar::default().get_date().and_then(Date::from_naive) {
+        Some(date) => date,
+
+        None => {
+            alert(500, 500, lang.tr("date-wasnt-selected"));
+            return None;
+        }
+    };
+
+    let (s, r) = app::channel();
+
+    let mut inp = Input1::<Input>::new(lang.tr("new-holiday"), lang.tr("holiday-name"));
+
+    inp.show();
+    (*inp.ok).borrow_mut().emit(s, true);
+
+    while app.wait() {
+        if let Some(true) = r.recv() {
+            inp.hide();
+
+            let name = inp.set_input(lang).ok()?.first().unwrap().clone();
+
+            return match choice2(
+                500,
+                500,
+                lang.tr("is-holiday-repeated"),
+                lang.tr("every-year"),
+                lang.tr("only-once"),
+                "",
+            ) {
+                Some(0) => Some(Holiday::new(date, &name, true)),
+                Some(1) => Some(Holiday::new(date, &name, false)),
+                _ => None,
+            };
+        } else if !inp.shown() {
+            return None;
+        }
+    }
+
+    None
+}
+
+/// Localized name of day of week
+
+#[inline]
+fn weekday_name(day: Weekday, lang: Lang) -> &'static str {
+    lang.tr(match day {
+        Weekday::Mon => "weekday-monday",
+        Weekday::Tue => "weekday-tuesday",
+        Weekday::Wed => "weekday-wednesday",
+        Weekday::Thu => "weekday-thursday",
+        Weekday::Fri => "weekday-friday",
+        Weekday::Sat => "weekday-saturday",
+        Weekday::Sun => "weekday-sunday",
+    })
+}
@@ -0,0 +1,324 @@
+extern crate chrono;
+
+use crate::{books::date::Date, calendar::Holiday};
+use chrono::{Duration, Local, NaiveDateTime, NaiveTime, TimeZone};
+use std::collections::HashMap;
+
+/// Format of dates in iCalendar
+
+const ICAL_FORMAT: &str = "%Y%m%d";
+
+/// Format of date with time in iCalendar
+
+const ICAL_TIME_FORMAT: &str = "%Y%m%dT%H%M%S";
+
+/// Longest event which is imported as holidays (in days)
+
+const MAX_EVENT_DAYS: i64 = 366;
+
+/// Event of iCalendar which is being read
+
+#[derive(Default)]
+struct Event {
+    start: Option<Date>,
+    end: Option<Date>,
+    summary: String,
+    yearly: bool,
+
+    /// Some property of event can't be read
+    wrong: bool,
+}
+
+impl Event {
+    /// Holidays on every day of event.
+    /// End of event isn't included (as in iCalendar),
+    /// event without end lasts one day.
+    /// If event is wrong, has no start or lasts too long,
+    /// it will return None
+
+    fn holidays(self) -> Option<Vec<Holiday>> {
+        if self.wrong {
+            return None;
+        }
+
+        let start = self.start?;
+
+        let days = match self.end {
+            Some(end) => start.days_until(end).max(1),
+            None => 1,
+        };
+
+        if days > MAX_EVENT_DAYS {
+            return None;
+        }
+
+        (0..days)
+            .map(|day| {
+                start
+                    .add_days(day)
+                    .map(|date| Holiday::new(date, &self.summary, self.yearly))
+            })
+            .collect()
+    }
+}
+
+/// Parses holidays from iCalendar (RFC 5545) text.
+/// Every VEVENT becomes holidays from DTSTART until DTEND,
+/// its SUMMARY becomes holidays' name.
+/// Events with yearly RRULE are repeated every year,
+/// other rules are ignored.
+/// Times in UTC or with TZID are converted to local time
+/// before their date is taken.
+/// Wrong events are skipped, their amount is returned with holidays.
+/// If text isn't iCalendar, it will return None
+
+pub fn parse(text: &str) -> Option<(Vec<Holiday>, usize)> {
+    let lines = unfold(text);
+
+    if !lines
+        .iter()
+        .any(|line| line.trim().eq_ignore_ascii_case("BEGIN:VCALENDAR"))
+    {
+        return None;
+    }
+
+    let zones = time_zones(&lines);
+
+    let mut holidays = vec![];
+    let mut skipped = 0;
+    let mut event: Option<Event> = None;
+
+    for line in lines.iter() {
+        let (name, params, value) = match property(line) {
+            Some(property) => property,
+            None => continue,
+        };
+
+        match name.as_str() {
+            "BEGIN" if value.eq_ignore_ascii_case("VEVENT") => event = Some(Event::default()),
+
+            "END" if value.eq_ignore_ascii_case("VEVENT") => {
+                if let Some(event) = event.take() {
+                    match event.holidays() {
+                        Some(event_holidays) => holidays.extend(event_holidays),
+                        None => skipped += 1,
+                    }
+                }
+            }
+
+            _ => {
+                if let Some(event) = &mut event {
+                    let zone = param(params, "TZID").and_then(|tzid| zones.get(tzid).copied());
+
+                    match name.as_str() {
+                        "DTSTART" => match parse_date(value, zone) {
+                            Some((date, _)) => event.start = Some(date),
+                            None => event.wrong = true,
+                        },
+
+                        "DTEND" => match parse_date(value, zone) {
+                            // Event ends during the day, so the day is included
+                            Some((date, true)) => match date.add_days(1) {
+                                Some(end) => event.end = Some(end),
+                                None => event.wrong = true,
+                            },
+
+                            Some((date, false)) => event.end = Some(date),
+                            None => event.wrong = true,
+                        },
+
+                        "SUMMARY" => event.summary = unescape(value),
+
+                        "RRULE" => {
+                            event.yearly = value
+                                .split(';')
+                                .any(|rule| rule.eq_ignore_ascii_case("FREQ=YEARLY"))
+                        }
+
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+
+    Some((holidays, skipped))
+}
+
+/// Splits content line into upper case name,
+/// parameters (e.g. `VALUE=DATE;TZID=Europe/Moscow`) and value.
+/// If line has no value, it will return None
+
+fn property(line: &str) -> Option<(String, &str, &str)> {
+    let (name, value) = line.split_once(':')?;
+
+    let (name, params) = match name.split_once(';') {
+        Some((name, params)) => (name, params),
+        None => (name, ""),
+    };
+
+    Some((name.trim().to_ascii_uppercase(), params, value.trim()))
+}
+
+/// Value of parameter with chosen name.
+/// Quotes around value are removed
+
+fn param<'a>(params: &'a str, name: &str) -> Option<&'a str> {
+    params.split(';').find_map(|param| {
+        let (key, value) = param.split_once('=')?;
+
+        key.trim()
+            .eq_ignore_ascii_case(name)
+            .then(|| value.trim().trim_matches('"'))
+    })
+}
+
+/// Offsets from UTC (in seconds) of VTIMEZONE components by their TZID.
+/// Standard time is used, daylight saving time is used
+/// only if time zone has no standard time
+
+fn time_zones(lines: &[String]) -> HashMap<String, i32> {
+    let mut zones = HashMap::new();
+
+    let mut tzid = None;
+    let mut standard = None;
+    let mut daylight = None;
+    let mut observance = String::new();
+
+    for line in lines.iter() {
+        let (name, _, value) = match property(line) {
+            Some(property) => property,
+            None => continue,
+        };
+
+        let value_upper = value.to_ascii_uppercase();
+
+        match (name.as_str(), value_upper.as_str()) {
+            ("BEGIN", "VTIMEZONE") => {
+                tzid = None;
+                standard = None;
+                daylight = None;
+            }
+
+            ("BEGIN", "STANDARD") | ("BEGIN", "DAYLIGHT") => observance = value_upper,
+            ("END", "STANDARD") | ("END", "DAYLIGHT") => observance.clear(),
+
+            ("END", "VTIMEZONE") => {
+                if let (Some(tzid), Some(offset)) = (tzid.take(), standard.or(daylight)) {
+                    zones.insert(tzid, offset);
+                }
+            }
+
+            ("TZID", _) => tzid = Some(value.to_string()),
+
+            ("TZOFFSETTO", _) => match observance.as_str() {
+                "STANDARD" => standard = parse_offset(value),
+                "DAYLIGHT" => daylight = parse_offset(value),
+                _ => {}
+            },
+
+            _ => {}
+        }
+    }
+
+    zones
+}
+
+/// Parses UTC offset (e.g. +0300 or -053000) in seconds
+
+fn parse_offset(value: &str) -> Option<i32> {
+    let sign = match value.get(..1)? {
+        "+" => 1,
+        "-" => -1,
+        _ => return None,
+    };
+
+    let digits = value.get(1..)?;
+
+    if !matches!(digits.len(), 4 | 6) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let hours = digits[..2].parse::<i32>().ok()?;
+    let minutes = digits[2..4].parse::<i32>().ok()?;
+    let seconds = match digits.len() {
+        6 => digits[4..].parse::<i32>().ok()?,
+        _ => 0,
+    };
+
+    Some(sign * (hours * 3600 + minutes * 60 + seconds))
+}
+
+/// Parses DATE or DATE-TIME value (e.g. 20240101 or 20240101T120000Z).
+/// Time in UTC (with Z) or in time zone with chosen offset
+/// is converted to local time, other times are already local.
+/// Returns date and flag if time isn't midnight
+
+fn parse_date(value: &str, offset: Option<i32>) -> Option<(Date, bool)> {
+    if value.len() == 8 {
+        return Some((Date::parse(value, ICAL_FORMAT).ok()?, false));
+    }
+
+    let (time, utc) = match value.strip_suffix(['Z', 'z']) {
+        Some(time) => (time, true),
+        None => (value, false),
+    };
+
+    let time = NaiveDateTime::parse_from_str(time, ICAL_TIME_FORMAT).ok()?;
+
+    let utc_time = match (utc, offset) {
+        (true, _) => Some(time),
+        (false, Some(offset)) => time.checked_sub_signed(Duration::seconds(offset as i64)),
+        (false, None) => None,
+    };
+
+    let time = match utc_time {
+        Some(utc_time) => Local.from_utc_datetime(&utc_time).naive_local(),
+        None => time,
+    };
+
+    Some((
+        Date::from_naive(time.date())?,
+        time.time() != NaiveTime::MIN,
+    ))
+}
+
+/// Joins folded lines.
+/// Line which starts with space or tab continues previous one
+
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = vec![];
+
+    text.lines()
+        .map(|line| line.trim_end_matches('\r'))
+        .for_each(
+            |line| match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+                (Some(rest), Some(last)) => last.push_str(rest),
+                _ => lines.push(line.to_string()),
+            },
+        );
+
+    lines
+}
+
+/// Replaces escaped characters of text value.
+/// Line breaks become spaces
+
+fn unescape(value: &str) -> String {
+    let mut text = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n') | Some('N') => text.push(' '),
+                Some(other) => text.push(other),
+                None => {}
+            },
+
+            _ => text.push(c),
+        }
+    }
+
+    text
+}
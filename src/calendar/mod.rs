@@ -0,0 +1,494 @@
+extern crate chrono;
+extern crate yaml_rust;
+
+use crate::{
+    auth::AccessDenied,
+    books::date::Date,
+    data::{
+        self,
+        error::{LoadError, LoadErrorKind, Record},
+        DataDir,
+    },
+    storage::{migration, yaml::emit},
+    Lang,
+};
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    io,
+    path::{Path, PathBuf},
+};
+
+use chrono::Weekday;
+
+use yaml_rust::{yaml::Hash, Yaml};
+
+pub mod editor;
+pub mod ical;
+
+/// Version of calendar's file
+
+const CALENDAR_VERSION: i64 = 1;
+
+/// Key of weekly closed days in calendar's file
+
+const CLOSED_KEY: &str = "Closed Days";
+
+/// Key of holidays in calendar's file
+
+const HOLIDAYS_KEY: &str = "Holidays";
+
+/// How many days after date are checked
+/// in search of open day
+
+const SEARCH_LIMIT: i64 = 366;
+
+/// Days of week from monday to sunday
+
+pub const WEEK: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+/// Day when library is closed.
+/// Yearly holiday is repeated on the same day
+/// every year since its date
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Holiday {
+    pub(crate) date: Date,
+    pub(crate) name: String,
+    pub(crate) yearly: bool,
+}
+
+impl Holiday {
+    /// Creates holiday on chosen date
+
+    #[inline]
+    pub fn new(date: Date, name: &str, yearly: bool) -> Self {
+        Holiday {
+            date,
+            name: name.trim().to_string(),
+            yearly,
+        }
+    }
+
+    /// Date of holiday (first date for yearly one)
+
+    #[inline]
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// Name of holiday
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Checks if holiday is repeated every year
+
+    #[inline]
+    pub fn is_yearly(&self) -> bool {
+        self.yearly
+    }
+
+    /// Checks if library is closed on chosen date
+    /// because of this holiday
+
+    #[inline]
+    pub fn falls_on(&self, date: Date) -> bool {
+        match self.yearly {
+            true => {
+                date.day == self.date.day
+                    && date.month == self.date.month
+                    && date.year >= self.date.year
+            }
+
+            false => date == self.date,
+        }
+    }
+
+    /// Holiday in list of holidays
+    /// (e.g. "01/01/2024  New Year (every year)")
+
+    #[inline]
+    pub fn to_string(&self, lang: Lang) -> String {
+        format!(
+            "{}  {}{}",
+            self.date.localized(lang),
+            self.name,
+            match self.yearly {
+                true => lang.tr("yearly-mark"),
+                false => "",
+            }
+        )
+    }
+}
+
+/// Days when library is open.
+/// Library is closed on weekly closed days and on holidays.
+/// Due dates which fall on closed day
+/// are moved to the next open day.
+/// Calendar is stored in calendar's file of data directory
+
+#[derive(Clone, Debug, Default)]
+pub struct OpeningCalendar {
+    path: Option<PathBuf>,
+    closed: Vec<Weekday>,
+    holidays: Vec<Holiday>,
+}
+
+impl OpeningCalendar {
+    /// Creates calendar of library which is open every day.
+    /// It isn't saved anywhere
+
+    #[inline]
+    pub fn new() -> Self {
+        OpeningCalendar::default()
+    }
+
+    /// Reads calendar from data directory.
+    /// If there is no calendar's file,
+    /// library is open every day
+
+    pub fn load(data_dir: &DataDir) -> Result<Self, LoadError> {
+        let path = data_dir.calendar();
+
+        let (closed, holidays) = match path.exists() {
+            true => match data::read_yaml(&path)? {
+                Some(doc) => calendar_from_yaml(&path, &doc)?,
+                None => Default::default(),
+            },
+
+            false => Default::default(),
+        };
+
+        Ok(OpeningCalendar {
+            path: Some(path),
+            closed,
+            holidays,
+        })
+    }
+
+    /// Days of week when library is closed
+
+    #[inline]
+    pub fn closed_days(&self) -> &[Weekday] {
+        &self.closed
+    }
+
+    /// All holidays sorted by date
+
+    #[inline]
+    pub fn holidays(&self) -> &[Holiday] {
+        &self.holidays
+    }
+
+    /// Checks if library is open on chosen date
+
+    #[inline]
+    pub fn is_open(&self, date: Date) -> bool {
+        !self.closed.contains(&date.weekday()) && !self.holidays.iter().any(|h| h.falls_on(date))
+    }
+
+    /// The first open day since chosen date (inclusive).
+    /// If library is closed for the whole year after it,
+    /// it will return None
+
+    #[inline]
+    pub fn next_open_day(&self, date: Date) -> Option<Date> {
+        (0..=SEARCH_LIMIT)
+            .map_while(|days| date.add_days(days))
+            .find(|&day| self.is_open(day))
+    }
+
+    /// Date when book should be returned
+    /// if it's given until chosen date.
+    /// Closed days are skipped
+
+    #[inline]
+    pub fn due_date(&self, finish: Date) -> Date {
+        self.next_open_day(finish).unwrap_or(finish)
+    }
+
+    /// Checks if book given until chosen date
+    /// isn't returned in time
+
+    #[inline]
+    pub fn is_overdue(&self, finish: Date, today: Date) -> bool {
+        today > self.due_date(finish)
+    }
+
+    /// How many days book given until chosen date is overdue.
+    /// If it isn't, it will return 0
+
+    #[inline]
+    pub fn days_overdue(&self, finish: Date, today: Date) -> i64 {
+        self.due_date(finish).days_until(today).max(0)
+    }
+
+    /// Sets days of week when library is closed.
+    /// Library must be open at least one day of week
+
+    pub fn set_closed_days(&mut self, days: &[Weekday]) -> Result<&mut Self, CalendarError> {
+        let closed = WEEK
+            .iter()
+            .copied()
+            .filter(|day| days.contains(day))
+            .collect::<Vec<_>>();
+
+        if closed.len() == WEEK.len() {
+            return Err(CalendarError::NoOpenDays);
+        }
+
+        self.closed = closed;
+        Ok(self)
+    }
+
+    /// Adds holiday.
+    /// If there is holiday on the same date, it will return error
+
+    pub fn add_holiday(&mut self, holiday: Holiday) -> Result<&mut Self, CalendarError> {
+        if self.holidays.iter().any(|h| h.date == holiday.date) {
+            return Err(CalendarError::AlreadyExists);
+        }
+
+        let ind = self.holidays.partition_point(|h| h.date <= holiday.date);
+
+        self.holidays.insert(ind, holiday);
+        Ok(self)
+    }
+
+    /// Removes holiday with chosen index
+
+    #[inline]
+    pub fn remove_holiday(&mut self, ind: usize) -> Result<Holiday, CalendarError> {
+        match ind < self.holidays.len() {
+            true => Ok(self.holidays.remove(ind)),
+            false => Err(CalendarError::NotFound),
+        }
+    }
+
+    /// Adds holidays from iCalendar file's content.
+    /// Holidays on days which are already holidays are skipped.
+    /// Returns number of added holidays and number of
+    /// wrong events which are skipped
+
+    pub fn import_ical(&mut self, text: &str) -> Result<(usize, usize), CalendarError> {
+        let (holidays, skipped) = ical::parse(text).ok_or(CalendarError::WrongICalendar)?;
+
+        let added = holidays
+            .into_iter()
+            .filter(|holiday| {
+                !self.holidays.iter().any(|h| h.falls_on(holiday.date))
+                    && self.add_holiday(holiday.clone()).is_ok()
+            })
+            .count();
+
+        Ok((added, skipped))
+    }
+
+    /// Writes calendar to calendar's file.
+    /// Calendar which isn't loaded from data directory isn't saved
+
+    #[inline]
+    pub fn save(&self) -> io::Result<()> {
+        match &self.path {
+            Some(path) => data::write_atomic(
+                path,
+                emit(&calendar_to_yaml(&self.closed, &self.holidays))?.as_bytes(),
+            ),
+
+            None => Ok(()),
+        }
+    }
+}
+
+/// Error of changing opening calendar
+
+#[derive(Debug)]
+pub enum CalendarError {
+    /// Library is closed every day of week
+    NoOpenDays,
+
+    /// Holiday on the same date exists
+    AlreadyExists,
+
+    /// Holiday isn't found
+    NotFound,
+
+    /// File isn't correct iCalendar
+    WrongICalendar,
+
+    /// User's role doesn't allow to change calendar
+    NoAccess,
+
+    /// Calendar's file can't be read or written
+    Io(io::Error),
+}
+
+impl CalendarError {
+    /// Localized description of error
+
+    #[inline]
+    pub fn message(&self, lang: Lang) -> &'static str {
+        match self {
+            CalendarError::NoOpenDays => lang.tr("calendar-error-no-open-days"),
+            CalendarError::AlreadyExists => lang.tr("calendar-error-already-exists"),
+            CalendarError::NotFound => lang.tr("calendar-error-not-found"),
+            CalendarError::WrongICalendar => lang.tr("calendar-error-wrong-icalendar"),
+            CalendarError::NoAccess => AccessDenied.message(lang),
+            CalendarError::Io(_) => lang.tr("calendar-error-io"),
+        }
+    }
+}
+
+impl Display for CalendarError {
+    /// Describes error in english
+
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message(Lang::default()))
+    }
+}
+
+impl Error for CalendarError {
+    /// Underlying io error
+
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CalendarError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CalendarError {
+    /// Wraps io error
+
+    #[inline]
+    fn from(err: io::Error) -> Self {
+        CalendarError::Io(err)
+    }
+}
+
+impl From<AccessDenied> for CalendarError {
+    #[inline]
+    fn from(_: AccessDenied) -> Self {
+        CalendarError::NoAccess
+    }
+}
+
+/// Converts closed days and holidays
+/// to yaml document with version header
+
+fn calendar_to_yaml(closed: &[Weekday], holidays: &[Holiday]) -> Yaml {
+    let holidays = holidays
+        .iter()
+        .map(|holiday| {
+            let mut data = Hash::new();
+
+            data.insert(
+                Yaml::String("Date".to_string()),
+                Yaml::Array(vec![
+                    Yaml::Integer(holiday.date.day as i64),
+                    Yaml::Integer(holiday.date.month as i64),
+                    Yaml::Integer(holiday.date.year as i64),
+                ]),
+            );
+
+            data.insert(
+                Yaml::String("Name".to_string()),
+                Yaml::String(holiday.name.clone()),
+            );
+
+            data.insert(
+                Yaml::String("Yearly".to_string()),
+                Yaml::Boolean(holiday.yearly),
+            );
+
+            Yaml::Hash(data)
+        })
+        .collect();
+
+    let mut hash = Hash::new();
+
+    hash.insert(
+        Yaml::String(migration::VERSION_KEY.to_string()),
+        Yaml::Integer(CALENDAR_VERSION),
+    );
+
+    hash.insert(
+        Yaml::String(CLOSED_KEY.to_string()),
+        Yaml::Array(
+            closed
+                .iter()
+                .map(|day| Yaml::String(format!("{:?}", day)))
+                .collect(),
+        ),
+    );
+
+    hash.insert(
+        Yaml::String(HOLIDAYS_KEY.to_string()),
+        Yaml::Array(holidays),
+    );
+    Yaml::Hash(hash)
+}
+
+/// Parses closed days and holidays from yaml document
+
+fn calendar_from_yaml(path: &Path, doc: &Yaml) -> Result<(Vec<Weekday>, Vec<Holiday>), LoadError> {
+    match migration::version(doc) {
+        Some(CALENDAR_VERSION) => {}
+        Some(version) => {
+            return Err(LoadError::file(
+                path,
+                LoadErrorKind::UnsupportedVersion(version),
+            ))
+        }
+        None => return Err(LoadError::file(path, LoadErrorKind::WrongLayout)),
+    }
+
+    let closed = doc[CLOSED_KEY]
+        .as_vec()
+        .ok_or_else(|| LoadError::file(path, LoadErrorKind::WrongLayout))?
+        .iter()
+        .map(|day| {
+            day.as_str()
+                .and_then(|d| d.parse::<Weekday>().ok())
+                .ok_or_else(|| LoadError::file(path, LoadErrorKind::InvalidValue))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if WEEK.iter().all(|day| closed.contains(day)) {
+        return Err(LoadError::file(path, LoadErrorKind::InvalidValue));
+    }
+
+    let mut holidays = doc[HOLIDAYS_KEY]
+        .as_vec()
+        .ok_or_else(|| LoadError::file(path, LoadErrorKind::WrongLayout))?
+        .iter()
+        .enumerate()
+        .map(|(ind, d)| {
+            let record = Record::new(path, ind);
+
+            Ok(Holiday {
+                date: record.date_array(d, "Date")?,
+                name: record.str(d, "Name")?,
+                yearly: record.bool(d, "Yearly")?,
+            })
+        })
+        .collect::<Result<Vec<_>, LoadError>>()?;
+
+    holidays.sort_by_key(|holiday| holiday.date);
+    Ok((closed, holidays))
+}
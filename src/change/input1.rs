@@ -29,14 +29,7 @@ impl<I: InputExt + WidgetBase> Inputable for Input1<I> {
             return Ok(vec![InputExt::value(&*(self.input).borrow())]);
         } else {
             self.hide();
-            alert(
-                500,
-                500,
-                match lang {
-                    Lang::English => "Nothing inputted",
-                    Lang::Russian => "Ничего не введено",
-                },
-            );
+            alert(500, 500, lang.tr("nothing-inputted"));
         }
 
         InputExt::set_value(&mut *(*self.input).borrow_mut(), "");
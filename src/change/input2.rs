@@ -42,14 +42,7 @@ where
             ]);
         } else {
             self.hide();
-            alert(
-                500,
-                500,
-                match lang {
-                    Lang::English => "Nothing inputted",
-                    Lang::Russian => "Ничего не введено",
-                },
-            );
+            alert(500, 500, lang.tr("nothing-inputted"));
         }
 
         InputExt::set_value(&mut *(*self.input1).borrow_mut(), "");
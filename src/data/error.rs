@@ -0,0 +1,275 @@
+extern crate yaml_rust;
+
+use crate::{books::date::Date, id::Id};
+
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter},
+    io,
+    path::{Path, PathBuf},
+};
+
+use yaml_rust::{yaml::Array, ScanError, Yaml};
+
+/// Reason why data file can't be loaded
+
+#[derive(Debug)]
+pub enum LoadErrorKind {
+    /// File can't be opened or read
+    Io(io::Error),
+
+    /// File isn't correct yaml
+    Yaml(ScanError),
+
+    /// Database can't be read.
+    /// Error is boxed, so results with load errors stay small
+    #[cfg(feature = "sqlite")]
+    Sqlite(Box<rusqlite::Error>),
+
+    /// Root of file has wrong type
+    WrongLayout,
+
+    /// File is written by newer version of program
+    UnsupportedVersion(i64),
+
+    /// Field is absent or has wrong type
+    MissingField,
+
+    /// Number is out of range
+    InvalidValue,
+
+    /// Date doesn't exist in real world
+    InvalidDate,
+
+    /// Book refers to reader which isn't in Reader Base
+    UnknownReader,
+
+    /// Id is already used by another record
+    DuplicateId,
+}
+
+impl Display for LoadErrorKind {
+    /// Describes error's reason
+
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadErrorKind::Io(err) => write!(f, "{}", err),
+            LoadErrorKind::Yaml(err) => write!(f, "{}", err),
+            #[cfg(feature = "sqlite")]
+            LoadErrorKind::Sqlite(err) => write!(f, "{}", err),
+            LoadErrorKind::WrongLayout => write!(f, "wrong file layout"),
+
+            LoadErrorKind::UnsupportedVersion(version) => {
+                write!(f, "format version {} isn't supported", version)
+            }
+
+            LoadErrorKind::MissingField => write!(f, "field is missing or has wrong type"),
+            LoadErrorKind::InvalidValue => write!(f, "value is out of range"),
+            LoadErrorKind::InvalidDate => write!(f, "date is incorrect"),
+            LoadErrorKind::UnknownReader => write!(f, "reader isn't found"),
+            LoadErrorKind::DuplicateId => write!(f, "id is already used"),
+        }
+    }
+}
+
+/// Error of loading data file.
+/// Names file, record (if it's known)
+/// and field (if it's known) that failed
+
+#[derive(Debug)]
+pub struct LoadError {
+    pub file: PathBuf,
+    pub record: Option<usize>,
+    pub field: Option<String>,
+    pub kind: LoadErrorKind,
+}
+
+impl Display for LoadError {
+    /// Displays error as
+    /// {file}: record №{n}, field '{field}': {reason}
+
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.file.display())?;
+
+        if let Some(record) = self.record {
+            write!(f, ": record №{}", record + 1)?;
+        }
+
+        if let Some(field) = &self.field {
+            write!(f, ", field '{}'", field)?;
+        }
+
+        write!(f, ": {}", self.kind)
+    }
+}
+
+impl Error for LoadError {
+    /// Underlying io or yaml error
+
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            LoadErrorKind::Io(err) => Some(err),
+            LoadErrorKind::Yaml(err) => Some(err),
+            #[cfg(feature = "sqlite")]
+            LoadErrorKind::Sqlite(err) => Some(err.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl LoadError {
+    /// Error of whole file
+
+    #[inline]
+    pub fn file(file: &Path, kind: LoadErrorKind) -> Self {
+        LoadError {
+            file: file.to_path_buf(),
+            record: None,
+            field: None,
+            kind,
+        }
+    }
+}
+
+/// Position of record in data file.
+/// Used to read fields and build errors
+
+pub(crate) struct Record<'a> {
+    file: &'a Path,
+    index: usize,
+    prefix: String,
+}
+
+impl<'a> Record<'a> {
+    /// Top-level record of file
+
+    #[inline]
+    pub(crate) fn new(file: &'a Path, index: usize) -> Self {
+        Record {
+            file,
+            index,
+            prefix: String::new(),
+        }
+    }
+
+    /// Record inside of array field.
+    /// Fields will be named like **Simple Books[0].Cabinet**
+
+    #[inline]
+    pub(crate) fn nested(&self, field: &str, ind: usize) -> Record<'a> {
+        Record {
+            file: self.file,
+            index: self.index,
+            prefix: format!("{}{}[{}].", self.prefix, field, ind),
+        }
+    }
+
+    /// Builds error for field of record
+
+    #[inline]
+    pub(crate) fn error(&self, field: &str, kind: LoadErrorKind) -> LoadError {
+        LoadError {
+            file: self.file.to_path_buf(),
+            record: Some(self.index),
+            field: Some(format!("{}{}", self.prefix, field)),
+            kind,
+        }
+    }
+
+    /// Reads string field
+
+    #[inline]
+    pub(crate) fn str(&self, yaml: &Yaml, field: &str) -> Result<String, LoadError> {
+        yaml[field]
+            .as_str()
+            .map(|s| s.to_string())
+            .ok_or_else(|| self.error(field, LoadErrorKind::MissingField))
+    }
+
+    /// Reads integer field and checks its range
+
+    #[inline]
+    pub(crate) fn int<T: TryFrom<i64>>(&self, yaml: &Yaml, field: &str) -> Result<T, LoadError> {
+        self.int_value(&yaml[field], field)
+    }
+
+    /// Checks integer value and its range
+
+    #[inline]
+    fn int_value<T: TryFrom<i64>>(&self, yaml: &Yaml, field: &str) -> Result<T, LoadError> {
+        match yaml.as_i64() {
+            None => Err(self.error(field, LoadErrorKind::MissingField)),
+            Some(x) => T::try_from(x).map_err(|_| self.error(field, LoadErrorKind::InvalidValue)),
+        }
+    }
+
+    /// Reads id field.
+    /// Ids start from 1
+
+    #[inline]
+    pub(crate) fn id(&self, yaml: &Yaml, field: &str) -> Result<Id, LoadError> {
+        match self.int::<u64>(yaml, field)? {
+            0 => Err(self.error(field, LoadErrorKind::InvalidValue)),
+            id => Ok(Id(id)),
+        }
+    }
+
+    /// Reads boolean field
+
+    #[inline]
+    pub(crate) fn bool(&self, yaml: &Yaml, field: &str) -> Result<bool, LoadError> {
+        yaml[field]
+            .as_bool()
+            .ok_or_else(|| self.error(field, LoadErrorKind::MissingField))
+    }
+
+    /// Reads array field
+
+    #[inline]
+    pub(crate) fn vec<'y>(&self, yaml: &'y Yaml, field: &str) -> Result<&'y Array, LoadError> {
+        yaml[field]
+            .as_vec()
+            .ok_or_else(|| self.error(field, LoadErrorKind::MissingField))
+    }
+
+    /// Reads date from three integer fields
+    /// (day, month and year)
+
+    #[inline]
+    pub(crate) fn date(
+        &self,
+        yaml: &Yaml,
+        day: &str,
+        month: &str,
+        year: &str,
+    ) -> Result<Date, LoadError> {
+        Date::new(
+            self.int(yaml, day)?,
+            self.int(yaml, month)?,
+            self.int(yaml, year)?,
+        )
+        .map_err(|_| self.error(day, LoadErrorKind::InvalidDate))
+    }
+
+    /// Reads date from array field [day, month, year]
+
+    #[inline]
+    pub(crate) fn date_array(&self, yaml: &Yaml, field: &str) -> Result<Date, LoadError> {
+        let date = &yaml[field];
+
+        if date.as_vec().map(|d| d.len()) != Some(3) {
+            return Err(self.error(field, LoadErrorKind::MissingField));
+        }
+
+        Date::new(
+            self.int_value(&date[0], field)?,
+            self.int_value(&date[1], field)?,
+            self.int_value(&date[2], field)?,
+        )
+        .map_err(|_| self.error(field, LoadErrorKind::InvalidDate))
+    }
+}
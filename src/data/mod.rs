@@ -0,0 +1,572 @@
+extern crate chrono;
+extern crate yaml_rust;
+
+use crate::data::error::{LoadError, LoadErrorKind};
+
+use std::{
+    env,
+    fs::{self, File, OpenOptions},
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+};
+
+use chrono::Local;
+
+use yaml_rust::{Yaml, YamlLoader};
+
+pub mod error;
+
+/// Command line flag to choose data directory.
+/// Can be used as `--data-dir <path>` or `--data-dir=<path>`
+
+pub const DATA_DIR_FLAG: &str = "--data-dir";
+
+/// Environment variable to choose data directory
+
+pub const DATA_DIR_ENV: &str = "LIBRS_DATA_DIR";
+
+/// Name of directory inside of XDG data home
+
+const APP_DIR: &str = "booklibrs";
+
+/// How many backup generations are kept by default
+
+pub const DEFAULT_BACKUPS: usize = 10;
+
+/// Format of backup's directory name.
+/// Names are sorted in the same order as time
+
+const BACKUP_FORMAT: &str = "%Y-%m-%d_%H-%M-%S%.3f";
+
+/// Directory where all library's data is stored
+/// (readers, books, genres, language and staff accounts).
+/// Every load and save call gets it,
+/// so several libraries can be run from one install
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DataDir {
+    root: PathBuf,
+    backups: usize,
+}
+
+impl Default for DataDir {
+    /// Default data directory.
+    /// It's **$XDG_DATA_HOME/booklibrs**
+    /// or **~/.local/share/booklibrs**
+
+    #[inline]
+    fn default() -> Self {
+        DataDir::new(xdg_data_home().join(APP_DIR))
+    }
+}
+
+impl DataDir {
+    /// Creates data directory with chosen root.
+    /// Nothing is created on disk
+
+    #[inline]
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        DataDir {
+            root: root.into(),
+            backups: DEFAULT_BACKUPS,
+        }
+    }
+
+    /// Sets how many backup generations are kept.
+    /// If it's 0, backups are not created
+
+    #[inline]
+    pub fn with_backups(mut self, backups: usize) -> Self {
+        self.backups = backups;
+        self
+    }
+
+    /// Chooses data directory in the next order:
+    /// command line flag, environment variable, XDG default
+
+    #[inline]
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Self {
+        if let Some(root) = parse_flag(args, DATA_DIR_FLAG) {
+            return DataDir::new(root);
+        }
+
+        match env::var_os(DATA_DIR_ENV) {
+            Some(root) if !root.is_empty() => DataDir::new(root),
+            _ => DataDir::default(),
+        }
+    }
+
+    /// Chooses data directory for current process
+
+    #[inline]
+    pub fn from_env() -> Self {
+        DataDir::from_args(env::args().skip(1))
+    }
+
+    /// Root of data directory
+
+    #[inline]
+    pub fn root(&self) -> &Path {
+        self.root.as_path()
+    }
+
+    /// Path to file inside of data directory
+
+    #[inline]
+    pub fn file(&self, name: &str) -> PathBuf {
+        self.root.join(name)
+    }
+
+    /// File with readers
+
+    #[inline]
+    pub fn readers(&self) -> PathBuf {
+        self.file("readers.yaml")
+    }
+
+    /// File with books
+
+    #[inline]
+    pub fn books(&self) -> PathBuf {
+        self.file("books.yaml")
+    }
+
+    /// File with genres
+
+    #[inline]
+    pub fn genres(&self) -> PathBuf {
+        self.file("genres.yaml")
+    }
+
+    /// File with history of changes
+
+    #[inline]
+    pub fn history(&self) -> PathBuf {
+        self.file("history.yaml")
+    }
+
+    /// File with audit log.
+    /// Every entry is separate yaml document
+    /// appended to the end of file
+
+    #[inline]
+    pub fn audit(&self) -> PathBuf {
+        self.file("audit.yaml")
+    }
+
+    /// File with events of journal
+    /// since the last compaction.
+    /// Every event is separate yaml document
+    /// appended to the end of file
+
+    #[inline]
+    pub fn journal(&self) -> PathBuf {
+        self.file("journal.yaml")
+    }
+
+    /// File with chosen language
+
+    #[inline]
+    pub fn lang(&self) -> PathBuf {
+        self.file("lang.bin")
+    }
+
+    /// Directory with message catalogues.
+    /// Every catalogue is `<code>.ftl` file
+    /// (e.g. `de.ftl` for german)
+
+    #[inline]
+    pub fn locales_dir(&self) -> PathBuf {
+        self.file("locales")
+    }
+
+    /// File with admin's login and password of old versions.
+    /// It's migrated to users' file after the first login
+
+    #[inline]
+    pub fn admin(&self) -> PathBuf {
+        self.file("admin.bin")
+    }
+
+    /// File with staff accounts and their roles
+
+    #[inline]
+    pub fn users(&self) -> PathBuf {
+        self.file("users.yaml")
+    }
+
+    /// File with weekly closed days and holidays of library
+
+    #[inline]
+    pub fn calendar(&self) -> PathBuf {
+        self.file("calendar.yaml")
+    }
+
+    /// File with loan periods of library
+
+    #[inline]
+    pub fn policy(&self) -> PathBuf {
+        self.file("policy.yaml")
+    }
+
+    /// Directory with timestamped backups.
+    /// Every backup is a directory
+    /// with readers, books and genres
+
+    #[inline]
+    pub fn backups_dir(&self) -> PathBuf {
+        self.file("backups")
+    }
+
+    /// Directory with named snapshots.
+    /// Every snapshot is one file
+    /// with readers, books and genres
+
+    #[inline]
+    pub fn snapshots_dir(&self) -> PathBuf {
+        self.file("snapshots")
+    }
+
+    /// Directory with compacted states of journal
+    /// and events between them
+
+    #[inline]
+    pub fn journal_dir(&self) -> PathBuf {
+        self.file("journal")
+    }
+
+    /// Compacted state of journal
+    /// after event with chosen number
+
+    #[inline]
+    pub fn journal_state(&self, seq: u64) -> PathBuf {
+        self.journal_dir().join(format!("state_{}.yaml", seq))
+    }
+
+    /// Archived events of journal,
+    /// which end with event with chosen number
+
+    #[inline]
+    pub fn journal_events(&self, seq: u64) -> PathBuf {
+        self.journal_dir().join(format!("events_{}.yaml", seq))
+    }
+
+    /// Numbers of all compacted states of journal
+    /// from oldest to newest
+
+    #[inline]
+    pub fn journal_states(&self) -> io::Result<Vec<u64>> {
+        self.journal_files("state_")
+    }
+
+    /// Numbers of last events of all archived parts of journal
+    /// from oldest to newest
+
+    #[inline]
+    pub fn journal_archives(&self) -> io::Result<Vec<u64>> {
+        self.journal_files("events_")
+    }
+
+    /// Numbers of journal files with chosen prefix in ascending order
+
+    fn journal_files(&self, prefix: &str) -> io::Result<Vec<u64>> {
+        let dir = self.journal_dir();
+
+        if !dir.is_dir() {
+            return Ok(vec![]);
+        }
+
+        let mut numbers = fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                entry
+                    .file_name()
+                    .to_str()?
+                    .strip_prefix(prefix)?
+                    .strip_suffix(".yaml")?
+                    .parse::<u64>()
+                    .ok()
+            })
+            .collect::<Vec<_>>();
+
+        numbers.sort_unstable();
+        Ok(numbers)
+    }
+
+    /// Creates data directory and all empty files
+    /// which are not exist yet.
+    /// Existing files are not changed
+
+    pub fn create(&self) -> io::Result<()> {
+        fs::create_dir_all(&self.root)?;
+
+        [
+            self.readers(),
+            self.books(),
+            self.genres(),
+            self.lang(),
+            self.admin(),
+        ]
+        .iter()
+        .try_for_each(|path| {
+            OpenOptions::new()
+                .write(true)
+                .create(true)
+                .open(path)
+                .map(|_| ())
+        })
+    }
+
+    /// Backup of data file with last successfully loaded data
+
+    #[inline]
+    pub fn backup_of(&self, path: &Path) -> PathBuf {
+        let mut name = path.file_name().unwrap_or_default().to_os_string();
+        name.push(".bak");
+        path.with_file_name(name)
+    }
+
+    /// Backup of data file which is made
+    /// before it's upgraded from chosen format version
+
+    #[inline]
+    pub fn version_backup_of(&self, path: &Path, version: i64) -> PathBuf {
+        let mut name = path.file_name().unwrap_or_default().to_os_string();
+        name.push(format!(".v{}.bak", version));
+        path.with_file_name(name)
+    }
+
+    /// Copies all data files to their backups.
+    /// Used after data is successfully loaded
+
+    pub fn keep_backups(&self) -> io::Result<()> {
+        [self.readers(), self.books(), self.genres()]
+            .iter()
+            .try_for_each(|path| fs::copy(path, self.backup_of(path)).map(|_| ()))
+    }
+
+    /// Replaces data file with its backup.
+    /// If there is no backup, it will return false
+
+    pub fn restore_backup(&self, path: &Path) -> io::Result<bool> {
+        let backup = self.backup_of(path);
+
+        if backup.is_file() {
+            fs::copy(backup, path)?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// All backups from newest to oldest.
+    /// Every backup can be loaded as data directory
+
+    pub fn backups(&self) -> io::Result<Vec<DataDir>> {
+        let dir = self.backups_dir();
+
+        if !dir.is_dir() {
+            return Ok(vec![]);
+        }
+
+        let mut backups = fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .collect::<Vec<_>>();
+
+        backups.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(backups.into_iter().map(DataDir::new).collect())
+    }
+
+    /// All snapshot files from newest to oldest
+
+    pub fn snapshots(&self) -> io::Result<Vec<PathBuf>> {
+        let dir = self.snapshots_dir();
+
+        if !dir.is_dir() {
+            return Ok(vec![]);
+        }
+
+        let mut snapshots = fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file() && path.extension().map_or(false, |e| e == "yaml"))
+            .collect::<Vec<_>>();
+
+        snapshots.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(snapshots)
+    }
+
+    /// Path of new snapshot file.
+    /// It's named with current time,
+    /// so it's never the same as existing one
+
+    pub(crate) fn new_snapshot(&self) -> io::Result<PathBuf> {
+        fs::create_dir_all(self.snapshots_dir())?;
+
+        let time = Local::now().format(BACKUP_FORMAT).to_string();
+        let mut path = self.snapshots_dir().join(format!("{}.yaml", time));
+        let mut ind = 1;
+
+        while path.exists() {
+            path = self.snapshots_dir().join(format!("{}_{}.yaml", time, ind));
+            ind += 1;
+        }
+
+        Ok(path)
+    }
+
+    /// Name of directory.
+    /// For backups it's the time when backup was made
+
+    #[inline]
+    pub fn name(&self) -> String {
+        self.root
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
+
+    /// Saves data file.
+    /// New content is atomically written,
+    /// backup is made by storage once for the whole save.
+    /// If content isn't changed, nothing happens
+
+    pub(crate) fn save_file(&self, path: &Path, content: &[u8]) -> io::Result<()> {
+        if fs::read(path).map(|old| old == content).unwrap_or(false) {
+            return Ok(());
+        }
+
+        write_atomic(path, content)
+    }
+
+    /// Copies readers, books and genres to new backup
+    /// and removes backups that are too old
+
+    pub(crate) fn make_backup(&self) -> io::Result<()> {
+        if self.backups == 0 {
+            return Ok(());
+        }
+
+        let time = Local::now().format(BACKUP_FORMAT).to_string();
+        let mut dir = self.backups_dir().join(&time);
+        let mut ind = 1;
+
+        while dir.exists() {
+            dir = self.backups_dir().join(format!("{}_{}", time, ind));
+            ind += 1;
+        }
+
+        fs::create_dir_all(&dir)?;
+        let backup = DataDir::new(dir);
+
+        [
+            (self.readers(), backup.readers()),
+            (self.books(), backup.books()),
+            (self.genres(), backup.genres()),
+        ]
+        .iter()
+        .filter(|(from, _)| from.is_file())
+        .try_for_each(|(from, to)| fs::copy(from, to).map(|_| ()))?;
+
+        self.backups()?
+            .into_iter()
+            .skip(self.backups)
+            .try_for_each(|old| fs::remove_dir_all(old.root()))
+    }
+}
+
+/// Writes content to temporary file,
+/// syncs it with disk and renames it over original.
+/// If anything fails, original file stays untouched
+
+pub(crate) fn write_atomic(path: &Path, content: &[u8]) -> io::Result<()> {
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".tmp");
+    let tmp = path.with_file_name(tmp_name);
+
+    let mut file = File::create(&tmp)?;
+    file.write_all(content)?;
+    file.sync_all()?;
+    drop(file);
+
+    fs::rename(&tmp, path)?;
+
+    #[cfg(unix)]
+    if let Some(dir) = path.parent() {
+        File::open(dir)?.sync_all()?;
+    }
+
+    Ok(())
+}
+
+/// Appends content to the end of file
+/// and syncs it with disk.
+/// If file doesn't exist, it's created
+
+pub(crate) fn append(path: &Path, content: &[u8]) -> io::Result<()> {
+    let mut file = OpenOptions::new().append(true).create(true).open(path)?;
+    file.write_all(content)?;
+    file.sync_all()
+}
+
+/// Reads and parses yaml data file.
+/// If file is empty, it will return None
+
+pub(crate) fn read_yaml(path: &Path) -> Result<Option<Yaml>, LoadError> {
+    let mut string = String::new();
+
+    File::open(path)
+        .and_then(|mut file| file.read_to_string(&mut string))
+        .map_err(|e| LoadError::file(path, LoadErrorKind::Io(e)))?;
+
+    if string.trim().is_empty() {
+        return Ok(None);
+    }
+
+    Ok(YamlLoader::load_from_str(string.as_str())
+        .map_err(|e| LoadError::file(path, LoadErrorKind::Yaml(e)))?
+        .into_iter()
+        .next())
+}
+
+/// Searches flag's value in command line arguments.
+/// Flag can be used as `<flag> <value>` or `<flag>=<value>`
+
+#[inline]
+pub(crate) fn parse_flag<I: IntoIterator<Item = String>>(args: I, flag: &str) -> Option<String> {
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        if arg == flag {
+            return args.next();
+        }
+
+        if let Some(value) = arg.strip_prefix(flag).and_then(|x| x.strip_prefix('=')) {
+            return Some(value.to_string());
+        }
+    }
+
+    None
+}
+
+/// XDG data home directory.
+/// If it isn't set, it will be **~/.local/share**
+/// (or **%APPDATA%** on Windows)
+
+#[inline]
+fn xdg_data_home() -> PathBuf {
+    match env::var_os("XDG_DATA_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+
+        _ => match env::var_os("HOME") {
+            Some(home) if !home.is_empty() => PathBuf::from(home).join(".local").join("share"),
+
+            _ => match env::var_os("APPDATA") {
+                Some(app_data) => PathBuf::from(app_data),
+                None => PathBuf::from("."),
+            },
+        },
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
+
+/// Persistent unique identifier of reader, The Book or simple book.
+/// It never changes, so it's used for all cross-references
+/// instead of names and indexes
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Id(pub(crate) u64);
+
+impl Display for Id {
+    /// Displays id as number
+
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Id {
+    /// Raw number of id
+
+    #[inline]
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+}
+
+/// Generator of ids.
+/// Every new id is greater than all ids seen before
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct IdGen {
+    last: u64,
+}
+
+impl IdGen {
+    /// Creates generator which starts from 1
+
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        IdGen { last: 0 }
+    }
+
+    /// Gives new unique id
+
+    #[inline]
+    pub(crate) fn next(&mut self) -> Id {
+        self.last += 1;
+        Id(self.last)
+    }
+
+    /// Remembers id which is already used
+    /// (for example, loaded from storage)
+
+    #[inline]
+    pub(crate) fn observe(&mut self, id: Id) {
+        self.last = self.last.max(id.0);
+    }
+
+    /// Ids given after chosen earlier state of generator
+
+    #[inline]
+    pub(crate) fn given_since(&self, earlier: IdGen) -> impl Iterator<Item = Id> {
+        (earlier.last + 1..=self.last).map(Id)
+    }
+}
+
+/// Positions of records (readers or The Books)
+/// in their vector by id.
+/// Helps to find record without scanning all vector
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Positions {
+    positions: HashMap<Id, usize>,
+}
+
+impl Positions {
+    /// Position of record with chosen id
+
+    #[inline]
+    pub(crate) fn get(&self, id: Id) -> Option<usize> {
+        self.positions.get(&id).copied()
+    }
+
+    /// Remembers record inserted at chosen position.
+    /// Records after it are shifted
+
+    #[inline]
+    pub(crate) fn insert(&mut self, id: Id, pos: usize) {
+        if pos < self.positions.len() {
+            self.positions
+                .values_mut()
+                .filter(|p| **p >= pos)
+                .for_each(|p| *p += 1);
+        }
+
+        self.positions.insert(id, pos);
+    }
+
+    /// Forgets removed record.
+    /// Records after it are shifted
+
+    #[inline]
+    pub(crate) fn remove(&mut self, id: Id) -> Option<usize> {
+        let pos = self.positions.remove(&id)?;
+
+        self.positions
+            .values_mut()
+            .filter(|p| **p > pos)
+            .for_each(|p| *p -= 1);
+
+        Some(pos)
+    }
+
+    /// Forgets all records
+
+    #[inline]
+    pub(crate) fn clear(&mut self) {
+        self.positions.clear();
+    }
+}
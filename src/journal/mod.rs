@@ -0,0 +1,607 @@
+extern crate chrono;
+
+use crate::{
+    books::date::Date,
+    data::error::LoadError,
+    id::Id,
+    restore::command::{Change, Command},
+    storage::{memory::MemoryStorage, BookRecord, CopyRecord, LoanRecord, ReaderRecord},
+    Lang,
+};
+
+use chrono::NaiveDateTime;
+
+pub mod viewer;
+
+/// Domain event of journal.
+/// Every change of readers, books and genres
+/// is stored as sequence of events,
+/// so any previous state can be restored by replaying them
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JournalEvent {
+    /// Reader was added to chosen index
+    ReaderAdded { index: usize, reader: ReaderRecord },
+
+    /// Reader's params were changed
+    ReaderChanged(ReaderRecord),
+
+    /// Reader with id was removed
+    ReaderRemoved(Id),
+
+    /// The Book was added to chosen index
+    BookAdded { index: usize, book: BookRecord },
+
+    /// Title, author or pages of The Book were changed
+    BookChanged {
+        book: Id,
+        title: String,
+        author: String,
+        pages: u16,
+    },
+
+    /// The Book was replaced with new state
+    /// (when change can't be split into smaller events)
+    BookReplaced(BookRecord),
+
+    /// The Book with id was removed
+    BookRemoved(Id),
+
+    /// Genres of The Book were set
+    GenresAssigned {
+        book: Id,
+        genres: Option<Vec<String>>,
+    },
+
+    /// Simple book was added to chosen index of The Book
+    CopyAdded {
+        book: Id,
+        index: usize,
+        copy: CopyRecord,
+    },
+
+    /// Simple book was removed
+    CopyRemoved { book: Id, copy: Id },
+
+    /// Simple book was moved to other cabinet or shelf
+    CopyMoved {
+        book: Id,
+        copy: Id,
+        cabinet: u16,
+        shelf: u8,
+    },
+
+    /// Simple book was given to reader
+    CopyGiven {
+        book: Id,
+        copy: Id,
+        loan: LoanRecord,
+    },
+
+    /// Simple book was returned at chosen date
+    CopyReturned { book: Id, copy: Id, finish: Date },
+
+    /// Return date of last giving was changed
+    DueDateChanged { book: Id, copy: Id, finish: Date },
+
+    /// Simple book was replaced with new state
+    /// (when change can't be split into smaller events)
+    CopyReplaced { book: Id, copy: CopyRecord },
+
+    /// Genre was added to list of genres
+    GenreAdded(String),
+
+    /// Genre was removed from list of genres
+    GenreRemoved(String),
+}
+
+impl JournalEvent {
+    /// Name of event as it's stored
+
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        match self {
+            JournalEvent::ReaderAdded { .. } => "ReaderAdded",
+            JournalEvent::ReaderChanged(_) => "ReaderChanged",
+            JournalEvent::ReaderRemoved(_) => "ReaderRemoved",
+            JournalEvent::BookAdded { .. } => "BookAdded",
+            JournalEvent::BookChanged { .. } => "BookChanged",
+            JournalEvent::BookReplaced(_) => "BookReplaced",
+            JournalEvent::BookRemoved(_) => "BookRemoved",
+            JournalEvent::GenresAssigned { .. } => "GenresAssigned",
+            JournalEvent::CopyAdded { .. } => "CopyAdded",
+            JournalEvent::CopyRemoved { .. } => "CopyRemoved",
+            JournalEvent::CopyMoved { .. } => "CopyMoved",
+            JournalEvent::CopyGiven { .. } => "CopyGiven",
+            JournalEvent::CopyReturned { .. } => "CopyReturned",
+            JournalEvent::DueDateChanged { .. } => "DueDateChanged",
+            JournalEvent::CopyReplaced { .. } => "CopyReplaced",
+            JournalEvent::GenreAdded(_) => "GenreAdded",
+            JournalEvent::GenreRemoved(_) => "GenreRemoved",
+        }
+    }
+
+    /// Localized kind of event
+
+    #[inline]
+    pub fn message(&self, lang: Lang) -> &'static str {
+        lang.tr(match self {
+            JournalEvent::ReaderAdded { .. } => "event-reader-added",
+            JournalEvent::ReaderChanged(_) => "event-reader-changed",
+            JournalEvent::ReaderRemoved(_) => "event-reader-removed",
+            JournalEvent::BookAdded { .. } => "event-book-added",
+            JournalEvent::BookChanged { .. } => "event-book-changed",
+            JournalEvent::BookReplaced(_) => "event-book-replaced",
+            JournalEvent::BookRemoved(_) => "event-book-removed",
+            JournalEvent::GenresAssigned { .. } => "event-genres-assigned",
+            JournalEvent::CopyAdded { .. } => "event-copy-added",
+            JournalEvent::CopyRemoved { .. } => "event-copy-removed",
+            JournalEvent::CopyMoved { .. } => "event-copy-moved",
+            JournalEvent::CopyGiven { .. } => "event-copy-given",
+            JournalEvent::CopyReturned { .. } => "event-copy-returned",
+            JournalEvent::DueDateChanged { .. } => "event-due-date-changed",
+            JournalEvent::CopyReplaced { .. } => "event-copy-replaced",
+            JournalEvent::GenreAdded(_) => "event-genre-added",
+            JournalEvent::GenreRemoved(_) => "event-genre-removed",
+        })
+    }
+
+    /// Splits command into events.
+    /// Events are ordered as changes are applied by command:
+    /// removed books and readers go first, then readers, books and genres
+
+    pub(crate) fn from_command(command: &Command) -> Vec<JournalEvent> {
+        let mut events = vec![];
+
+        let mut removed_books = command
+            .books
+            .iter()
+            .filter_map(|c| match c {
+                Change::Removed(ind, record) => Some((*ind, record.id)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        removed_books.sort_unstable_by(|a, b| b.cmp(a));
+
+        events.extend(
+            removed_books
+                .into_iter()
+                .map(|(_, id)| JournalEvent::BookRemoved(id)),
+        );
+
+        let mut removed_readers = command
+            .readers
+            .iter()
+            .filter_map(|c| match c {
+                Change::Removed(ind, record) => Some((*ind, record.id)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        removed_readers.sort_unstable_by(|a, b| b.cmp(a));
+
+        events.extend(
+            removed_readers
+                .into_iter()
+                .map(|(_, id)| JournalEvent::ReaderRemoved(id)),
+        );
+
+        let mut added_readers = vec![];
+
+        command.readers.iter().for_each(|c| match c {
+            Change::Changed(_, after) => events.push(JournalEvent::ReaderChanged(after.clone())),
+            Change::Added(ind, record) => added_readers.push((*ind, record)),
+            Change::Removed(_, _) => {}
+        });
+
+        added_readers.sort_unstable_by_key(|(ind, _)| *ind);
+
+        events.extend(
+            added_readers
+                .into_iter()
+                .map(|(index, reader)| JournalEvent::ReaderAdded {
+                    index,
+                    reader: reader.clone(),
+                }),
+        );
+
+        let mut added_books = vec![];
+
+        command.books.iter().for_each(|c| match c {
+            Change::Changed(before, after) => events.extend(book_events(before, after)),
+            Change::Added(ind, record) => added_books.push((*ind, record)),
+            Change::Removed(_, _) => {}
+        });
+
+        added_books.sort_unstable_by_key(|(ind, _)| *ind);
+
+        events.extend(
+            added_books
+                .into_iter()
+                .map(|(index, book)| JournalEvent::BookAdded {
+                    index,
+                    book: book.clone(),
+                }),
+        );
+
+        events.extend(
+            command
+                .removed_genres
+                .iter()
+                .cloned()
+                .map(JournalEvent::GenreRemoved),
+        );
+
+        events.extend(
+            command
+                .added_genres
+                .iter()
+                .cloned()
+                .map(JournalEvent::GenreAdded),
+        );
+
+        events
+    }
+}
+
+/// Splits change of The Book into events.
+/// If order of simple books is changed,
+/// The Book is replaced as whole
+
+fn book_events(before: &BookRecord, after: &BookRecord) -> Vec<JournalEvent> {
+    let book = after.id;
+    let mut events = vec![];
+
+    if before.title != after.title || before.author != after.author || before.pages != after.pages {
+        events.push(JournalEvent::BookChanged {
+            book,
+            title: after.title.clone(),
+            author: after.author.clone(),
+            pages: after.pages,
+        });
+    }
+
+    if before.genres != after.genres {
+        events.push(JournalEvent::GenresAssigned {
+            book,
+            genres: after.genres.clone(),
+        });
+    }
+
+    let kept_before = before
+        .copies
+        .iter()
+        .filter(|b| after.copies.iter().any(|a| a.id == b.id))
+        .collect::<Vec<_>>();
+
+    let kept_after = after
+        .copies
+        .iter()
+        .filter(|a| before.copies.iter().any(|b| b.id == a.id))
+        .collect::<Vec<_>>();
+
+    if kept_before
+        .iter()
+        .map(|c| c.id)
+        .ne(kept_after.iter().map(|c| c.id))
+    {
+        return vec![JournalEvent::BookReplaced(after.clone())];
+    }
+
+    events.extend(
+        before
+            .copies
+            .iter()
+            .filter(|b| !after.copies.iter().any(|a| a.id == b.id))
+            .map(|b| JournalEvent::CopyRemoved { book, copy: b.id }),
+    );
+
+    kept_before
+        .into_iter()
+        .zip(kept_after)
+        .for_each(|(b, a)| events.extend(copy_events(book, b, a)));
+
+    events.extend(
+        after
+            .copies
+            .iter()
+            .enumerate()
+            .filter(|(_, a)| !before.copies.iter().any(|b| b.id == a.id))
+            .map(|(index, a)| JournalEvent::CopyAdded {
+                book,
+                index,
+                copy: a.clone(),
+            }),
+    );
+
+    events
+}
+
+/// Splits change of simple book into events:
+/// moving, giving, returning and changing of return date.
+/// Other changes replace simple book as whole
+
+fn copy_events(book: Id, before: &CopyRecord, after: &CopyRecord) -> Vec<JournalEvent> {
+    let copy = after.id;
+    let mut events = vec![];
+
+    let same_loans = |len: usize| before.loans[..len] == after.loans[..len];
+
+    let loan_event = match (before.loans.len(), after.loans.len()) {
+        (b, a) if b == a && before.loans == after.loans && before.is_using == after.is_using => {
+            None
+        }
+
+        (b, a) if b + 1 == a && same_loans(b) && after.is_using => Some(JournalEvent::CopyGiven {
+            book,
+            copy,
+            loan: after.loans.last().unwrap().clone(),
+        }),
+
+        (b, a) if b == a && b > 0 && same_loans(b - 1) => {
+            let (old, new) = (before.loans.last().unwrap(), after.loans.last().unwrap());
+
+            match (before.is_using, after.is_using) {
+                _ if old.reader != new.reader || old.start != new.start => None,
+
+                (true, false) => Some(JournalEvent::CopyReturned {
+                    book,
+                    copy,
+                    finish: new.finish,
+                }),
+
+                (using, now_using) if using == now_using => Some(JournalEvent::DueDateChanged {
+                    book,
+                    copy,
+                    finish: new.finish,
+                }),
+
+                _ => None,
+            }
+        }
+
+        _ => None,
+    };
+
+    let loans_changed = before.loans != after.loans || before.is_using != after.is_using;
+
+    if loans_changed && loan_event.is_none() || before.copy_type != after.copy_type {
+        return vec![JournalEvent::CopyReplaced {
+            book,
+            copy: after.clone(),
+        }];
+    }
+
+    if before.cabinet != after.cabinet || before.shelf != after.shelf {
+        events.push(JournalEvent::CopyMoved {
+            book,
+            copy,
+            cabinet: after.cabinet,
+            shelf: after.shelf,
+        });
+    }
+
+    events.extend(loan_event);
+    events
+}
+
+/// Event with its number and time.
+/// Numbers of events grow by one starting from 1
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JournalEntry {
+    pub(crate) seq: u64,
+    pub(crate) time: NaiveDateTime,
+    pub(crate) event: JournalEvent,
+}
+
+impl JournalEntry {
+    /// Number of event in journal
+
+    #[inline]
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    /// Time when event happened
+
+    #[inline]
+    pub fn time(&self) -> NaiveDateTime {
+        self.time
+    }
+
+    /// What happened
+
+    #[inline]
+    pub fn event(&self) -> &JournalEvent {
+        &self.event
+    }
+}
+
+/// Readers, books and genres restored from journal.
+/// Incorrect records keep their places,
+/// but events don't change them
+
+#[derive(Default)]
+pub(crate) struct Replay {
+    pub(crate) readers: Vec<Result<ReaderRecord, LoadError>>,
+    pub(crate) books: Vec<Result<BookRecord, LoadError>>,
+    pub(crate) genres: Vec<Result<String, LoadError>>,
+}
+
+impl Replay {
+    /// Starts replaying from stored records
+
+    #[inline]
+    pub(crate) fn from_storage(state: &MemoryStorage) -> Self {
+        Replay {
+            readers: state.readers().into_iter().map(Ok).collect(),
+            books: state.books().into_iter().map(Ok).collect(),
+            genres: state.genres().into_iter().map(Ok).collect(),
+        }
+    }
+
+    /// Applies event to records.
+    /// Events with unknown records are skipped
+
+    pub(crate) fn apply(&mut self, event: &JournalEvent) -> &mut Self {
+        match event {
+            JournalEvent::ReaderAdded { index, reader } => {
+                let index = (*index).min(self.readers.len());
+                self.readers.insert(index, Ok(reader.clone()));
+            }
+
+            JournalEvent::ReaderChanged(reader) => {
+                if let Some(old) = find(&mut self.readers, |r| r.id == reader.id) {
+                    *old = reader.clone();
+                }
+            }
+
+            JournalEvent::ReaderRemoved(id) => {
+                self.readers
+                    .retain(|r| r.as_ref().map_or(true, |r| r.id != *id));
+            }
+
+            JournalEvent::BookAdded { index, book } => {
+                let index = (*index).min(self.books.len());
+                self.books.insert(index, Ok(book.clone()));
+            }
+
+            JournalEvent::BookChanged {
+                book,
+                title,
+                author,
+                pages,
+            } => {
+                if let Some(old) = find(&mut self.books, |b| b.id == *book) {
+                    old.title = title.clone();
+                    old.author = author.clone();
+                    old.pages = *pages;
+                }
+            }
+
+            JournalEvent::BookReplaced(book) => {
+                if let Some(old) = find(&mut self.books, |b| b.id == book.id) {
+                    *old = book.clone();
+                }
+            }
+
+            JournalEvent::BookRemoved(id) => {
+                self.books
+                    .retain(|b| b.as_ref().map_or(true, |b| b.id != *id));
+            }
+
+            JournalEvent::GenresAssigned { book, genres } => {
+                if let Some(old) = find(&mut self.books, |b| b.id == *book) {
+                    old.genres = genres.clone();
+                }
+            }
+
+            JournalEvent::CopyAdded { book, index, copy } => {
+                if let Some(old) = find(&mut self.books, |b| b.id == *book) {
+                    let index = (*index).min(old.copies.len());
+                    old.copies.insert(index, copy.clone());
+                }
+            }
+
+            JournalEvent::CopyRemoved { book, copy } => {
+                if let Some(old) = find(&mut self.books, |b| b.id == *book) {
+                    old.copies.retain(|c| c.id != *copy);
+                }
+            }
+
+            JournalEvent::CopyMoved {
+                book,
+                copy,
+                cabinet,
+                shelf,
+            } => {
+                if let Some(old) = self.copy(*book, *copy) {
+                    old.cabinet = *cabinet;
+                    old.shelf = *shelf;
+                }
+            }
+
+            JournalEvent::CopyGiven { book, copy, loan } => {
+                if let Some(old) = self.copy(*book, *copy) {
+                    old.loans.push(loan.clone());
+                    old.is_using = true;
+                }
+            }
+
+            JournalEvent::CopyReturned { book, copy, finish } => {
+                if let Some(old) = self.copy(*book, *copy) {
+                    if let Some(loan) = old.loans.last_mut() {
+                        loan.finish = *finish;
+                    }
+
+                    old.is_using = false;
+                }
+            }
+
+            JournalEvent::DueDateChanged { book, copy, finish } => {
+                if let Some(loan) = self.copy(*book, *copy).and_then(|c| c.loans.last_mut()) {
+                    loan.finish = *finish;
+                }
+            }
+
+            JournalEvent::CopyReplaced { book, copy } => {
+                if let Some(old) = self.copy(*book, copy.id) {
+                    *old = copy.clone();
+                }
+            }
+
+            JournalEvent::GenreAdded(genre) => {
+                if find(&mut self.genres, |g| g == genre).is_none() {
+                    let index = self
+                        .genres
+                        .iter()
+                        .position(|g| g.as_ref().map_or(false, |g| g > genre))
+                        .unwrap_or(self.genres.len());
+
+                    self.genres.insert(index, Ok(genre.clone()));
+                }
+            }
+
+            JournalEvent::GenreRemoved(genre) => {
+                self.genres
+                    .retain(|g| g.as_ref().map_or(true, |g| g != genre));
+            }
+        }
+
+        self
+    }
+
+    /// Simple book with chosen id of The Book
+
+    #[inline]
+    fn copy(&mut self, book: Id, copy: Id) -> Option<&mut CopyRecord> {
+        find(&mut self.books, |b| b.id == book)?
+            .copies
+            .iter_mut()
+            .find(|c| c.id == copy)
+    }
+
+    /// Converts restored records to storage.
+    /// If any record is incorrect, it will return error
+
+    #[inline]
+    pub(crate) fn into_storage(self) -> Result<MemoryStorage, LoadError> {
+        Ok(MemoryStorage::with_records(
+            self.readers.into_iter().collect::<Result<_, _>>()?,
+            self.books.into_iter().collect::<Result<_, _>>()?,
+            self.genres.into_iter().collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+/// Searches correct record which satisfies predicate
+
+#[inline]
+fn find<T>(records: &mut [Result<T, LoadError>], predicate: impl Fn(&T) -> bool) -> Option<&mut T> {
+    records
+        .iter_mut()
+        .filter_map(|r| r.as_mut().ok())
+        .find(|r| predicate(r))
+}
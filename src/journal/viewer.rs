@@ -0,0 +1,156 @@
+extern crate fltk;
+extern crate fltk_calendar;
+
+use crate::{
+    books::date::Date,
+    library::Library,
+    restore::snapshot::{describe_reader, describe_title},
+    Lang,
+};
+
+use fltk::{dialog::alert, prelude::*, tree::Tree, window::SingleWindow};
+
+use fltk_calendar::calendar::Calendar;
+
+/// Function that shows library as it was
+/// at the end of chosen day: readers, books
+/// with simple books, givings and genres.
+/// State is restored from journal of changes
+
+pub fn time_travel(library: &Library, lang: Lang) {
+    let date = match Calendar::default().get_date().and_then(Date::from_naive) {
+        Some(date) => date,
+
+        None => {
+            alert(500, 500, lang.tr("date-wasnt-selected"));
+            return;
+        }
+    };
+
+    match library.on_date(date) {
+        Ok(Some(past)) => show_library(&past, date, lang),
+
+        Ok(None) => alert(500, 500, lang.tr("journal-has-no-changes-until-this-day")),
+
+        Err(err) => alert(
+            500,
+            500,
+            format!("{}\n{}", lang.tr("journal-is-damaged"), err).as_str(),
+        ),
+    }
+}
+
+/// Shows all records of restored library
+
+fn show_library(past: &Library, date: Date, lang: Lang) {
+    let records = past.records();
+    let readers = records.readers();
+    let books = records.books();
+
+    let mut wind = SingleWindow::new(500, 100, 600, 500, "");
+
+    wind.set_label(format!("{} {}", lang.tr("library-on"), date.localized(lang)).as_str());
+
+    let mut tree = Tree::new(0, 0, 600, 500, "");
+    tree.set_root_label(wind.label().as_str());
+
+    let readers_branch = lang.tr("readers");
+
+    let books_branch = lang.tr("books");
+
+    let loans_branch = lang.tr("givings");
+
+    let genres_branch = lang.tr("genres");
+
+    [readers_branch, books_branch, loans_branch, genres_branch]
+        .iter()
+        .for_each(|branch| {
+            tree.add(branch).unwrap();
+        });
+
+    let reader_name = |id| {
+        readers
+            .iter()
+            .find(|r| r.id == id)
+            .map(|r| format!("{} {} {}", r.name, r.family, r.father))
+            .unwrap_or_else(|| id.to_string())
+    };
+
+    readers.iter().for_each(|reader| {
+        add_item(&mut tree, &[readers_branch, &describe_reader(reader, lang)]);
+    });
+
+    books.iter().for_each(|book| {
+        let title = describe_title(book);
+        add_item(&mut tree, &[books_branch, &title]);
+
+        book.copies.iter().for_each(|copy| {
+            add_item(
+                &mut tree,
+                &[
+                    books_branch,
+                    &title,
+                    &format!(
+                        "№{}: {} {}, {} {}{}",
+                        copy.id,
+                        lang.tr("cabinet-lowercase"),
+                        copy.cabinet,
+                        lang.tr("shelf-lowercase"),
+                        copy.shelf,
+                        match copy.is_using {
+                            false => "",
+                            true => lang.tr("given-mark"),
+                        }
+                    ),
+                ],
+            );
+
+            copy.loans.iter().enumerate().for_each(|(ind, loan)| {
+                let returned = !copy.is_using || ind + 1 != copy.loans.len();
+
+                add_item(
+                    &mut tree,
+                    &[
+                        loans_branch,
+                        &format!(
+                            "{}: {} {} №{} ({} - {}){}",
+                            reader_name(loan.reader),
+                            book.title,
+                            book.author,
+                            copy.id,
+                            loan.start.localized(lang),
+                            loan.finish.localized(lang),
+                            match returned {
+                                true => "",
+                                false => lang.tr("not-returned-mark"),
+                            }
+                        ),
+                    ],
+                );
+            });
+        });
+    });
+
+    records.genres().iter().for_each(|genre| {
+        add_item(&mut tree, &[genres_branch, genre]);
+    });
+
+    wind.end();
+    wind.show();
+}
+
+/// Adds item with chosen path to tree.
+/// Slashes separate levels of tree,
+/// so they are escaped in labels
+
+#[inline]
+fn add_item(tree: &mut Tree, path: &[&str]) {
+    tree.add(
+        path.iter()
+            .map(|label| label.replace('/', "\\/"))
+            .collect::<Vec<_>>()
+            .join("/")
+            .as_str(),
+    )
+    .unwrap();
+}
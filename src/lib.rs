@@ -1,21 +1,28 @@
 //#![feature(option_result_unwrap_unchecked)]
 
+use crate::{auth::Permission, books::date::ISO_FORMAT, data::DataDir, locale::Catalogue};
+
 use std::{
-    fs::File,
-    io::{Read, Write},
+    fmt::{Debug, Display, Formatter},
+    io,
+    sync::OnceLock,
 };
 
 pub mod actions;
+pub mod audit;
+pub mod auth;
 pub mod books;
+pub mod calendar;
 pub mod change;
+pub mod data;
+pub mod id;
+pub mod journal;
+pub mod library;
+pub mod locale;
+pub mod policy;
 pub mod reading;
 pub mod restore;
-
-#[allow(dead_code)]
-#[allow(unused_imports)]
-#[deprecated(note = "It has been a lot of changes since I need it,\
-     since everything works correct, I have no need for tests")]
-mod tests;
+pub mod storage;
 
 /// All messages, which used to call functions
 
@@ -28,6 +35,7 @@ pub enum Message {
     ChangeFather,
     ChangeAge,
     ChangeInfo,
+    ChangeCategory,
     InfoReaderReading,
     InfoReaderAllBooks,
     AddBooks,
@@ -37,6 +45,7 @@ pub enum Message {
     ChangeAuthor,
     ChangePages,
     ChangeLocation,
+    ChangeCopyType,
     InfoTheBook,
     InfoBook,
     GiveBook,
@@ -50,63 +59,226 @@ pub enum Message {
     CustomizeBookGenre,
     PrevData,
     NextData,
-    English,
-    Russian,
+    History,
+    Audit,
+    TimeTravel,
+    RestoreBackup,
+    CreateSnapshot,
+    RestoreSnapshot,
+    Users,
+    OpeningCalendar,
+    LoanPolicy,
+    ChangeCredentials,
+    Lock,
+    Language(usize),
     Help,
 }
 
+impl Message {
+    /// Permission which user needs to call function.
+    /// Restoring data and managing users is for administrator,
+    /// changing data is for librarian
+
+    #[inline]
+    pub fn permission(self) -> Permission {
+        match self {
+            Message::InfoReaderReading
+            | Message::InfoReaderAllBooks
+            | Message::InfoTheBook
+            | Message::InfoBook
+            | Message::ShowAllBooks
+            | Message::ShowGenres
+            | Message::ShowAuthor
+            | Message::TimeTravel
+            | Message::ChangeCredentials
+            | Message::Lock
+            | Message::Language(_)
+            | Message::Help => Permission::View,
+
+            Message::PrevData
+            | Message::NextData
+            | Message::History
+            | Message::Audit
+            | Message::RestoreBackup
+            | Message::CreateSnapshot
+            | Message::RestoreSnapshot
+            | Message::Users
+            | Message::OpeningCalendar
+            | Message::LoanPolicy => Permission::Administer,
+
+            _ => Permission::Edit,
+        }
+    }
+}
+
 /// Language.
-/// Helps to choose preferred language
+/// Helps to choose preferred language.
+/// All messages are taken from language's catalogue,
+/// untranslated messages are taken from english one
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum Lang {
-    English,
-    Russian,
+#[derive(Clone, Copy)]
+pub struct Lang {
+    catalogue: &'static Catalogue,
+    fallback: &'static Catalogue,
 }
 
 impl Default for Lang {
     /// Creates default localization.
-    /// By default it's english
+    /// By default it's built in english
 
     #[inline]
     fn default() -> Self {
-        Lang::English
+        static ENGLISH: OnceLock<Catalogue> = OnceLock::new();
+
+        let english = ENGLISH.get_or_init(|| {
+            locale::built_in()
+                .into_iter()
+                .find(|catalogue| catalogue.code() == locale::DEFAULT_CODE)
+                .unwrap_or_default()
+        });
+
+        Lang {
+            catalogue: english,
+            fallback: english,
+        }
+    }
+}
+
+impl PartialEq for Lang {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.code() == other.code()
+    }
+}
+
+impl Eq for Lang {}
+
+impl Debug for Lang {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Lang").field(&self.code()).finish()
     }
 }
 
 impl Lang {
+    /// All available languages:
+    /// built in and from catalogues in data directory.
+    /// Catalogues live until the end of program,
+    /// so it should be called once
+
+    pub fn all(data_dir: &DataDir) -> Vec<Lang> {
+        let catalogues: &'static [Catalogue] =
+            Box::leak(locale::load_all(&data_dir.locales_dir()).into_boxed_slice());
+
+        let fallback = catalogues
+            .iter()
+            .find(|catalogue| catalogue.code() == locale::DEFAULT_CODE)
+            .unwrap_or(Lang::default().catalogue);
+
+        catalogues
+            .iter()
+            .map(|catalogue| Lang {
+                catalogue,
+                fallback,
+            })
+            .collect()
+    }
+
     /// Creates new localization.
-    /// Reads from file
+    /// Reads language's code from file in data directory
+    /// and chooses it from available languages
 
     #[inline]
-    pub fn new() -> Self {
-        let mut buf = String::new();
-
-        File::open("src/utils/lang.bin")
-            .unwrap()
-            .read_to_string(&mut buf)
-            .unwrap();
-
-        match buf.trim().to_lowercase().as_str() {
-            "english" => Lang::English,
-            "russian" => Lang::Russian,
-            _ => Lang::default(),
-        }
+    pub fn new(data_dir: &DataDir, languages: &[Lang]) -> Self {
+        let code = std::fs::read_to_string(data_dir.lang())
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase();
+
+        // Old versions keep language's name
+
+        let code = match code.as_str() {
+            "english" => "en",
+            "russian" => "ru",
+            code => code,
+        };
+
+        languages
+            .iter()
+            .find(|lang| lang.code() == code)
+            .or_else(|| {
+                languages
+                    .iter()
+                    .find(|lang| lang.code() == locale::DEFAULT_CODE)
+            })
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Changes language in file in data directory.
+    /// If file can't be written, it will return error
+
+    #[inline]
+    pub fn change(new_lang: Lang, data_dir: &DataDir) -> io::Result<()> {
+        locale::save_code(&data_dir.lang(), new_lang.code())
+    }
+
+    /// Message with given id.
+    /// If it isn't translated, english message is returned,
+    /// if there is no such message at all, id itself is returned
+
+    #[inline]
+    pub fn tr(self, id: &'static str) -> &'static str {
+        self.catalogue
+            .get(id)
+            .or_else(|| self.fallback.get(id))
+            .unwrap_or(id)
+    }
+
+    /// Message with given id and substituted arguments `{ $name }`
+
+    #[inline]
+    pub fn format(self, id: &'static str, args: &[(&str, &dyn Display)]) -> String {
+        locale::substitute(self.tr(id), args)
+    }
+
+    /// Plural form of message with given id for chosen count
+    /// with substituted arguments `{ $name }`.
+    /// Form is taken from the same catalogue as `tr` takes message
+
+    #[inline]
+    pub fn plural(self, id: &'static str, count: usize, args: &[(&str, &dyn Display)]) -> String {
+        let message = self
+            .catalogue
+            .plural(id, count)
+            .or_else(|| self.fallback.plural(id, count))
+            .unwrap_or(id);
+
+        locale::substitute(message, args)
+    }
+
+    /// Chrono's format of dates (e.g. "%d.%m.%Y").
+    /// It isn't taken from english catalogue,
+    /// language without its own format uses ISO 8601
+
+    #[inline]
+    pub fn date_format(self) -> &'static str {
+        self.catalogue
+            .get(locale::DATE_FORMAT_ID)
+            .unwrap_or(ISO_FORMAT)
+    }
+
+    /// Code of language (e.g. "en")
+
+    #[inline]
+    pub fn code(self) -> &'static str {
+        self.catalogue.code()
     }
 
-    /// Changes language in file
+    /// Name of language in itself (e.g. "English", "Русский")
 
     #[inline]
-    pub fn change(new_lang: Lang) {
-        File::create("src/utils/lang.bin")
-            .unwrap()
-            .write(
-                match new_lang {
-                    Lang::English => "english",
-                    Lang::Russian => "russian",
-                }
-                .as_bytes(),
-            )
-            .unwrap();
+    pub fn name(self) -> &'static str {
+        self.catalogue.get(locale::NAME_ID).unwrap_or(self.code())
     }
 }
@@ -0,0 +1,1388 @@
+extern crate chrono;
+
+use crate::{
+    audit::{AuditAction, AuditEntry},
+    auth::{AccessDenied, Permission, Role},
+    books::{
+        book::Book,
+        book_sys::BookSystem,
+        date::Date,
+        error::{BookError, GenreError, LoanError},
+        genres::Genres,
+        ResultSelf,
+    },
+    calendar::{CalendarError, OpeningCalendar},
+    data::error::LoadError,
+    journal::{JournalEntry, JournalEvent},
+    policy::{LoanItem, LoanPolicy, PolicyError, Refusal},
+    reading::{error::ReaderError, read_base::ReaderBase},
+    restore::{
+        caretaker::Caretaker,
+        command::{Command, Operation, Snapshot, Touched},
+        diff::SnapshotDiff,
+        error::RestoreError,
+    },
+    storage::{memory::MemoryStorage, SnapshotRecord, Storage},
+};
+
+use std::{
+    collections::HashSet,
+    fmt::{Debug, Formatter},
+    io,
+    rc::{Rc, Weak},
+};
+
+use chrono::{Local, NaiveDate};
+
+/// Library with all readers, books and genres.
+/// Every operation remembers changed records (so it can be undone)
+/// and saves changed data to storage.
+/// It doesn't need GUI, so it can be used from any client
+
+pub struct Library {
+    reader_base: ReaderBase,
+    book_system: BookSystem,
+    genres: Genres,
+    caretaker: Caretaker,
+    calendar: OpeningCalendar,
+    policy: LoanPolicy,
+    storage: Box<dyn Storage>,
+    user: String,
+    role: Role,
+}
+
+impl Debug for Library {
+    /// Print for Library.
+    /// It is used for debug code
+
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Library")
+            .field("reader_base", &self.reader_base)
+            .field("book_system", &self.book_system)
+            .field("genres", &self.genres)
+            .finish()
+    }
+}
+
+impl Library {
+    /// Creates empty library,
+    /// which saves data to chosen storage
+
+    #[inline]
+    pub fn new(storage: Box<dyn Storage>) -> Self {
+        Library {
+            reader_base: ReaderBase::new(),
+            book_system: BookSystem::new(),
+            genres: Genres::new(),
+            caretaker: Caretaker::new(),
+            calendar: OpeningCalendar::new(),
+            policy: LoanPolicy::new(),
+            storage,
+            user: String::new(),
+            role: Role::Administrator,
+        }
+    }
+
+    /// Sets how many commands are kept in history.
+    /// If it's 0, nothing can be undone
+
+    #[inline]
+    pub fn with_history_depth(mut self, depth: usize) -> Self {
+        self.caretaker = Caretaker::with_depth(depth);
+        self
+    }
+
+    /// Sets opening calendar.
+    /// Due dates are moved to its open days.
+    /// By default library is open every day
+
+    #[inline]
+    pub fn with_calendar(mut self, calendar: OpeningCalendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// Opening calendar of library
+
+    #[inline]
+    pub fn calendar(&self) -> &OpeningCalendar {
+        &self.calendar
+    }
+
+    /// Replaces opening calendar and saves it.
+    /// Only administrator can change calendar.
+    /// Due dates of given books aren't changed,
+    /// but they are checked with new calendar
+
+    #[inline]
+    pub fn set_calendar(&mut self, calendar: OpeningCalendar) -> Result<(), CalendarError> {
+        if !self.allows(Permission::Administer) {
+            return Err(AccessDenied.into());
+        }
+
+        calendar.save()?;
+        self.calendar = calendar;
+        Ok(())
+    }
+
+    /// Sets loan policy.
+    /// Due dates of given books are proposed by it.
+    /// By default every book is given for 14 days
+
+    #[inline]
+    pub fn with_policy(mut self, policy: LoanPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Loan policy of library
+
+    #[inline]
+    pub fn policy(&self) -> &LoanPolicy {
+        &self.policy
+    }
+
+    /// Replaces loan policy and saves it.
+    /// Only administrator can change policy.
+    /// Due dates of given books aren't changed
+
+    #[inline]
+    pub fn set_policy(&mut self, policy: LoanPolicy) -> Result<(), PolicyError> {
+        if !self.allows(Permission::Administer) {
+            return Err(AccessDenied.into());
+        }
+
+        policy.save()?;
+        self.policy = policy;
+        Ok(())
+    }
+
+    /// Sets login of user, who makes changes.
+    /// It's written to audit log with every change
+
+    #[inline]
+    pub fn set_user(&mut self, user: &str) {
+        self.user = user.to_string();
+    }
+
+    /// Login of user, who makes changes
+
+    #[inline]
+    pub fn user(&self) -> &str {
+        self.user.as_str()
+    }
+
+    /// Sets role of user, who makes changes.
+    /// Operations which role doesn't allow are rejected.
+    /// By default it's administrator
+
+    #[inline]
+    pub fn set_role(&mut self, role: Role) {
+        self.role = role;
+    }
+
+    /// Role of user, who makes changes
+
+    #[inline]
+    pub fn role(&self) -> Role {
+        self.role
+    }
+
+    /// Checks if user's role has permission
+
+    #[inline]
+    pub fn allows(&self, permission: Permission) -> bool {
+        self.role.allows(permission)
+    }
+
+    /// All entries of audit log from oldest to newest
+
+    #[inline]
+    pub fn audit(&self) -> Result<Vec<AuditEntry>, LoadError> {
+        self.storage.read_audit()
+    }
+
+    /// All events of journal from oldest to newest.
+    /// If storage doesn't keep journal, there are no events
+
+    #[inline]
+    pub fn journal(&self) -> Result<Vec<JournalEntry>, LoadError> {
+        self.storage.read_journal()
+    }
+
+    /// Library as it was at the end of chosen day.
+    /// It's restored from journal, and its changes aren't saved.
+    /// Before the first event it's library journal started with.
+    /// If storage doesn't keep journal, it will return None
+
+    pub fn on_date(&self, date: Date) -> Result<Option<Library>, LoadError> {
+        let end = NaiveDate::from(date).and_hms_opt(23, 59, 59).unwrap();
+
+        match self.storage.state_at(end)? {
+            None => Ok(None),
+
+            Some(state) => {
+                let mut library = Library::new(Box::new(state))
+                    .with_calendar(self.calendar.clone())
+                    .with_policy(self.policy.clone());
+                library.load()?;
+                Ok(Some(library))
+            }
+        }
+    }
+
+    /// Appends entry to audit log.
+    /// Entry's time is time of action
+
+    #[inline]
+    fn log(&self, action: AuditAction, command: &Command) -> io::Result<()> {
+        self.storage.append_audit(&AuditEntry {
+            user: self.user.clone(),
+            action,
+            command: Command {
+                time: Local::now().naive_local(),
+                ..command.clone()
+            },
+        })
+    }
+
+    /// Loads readers, books, genres
+    /// and history of changes from storage.
+    /// If any record is incorrect,
+    /// it will return error and library will be empty
+
+    #[inline]
+    pub fn load(&mut self) -> Result<(), LoadError> {
+        self.clear();
+
+        let result = self.reader_base.load(&*self.storage).and_then(|_| {
+            self.book_system
+                .load(&mut self.reader_base, &*self.storage)
+                .and_then(|_| self.genres.load(&*self.storage))
+        });
+
+        match result {
+            Ok(_) => self.load_history(),
+            Err(_) => self.clear(),
+        }
+
+        result
+    }
+
+    /// Loads readers, books and genres from storage
+    /// and skips incorrect records.
+    /// Returns errors of all skipped records
+
+    #[inline]
+    pub fn load_skip_bad(&mut self) -> Result<Vec<LoadError>, LoadError> {
+        self.clear();
+
+        let mut skipped = self.reader_base.load_skip_bad(&*self.storage)?;
+
+        skipped.extend(
+            self.book_system
+                .load_skip_bad(&mut self.reader_base, &*self.storage)?,
+        );
+
+        skipped.extend(self.genres.load_skip_bad(&*self.storage)?);
+        self.load_history();
+        Ok(skipped)
+    }
+
+    /// Loads history of changes from storage.
+    /// History is used only if it ends in loaded state
+    /// (last done command must be done and next one must be undone),
+    /// otherwise data was changed outside of library
+    /// and history is forgotten. Unreadable history is forgotten too
+
+    fn load_history(&mut self) {
+        let (commands, done) = match self.storage.read_history() {
+            Ok(history) => history,
+            Err(_) => return,
+        };
+
+        let is_done = done <= commands.len()
+            && (done == 0
+                || commands[done - 1].is_done(&self.reader_base, &self.book_system, &self.genres))
+            && (done == commands.len()
+                || commands[done].inverse().is_done(
+                    &self.reader_base,
+                    &self.book_system,
+                    &self.genres,
+                ));
+
+        if is_done {
+            self.caretaker.restore(commands, done);
+        }
+    }
+
+    /// Removes all data from memory
+    /// (but not from storage)
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.book_system = BookSystem::new();
+        self.reader_base = ReaderBase::new();
+        self.genres = Genres::new();
+        self.caretaker = Caretaker::with_depth(self.caretaker.depth());
+    }
+
+    /// All readers
+
+    #[inline]
+    pub fn reader_base(&self) -> &ReaderBase {
+        &self.reader_base
+    }
+
+    /// All books
+
+    #[inline]
+    pub fn book_system(&self) -> &BookSystem {
+        &self.book_system
+    }
+
+    /// All genres
+
+    #[inline]
+    pub fn genres(&self) -> &Genres {
+        &self.genres
+    }
+
+    /// History of changes
+
+    #[inline]
+    pub fn caretaker(&self) -> &Caretaker {
+        &self.caretaker
+    }
+
+    /// Storage where data is saved
+
+    #[inline]
+    pub fn storage(&self) -> &dyn Storage {
+        &*self.storage
+    }
+
+    /// Searches reader by his params
+
+    #[inline]
+    pub fn find_reader(
+        &self,
+        name: &String,
+        family: &String,
+        father: &String,
+        birth: Date,
+    ) -> Option<usize> {
+        self.reader_base.find_reader(name, family, father, birth)
+    }
+
+    /// Searches The Book by its params
+
+    #[inline]
+    pub fn find_book(&self, title: &String, author: &String, pages: u16) -> Option<usize> {
+        self.book_system.find_book(title, author, pages)
+    }
+
+    /// Searches all The Books of author.
+    /// Returns indexes in ascending order
+
+    #[inline]
+    pub fn find_by_author(&self, author: &String) -> Vec<usize> {
+        self.book_system.find_by_author(author)
+    }
+
+    /// Searches all The Books with genre.
+    /// Returns indexes in ascending order
+
+    #[inline]
+    pub fn find_by_genre(&self, genre: &String) -> Vec<usize> {
+        self.book_system.find_by_genre(genre)
+    }
+
+    /// Saves readers, books, genres and history of changes
+
+    #[inline]
+    pub fn save(&self) -> io::Result<()> {
+        self.storage.begin_save()?;
+
+        self.storage.write_all(
+            &self.reader_base.records(),
+            &self.book_system.records(),
+            &self.genres.records(),
+        )?;
+
+        self.save_history()
+    }
+
+    /// Saves history of changes
+
+    #[inline]
+    fn save_history(&self) -> io::Result<()> {
+        self.storage
+            .write_history(self.caretaker.commands(), self.caretaker.done())
+    }
+
+    /// Saves changed parts of library:
+    /// readers, books and genres' list.
+    /// History is saved too, because it's always changed
+
+    #[inline]
+    fn save_parts(&self, (readers, books, genres): (bool, bool, bool)) -> io::Result<()> {
+        if readers || books || genres {
+            self.storage.begin_save()?;
+        }
+
+        if readers {
+            self.reader_base.save(&*self.storage)?;
+        }
+
+        if books {
+            self.book_system.save(&*self.storage)?;
+        }
+
+        if genres {
+            self.genres.save(&*self.storage)?;
+        }
+
+        self.save_history()
+    }
+
+    /// Saves changes made by commands.
+    /// If storage keeps journal, their events are appended to it,
+    /// otherwise changed parts are rewritten.
+    /// Changes stay in memory even if they can't be saved
+
+    fn save_changes(&self, commands: &[Command]) -> io::Result<()> {
+        let events = commands
+            .iter()
+            .flat_map(JournalEvent::from_command)
+            .collect::<Vec<_>>();
+
+        if self.storage.append_events(&events)? {
+            return self.save_history();
+        }
+
+        self.save_parts(
+            commands
+                .iter()
+                .map(Command::changed_parts)
+                .fold((false, false, false), |(r, b, g), parts| {
+                    (r || parts.0, b || parts.1, g || parts.2)
+                }),
+        )
+    }
+
+    /// Current readers, books and genres as they're stored
+
+    #[inline]
+    pub(crate) fn records(&self) -> MemoryStorage {
+        MemoryStorage::with_records(
+            self.reader_base.records(),
+            self.book_system.records(),
+            self.genres.records(),
+        )
+    }
+
+    /// Saves current readers, books and genres
+    /// as named snapshot
+
+    #[inline]
+    pub fn create_snapshot(&self, label: &str) -> io::Result<SnapshotRecord> {
+        let records = self.records();
+
+        self.storage.write_snapshot(
+            label,
+            &records.readers(),
+            &records.books(),
+            &records.genres(),
+        )
+    }
+
+    /// All named snapshots from newest to oldest
+
+    #[inline]
+    pub fn snapshots(&self) -> io::Result<Vec<SnapshotRecord>> {
+        self.storage.snapshots()
+    }
+
+    /// Replaces all data with data from another storage
+    /// (backup or snapshot).
+    /// Previous state can be restored with undo.
+    /// If user isn't administrator or any record is incorrect,
+    /// nothing is changed
+
+    pub fn restore_from(&mut self, storage: &dyn Storage) -> Result<(), RestoreError> {
+        if !self.allows(Operation::Replace.permission()) {
+            return Err(RestoreError::NoAccess);
+        }
+
+        let mut reader_base = ReaderBase::new();
+        let mut book_system = BookSystem::new();
+        let mut genres = Genres::new();
+
+        reader_base.load(storage)?;
+        book_system.load(&mut reader_base, storage)?;
+        genres.load(storage)?;
+
+        self.replace(reader_base, book_system, genres)
+    }
+
+    /// Restores readers, books and genres of named snapshot.
+    /// Previous state can be restored with undo
+
+    #[inline]
+    pub fn restore_snapshot(&mut self, snapshot: &SnapshotRecord) -> Result<(), RestoreError> {
+        let records = self.storage.read_snapshot(snapshot)?;
+        self.restore_from(&records)
+    }
+
+    /// Finds differences between named snapshot and current state
+
+    #[inline]
+    pub fn diff_snapshot(&self, snapshot: &SnapshotRecord) -> Result<SnapshotDiff, LoadError> {
+        SnapshotDiff::new(&self.storage.read_snapshot(snapshot)?, &self.records())
+    }
+
+    /// Runs operation as one step of history.
+    /// Touched records are remembered before and after operation.
+    /// If operation fails or isn't allowed for user's role,
+    /// it is forgotten, otherwise changed parts are saved.
+    /// If operation can't be written to audit log,
+    /// it is undone and error is returned.
+    /// If changed parts can't be saved, it will return error,
+    /// but operation stays done and can be undone
+
+    #[inline]
+    fn apply<T, E: From<AccessDenied> + From<io::Error>>(
+        &mut self,
+        operation: Operation,
+        mut touched: Touched,
+        op: impl FnOnce(&mut Self) -> Result<T, E>,
+    ) -> Result<T, E> {
+        if !self.allows(operation.permission()) {
+            return Err(AccessDenied.into());
+        }
+
+        let before = Snapshot::take(&touched, &self.reader_base, &self.book_system, &self.genres);
+        let reader_ids = self.reader_base.ids;
+        let book_ids = self.book_system.the_book_ids;
+
+        let result = op(self);
+
+        if result.is_ok() {
+            touched
+                .readers
+                .extend(self.reader_base.ids.given_since(reader_ids));
+
+            touched
+                .books
+                .extend(self.book_system.the_book_ids.given_since(book_ids));
+
+            let after =
+                Snapshot::take(&touched, &self.reader_base, &self.book_system, &self.genres);
+            let command = Command::new(operation, &before, &after);
+
+            if !command.is_empty() {
+                if let Err(err) = self.log(AuditAction::Done, &command) {
+                    command.inverse().apply(
+                        &mut self.reader_base,
+                        &mut self.book_system,
+                        &mut self.genres,
+                    );
+
+                    return Err(err.into());
+                }
+
+                self.caretaker.push(command.clone());
+                self.save_changes(&[command])?;
+            }
+        }
+
+        result
+    }
+
+    /// Reader with chosen index and The Books he has ever taken
+
+    #[inline]
+    fn reader_with_books(&self, ind: usize) -> Touched {
+        Touched::records(
+            self.reader_base.id(ind).into_iter().collect(),
+            self.reader_base.book_ids(ind),
+        )
+    }
+
+    /// Reader with chosen index
+
+    #[inline]
+    fn reader(&self, ind: usize) -> Touched {
+        Touched::records(self.reader_base.id(ind).into_iter().collect(), vec![])
+    }
+
+    /// The Book with chosen index and all its readers
+
+    #[inline]
+    fn book_with_readers(&self, ind: usize) -> Touched {
+        Touched::records(
+            self.book_system.reader_ids(ind),
+            self.book_system.id(ind).into_iter().collect(),
+        )
+    }
+
+    /// The Book with chosen index
+
+    #[inline]
+    fn book(&self, ind: usize) -> Touched {
+        Touched::records(vec![], self.book_system.id(ind).into_iter().collect())
+    }
+
+    /// Adds reader. If reader with same params exists,
+    /// it will return error
+
+    #[inline]
+    pub fn add_reader(
+        &mut self,
+        name: String,
+        family: String,
+        father: String,
+        info: String,
+        birth: Date,
+    ) -> ResultSelf<Self, ReaderError> {
+        self.apply(Operation::AddReader, Touched::default(), |lib| {
+            lib.reader_base
+                .add_reader(name, family, father, info, birth)
+                .map(|_| ())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Removes reader with all his history.
+    /// If reader isn't found, it will return error
+
+    #[inline]
+    pub fn remove_reader(&mut self, ind: usize) -> ResultSelf<Self, ReaderError> {
+        self.apply(
+            Operation::RemoveReader,
+            self.reader_with_books(ind),
+            |lib| lib.reader_base.remove_reader(ind).map(|_| ()),
+        )?;
+
+        Ok(self)
+    }
+
+    /// Changes reader's name
+
+    #[inline]
+    pub fn change_name(&mut self, ind: usize, new_name: String) -> ResultSelf<Self, ReaderError> {
+        self.apply(Operation::ChangeName, self.reader(ind), |lib| {
+            lib.reader_base.change_name(ind, new_name).map(|_| ())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Changes reader's 2-nd name
+
+    #[inline]
+    pub fn change_family(
+        &mut self,
+        ind: usize,
+        new_family: String,
+    ) -> ResultSelf<Self, ReaderError> {
+        self.apply(Operation::ChangeFamily, self.reader(ind), |lib| {
+            lib.reader_base.change_family(ind, new_family).map(|_| ())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Changes reader's mid. name
+
+    #[inline]
+    pub fn change_father(
+        &mut self,
+        ind: usize,
+        new_father: String,
+    ) -> ResultSelf<Self, ReaderError> {
+        self.apply(Operation::ChangeFather, self.reader(ind), |lib| {
+            lib.reader_base.change_father(ind, new_father).map(|_| ())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Changes reader's info
+
+    #[inline]
+    pub fn change_info(&mut self, ind: usize, new_info: String) -> ResultSelf<Self, ReaderError> {
+        self.apply(Operation::ChangeInfo, self.reader(ind), |lib| {
+            lib.reader_base.change_info(ind, new_info).map(|_| ())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Changes reader's category.
+    /// Loan rules of category are used for reader's next loans.
+    /// Empty category means that reader has no category
+
+    #[inline]
+    pub fn change_category(
+        &mut self,
+        ind: usize,
+        new_category: String,
+    ) -> ResultSelf<Self, ReaderError> {
+        self.apply(Operation::ChangeCategory, self.reader(ind), |lib| {
+            lib.reader_base
+                .change_category(ind, new_category)
+                .map(|_| ())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Changes reader's birth date
+
+    #[inline]
+    pub fn change_age(&mut self, ind: usize, new_birth: Date) -> ResultSelf<Self, ReaderError> {
+        self.apply(Operation::ChangeAge, self.reader(ind), |lib| {
+            lib.reader_base.change_age(ind, new_birth).map(|_| ())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Adds new The Book with simple books
+    /// in chosen locations (cabinet and shelf).
+    /// If book with same params exists,
+    /// it will return error
+
+    #[inline]
+    pub fn add_book(
+        &mut self,
+        title: String,
+        author: String,
+        pages: u16,
+        locations: &[(u16, u8)],
+    ) -> ResultSelf<Self, BookError> {
+        self.apply(Operation::AddBook, Touched::default(), |lib| {
+            lib.book_system
+                .add_book(title, author, pages, locations)
+                .map(|_| ())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Adds simple books in chosen locations
+    /// (cabinet and shelf) to existing The Book
+
+    #[inline]
+    pub fn add_books(
+        &mut self,
+        ind: usize,
+        locations: &[(u16, u8)],
+    ) -> ResultSelf<Self, BookError> {
+        self.apply(Operation::AddBooks, self.book(ind), |lib| {
+            lib.book_system.add_books(ind, locations).map(|_| ())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Removes The Book with all simple books.
+    /// If book isn't found, it will return error
+
+    #[inline]
+    pub fn remove_book(&mut self, ind: usize) -> ResultSelf<Self, BookError> {
+        self.apply(Operation::RemoveBook, self.book_with_readers(ind), |lib| {
+            lib.book_system.remove_book(ind).map(|_| ())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Removes one simple book.
+    /// If it was the last one, The Book is removed too
+
+    #[inline]
+    pub fn remove_one_book(&mut self, ind: usize, s_ind: usize) -> ResultSelf<Self, BookError> {
+        self.apply(
+            Operation::RemoveOneBook,
+            self.book_with_readers(ind),
+            |lib| lib.book_system.remove_one_book(ind, s_ind).map(|_| ()),
+        )?;
+
+        Ok(self)
+    }
+
+    /// Changes title of The Book.
+
+    #[inline]
+    pub fn change_title(&mut self, ind: usize, new_title: String) -> ResultSelf<Self, BookError> {
+        self.apply(Operation::ChangeTitle, self.book_with_readers(ind), |lib| {
+            lib.book_system.change_title(ind, new_title).map(|_| ())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Changes author of The Book.
+
+    #[inline]
+    pub fn change_author(&mut self, ind: usize, new_author: String) -> ResultSelf<Self, BookError> {
+        self.apply(
+            Operation::ChangeAuthor,
+            self.book_with_readers(ind),
+            |lib| lib.book_system.change_author(ind, new_author).map(|_| ()),
+        )?;
+
+        Ok(self)
+    }
+
+    /// Changes amount of pages of The Book
+
+    #[inline]
+    pub fn change_pages(&mut self, ind: usize, new_pages: u16) -> ResultSelf<Self, BookError> {
+        self.apply(Operation::ChangePages, self.book_with_readers(ind), |lib| {
+            lib.book_system.change_pages(ind, new_pages).map(|_| ())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Changes location of simple book
+
+    #[inline]
+    pub fn change_location(
+        &mut self,
+        ind: usize,
+        s_ind: usize,
+        new_cabinet: u16,
+        new_shelf: u8,
+    ) -> ResultSelf<Self, BookError> {
+        self.apply(Operation::ChangeLocation, self.book(ind), |lib| {
+            lib.book_system
+                .change_location(ind, s_ind, new_cabinet, new_shelf)
+                .map(|_| ())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Changes type of simple book (e.g. "Reference").
+    /// Empty type means ordinary copy
+
+    #[inline]
+    pub fn change_copy_type(
+        &mut self,
+        ind: usize,
+        s_ind: usize,
+        new_copy_type: String,
+    ) -> ResultSelf<Self, BookError> {
+        self.apply(Operation::ChangeCopyType, self.book(ind), |lib| {
+            lib.book_system
+                .change_copy_type(ind, s_ind, new_copy_type)
+                .map(|_| ())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Loan period of The Book for reader.
+    /// It's chosen by reader's category and book's genres
+
+    #[inline]
+    pub fn loan_period(&self, rind: usize, ind: usize) -> Result<u16, LoanError> {
+        let reader = self
+            .reader_base
+            .readers
+            .get(rind)
+            .ok_or(ReaderError::NotFound)?;
+        let the_book = self.book_system.books.get(ind).ok_or(BookError::NotFound)?;
+
+        let genres = (**the_book)
+            .borrow()
+            .genres
+            .as_ref()
+            .map(|genres| genres.iter().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        Ok(self.policy.period(&(**reader).borrow().category, &genres))
+    }
+
+    /// Due date which is proposed if The Book
+    /// is given to reader today.
+    /// It's today plus loan period
+    /// moved to the next open day
+
+    #[inline]
+    pub fn propose_due_date(&self, rind: usize, ind: usize) -> Result<Date, LoanError> {
+        let period = self.loan_period(rind, ind)?;
+
+        let finish = Date::today()
+            .add_days(period as i64)
+            .ok_or(LoanError::WrongDate)?;
+
+        self.calendar
+            .next_open_day(finish)
+            .ok_or(LoanError::WrongDate)
+    }
+
+    /// Reasons why loan policy doesn't allow
+    /// to give The Book to reader now.
+    /// If some free simple book can be given,
+    /// it will return empty vector
+
+    #[inline]
+    pub fn loan_refusals(&self, rind: usize, ind: usize) -> Result<Vec<Refusal>, LoanError> {
+        self.free_copy(rind, ind).map(|(_, refusals)| refusals)
+    }
+
+    /// Gives free simple book of The Book to reader
+    /// until chosen date. If library is closed on that date,
+    /// book is given until the next open day.
+    /// Book isn't given if loan policy refuses it.
+    /// Returns index of given simple book
+
+    #[inline]
+    pub fn give_book(&mut self, rind: usize, ind: usize, finish: Date) -> Result<usize, LoanError> {
+        self.give(Operation::GiveBook, rind, ind, finish)
+    }
+
+    /// Gives free simple book of The Book to reader
+    /// even if loan policy refuses it.
+    /// Only users who can override limits are allowed to do it.
+    /// Returns index of given simple book
+
+    #[inline]
+    pub fn give_book_over_limits(
+        &mut self,
+        rind: usize,
+        ind: usize,
+        finish: Date,
+    ) -> Result<usize, LoanError> {
+        self.give(Operation::GiveBookOverLimits, rind, ind, finish)
+    }
+
+    /// Gives simple book which is chosen by loan policy.
+    /// Limits are checked only for ordinary giving
+
+    fn give(
+        &mut self,
+        operation: Operation,
+        rind: usize,
+        ind: usize,
+        finish: Date,
+    ) -> Result<usize, LoanError> {
+        let touched = Touched::records(
+            self.reader_base.id(rind).into_iter().collect(),
+            self.book_system.id(ind).into_iter().collect(),
+        );
+
+        self.apply(operation, touched, |lib| {
+            if rind >= lib.reader_base.len() {
+                return Err(ReaderError::NotFound.into());
+            }
+
+            if ind >= lib.book_system.len() {
+                return Err(BookError::NotFound.into());
+            }
+
+            if finish < Date::today() {
+                return Err(LoanError::WrongDate);
+            }
+
+            let finish = lib
+                .calendar
+                .next_open_day(finish)
+                .ok_or(LoanError::WrongDate)?;
+
+            let (s_ind, refusals) = lib.free_copy(rind, ind)?;
+
+            if let (Operation::GiveBook, Some(&refusal)) = (operation, refusals.first()) {
+                return Err(LoanError::Refused(refusal));
+            }
+
+            let the_book = unsafe { lib.book_system.books.get_unchecked(ind) }.clone();
+            let reader = unsafe { lib.reader_base.readers.get_unchecked(rind) }.clone();
+            let book = unsafe { (*the_book).borrow().books.get_unchecked(s_ind) }.clone();
+
+            (*reader).borrow_mut().start_reading(&book, &finish)?;
+
+            (*book).borrow_mut().start_reading(&reader, finish);
+            Ok(s_ind)
+        })
+    }
+
+    /// Free simple book of The Book which is given to reader
+    /// and reasons why loan policy refuses it.
+    /// Book which policy allows is preferred,
+    /// otherwise the first free one is chosen
+
+    fn free_copy(&self, rind: usize, ind: usize) -> Result<(usize, Vec<Refusal>), LoanError> {
+        let reader = self
+            .reader_base
+            .readers
+            .get(rind)
+            .ok_or(ReaderError::NotFound)?;
+        let the_book = self.book_system.books.get(ind).ok_or(BookError::NotFound)?;
+        let today = Date::today();
+
+        let reader = (**reader).borrow();
+
+        let reading = reader
+            .reading
+            .iter()
+            .flatten()
+            .filter_map(Weak::upgrade)
+            .map(|book| self.loan_item(&(*book).borrow(), today))
+            .collect::<Vec<_>>();
+
+        let mut refused = None;
+
+        for (s_ind, book) in (**the_book).borrow().books.iter().enumerate() {
+            let book = (**book).borrow();
+
+            if book.is_using {
+                continue;
+            }
+
+            let refusals =
+                self.policy
+                    .refusals(&reader.category, &self.loan_item(&book, today), &reading);
+
+            if refusals.is_empty() {
+                return Ok((s_ind, refusals));
+            }
+
+            refused.get_or_insert((s_ind, refusals));
+        }
+
+        refused.ok_or_else(|| BookError::NoFreeBooks.into())
+    }
+
+    /// Simple book as it's seen by loan policy
+
+    #[inline]
+    fn loan_item(&self, book: &Book, today: Date) -> LoanItem {
+        let genres = book
+            .the_book
+            .as_ref()
+            .and_then(Weak::upgrade)
+            .and_then(|the_book| (*the_book).borrow().genres.clone())
+            .map(|genres| genres.into_iter().collect())
+            .unwrap_or_default();
+
+        let overdue = book.is_using
+            && book.readers.last().map_or(false, |(_, (_, finish))| {
+                self.calendar.is_overdue(*finish, today)
+            });
+
+        LoanItem::new(genres, &book.copy_type, overdue)
+    }
+
+    /// Returns book of The Book which is read by reader.
+    /// Returns true if reader has returned it after deadline
+
+    #[inline]
+    pub fn return_book(&mut self, rind: usize, ind: usize) -> Result<bool, LoanError> {
+        let touched = Touched::records(
+            self.reader_base.id(rind).into_iter().collect(),
+            self.book_system.id(ind).into_iter().collect(),
+        );
+
+        self.apply(Operation::ReturnBook, touched, |lib| {
+            if rind >= lib.reader_base.len() {
+                return Err(ReaderError::NotFound.into());
+            }
+
+            if ind >= lib.book_system.len() {
+                return Err(BookError::NotFound.into());
+            }
+
+            let the_book = unsafe { lib.book_system.books.get_unchecked(ind) }.clone();
+            let reader = unsafe { lib.reader_base.readers.get_unchecked(rind) }.clone();
+            let s_ind = (*the_book)
+                .borrow()
+                .find_by_reader(&reader)
+                .ok_or(ReaderError::NotReading)?;
+            let book = unsafe { (*the_book).borrow().books.get_unchecked(s_ind) }.clone();
+
+            (*reader).borrow_mut().finish_reading(&Rc::downgrade(&book));
+            let is_late = (*book).borrow_mut().finish_reading(&lib.calendar).is_err();
+            Ok(is_late)
+        })
+    }
+
+    /// Changes return date of used simple book.
+    /// If library is closed on that date,
+    /// it's moved to the next open day
+
+    #[inline]
+    pub fn change_return_date(
+        &mut self,
+        ind: usize,
+        s_ind: usize,
+        new_date: Date,
+    ) -> ResultSelf<Self, LoanError> {
+        self.apply(
+            Operation::ChangeReturnDate,
+            self.book_with_readers(ind),
+            |lib| {
+                let book = lib.book_system.books.get(ind).ok_or(BookError::NotFound)?;
+
+                let book = (**book)
+                    .borrow()
+                    .books
+                    .get(s_ind)
+                    .cloned()
+                    .ok_or(BookError::SimpleBookNotFound)?;
+
+                if !(*book).borrow().is_using {
+                    return Err(BookError::NotUsed.into());
+                }
+
+                let (reader, start) = {
+                    let book = (*book).borrow();
+                    let last = book.readers.last().unwrap();
+                    (last.0.upgrade().unwrap(), (last.1).0)
+                };
+
+                if new_date < start || new_date < Date::today() {
+                    return Err(LoanError::WrongDate);
+                }
+
+                let new_date = lib
+                    .calendar
+                    .next_open_day(new_date)
+                    .ok_or(LoanError::WrongDate)?;
+
+                ((*book).borrow_mut().readers.last_mut().unwrap().1).1 = new_date;
+
+                if let Some(reading) = &mut (*reader).borrow_mut().reading {
+                    reading.sort_by_key(|b| {
+                        ((*b.upgrade().unwrap()).borrow().readers.last().unwrap().1).1
+                    });
+                }
+
+                Ok(())
+            },
+        )?;
+
+        Ok(self)
+    }
+
+    /// Adds new genre.
+    /// If genre already exists, it will return error
+
+    #[inline]
+    pub fn add_genre(&mut self, genre: String) -> ResultSelf<Self, GenreError> {
+        self.apply(Operation::AddGenre, Touched::genres(), |lib| {
+            match lib.genres.add(genre) {
+                true => Ok(()),
+                false => Err(GenreError::AlreadyExists),
+            }
+        })?;
+
+        Ok(self)
+    }
+
+    /// Removes genre from genres and from all books.
+    /// If genre isn't found, it will return error
+
+    #[inline]
+    pub fn remove_genre(&mut self, genre: &String) -> ResultSelf<Self, GenreError> {
+        let mut touched = Touched::genres();
+
+        touched.books = self
+            .book_system
+            .find_by_genre(&genre.to_lowercase())
+            .into_iter()
+            .filter_map(|ind| self.book_system.id(ind))
+            .collect();
+
+        self.apply(Operation::RemoveGenre, touched, |lib| {
+            if !lib.genres.remove(genre) {
+                return Err(GenreError::NotFound);
+            }
+
+            lib.book_system.remove_genre(&genre.to_lowercase());
+            Ok(())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Replaces genres of The Book.
+    /// All genres must exist
+
+    #[inline]
+    pub fn set_book_genres(
+        &mut self,
+        ind: usize,
+        new_genres: HashSet<String>,
+    ) -> ResultSelf<Self, BookError> {
+        self.apply(Operation::SetBookGenres, self.book(ind), |lib| {
+            if ind >= lib.book_system.len() {
+                return Err(BookError::NotFound);
+            }
+
+            if new_genres.iter().any(|g| !lib.genres.genres.contains(g)) {
+                return Err(BookError::UnknownGenre);
+            }
+
+            lib.book_system.set_genres(
+                ind,
+                match new_genres.is_empty() {
+                    true => None,
+                    false => Some(new_genres),
+                },
+            )?;
+
+            Ok(())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Replaces all data with chosen one,
+    /// previous state can be restored with undo.
+    /// Only administrator can replace data
+
+    #[inline]
+    pub fn replace(
+        &mut self,
+        reader_base: ReaderBase,
+        book_system: BookSystem,
+        genres: Genres,
+    ) -> Result<(), RestoreError> {
+        self.apply(Operation::Replace, Touched::all(), |lib| {
+            lib.reader_base = reader_base;
+            lib.book_system = book_system;
+            lib.genres = genres;
+            Ok(())
+        })
+    }
+
+    /// Undoes or does again one command.
+    /// Action is written to audit log
+    /// (undone command is inverted, so it shows real change).
+    /// Returns real change.
+    /// Only administrator can move through history.
+    /// If action can't be written to audit log,
+    /// it's cancelled and error is returned
+
+    fn step(&mut self, undo: bool) -> io::Result<Option<Command>> {
+        if !self.allows(Permission::Administer) {
+            return Ok(None);
+        }
+
+        let (action, command) = if undo {
+            let command = match self.caretaker.undo(
+                &mut self.reader_base,
+                &mut self.book_system,
+                &mut self.genres,
+            ) {
+                Some(command) => command.inverse(),
+                None => return Ok(None),
+            };
+
+            (AuditAction::Undone, command)
+        } else {
+            let command = match self.caretaker.redo(
+                &mut self.reader_base,
+                &mut self.book_system,
+                &mut self.genres,
+            ) {
+                Some(command) => command.clone(),
+                None => return Ok(None),
+            };
+
+            (AuditAction::Redone, command)
+        };
+
+        if let Err(err) = self.log(action, &command) {
+            let (reader_base, book_system, genres) = (
+                &mut self.reader_base,
+                &mut self.book_system,
+                &mut self.genres,
+            );
+
+            match undo {
+                true => self.caretaker.redo(reader_base, book_system, genres),
+                false => self.caretaker.undo(reader_base, book_system, genres),
+            };
+
+            return Err(err);
+        }
+
+        Ok(Some(command))
+    }
+
+    /// Returns to previous state.
+    /// If it's the first version or user isn't administrator,
+    /// it will return None,
+    /// else it will return undone command.
+    /// If change can't be saved, it will return error
+
+    #[inline]
+    pub fn undo(&mut self) -> io::Result<Option<&Command>> {
+        let command = match self.step(true)? {
+            Some(command) => command,
+            None => return Ok(None),
+        };
+
+        self.save_changes(&[command])?;
+        Ok(self.caretaker.commands().get(self.caretaker.done()))
+    }
+
+    /// Returns to next state.
+    /// If it's the last version or user isn't administrator,
+    /// it will return None,
+    /// else it will return done command.
+    /// If change can't be saved, it will return error
+
+    #[inline]
+    pub fn redo(&mut self) -> io::Result<Option<&Command>> {
+        let command = match self.step(false)? {
+            Some(command) => command,
+            None => return Ok(None),
+        };
+
+        self.save_changes(&[command])?;
+        Ok(self.caretaker.commands().get(self.caretaker.done() - 1))
+    }
+
+    /// Jumps to state after chosen amount of commands
+    /// (0 is the first version). Commands are undone
+    /// or done again one by one, so redo is preserved.
+    /// If there is no such state, it's current one
+    /// or user isn't administrator, it will return false.
+    /// If changes can't be saved, it will return error
+
+    pub fn jump_to(&mut self, done: usize) -> io::Result<bool> {
+        if done > self.caretaker.len()
+            || done == self.caretaker.done()
+            || !self.allows(Permission::Administer)
+        {
+            return Ok(false);
+        }
+
+        let mut changes = vec![];
+
+        while self.caretaker.done() != done {
+            match self.step(self.caretaker.done() > done) {
+                Ok(command) => changes.extend(command),
+
+                Err(err) => {
+                    self.save_changes(&changes)?;
+                    return Err(err);
+                }
+            }
+        }
+
+        self.save_changes(&changes)?;
+        Ok(true)
+    }
+}
@@ -0,0 +1,217 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{self, File},
+    io,
+    path::Path,
+};
+
+/// Code of language which is used
+/// when message isn't translated
+
+pub const DEFAULT_CODE: &str = "en";
+
+/// Extension of catalogue files
+
+pub const EXTENSION: &str = "ftl";
+
+/// Message with language's name in itself
+/// (e.g. "Deutsch" for german)
+
+pub const NAME_ID: &str = "language-name";
+
+/// Message with chrono's format of dates
+/// (e.g. "%d.%m.%Y")
+
+pub const DATE_FORMAT_ID: &str = "date-format";
+
+/// Catalogues which are built in program.
+/// Catalogues from data directory
+/// can override or extend them
+
+const BUILT_IN: [(&str, &str); 2] = [
+    ("en", include_str!("../../locales/en.ftl")),
+    ("ru", include_str!("../../locales/ru.ftl")),
+];
+
+/// Message catalogue of one language.
+/// Every line of catalogue file is either
+/// `id = message`, comment that starts with `#` or empty line.
+/// Messages may contain `\n`, `\t` and `\\`,
+/// arguments are written as `{ $name }`.
+/// Message with count has plural forms `id.one`, `id.few` and `id.many`,
+/// `id` itself is used for other counts
+
+#[derive(Debug, Clone, Default)]
+pub struct Catalogue {
+    code: String,
+    messages: HashMap<String, String>,
+}
+
+impl Catalogue {
+    /// Parses catalogue of language with given code.
+    /// Lines without `=` are ignored
+
+    pub fn parse(code: &str, text: &str) -> Self {
+        let messages = text
+            .lines()
+            .map(str::trim_start)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_once('='))
+            .map(|(id, message)| {
+                (
+                    id.trim().to_string(),
+                    unescape(message.strip_prefix(' ').unwrap_or(message)),
+                )
+            })
+            .collect();
+
+        Catalogue {
+            code: code.to_string(),
+            messages,
+        }
+    }
+
+    /// Reads catalogue from file.
+    /// Language's code is a name of file without extension
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let code = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "bad catalogue name"))?;
+
+        Ok(Catalogue::parse(code, &fs::read_to_string(path)?))
+    }
+
+    /// Code of language (e.g. "en", "ru", "kk", "de")
+
+    #[inline]
+    pub fn code(&self) -> &str {
+        self.code.as_str()
+    }
+
+    /// Message with given id if it's translated
+
+    #[inline]
+    pub fn get(&self, id: &str) -> Option<&str> {
+        self.messages.get(id).map(String::as_str)
+    }
+
+    /// Form of message with given id for chosen count.
+    /// If there is no such form, it's message itself
+
+    #[inline]
+    pub fn plural(&self, id: &str, count: usize) -> Option<&str> {
+        self.get(&format!("{}.{}", id, plural_form(self.code(), count)))
+            .or_else(|| self.get(id))
+    }
+
+    /// Adds messages from other catalogue.
+    /// Messages with the same id are replaced
+
+    #[inline]
+    pub fn extend(&mut self, other: Catalogue) {
+        self.messages.extend(other.messages)
+    }
+}
+
+/// Catalogues which are built in program
+
+#[inline]
+pub fn built_in() -> Vec<Catalogue> {
+    BUILT_IN
+        .iter()
+        .map(|(code, text)| Catalogue::parse(code, text))
+        .collect()
+}
+
+/// Built in catalogues and catalogues from directory.
+/// Catalogue of built in language from directory
+/// overrides only messages which it contains.
+/// Unreadable files are skipped
+
+pub fn load_all(dir: &Path) -> Vec<Catalogue> {
+    let mut catalogues = built_in();
+
+    let mut files = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().map_or(false, |ext| ext == EXTENSION))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    files.sort();
+
+    files
+        .iter()
+        .filter_map(|path| Catalogue::load(path).ok())
+        .for_each(
+            |catalogue| match catalogues.iter_mut().find(|c| c.code == catalogue.code) {
+                Some(existing) => existing.extend(catalogue),
+                None => catalogues.push(catalogue),
+            },
+        );
+
+    catalogues
+}
+
+/// Substitutes arguments `{ $name }` in message
+
+pub fn substitute(message: &str, args: &[(&str, &dyn Display)]) -> String {
+    args.iter()
+        .fold(message.to_string(), |text, (name, value)| {
+            text.replace(&format!("{{ ${} }}", name), &value.to_string())
+        })
+}
+
+/// Plural form of count in language with given code:
+/// "one", "few" or "many" for east slavic languages,
+/// "one" or "other" for the rest (as in English)
+
+pub fn plural_form(code: &str, count: usize) -> &'static str {
+    match code {
+        "ru" | "uk" | "be" => match (count % 10, count % 100) {
+            (1, rem) if rem != 11 => "one",
+            (2..=4, rem) if !(12..=14).contains(&rem) => "few",
+            _ => "many",
+        },
+
+        _ => match count {
+            1 => "one",
+            _ => "other",
+        },
+    }
+}
+
+/// Writes language's code to file
+
+#[inline]
+pub(crate) fn save_code(path: &Path, code: &str) -> io::Result<()> {
+    io::Write::write_all(&mut File::create(path)?, code.as_bytes())
+}
+
+/// Replaces `\n`, `\t` and `\\` with characters
+
+fn unescape(message: &str) -> String {
+    let mut text = String::with_capacity(message.len());
+    let mut chars = message.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n') => text.push('\n'),
+                Some('t') => text.push('\t'),
+                Some(other) => text.push(other),
+                None => text.push('\\'),
+            },
+
+            _ => text.push(c),
+        }
+    }
+
+    text
+}
@@ -7,6 +7,7 @@ use booklibrs::{
             add_rem::full::*,
             info::{full::*, simple::book_info_simple},
         },
+        fit_menu,
         genres::full::*,
         giveaway::{full::*, simple::change_return_date_first_book},
         help,
@@ -17,11 +18,26 @@ use booklibrs::{
         },
         tables::{cell_reader, draw_data, draw_header},
     },
-    books::{book_sys::BookSystem, genres::Genres},
+    audit::viewer::audit,
+    auth::{
+        idle_timeout_from_env,
+        login::{authorize, change_credentials},
+        manager::users,
+        normalize, Role, Users,
+    },
+    calendar::{editor::opening_calendar, OpeningCalendar},
     change::{input2::Input2, Inputable},
-    reading::read_base::ReaderBase,
-    restore::caretaker::Caretaker,
-    Lang, Message,
+    data::DataDir,
+    journal::viewer::time_travel,
+    library::Library,
+    policy::{editor::loan_policy, LoanPolicy},
+    restore::{
+        backup::restore_backup,
+        caretaker::Caretaker,
+        history::history,
+        snapshot::{create_snapshot, restore_snapshot},
+    },
+    storage, Lang, Message,
 };
 
 use fltk::{
@@ -30,7 +46,7 @@ use fltk::{
     dialog::alert,
     draw,
     enums::Shortcut,
-    enums::{Color, Font},
+    enums::{Color, Event, Font},
     frame::Frame,
     image::*,
     input::{Input, SecretInput},
@@ -41,74 +57,97 @@ use fltk::{
     window::*,
 };
 
-use booklibrs::actions::tables::cell_date_time;
+use booklibrs::actions::tables::{cell_date_time, date_time_header};
 
 use std::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
     cmp::max,
     error::Error,
-    fs::File,
-    io::{Read, Write},
     rc::Rc,
     thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
-/// Hashing login and password
+fn main() -> Result<(), Box<dyn Error>> {
+    let data_dir = DataDir::from_env();
+    data_dir.create()?;
 
-#[inline]
-fn get_hash(str: &String, p: u128, module: u128) -> Vec<u128> {
-    let mut ans = vec![0; str.len()];
-    let bytes = str.as_bytes();
+    let library = Rc::new(RefCell::new(
+        Library::new(storage::from_env(&data_dir)?)
+            .with_history_depth(Caretaker::depth_from_env())
+            .with_calendar(OpeningCalendar::load(&data_dir)?)
+            .with_policy(LoanPolicy::load(&data_dir)?),
+    ));
 
-    unsafe {
-        *ans.get_unchecked_mut(0) = *bytes.get_unchecked(0) as u128;
+    let languages = Lang::all(&data_dir);
+    let lang = Lang::new(&data_dir, &languages);
 
-        (1..str.len()).for_each(|i| {
-            *ans.get_unchecked_mut(i) = ((ans.get_unchecked(i - 1).overflowing_mul(p))
-                .0
-                .overflowing_add(*bytes.get_unchecked(i) as u128)
-                .0)
-                % module;
-        });
-    }
+    let app = app::App::default().with_scheme(app::AppScheme::Plastic);
+    let (s, r) = app::channel();
 
-    ans
-}
+    let mut skip_bad = false;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let reader_base = Rc::new(RefCell::new(ReaderBase::new()));
-    let book_system = Rc::new(RefCell::new(BookSystem::new()));
-    let genres = Rc::new(RefCell::new(Genres::new()));
-    let lang = Lang::new();
+    loop {
+        let loaded = match skip_bad {
+            true => (*library).borrow_mut().load_skip_bad(),
+            false => (*library).borrow_mut().load().map(|_| vec![]),
+        };
 
-    let app = app::App::default().with_scheme(app::AppScheme::Plastic);
-    let (s, r) = app::channel();
+        match loaded {
+            Ok(skipped) => {
+                if skip_bad {
+                    fltk::dialog::message(
+                        500,
+                        500,
+                        format!("{}: {}", lang.tr("skipped-records"), skipped.len()).as_str(),
+                    );
+                } else {
+                    data_dir.keep_backups()?;
+                }
 
-    (*reader_base).borrow_mut().load();
+                break;
+            }
 
-    (*book_system)
-        .borrow_mut()
-        .load(&mut (*reader_base).borrow_mut());
+            Err(err) => match fltk::dialog::choice2(
+                500,
+                500,
+                format!("{}\n{}", lang.tr("data-cant-be-loaded"), err).as_str(),
+                lang.tr("start-empty"),
+                lang.tr("restore-backup"),
+                lang.tr("skip-bad-records"),
+            ) {
+                Some(0) => {
+                    (*library).borrow_mut().clear();
+                    break;
+                }
 
-    (*genres).borrow_mut().load();
+                Some(1) => {
+                    if !data_dir.restore_backup(&err.file)? {
+                        alert(500, 500, lang.tr("backup-isnt-found"));
+                    }
+                }
 
-    let caretaker = Rc::new(RefCell::new(Caretaker::new()));
+                Some(_) => skip_bad = true,
+                None => return Err(Box::new(err)),
+            },
+        }
+    }
 
-    let mut admin = File::open("src/utils/admin.bin")?;
-    let mut adm = String::new();
-    admin.read_to_string(&mut adm)?;
+    let mut staff = Users::load(&data_dir)?;
 
     #[allow(unused_assignments)]
     let mut success = 0; // 0 - no input / 1 - ok / 2 - mistake
 
-    if adm.is_empty() {
+    if staff.is_empty() {
         let (s, r): (Sender<bool>, _) = app::channel();
 
         loop {
             success = 0;
-            let mut password =
-                Input2::<Input, SecretInput>::new("New User", "New Login", "New Password");
+            let mut password = Input2::<Input, SecretInput>::new(
+                lang.tr("new-user"),
+                lang.tr("new-login"),
+                lang.tr("new-password"),
+            );
             password.show();
 
             (*password.ok).borrow_mut().emit(s, true);
@@ -119,37 +158,27 @@ fn main() -> Result<(), Box<dyn Error>> {
                         password.hide();
 
                         if let Ok(data) = password.set_input(lang) {
-                            if !data.first().unwrap().is_ascii() || !data.last().unwrap().is_ascii()
-                            {
-                                alert(
-                                    500,
-                                    500,
-                                    "Incorrect Password. You must use only English letters. Try again",
-                                );
-
+                            if let Err(err) = staff.add(
+                                data.first().unwrap(),
+                                data.last().unwrap(),
+                                Role::Administrator,
+                            ) {
+                                alert(500, 500, err.message(lang));
                                 success = 2;
-                            } else {
-                                let hash1 = get_hash(&data.first().unwrap(), 97, 1e9 as u128 + 7);
-                                let hash2 = get_hash(&data.last().unwrap(), 101, 1e9 as u128 + 7);
-
-                                File::create("src/utils/admin.bin").unwrap().write(
-                                    format!(
-                                        "{}",
-                                        hash1.iter().map(|x| *x as u8 as char).collect::<String>()
-                                            + "\0"
-                                            + hash2
-                                                .iter()
-                                                .map(|x| *x as u8 as char)
-                                                .collect::<String>()
-                                                .as_str()
-                                    )
-                                    .as_bytes(),
-                                )?;
-
-                                fltk::dialog::message(500, 500, "New login and password are saved");
-                                success = 1;
                                 break;
                             }
+
+                            (*library)
+                                .borrow_mut()
+                                .set_user(&normalize(data.first().unwrap()));
+
+                            fltk::dialog::message(
+                                500,
+                                500,
+                                lang.tr("new-login-and-password-are-saved"),
+                            );
+                            success = 1;
+                            break;
                         }
                     }
                 }
@@ -160,79 +189,12 @@ fn main() -> Result<(), Box<dyn Error>> {
                 break;
             }
         }
-    } else {
-        let admin_data = adm.split('\0').collect::<Vec<_>>();
-        let (s, r): (Sender<bool>, _) = app::channel();
-
-        loop {
-            success = 0;
-            let mut password = Input2::<Input, SecretInput>::new(
-                match lang {
-                    Lang::English => "Authorization",
-                    Lang::Russian => "Авторизация",
-                },
-                match lang {
-                    Lang::English => "Login",
-                    Lang::Russian => "Логин",
-                },
-                match lang {
-                    Lang::English => "Password",
-                    Lang::Russian => "Пароль",
-                },
-            );
-            password.show();
-
-            (*password.ok).borrow_mut().emit(s, true);
+    } else if let Some((login, role)) = authorize(&mut staff, None, &app, lang) {
+        (*library).borrow_mut().set_user(&login);
+        (*library).borrow_mut().set_role(role);
 
-            while app.wait() {
-                if let Some(msg) = r.recv() {
-                    if msg {
-                        let input = password.set_input(lang);
-                        password.hide();
-
-                        if let Ok(data) = input {
-                            let hash1 = get_hash(&data.first().unwrap(), 97, 1e9 as u128 + 7);
-                            let hash2 = get_hash(&data.last().unwrap(), 101, 1e9 as u128 + 7);
-
-                            let rehash1 =
-                                hash1.iter().map(|x| *x as u8 as char).collect::<String>();
-                            let rehash2 =
-                                hash2.iter().map(|x| *x as u8 as char).collect::<String>();
-
-                            if format!("{}", rehash1) == format!("{}", admin_data.first().unwrap())
-                                && format!("{}", rehash2)
-                                    == format!("{}", *admin_data.last().unwrap())
-                            {
-                                fltk::dialog::message(
-                                    500,
-                                    500,
-                                    match lang {
-                                        Lang::English => "Authorization is complete",
-                                        Lang::Russian => "Авторизация пройдена",
-                                    },
-                                );
-                                success = 1;
-                                break;
-                            } else {
-                                success = 2;
-                                alert(
-                                    500,
-                                    500,
-                                    match lang {
-                                        Lang::English => "Wrong login or password. Try again",
-                                        Lang::Russian => "Неправильный логин или пароль",
-                                    },
-                                );
-                            }
-                        }
-                    }
-                }
-            }
-
-            if success != 2 {
-                break;
-            }
-        }
+        fltk::dialog::message(500, 500, lang.tr("authorization-is-complete"));
+        success = 1;
     }
 
     if success == 0 {
@@ -240,18 +202,21 @@ fn main() -> Result<(), Box<dyn Error>> {
     }
 
     let mut main_window = MenuWindow::default()
-        .with_label(match lang {
-            Lang::English => "Library System",
-            Lang::Russian => "Система Учёта Библиотеки",
-        })
+        .with_label(lang.tr("library-system"))
         .with_size(1800, 900)
         .center_screen();
 
     let mut frame = Frame::new(0, 0, 1800, 900, "");
-    let mut background = SharedImage::load("src/utils/background.jpg")?;
-    frame.draw(move |f| background.draw(f.x(), f.y(), f.width(), f.height()));
+    let mut background = JpegImage::from_data(include_bytes!("utils/background.jpg"))?;
 
-    main_window.set_icon(Some(JpegImage::load("src/utils/icon.jpg")?));
+    frame.draw(move |f| {
+        background.scale(f.width(), f.height(), false, true);
+        background.draw(f.x(), f.y(), f.width(), f.height())
+    });
+
+    main_window.set_icon(Some(JpegImage::from_data(include_bytes!(
+        "utils/icon.jpg"
+    ))?));
 
     let mut time = fltk::misc::Clock::new(1680, 10, 100, 100, "");
     time.set_type(fltk::misc::ClockType::Square);
@@ -264,23 +229,14 @@ fn main() -> Result<(), Box<dyn Error>> {
     date_frame.end();
 
     let mut table = Table::new(10, 120, 1780, 890, "");
-    table.set_rows(max(50, (*reader_base).borrow().len() as i32));
+    table.set_rows(max(50, (*library).borrow().reader_base().len() as i32));
     table.set_row_header(true);
     table.set_cols(4);
     table.set_col_header(true);
     table.set_col_width_all(460);
     table.end();
 
-    let mut hello = Frame::new(
-        0,
-        15,
-        1800,
-        80,
-        match lang {
-            Lang::English => "BOOK LIBRARY INTERFACE",
-            Lang::Russian => "СИСТЕМА УЧЁТА КНИГ",
-        },
-    );
+    let mut hello = Frame::new(0, 15, 1800, 80, lang.tr("book-library-interface"));
 
     hello.set_label_font(Font::HelveticaBoldItalic);
     hello.set_label_color(Color::from_u32(0x1DE7D8));
@@ -289,43 +245,20 @@ fn main() -> Result<(), Box<dyn Error>> {
     main_window.end();
     main_window.make_resizable(true);
 
-    let rb = reader_base.clone();
-    let bs = book_system.clone();
+    let lib = library.clone();
 
     date_frame.draw_cell(move |t, ctx, row, col, x, y, w, h| match ctx {
         table::TableContext::StartPage => draw::set_font(Font::Helvetica, 14),
 
-        table::TableContext::ColHeader => draw_header(
-            &format!(
-                "{}",
-                match col {
-                    0 => match lang {
-                        Lang::English => "Day",
-                        Lang::Russian => "День",
-                    },
-
-                    1 => match lang {
-                        Lang::English => "Month",
-                        Lang::Russian => "Месяц",
-                    },
-
-                    _ => match lang {
-                        Lang::English => "Year",
-                        Lang::Russian => "Год",
-                    },
-                }
-            ),
-            x,
-            y,
-            w,
-            h,
-        ),
+        table::TableContext::ColHeader => {
+            draw_header(&format!("{}", date_time_header(col, lang)), x, y, w, h)
+        }
 
         table::TableContext::RowHeader => draw_header(&format!("{}", row + 1), x, y, w, h),
 
         table::TableContext::Cell => {
             draw_data(
-                &format!("{}", cell_date_time(col)),
+                &format!("{}", cell_date_time(col, lang)),
                 x,
                 y,
                 w,
@@ -345,25 +278,13 @@ fn main() -> Result<(), Box<dyn Error>> {
             &format!(
                 "{}",
                 match col {
-                    0 => match lang {
-                        Lang::English => "Reader",
-                        Lang::Russian => "Читатель",
-                    },
-
-                    1 => match lang {
-                        Lang::English => "Book",
-                        Lang::Russian => "Книга",
-                    },
-
-                    2 => match lang {
-                        Lang::English => "Start Date",
-                        Lang::Russian => "Дата Выдачи",
-                    },
-
-                    _ => match lang {
-                        Lang::English => "Finish Date",
-                        Lang::Russian => "Срок Сдачи",
-                    },
+                    0 => lang.tr("reader"),
+
+                    1 => lang.tr("book"),
+
+                    2 => lang.tr("start-date-2"),
+
+                    _ => lang.tr("finish-date"),
                 }
             ),
             x,
@@ -375,7 +296,19 @@ fn main() -> Result<(), Box<dyn Error>> {
         table::TableContext::RowHeader => draw_header(&format!("{}", row + 1), x, y, w, h),
 
         table::TableContext::Cell => {
-            let pair = unsafe { cell_reader(col, row, &*(*rb).as_ptr(), &*(*bs).as_ptr(), lang) };
+            let pair = unsafe {
+                {
+                    let lib = &*(*lib).as_ptr();
+                    cell_reader(
+                        col,
+                        row,
+                        lib.reader_base(),
+                        lib.book_system(),
+                        lib.calendar(),
+                        lang,
+                    )
+                }
+            };
 
             draw_data(
                 &format!("{}", pair.0),
@@ -391,372 +324,406 @@ fn main() -> Result<(), Box<dyn Error>> {
         _ => (),
     });
 
-    let mut menu = MenuBar::new(
-        0,
-        0,
-        455 - match lang {
-            Lang::English => 0,
-            Lang::Russian => 25,
-        },
-        30,
-        "",
-    );
+    let mut menu = MenuBar::new(0, 0, 520, 30, "");
     main_window.add(&menu);
 
+    let role = (*library).borrow().role();
+
+    // Actions which aren't allowed for user's role are inactive
+
+    let flag = |msg: Message| match role.allows(msg.permission()) {
+        true => MenuFlag::Normal,
+        false => MenuFlag::Inactive,
+    };
+
     menu.add_emit(
-        match lang {
-            Lang::English => "&Readers/Add reader\t",
-            Lang::Russian => "&Читатели/Добавить читателя\t",
-        },
+        lang.tr("readers-add-reader"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::AddReader),
         s,
         Message::AddReader,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Readers/Remove reader\t",
-            Lang::Russian => "&Читатели/Удалить читателя\t",
-        },
+        lang.tr("readers-remove-reader"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::RemoveReader),
         s,
         Message::RemoveReader,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Readers/Change name\t",
-            Lang::Russian => "&Читатели/Изменить имя\t",
-        },
+        lang.tr("readers-change-name"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::ChangeName),
         s,
         Message::ChangeName,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Readers/Change second name\t",
-            Lang::Russian => "&Читатели/Изменить фамилию\t",
-        },
+        lang.tr("readers-change-second-name"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::ChangeFamily),
         s,
         Message::ChangeFamily,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Readers/Change middle name\t",
-            Lang::Russian => "&Читатели/Изменить отчество\t",
-        },
+        lang.tr("readers-change-middle-name"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::ChangeFather),
         s,
         Message::ChangeFather,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Readers/Change age\t",
-            Lang::Russian => "&Читатели/Изменить возраст\t",
-        },
+        lang.tr("readers-change-age"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::ChangeAge),
         s,
         Message::ChangeAge,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Readers/Change additional information",
-            Lang::Russian => "&Читатели/Изменить дополнительную информацию",
-        },
+        lang.tr("readers-change-additional-information"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::ChangeInfo),
         s,
         Message::ChangeInfo,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Readers/Reader's information with reading now books\t",
-            Lang::Russian => "&Читатели/Информация о читателе с читаемыми книгами\t",
-        },
+        lang.tr("readers-change-category"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::ChangeCategory),
+        s,
+        Message::ChangeCategory,
+    );
+
+    menu.add_emit(
+        lang.tr("readers-readers-information-with-reading-now-books"),
+        Shortcut::empty(),
+        flag(Message::InfoReaderReading),
         s,
         Message::InfoReaderReading,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Readers/Reader's information with read books\t",
-            Lang::Russian => "&Читатели/Информация о читателе с прочитанными книгами\t",
-        },
+        lang.tr("readers-readers-information-with-read-books"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::InfoReaderAllBooks),
         s,
         Message::InfoReaderAllBooks,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Books/Add books\t",
-            Lang::Russian => "&Книги/Добавить книги\t",
-        },
+        lang.tr("books-add-books"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::AddBooks),
         s,
         Message::AddBooks,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Books/Remove book\t",
-            Lang::Russian => "&Книги/Удалить книгу\t",
-        },
+        lang.tr("books-remove-book"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::RemoveBook),
         s,
         Message::RemoveBook,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Books/Remove all specific books\t",
-            Lang::Russian => "&Книги/Убрать все схожие книги\t",
-        },
+        lang.tr("books-remove-all-specific-books"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::RemoveTheBook),
         s,
         Message::RemoveTheBook,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Books/Change book's title\t",
-            Lang::Russian => "&Книги/Изменить название\t",
-        },
+        lang.tr("books-change-books-title"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::ChangeTitle),
         s,
         Message::ChangeTitle,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Books/Change book's author\t",
-            Lang::Russian => "&Книги/Изменить автора\t",
-        },
+        lang.tr("books-change-books-author"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::ChangeAuthor),
         s,
         Message::ChangeAuthor,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Books/Change book's amount of pages\t",
-            Lang::Russian => "&Книги/Изменить количество страниц\t",
-        },
+        lang.tr("books-change-books-amount-of-pages"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::ChangePages),
         s,
         Message::ChangePages,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Books/Change book's location\t",
-            Lang::Russian => "&Книги/Изменить расположение книги\t",
-        },
+        lang.tr("books-change-books-location"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::ChangeLocation),
         s,
         Message::ChangeLocation,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Books/Get type book's information\t",
-            Lang::Russian => "&Книги/Получить информацию о всех схожих книгах\t",
-        },
+        lang.tr("books-change-copy-type"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::ChangeCopyType),
+        s,
+        Message::ChangeCopyType,
+    );
+
+    menu.add_emit(
+        lang.tr("books-get-type-books-information"),
+        Shortcut::empty(),
+        flag(Message::InfoTheBook),
         s,
         Message::InfoTheBook,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Books/Get current book's information\t",
-            Lang::Russian => "&Книги/Получить информацию о конкретной книгe\t",
-        },
+        lang.tr("books-get-current-books-information"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::InfoBook),
         s,
         Message::InfoBook,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Books/List of all books by authors\t",
-            Lang::Russian => "&Книги/Список всех книг по авторам\t",
-        },
+        lang.tr("books-list-of-all-books-by-authors"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::ShowAuthor),
         s,
         Message::ShowAuthor,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Books/List of all books\t",
-            Lang::Russian => "&Книги/Все книги\t",
-        },
+        lang.tr("books-list-of-all-books"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::ShowAllBooks),
         s,
         Message::ShowAllBooks,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Genres/List of all books by genres\t",
-            Lang::Russian => "&Жанры/Список всех книг по жанрам\t",
-        },
+        lang.tr("genres-list-of-all-books-by-genres"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::ShowGenres),
         s,
         Message::ShowGenres,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Genres/Add genre\t",
-            Lang::Russian => "&Жанры/Добавить жанр\t",
-        },
+        lang.tr("genres-add-genre"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::AddGenre),
         s,
         Message::AddGenre,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Genres/Remove genre\t",
-            Lang::Russian => "&Жанры/Удалить жанр\t",
-        },
+        lang.tr("genres-remove-genre"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::RemoveGenre),
         s,
         Message::RemoveGenre,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Genres/Customize book genres\t",
-            Lang::Russian => "&Жанры/Изменить жанры книги\t",
-        },
+        lang.tr("genres-customize-book-genres"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::CustomizeBookGenre),
         s,
         Message::CustomizeBookGenre,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Giveaway/Give book\t",
-            Lang::Russian => "&Выдача/Выдать книгу\t",
-        },
+        lang.tr("giveaway-give-book"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::GiveBook),
         s,
         Message::GiveBook,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Giveaway/Get book\t",
-            Lang::Russian => "&Выдача/Вернуть книгу\t",
-        },
+        lang.tr("giveaway-get-book"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::GetBook),
         s,
         Message::GetBook,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Giveaway/Change return date\t",
-            Lang::Russian => "&Выдача/Изменить срок сдачи\t",
-        },
+        lang.tr("giveaway-change-return-date"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::ChangeReturnDate),
         s,
         Message::ChangeReturnDate,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Restore/Restore previous data\t",
-            Lang::Russian => "&Откат/Откатить изменения назад\t",
-        },
+        lang.tr("giveaway-opening-calendar"),
+        Shortcut::empty(),
+        flag(Message::OpeningCalendar),
+        s,
+        Message::OpeningCalendar,
+    );
+
+    menu.add_emit(
+        lang.tr("giveaway-loan-periods"),
+        Shortcut::empty(),
+        flag(Message::LoanPolicy),
+        s,
+        Message::LoanPolicy,
+    );
+
+    menu.add_emit(
+        lang.tr("restore-restore-previous-data"),
         Shortcut::Ctrl | 'z',
-        MenuFlag::Normal,
+        flag(Message::PrevData),
         s,
         Message::PrevData,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Restore/Restore next data\t",
-            Lang::Russian => "&Откат/Откатить изменения вперед\t",
-        },
+        lang.tr("restore-restore-next-data"),
         Shortcut::Ctrl | Shortcut::Shift | 'z',
-        MenuFlag::Normal,
+        flag(Message::NextData),
         s,
         Message::NextData,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Language/English\t",
-            Lang::Russian => "&Язык/English\t",
-        },
+        lang.tr("restore-history"),
+        Shortcut::Ctrl | 'h',
+        flag(Message::History),
+        s,
+        Message::History,
+    );
+
+    menu.add_emit(
+        lang.tr("restore-audit-log"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::Audit),
         s,
-        Message::English,
+        Message::Audit,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Language/Русский\t",
-            Lang::Russian => "&Язык/Русский\t",
-        },
+        lang.tr("restore-library-on-date"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::TimeTravel),
         s,
-        Message::Russian,
+        Message::TimeTravel,
     );
 
     menu.add_emit(
-        match lang {
-            Lang::English => "&Help",
-            Lang::Russian => "&Помощь",
-        },
+        lang.tr("restore-restore-backup"),
         Shortcut::empty(),
-        MenuFlag::Normal,
+        flag(Message::RestoreBackup),
+        s,
+        Message::RestoreBackup,
+    );
+
+    menu.add_emit(
+        lang.tr("restore-create-snapshot"),
+        Shortcut::empty(),
+        flag(Message::CreateSnapshot),
+        s,
+        Message::CreateSnapshot,
+    );
+
+    menu.add_emit(
+        lang.tr("restore-snapshots"),
+        Shortcut::empty(),
+        flag(Message::RestoreSnapshot),
+        s,
+        Message::RestoreSnapshot,
+    );
+
+    menu.add_emit(
+        lang.tr("users-accounts"),
+        Shortcut::empty(),
+        flag(Message::Users),
+        s,
+        Message::Users,
+    );
+
+    menu.add_emit(
+        lang.tr("users-change-credentials"),
+        Shortcut::empty(),
+        flag(Message::ChangeCredentials),
+        s,
+        Message::ChangeCredentials,
+    );
+
+    menu.add_emit(
+        lang.tr("users-lock"),
+        Shortcut::Ctrl | 'l',
+        flag(Message::Lock),
+        s,
+        Message::Lock,
+    );
+
+    languages.iter().enumerate().for_each(|(ind, language)| {
+        menu.add_emit(
+            format!("{}/{}\t", lang.tr("menu-language"), language.name()).as_str(),
+            Shortcut::empty(),
+            flag(Message::Language(ind)),
+            s,
+            Message::Language(ind),
+        );
+    });
+
+    menu.add_emit(
+        lang.tr("menu-help"),
+        Shortcut::empty(),
+        flag(Message::Help),
         s,
         Message::Help,
     );
 
+    fit_menu(&mut menu);
     main_window.show();
 
+    // Any input in main window postpones locking
+
+    let last_input = Rc::new(Cell::new(Instant::now()));
+
+    main_window.handle({
+        let last_input = last_input.clone();
+
+        move |_, event| {
+            if let Event::Push | Event::KeyDown | Event::MouseWheel | Event::Move | Event::Drag =
+                event
+            {
+                last_input.set(Instant::now());
+            }
+
+            false
+        }
+    });
+
+    if let Some(timeout) = idle_timeout_from_env() {
+        let last_input = last_input.clone();
+
+        app::add_timeout3(1.0, move |handle| {
+            if last_input.get().elapsed() >= timeout {
+                last_input.set(Instant::now());
+                s.send(Message::Lock);
+            }
+
+            app::repeat_timeout3(1.0, handle);
+        });
+    }
+
     thread::spawn(move || loop {
         date_frame.redraw();
         thread::sleep(Duration::new(1, 0))
@@ -766,160 +733,80 @@ fn main() -> Result<(), Box<dyn Error>> {
         if let Some(msg) = r.recv() {
             match msg {
                 Message::AddReader => {
-                    add_reader(
-                        &mut (*reader_base).borrow_mut(),
-                        &mut (*book_system).borrow_mut(),
-                        &(*genres).borrow(),
-                        &mut (*caretaker).borrow_mut(),
-                        &app,
-                        lang,
-                    );
+                    add_reader(&mut *(*library).borrow_mut(), &app, lang);
 
-                    table.set_rows(max(50, (*reader_base).borrow().len() as i32));
+                    table.set_rows(max(50, (*library).borrow().reader_base().len() as i32));
                     table.redraw();
                 }
 
                 Message::RemoveReader => {
-                    remove_reader(
-                        &mut (*reader_base).borrow_mut(),
-                        &mut (*book_system).borrow_mut(),
-                        &(*genres).borrow(),
-                        &mut (*caretaker).borrow_mut(),
-                        &app,
-                        lang,
-                    );
+                    remove_reader(&mut *(*library).borrow_mut(), &app, lang);
 
-                    table.set_rows(max(50, (*reader_base).borrow().len() as i32));
+                    table.set_rows(max(50, (*library).borrow().reader_base().len() as i32));
                     table.redraw();
                 }
 
                 Message::ChangeName => {
-                    change_name(
-                        &mut (*reader_base).borrow_mut(),
-                        &mut (*book_system).borrow_mut(),
-                        &(*genres).borrow(),
-                        &mut (*caretaker).borrow_mut(),
-                        &app,
-                        lang,
-                    );
+                    change_name(&mut *(*library).borrow_mut(), &app, lang);
 
                     table.redraw();
                 }
 
                 Message::ChangeFamily => {
-                    change_family(
-                        &mut (*reader_base).borrow_mut(),
-                        &mut (*book_system).borrow_mut(),
-                        &(*genres).borrow(),
-                        &mut (*caretaker).borrow_mut(),
-                        &app,
-                        lang,
-                    );
+                    change_family(&mut *(*library).borrow_mut(), &app, lang);
 
                     table.redraw();
                 }
 
                 Message::ChangeFather => {
-                    change_father(
-                        &mut (*reader_base).borrow_mut(),
-                        &mut (*book_system).borrow_mut(),
-                        &(*genres).borrow(),
-                        &mut (*caretaker).borrow_mut(),
-                        &app,
-                        lang,
-                    );
+                    change_father(&mut *(*library).borrow_mut(), &app, lang);
 
                     table.redraw();
                 }
 
                 Message::ChangeAge => {
-                    change_age(
-                        &mut (*reader_base).borrow_mut(),
-                        &mut (*book_system).borrow_mut(),
-                        &(*genres).borrow(),
-                        &mut (*caretaker).borrow_mut(),
-                        &app,
-                        lang,
-                    );
+                    change_age(&mut *(*library).borrow_mut(), &app, lang);
 
                     table.redraw();
                 }
 
-                Message::ChangeInfo => change_info(
-                    &mut (*reader_base).borrow_mut(),
-                    &mut (*book_system).borrow_mut(),
-                    &(*genres).borrow(),
-                    &mut (*caretaker).borrow_mut(),
-                    &app,
-                    lang,
-                ),
+                Message::ChangeInfo => change_info(&mut *(*library).borrow_mut(), &app, lang),
+
+                Message::ChangeCategory => {
+                    change_category(&mut *(*library).borrow_mut(), &app, lang)
+                }
 
                 Message::InfoReaderReading => {
-                    reader_info_reading(
-                        reader_base.clone(),
-                        book_system.clone(),
-                        &(*genres).borrow(),
-                        &mut (*caretaker).borrow_mut(),
-                        &app,
-                        lang,
-                        &mut table
-                    );
+                    reader_info_reading(library.clone(), &app, lang, &mut table);
 
                     table.redraw();
                 }
 
                 Message::InfoReaderAllBooks => {
-                    reader_info_all_books(
-                        reader_base.clone(),
-                        book_system.clone(),
-                        &(*genres).borrow(),
-                        &mut (*caretaker).borrow_mut(),
-                        &app,
-                        lang,
-                        &mut table
-                    );
+                    reader_info_all_books(library.clone(), &app, lang, &mut table);
 
                     table.redraw();
                 }
 
                 Message::AddBooks => {
-                    add_books(
-                        &mut (*book_system).borrow_mut(),
-                        &(*reader_base).borrow(),
-                        &(*genres).borrow(),
-                        &mut (*caretaker).borrow_mut(),
-                        &app,
-                        lang,
-                    );
+                    add_books(&mut *(*library).borrow_mut(), &app, lang);
 
                     table.redraw();
                 }
 
                 Message::RemoveBook => {
-                    action_books(
-                        book_system.clone(),
-                        &mut (*reader_base).borrow_mut(),
-                        &(*genres).borrow(),
-                        &mut (*caretaker).borrow_mut(),
-                        &app,
-                        lang,
-                        &mut table,
-                        Message::RemoveBook
-                    );
+                    action_books(library.clone(), &app, lang, &mut table, Message::RemoveBook);
 
                     table.redraw();
                 }
 
                 Message::RemoveTheBook => {
                     action_books(
-                        book_system.clone(),
-                        &mut (*reader_base).borrow_mut(),
-                        &(*genres).borrow(),
-                        &mut (*caretaker).borrow_mut(),
+                        library.clone(),
                         &app,
                         lang,
                         &mut table,
-                        Message::RemoveTheBook
+                        Message::RemoveTheBook,
                     );
 
                     table.redraw();
@@ -927,14 +814,11 @@ fn main() -> Result<(), Box<dyn Error>> {
 
                 Message::ChangeTitle => {
                     action_books(
-                        book_system.clone(),
-                        &mut (*reader_base).borrow_mut(),
-                        &(*genres).borrow(),
-                        &mut (*caretaker).borrow_mut(),
+                        library.clone(),
                         &app,
                         lang,
                         &mut table,
-                        Message::ChangeTitle
+                        Message::ChangeTitle,
                     );
 
                     table.redraw();
@@ -942,14 +826,11 @@ fn main() -> Result<(), Box<dyn Error>> {
 
                 Message::ChangeAuthor => {
                     action_books(
-                        book_system.clone(),
-                        &mut (*reader_base).borrow_mut(),
-                        &(*genres).borrow(),
-                        &mut (*caretaker).borrow_mut(),
+                        library.clone(),
                         &app,
                         lang,
                         &mut table,
-                        Message::ChangeAuthor
+                        Message::ChangeAuthor,
                     );
 
                     table.redraw();
@@ -957,14 +838,11 @@ fn main() -> Result<(), Box<dyn Error>> {
 
                 Message::ChangePages => {
                     action_books(
-                        book_system.clone(),
-                        &mut (*reader_base).borrow_mut(),
-                        &(*genres).borrow(),
-                        &mut (*caretaker).borrow_mut(),
+                        library.clone(),
                         &app,
                         lang,
                         &mut table,
-                        Message::ChangePages
+                        Message::ChangePages,
                     );
 
                     table.redraw();
@@ -972,248 +850,231 @@ fn main() -> Result<(), Box<dyn Error>> {
 
                 Message::ChangeLocation => {
                     action_books(
-                        book_system.clone(),
-                        &mut (*reader_base).borrow_mut(),
-                        &(*genres).borrow(),
-                        &mut (*caretaker).borrow_mut(),
+                        library.clone(),
                         &app,
                         lang,
                         &mut table,
-                        Message::ChangeLocation
+                        Message::ChangeLocation,
                     );
                 }
 
-                Message::InfoTheBook => {
+                Message::ChangeCopyType => {
                     action_books(
-                        book_system.clone(),
-                        &mut (*reader_base).borrow_mut(),
-                        &(*genres).borrow(),
-                        &mut (*caretaker).borrow_mut(),
+                        library.clone(),
                         &app,
                         lang,
                         &mut table,
-                        Message::InfoTheBook
+                        Message::ChangeCopyType,
                     );
-
-                    table.redraw();
                 }
 
-                Message::InfoBook => {
+                Message::InfoTheBook => {
                     action_books(
-                        book_system.clone(),
-                        &mut (*reader_base).borrow_mut(),
-                        &(*genres).borrow(),
-                        &mut (*caretaker).borrow_mut(),
+                        library.clone(),
                         &app,
                         lang,
                         &mut table,
-                        Message::InfoBook
+                        Message::InfoTheBook,
                     );
 
                     table.redraw();
                 }
 
-                Message::ShowAuthor => show_all_authors(&(*book_system).borrow(), lang),
+                Message::InfoBook => {
+                    action_books(library.clone(), &app, lang, &mut table, Message::InfoBook);
 
-                Message::ShowGenres => {
-                    all_genres(&(*genres).borrow(), &*(*book_system).borrow(), &app, lang);
+                    table.redraw();
                 }
 
-                Message::AddGenre => add_genre(
-                    &mut (*genres).borrow_mut(),
-                    &(*reader_base).borrow(),
-                    &(*book_system).borrow(),
-                    &mut *(caretaker).borrow_mut(),
-                    &app,
-                    lang,
-                ),
-
-                Message::RemoveGenre => remove_genre(
-                    &mut (*genres).borrow_mut(),
-                    &(*reader_base).borrow(),
-                    &mut (*book_system).borrow_mut(),
-                    &mut *(caretaker).borrow_mut(),
-                    &app,
-                    lang,
-                ),
-
-                Message::CustomizeBookGenre => customize_book_genre(
-                    &(*genres).borrow(),
-                    &mut (*book_system).borrow_mut(),
-                    &(*reader_base).borrow(),
-                    &mut *(caretaker).borrow_mut(),
-                    &app,
-                    lang,
-                ),
-
-                Message::ShowAllBooks => show_all_books(
-                    book_system.clone(),
-                    &mut (*reader_base).borrow_mut(),
-                    &(*genres).borrow(),
-                    &mut (*caretaker).borrow_mut(),
-                    &app,
-                    lang,
-                    &mut table
-                ),
+                Message::ShowAuthor => show_all_authors((*library).borrow().book_system(), lang),
 
-                Message::GiveBook => {
-                    give_book(
-                        &mut (*reader_base).borrow_mut(),
-                        &mut (*book_system).borrow_mut(),
-                        &(*genres).borrow(),
-                        &mut *(caretaker).borrow_mut(),
+                Message::ShowGenres => {
+                    all_genres(
+                        (*library).borrow().genres(),
+                        (*library).borrow().book_system(),
                         &app,
                         lang,
                     );
+                }
+
+                Message::AddGenre => add_genre(&mut *(*library).borrow_mut(), &app, lang),
+
+                Message::RemoveGenre => remove_genre(&mut *(*library).borrow_mut(), &app, lang),
+
+                Message::CustomizeBookGenre => {
+                    customize_book_genre(&mut *(*library).borrow_mut(), &app, lang)
+                }
+
+                Message::ShowAllBooks => show_all_books(library.clone(), &app, lang, &mut table),
+
+                Message::GiveBook => {
+                    give_book(&mut *(*library).borrow_mut(), &app, lang);
 
                     table.redraw();
                 }
 
                 Message::GetBook => {
-                    get_book(
-                        &mut (*reader_base).borrow_mut(),
-                        &mut (*book_system).borrow_mut(),
-                        &(*genres).borrow(),
-                        &mut *(caretaker).borrow_mut(),
-                        &app,
-                        lang,
-                    );
+                    get_book(&mut *(*library).borrow_mut(), &app, lang);
 
                     table.redraw();
                 }
 
                 Message::ChangeReturnDate => {
-                    change_return_date(
-                        &mut (*book_system).borrow_mut(),
-                        &(*reader_base).borrow_mut(),
-                        &(*genres).borrow(),
-                        &mut *(caretaker).borrow_mut(),
-                        &app,
-                        lang,
-                    );
+                    change_return_date(&mut *(*library).borrow_mut(), &app, lang);
+
+                    table.redraw();
+                }
+
+                Message::OpeningCalendar => {
+                    opening_calendar(&mut *(*library).borrow_mut(), &app, lang);
 
                     table.redraw();
                 }
 
+                Message::LoanPolicy => loan_policy(&mut *(*library).borrow_mut(), &app, lang),
+
                 Message::PrevData => {
-                    (*caretaker).borrow_mut().get_memento_back(
-                        &mut *(reader_base).borrow_mut(),
-                        &mut *(book_system).borrow_mut(),
-                        &mut *(genres).borrow_mut(),
-                        lang
-                    );
+                    match (*library).borrow_mut().undo() {
+                        Ok(Some(command)) => fltk::dialog::message(
+                            500,
+                            500,
+                            format!("{}:\n{}", lang.tr("undone"), command.message(lang)).as_str(),
+                        ),
+
+                        Ok(None) => alert(500, 500, lang.tr("first-version")),
+
+                        Err(err) => alert(
+                            500,
+                            500,
+                            format!("{}\n{}", lang.tr("changes-arent-saved"), err).as_str(),
+                        ),
+                    }
 
+                    table.set_rows(max(50, (*library).borrow().reader_base().len() as i32));
                     table.redraw();
                 }
 
                 Message::NextData => {
-                    (*caretaker).borrow_mut().get_memento_forward(
-                        &mut *(reader_base).borrow_mut(),
-                        &mut *(book_system).borrow_mut(),
-                        &mut *(genres).borrow_mut(),
-                        lang
-                    );
+                    match (*library).borrow_mut().redo() {
+                        Ok(Some(command)) => fltk::dialog::message(
+                            500,
+                            500,
+                            format!("{}:\n{}", lang.tr("done-again"), command.message(lang))
+                                .as_str(),
+                        ),
+
+                        Ok(None) => alert(500, 500, lang.tr("last-version")),
+
+                        Err(err) => alert(
+                            500,
+                            500,
+                            format!("{}\n{}", lang.tr("changes-arent-saved"), err).as_str(),
+                        ),
+                    }
 
+                    table.set_rows(max(50, (*library).borrow().reader_base().len() as i32));
                     table.redraw();
                 }
 
-                Message::English => {
-                    if fltk::dialog::choice2(
-                        500,
-                        500,
-                        match lang {
-                            Lang::English => "Are you sure you want to change your language? You'll have to restart the program",
-                            Lang::Russian => "Вы уверены, что хотите сменить язык? Для этого придётся перезапустить программу"
-                        },
-                        match lang {
-                            Lang::English => "Ok",
-                            Lang::Russian => "Ок"
-                        },
-                        match lang {
-                            Lang::English => "Cancel",
-                            Lang::Russian => "Отмена"
-                        },
-                        ""
-                    ) == Some(0) {
-                        Lang::change(Lang::English);
-                        app.quit()
+                Message::History => {
+                    history(&mut *(*library).borrow_mut(), &app, lang);
+
+                    table.set_rows(max(50, (*library).borrow().reader_base().len() as i32));
+                    table.redraw();
+                }
+
+                Message::Audit => audit(&*(*library).borrow(), &app, lang),
+
+                Message::TimeTravel => time_travel(&*(*library).borrow(), lang),
+
+                Message::RestoreBackup => {
+                    restore_backup(&mut *(*library).borrow_mut(), &app, lang);
+
+                    table.redraw();
+                }
+
+                Message::CreateSnapshot => create_snapshot(&*(*library).borrow(), &app, lang),
+
+                Message::RestoreSnapshot => {
+                    restore_snapshot(&mut *(*library).borrow_mut(), &app, lang);
+
+                    table.set_rows(max(50, (*library).borrow().reader_base().len() as i32));
+                    table.redraw();
+                }
+
+                Message::Users => users(&mut staff, &app, lang),
+
+                Message::ChangeCredentials => {
+                    change_credentials(&mut staff, &mut *(*library).borrow_mut(), &app, lang)
+                }
+
+                Message::Lock => {
+                    let mut shown = app::windows().unwrap_or_default();
+                    shown.iter_mut().for_each(|w| w.hide());
+
+                    let user = (*library).borrow().user().to_string();
+
+                    match authorize(&mut staff, Some(&user), &app, lang) {
+                        Some(_) => shown.iter_mut().for_each(|w| w.show()),
+                        None => return Ok(()),
                     }
+
+                    last_input.set(Instant::now());
                 }
 
-                Message::Russian => {
+                Message::Language(ind) => {
                     if fltk::dialog::choice2(
                         500,
                         500,
-                        match lang {
-                            Lang::English => "Are you sure you want to change your language? You'll have to restart the program",
-                            Lang::Russian => "Вы уверены, что хотите сменить язык? Для этого придётся перезапустить программу"
-                        },
-                        match lang {
-                            Lang::English => "Ok",
-                            Lang::Russian => "Ок"
-                        },
-                        match lang {
-                            Lang::English => "Cancel",
-                            Lang::Russian => "Отмена"
-                        },
-                        ""
-                    ) == Some(0) {
-                        Lang::change(Lang::Russian);
-                        app.quit()
+                        lang.tr("are-you-sure-you-want-to-change"),
+                        lang.tr("ok-2"),
+                        lang.tr("cancel"),
+                        "",
+                    ) == Some(0)
+                    {
+                        match Lang::change(languages[ind], &data_dir) {
+                            Ok(()) => app.quit(),
+
+                            Err(err) => alert(
+                                500,
+                                500,
+                                format!("{}\n{}", lang.tr("language-isnt-changed"), err).as_str(),
+                            ),
+                        }
                     }
                 }
 
-                Message::Help => help(lang)
+                Message::Help => help(lang),
             }
         }
 
-        let len = (*reader_base).borrow().len();
+        let len = (*library).borrow().reader_base().len();
 
         (0..len).for_each(|i| {
             if table.is_selected(i as i32, 0) {
-                reader_info_simple_reading(
-                    i,
-                    reader_base.clone(),
-                    book_system.clone(),
-                    &(*genres).borrow(),
-                    &mut (*caretaker).borrow_mut(),
-                    &app,
-                    lang,
-                    &mut table,
-                );
+                reader_info_simple_reading(i, library.clone(), &app, lang, &mut table);
 
                 table.unset_selection();
                 return;
             } else if table.is_selected(i as i32, 1) {
-                if (*reader_base).borrow().get_book(i).is_some() {
-                    book_info_simple(
-                        (*reader_base)
-                            .borrow()
-                            .get_book(i)
-                            .unwrap()
-                            .first()
-                            .unwrap(),
-                        book_system.clone(),
-                        &*(*reader_base).borrow(),
-                        &*(*genres).borrow(),
-                        &mut *(*caretaker).borrow_mut(),
-                        &app,
-                        lang,
-                    );
+                if (*library).borrow().reader_base().get_book(i).is_some() {
+                    let book = (*library)
+                        .borrow()
+                        .reader_base()
+                        .get_book(i)
+                        .unwrap()
+                        .first()
+                        .unwrap()
+                        .clone();
+
+                    book_info_simple(&book, library.clone(), &app, lang);
                 }
 
                 table.unset_selection();
                 return;
             } else if table.is_selected(i as i32, 3) {
-                if (*reader_base).borrow().get_book(i).is_some() {
-                    if change_return_date_first_book(
-                        i,
-                        &mut (*book_system).borrow_mut(),
-                        &(*reader_base).borrow(),
-                        &(*genres).borrow(),
-                        &mut (*caretaker).borrow_mut(),
-                        lang,
-                    ) {
+                if (*library).borrow().reader_base().get_book(i).is_some() {
+                    if change_return_date_first_book(i, &mut *(*library).borrow_mut(), lang) {
                         table.redraw();
                     }
                 }
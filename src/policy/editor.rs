@@ -0,0 +1,268 @@
+extern crate fltk;
+
+use crate::{
+    books::error::GenreError,
+    library::Library,
+    policy::{Limit, LimitRule, LoanRule, PolicyError},
+    Lang,
+};
+
+use fltk::{
+    app,
+    app::App,
+    browser::HoldBrowser,
+    button::Button,
+    dialog::alert,
+    enums::Align,
+    frame::Frame,
+    input::{Input, IntInput},
+    menu::Choice,
+    prelude::*,
+    window::SingleWindow,
+};
+
+/// Buttons of policy's window
+
+#[derive(Clone, Copy)]
+enum Event {
+    SetDefault,
+    Add,
+    Remove,
+    AddLimit,
+    RemoveLimit,
+}
+
+/// Function that shows loan periods and borrowing limits of library.
+/// Administrator can change default period,
+/// add and remove rules for categories of readers
+/// and genres of books, add and remove limits
+/// for categories, genres and types of copies.
+/// Every change is saved at once
+
+pub fn loan_policy(library: &mut Library, app: &App, lang: Lang) {
+    let (s, r) = app::channel();
+
+    let mut wind = SingleWindow::new(500, 100, 600, 745, lang.tr("loan-periods"));
+
+    let _ = Frame::new(10, 10, 270, 25, lang.tr("default-loan-period"));
+    let mut default_period = IntInput::new(290, 10, 60, 25, "");
+
+    let mut set_default = Button::new(360, 10, 90, 25, lang.tr("ok"));
+
+    let mut browser = HoldBrowser::new(10, 45, 580, 270, "");
+
+    let mut category = Input::new(10, 345, 170, 25, lang.tr("category-of-readers"));
+    let mut genre = Input::new(190, 345, 170, 25, lang.tr("genre"));
+    let mut period = IntInput::new(370, 345, 60, 25, lang.tr("days"));
+
+    category.set_align(Align::TopLeft);
+    genre.set_align(Align::TopLeft);
+    period.set_align(Align::TopLeft);
+
+    let mut add = Button::new(440, 345, 70, 25, lang.tr("add"));
+
+    let mut remove = Button::new(520, 345, 70, 25, lang.tr("remove"));
+
+    let _ = Frame::new(10, 395, 580, 25, lang.tr("borrowing-limits"));
+    let mut limits = HoldBrowser::new(10, 425, 580, 200, "");
+
+    let mut limit_category = Input::new(10, 655, 190, 25, lang.tr("category-of-readers"));
+    let mut limit_genre = Input::new(210, 655, 190, 25, lang.tr("genre"));
+    let mut limit_copy_type = Input::new(410, 655, 180, 25, lang.tr("copy-type"));
+    let mut limit = Choice::new(10, 705, 260, 25, "");
+    let mut max = IntInput::new(280, 705, 60, 25, lang.tr("max-books"));
+
+    limit_category.set_align(Align::TopLeft);
+    limit_genre.set_align(Align::TopLeft);
+    limit_copy_type.set_align(Align::TopLeft);
+    max.set_align(Align::TopLeft);
+
+    limit.add_choice(
+        format!(
+            "{}|{}|{}",
+            lang.tr("limit-kind-max-loans"),
+            lang.tr("limit-kind-no-overdue"),
+            lang.tr("limit-kind-not-loanable"),
+        )
+        .as_str(),
+    );
+
+    limit.set_value(0);
+
+    let mut add_limit = Button::new(440, 705, 70, 25, lang.tr("add"));
+
+    let mut remove_limit = Button::new(520, 705, 70, 25, lang.tr("remove"));
+
+    set_default.emit(s, Event::SetDefault);
+    add.emit(s, Event::Add);
+    remove.emit(s, Event::Remove);
+    add_limit.emit(s, Event::AddLimit);
+    remove_limit.emit(s, Event::RemoveLimit);
+
+    wind.end();
+    wind.show();
+
+    fill_policy(
+        &mut default_period,
+        &mut browser,
+        &mut limits,
+        library,
+        lang,
+    );
+
+    while app.wait() {
+        if let Some(event) = r.recv() {
+            let mut policy = library.policy().clone();
+
+            let result = match event {
+                Event::SetDefault => match default_period.value().trim().parse() {
+                    Ok(days) => policy.set_default_period(days).map(|_| ()),
+                    Err(_) => Err(PolicyError::WrongPeriod),
+                },
+
+                Event::Add => {
+                    let genre_name = genre.value().trim().to_string();
+
+                    if !is_genre(&genre_name, library) {
+                        alert(500, 500, GenreError::NotFound.message(lang));
+                        continue;
+                    }
+
+                    match period.value().trim().parse() {
+                        Ok(days) => policy
+                            .add_rule(LoanRule::new(
+                                Some(category.value().as_str()),
+                                Some(genre_name.as_str()),
+                                days,
+                            ))
+                            .map(|_| ()),
+
+                        Err(_) => Err(PolicyError::WrongPeriod),
+                    }
+                }
+
+                Event::Remove => {
+                    let ind = browser.value();
+
+                    if ind <= 0 {
+                        alert(500, 500, lang.tr("rule-isnt-selected"));
+                        continue;
+                    }
+
+                    policy.remove_rule(ind as usize - 1).map(|_| ())
+                }
+
+                Event::AddLimit => {
+                    let genre_name = limit_genre.value().trim().to_string();
+
+                    if !is_genre(&genre_name, library) {
+                        alert(500, 500, GenreError::NotFound.message(lang));
+                        continue;
+                    }
+
+                    let chosen = match limit.value() {
+                        0 => match max.value().trim().parse() {
+                            Ok(max) => Limit::MaxLoans(max),
+
+                            Err(_) => {
+                                alert(500, 500, lang.tr("incorrect-max-books"));
+                                continue;
+                            }
+                        },
+
+                        1 => Limit::NoOverdue,
+                        _ => Limit::NotLoanable,
+                    };
+
+                    policy
+                        .add_limit(LimitRule::new(
+                            Some(limit_category.value().as_str()),
+                            Some(genre_name.as_str()),
+                            Some(limit_copy_type.value().as_str()),
+                            chosen,
+                        ))
+                        .map(|_| ())
+                }
+
+                Event::RemoveLimit => {
+                    let ind = limits.value();
+
+                    if ind <= 0 {
+                        alert(500, 500, lang.tr("rule-isnt-selected"));
+                        continue;
+                    }
+
+                    policy.remove_limit(ind as usize - 1).map(|_| ())
+                }
+            };
+
+            match result.and_then(|_| library.set_policy(policy)) {
+                Ok(_) => match event {
+                    Event::Add => {
+                        category.set_value("");
+                        genre.set_value("");
+                        period.set_value("");
+                    }
+
+                    Event::AddLimit => {
+                        limit_category.set_value("");
+                        limit_genre.set_value("");
+                        limit_copy_type.set_value("");
+                        max.set_value("");
+                    }
+
+                    _ => {}
+                },
+
+                Err(err) => alert(500, 500, err.message(lang)),
+            }
+
+            fill_policy(
+                &mut default_period,
+                &mut browser,
+                &mut limits,
+                library,
+                lang,
+            );
+        }
+
+        if !wind.shown() {
+            return;
+        }
+    }
+}
+
+/// Shows default period and lists all rules and limits
+
+fn fill_policy(
+    default_period: &mut IntInput,
+    browser: &mut HoldBrowser,
+    limits: &mut HoldBrowser,
+    library: &Library,
+    lang: Lang,
+) {
+    default_period.set_value(library.policy().default_period().to_string().as_str());
+    browser.clear();
+    limits.clear();
+
+    library
+        .policy()
+        .rules()
+        .iter()
+        .for_each(|rule| browser.add(rule.to_string(lang).as_str()));
+
+    library
+        .policy()
+        .limits()
+        .iter()
+        .for_each(|rule| limits.add(rule.to_string(lang).as_str()));
+}
+
+/// Checks if genre exists (in any case).
+/// Empty genre means any, so it's accepted
+
+#[inline]
+fn is_genre(genre: &str, library: &Library) -> bool {
+    let genre = genre.to_lowercase();
+    genre.is_empty() || library.genres().iter().any(|g| *g == genre)
+}
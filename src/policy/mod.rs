@@ -0,0 +1,841 @@
+extern crate yaml_rust;
+
+use crate::{
+    auth::AccessDenied,
+    data::{
+        self,
+        error::{LoadError, LoadErrorKind, Record},
+        DataDir,
+    },
+    storage::{migration, yaml::emit},
+    Lang,
+};
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    io,
+    path::{Path, PathBuf},
+};
+
+use yaml_rust::{yaml::Hash, Yaml};
+
+pub mod editor;
+
+/// Version of policy's file
+
+const POLICY_VERSION: i64 = 1;
+
+/// Key of default loan period in policy's file
+
+const DEFAULT_KEY: &str = "Default Period";
+
+/// Key of loan rules in policy's file
+
+const RULES_KEY: &str = "Rules";
+
+/// Key of borrowing limits in policy's file
+
+const LIMITS_KEY: &str = "Limits";
+
+/// Loan period which is used
+/// if library has no policy's file (in days)
+
+pub const DEFAULT_PERIOD: u16 = 14;
+
+/// The longest loan period (in days)
+
+pub const MAX_PERIOD: u16 = 366;
+
+/// Loan period for readers of category
+/// who take book of genre.
+/// Rule without category is for all readers,
+/// rule without genre is for all books
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LoanRule {
+    pub(crate) category: Option<String>,
+    pub(crate) genre: Option<String>,
+    pub(crate) period: u16,
+}
+
+impl LoanRule {
+    /// Creates rule.
+    /// Empty category or genre means any.
+    /// Category is kept in lower case,
+    /// genre is kept in lower case as in list of genres
+
+    #[inline]
+    pub fn new(category: Option<&str>, genre: Option<&str>, period: u16) -> Self {
+        LoanRule {
+            category: non_empty(category).map(|c| c.to_lowercase()),
+            genre: non_empty(genre).map(|g| g.to_lowercase()),
+            period,
+        }
+    }
+
+    /// Category of readers (None for all readers)
+
+    #[inline]
+    pub fn category(&self) -> Option<&str> {
+        self.category.as_deref()
+    }
+
+    /// Genre of books (None for all books)
+
+    #[inline]
+    pub fn genre(&self) -> Option<&str> {
+        self.genre.as_deref()
+    }
+
+    /// Loan period in days
+
+    #[inline]
+    pub fn period(&self) -> u16 {
+        self.period
+    }
+
+    /// Checks if rule is for reader of category
+    /// who takes book with chosen genres.
+    /// Category (trimmed) and genres are compared in lower case
+
+    #[inline]
+    pub fn applies(&self, category: &str, genres: &[String]) -> bool {
+        self.category
+            .as_ref()
+            .map_or(true, |c| *c == category.trim().to_lowercase())
+            && self
+                .genre
+                .as_ref()
+                .map_or(true, |g| genres.iter().any(|x| x.to_lowercase() == *g))
+    }
+
+    /// How specific rule is.
+    /// Category is more specific than genre
+
+    #[inline]
+    fn specificity(&self) -> u8 {
+        self.category.is_some() as u8 * 2 + self.genre.is_some() as u8
+    }
+
+    /// Rule in list of rules
+    /// (e.g. "Student / Fiction: 21 days")
+
+    #[inline]
+    pub fn to_string(&self, lang: Lang) -> String {
+        format!(
+            "{} / {}: {}",
+            self.category().unwrap_or(lang.tr("any-category")),
+            self.genre().unwrap_or(lang.tr("any-genre")),
+            lang.plural(
+                "loan-period-days",
+                self.period as usize,
+                &[("days", &self.period)]
+            )
+        )
+    }
+}
+
+/// What limit rule restricts
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Limit {
+    /// Reader can't have more such books at once
+    MaxLoans(u16),
+
+    /// Reader who has overdue books can't take such books
+    NoOverdue,
+
+    /// Such books aren't given at all
+    /// (e.g. reference copies, which are read in library)
+    NotLoanable,
+}
+
+impl Limit {
+    /// Name of limit as it's stored
+
+    #[inline]
+    fn name(self) -> &'static str {
+        match self {
+            Limit::MaxLoans(_) => "MaxLoans",
+            Limit::NoOverdue => "NoOverdue",
+            Limit::NotLoanable => "NotLoanable",
+        }
+    }
+
+    /// Localized description of limit
+
+    #[inline]
+    pub fn to_string(self, lang: Lang) -> String {
+        match self {
+            Limit::MaxLoans(max) => lang.plural("limit-max-loans", max as usize, &[("max", &max)]),
+            Limit::NoOverdue => lang.tr("limit-no-overdue").to_string(),
+            Limit::NotLoanable => lang.tr("limit-not-loanable").to_string(),
+        }
+    }
+}
+
+/// Limit for readers of category
+/// who take books of genre and copy type.
+/// Rule without category, genre or copy type
+/// is for all readers, genres or copies
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LimitRule {
+    pub(crate) category: Option<String>,
+    pub(crate) genre: Option<String>,
+    pub(crate) copy_type: Option<String>,
+    pub(crate) limit: Limit,
+}
+
+impl LimitRule {
+    /// Creates rule.
+    /// Empty category, genre or copy type means any.
+    /// Category is kept in lower case,
+    /// genre is kept in lower case as in list of genres
+
+    #[inline]
+    pub fn new(
+        category: Option<&str>,
+        genre: Option<&str>,
+        copy_type: Option<&str>,
+        limit: Limit,
+    ) -> Self {
+        LimitRule {
+            category: non_empty(category).map(|c| c.to_lowercase()),
+            genre: non_empty(genre).map(|g| g.to_lowercase()),
+            copy_type: non_empty(copy_type),
+            limit,
+        }
+    }
+
+    /// Category of readers (None for all readers)
+
+    #[inline]
+    pub fn category(&self) -> Option<&str> {
+        self.category.as_deref()
+    }
+
+    /// Genre of books (None for all books)
+
+    #[inline]
+    pub fn genre(&self) -> Option<&str> {
+        self.genre.as_deref()
+    }
+
+    /// Type of copies (None for all copies)
+
+    #[inline]
+    pub fn copy_type(&self) -> Option<&str> {
+        self.copy_type.as_deref()
+    }
+
+    /// What rule restricts
+
+    #[inline]
+    pub fn limit(&self) -> Limit {
+        self.limit
+    }
+
+    /// Checks if rule is for simple book
+    /// (without checking reader's category).
+    /// Genres are compared in lower case
+
+    #[inline]
+    fn covers(&self, item: &LoanItem) -> bool {
+        self.genre
+            .as_ref()
+            .map_or(true, |g| item.genres.contains(g))
+            && self
+                .copy_type
+                .as_ref()
+                .map_or(true, |t| *t == item.copy_type)
+    }
+
+    /// Checks if rule is for reader of category
+    /// who takes simple book.
+    /// Category is compared trimmed in lower case
+
+    #[inline]
+    pub fn applies(&self, category: &str, item: &LoanItem) -> bool {
+        self.category
+            .as_ref()
+            .map_or(true, |c| *c == category.trim().to_lowercase())
+            && self.covers(item)
+    }
+
+    /// Reason why reader, who has chosen books now,
+    /// can't take simple book because of this rule.
+    /// If rule doesn't forbid it, it will return None
+
+    pub fn check(&self, category: &str, item: &LoanItem, reading: &[LoanItem]) -> Option<Refusal> {
+        if !self.applies(category, item) {
+            return None;
+        }
+
+        match self.limit {
+            Limit::NotLoanable => Some(Refusal::NotLoanable),
+
+            Limit::NoOverdue => match reading.iter().filter(|book| book.overdue).count() {
+                0 => None,
+                overdue => Some(Refusal::Overdue(overdue)),
+            },
+
+            Limit::MaxLoans(max) => {
+                match reading.iter().filter(|book| self.covers(book)).count() >= max as usize {
+                    true => Some(Refusal::TooManyLoans(max)),
+                    false => None,
+                }
+            }
+        }
+    }
+
+    /// Rule in list of rules
+    /// (e.g. "Student / Fiction / Reference: not loanable")
+
+    #[inline]
+    pub fn to_string(&self, lang: Lang) -> String {
+        format!(
+            "{} / {} / {}: {}",
+            self.category().unwrap_or(lang.tr("any-category")),
+            self.genre().unwrap_or(lang.tr("any-genre")),
+            self.copy_type().unwrap_or(lang.tr("any-copy-type")),
+            self.limit.to_string(lang)
+        )
+    }
+}
+
+/// Simple book as it's seen by loan policy:
+/// genres of its The Book, type of copy
+/// and if it's overdue (for books which reader has now)
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LoanItem {
+    pub(crate) genres: Vec<String>,
+    pub(crate) copy_type: String,
+    pub(crate) overdue: bool,
+}
+
+impl LoanItem {
+    /// Creates simple book with genres and copy type.
+    /// Genres are kept in lower case as in list of genres
+
+    #[inline]
+    pub fn new(genres: Vec<String>, copy_type: &str, overdue: bool) -> Self {
+        LoanItem {
+            genres: genres.iter().map(|g| g.to_lowercase()).collect(),
+            copy_type: copy_type.trim().to_string(),
+            overdue,
+        }
+    }
+}
+
+/// Reason why book can't be given to reader
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    /// Copy isn't given out of library
+    NotLoanable,
+
+    /// Reader has overdue books (how many)
+    Overdue(usize),
+
+    /// Reader already has the most books allowed (limit)
+    TooManyLoans(u16),
+}
+
+impl Refusal {
+    /// Localized description of refusal
+
+    #[inline]
+    pub fn message(&self, lang: Lang) -> &'static str {
+        lang.tr(match self {
+            Refusal::NotLoanable => "refusal-not-loanable",
+            Refusal::Overdue(_) => "refusal-overdue",
+            Refusal::TooManyLoans(_) => "refusal-too-many-loans",
+        })
+    }
+
+    /// Localized description of refusal with details
+    /// (how many books are overdue or what the limit is)
+
+    #[inline]
+    pub fn to_string(&self, lang: Lang) -> String {
+        match self {
+            Refusal::NotLoanable => self.message(lang).to_string(),
+
+            Refusal::Overdue(count) => {
+                lang.plural("refusal-overdue-details", *count, &[("count", count)])
+            }
+
+            Refusal::TooManyLoans(max) => lang.plural(
+                "refusal-too-many-loans-details",
+                *max as usize,
+                &[("max", max)],
+            ),
+        }
+    }
+}
+
+impl Display for Refusal {
+    /// Describes refusal in english
+
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_string(Lang::default()))
+    }
+}
+
+/// Loan periods of library.
+/// When book is given, the most specific rule
+/// for reader's category and book's genres is used
+/// (category and genre, then category, then genre).
+/// If several rules are equally specific,
+/// the shortest period is used.
+/// If no rule is found, default period is used.
+/// Limit rules restrict who can take which books,
+/// every limit which applies to giving is checked.
+/// Policy is stored in policy's file of data directory
+
+#[derive(Clone, Debug)]
+pub struct LoanPolicy {
+    path: Option<PathBuf>,
+    default_period: u16,
+    rules: Vec<LoanRule>,
+    limits: Vec<LimitRule>,
+}
+
+impl Default for LoanPolicy {
+    #[inline]
+    fn default() -> Self {
+        LoanPolicy {
+            path: None,
+            default_period: DEFAULT_PERIOD,
+            rules: vec![],
+            limits: vec![],
+        }
+    }
+}
+
+impl LoanPolicy {
+    /// Creates policy with default period and without rules.
+    /// It isn't saved anywhere
+
+    #[inline]
+    pub fn new() -> Self {
+        LoanPolicy::default()
+    }
+
+    /// Reads policy from data directory.
+    /// If there is no policy's file,
+    /// default period is used for all books
+
+    pub fn load(data_dir: &DataDir) -> Result<Self, LoadError> {
+        let path = data_dir.policy();
+
+        let mut policy = match path.exists() {
+            true => match data::read_yaml(&path)? {
+                Some(doc) => policy_from_yaml(&path, &doc)?,
+                None => LoanPolicy::new(),
+            },
+
+            false => LoanPolicy::new(),
+        };
+
+        policy.path = Some(path);
+        Ok(policy)
+    }
+
+    /// Loan period which is used
+    /// if there is no rule for book
+
+    #[inline]
+    pub fn default_period(&self) -> u16 {
+        self.default_period
+    }
+
+    /// All rules in order of adding
+
+    #[inline]
+    pub fn rules(&self) -> &[LoanRule] {
+        &self.rules
+    }
+
+    /// All limit rules in order of adding
+
+    #[inline]
+    pub fn limits(&self) -> &[LimitRule] {
+        &self.limits
+    }
+
+    /// Reasons why reader of category, who has chosen books now,
+    /// can't take simple book. Every reason is given once.
+    /// If book can be given, it will return empty vector
+
+    pub fn refusals(&self, category: &str, item: &LoanItem, reading: &[LoanItem]) -> Vec<Refusal> {
+        let mut refusals = vec![];
+
+        self.limits
+            .iter()
+            .filter_map(|rule| rule.check(category, item, reading))
+            .for_each(|refusal| {
+                if !refusals.contains(&refusal) {
+                    refusals.push(refusal);
+                }
+            });
+
+        refusals
+    }
+
+    /// The rule which is used for reader of category
+    /// who takes book with chosen genres.
+    /// If there is no such rule, it will return None
+
+    #[inline]
+    pub fn rule_for(&self, category: &str, genres: &[String]) -> Option<&LoanRule> {
+        self.rules
+            .iter()
+            .filter(|rule| rule.applies(category, genres))
+            .min_by_key(|rule| (u8::MAX - rule.specificity(), rule.period))
+    }
+
+    /// Loan period for reader of category
+    /// who takes book with chosen genres
+
+    #[inline]
+    pub fn period(&self, category: &str, genres: &[String]) -> u16 {
+        self.rule_for(category, genres)
+            .map_or(self.default_period, LoanRule::period)
+    }
+
+    /// Sets default loan period.
+    /// It must be from 1 to 366 days
+
+    #[inline]
+    pub fn set_default_period(&mut self, period: u16) -> Result<&mut Self, PolicyError> {
+        check_period(period)?;
+        self.default_period = period;
+        Ok(self)
+    }
+
+    /// Adds rule.
+    /// If there is rule for the same category and genre,
+    /// it will return error
+
+    pub fn add_rule(&mut self, rule: LoanRule) -> Result<&mut Self, PolicyError> {
+        check_period(rule.period)?;
+
+        if self
+            .rules
+            .iter()
+            .any(|r| r.category == rule.category && r.genre == rule.genre)
+        {
+            return Err(PolicyError::AlreadyExists);
+        }
+
+        self.rules.push(rule);
+        Ok(self)
+    }
+
+    /// Removes rule with chosen index
+
+    #[inline]
+    pub fn remove_rule(&mut self, ind: usize) -> Result<LoanRule, PolicyError> {
+        match ind < self.rules.len() {
+            true => Ok(self.rules.remove(ind)),
+            false => Err(PolicyError::NotFound),
+        }
+    }
+
+    /// Adds limit rule.
+    /// If there is the same kind of limit
+    /// for the same category, genre and copy type,
+    /// it will return error
+
+    pub fn add_limit(&mut self, rule: LimitRule) -> Result<&mut Self, PolicyError> {
+        if self.limits.iter().any(|r| {
+            r.category == rule.category
+                && r.genre == rule.genre
+                && r.copy_type == rule.copy_type
+                && r.limit.name() == rule.limit.name()
+        }) {
+            return Err(PolicyError::AlreadyExists);
+        }
+
+        self.limits.push(rule);
+        Ok(self)
+    }
+
+    /// Removes limit rule with chosen index
+
+    #[inline]
+    pub fn remove_limit(&mut self, ind: usize) -> Result<LimitRule, PolicyError> {
+        match ind < self.limits.len() {
+            true => Ok(self.limits.remove(ind)),
+            false => Err(PolicyError::NotFound),
+        }
+    }
+
+    /// Writes policy to policy's file.
+    /// Policy which isn't loaded from data directory isn't saved
+
+    #[inline]
+    pub fn save(&self) -> io::Result<()> {
+        match &self.path {
+            Some(path) => data::write_atomic(path, emit(&policy_to_yaml(self))?.as_bytes()),
+
+            None => Ok(()),
+        }
+    }
+}
+
+/// Error of changing loan policy
+
+#[derive(Debug)]
+pub enum PolicyError {
+    /// Loan period is 0 or longer than year
+    WrongPeriod,
+
+    /// Rule for the same books and readers exists
+    AlreadyExists,
+
+    /// Rule isn't found
+    NotFound,
+
+    /// User's role doesn't allow to change policy
+    NoAccess,
+
+    /// Policy's file can't be written
+    Io(io::Error),
+}
+
+impl PolicyError {
+    /// Localized description of error
+
+    #[inline]
+    pub fn message(&self, lang: Lang) -> &'static str {
+        match self {
+            PolicyError::WrongPeriod => lang.tr("policy-error-wrong-period"),
+            PolicyError::AlreadyExists => lang.tr("policy-error-already-exists"),
+            PolicyError::NotFound => lang.tr("policy-error-not-found"),
+            PolicyError::NoAccess => AccessDenied.message(lang),
+            PolicyError::Io(_) => lang.tr("policy-error-io"),
+        }
+    }
+}
+
+impl Display for PolicyError {
+    /// Describes error in english
+
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message(Lang::default()))
+    }
+}
+
+impl Error for PolicyError {
+    /// Underlying io error
+
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PolicyError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for PolicyError {
+    /// Wraps io error
+
+    #[inline]
+    fn from(err: io::Error) -> Self {
+        PolicyError::Io(err)
+    }
+}
+
+impl From<AccessDenied> for PolicyError {
+    #[inline]
+    fn from(_: AccessDenied) -> Self {
+        PolicyError::NoAccess
+    }
+}
+
+/// Trimmed string or None if it's empty
+
+#[inline]
+fn non_empty(text: Option<&str>) -> Option<String> {
+    text.map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+}
+
+/// Checks that loan period is from 1 to 366 days
+
+#[inline]
+fn check_period(period: u16) -> Result<(), PolicyError> {
+    match (1..=MAX_PERIOD).contains(&period) {
+        true => Ok(()),
+        false => Err(PolicyError::WrongPeriod),
+    }
+}
+
+/// Converts default period, rules and limits
+/// to yaml document with version header
+
+fn policy_to_yaml(policy: &LoanPolicy) -> Yaml {
+    let rules = policy
+        .rules
+        .iter()
+        .map(|rule| {
+            let mut data = Hash::new();
+            insert_optional(&mut data, "Category", &rule.category);
+            insert_optional(&mut data, "Genre", &rule.genre);
+
+            data.insert(
+                Yaml::String("Period".to_string()),
+                Yaml::Integer(rule.period as i64),
+            );
+
+            Yaml::Hash(data)
+        })
+        .collect();
+
+    let limits = policy
+        .limits
+        .iter()
+        .map(|rule| {
+            let mut data = Hash::new();
+            insert_optional(&mut data, "Category", &rule.category);
+            insert_optional(&mut data, "Genre", &rule.genre);
+            insert_optional(&mut data, "Type", &rule.copy_type);
+
+            data.insert(
+                Yaml::String("Limit".to_string()),
+                Yaml::String(rule.limit.name().to_string()),
+            );
+
+            if let Limit::MaxLoans(max) = rule.limit {
+                data.insert(Yaml::String("Max".to_string()), Yaml::Integer(max as i64));
+            }
+
+            Yaml::Hash(data)
+        })
+        .collect();
+
+    let mut hash = Hash::new();
+
+    hash.insert(
+        Yaml::String(migration::VERSION_KEY.to_string()),
+        Yaml::Integer(POLICY_VERSION),
+    );
+
+    hash.insert(
+        Yaml::String(DEFAULT_KEY.to_string()),
+        Yaml::Integer(policy.default_period as i64),
+    );
+
+    hash.insert(Yaml::String(RULES_KEY.to_string()), Yaml::Array(rules));
+    hash.insert(Yaml::String(LIMITS_KEY.to_string()), Yaml::Array(limits));
+    Yaml::Hash(hash)
+}
+
+/// Inserts string field if it's set
+
+#[inline]
+fn insert_optional(data: &mut Hash, field: &str, value: &Option<String>) {
+    if let Some(value) = value {
+        data.insert(Yaml::String(field.to_string()), Yaml::String(value.clone()));
+    }
+}
+
+/// Reads string field which may be absent
+
+#[inline]
+fn read_optional(record: &Record, d: &Yaml, field: &str) -> Result<Option<String>, LoadError> {
+    match &d[field] {
+        Yaml::BadValue => Ok(None),
+        _ => record.str(d, field).map(Some),
+    }
+}
+
+/// Parses default period, rules and limits from yaml document.
+/// Policy without limits has none
+
+fn policy_from_yaml(path: &Path, doc: &Yaml) -> Result<LoanPolicy, LoadError> {
+    match migration::version(doc) {
+        Some(POLICY_VERSION) => {}
+        Some(version) => {
+            return Err(LoadError::file(
+                path,
+                LoadErrorKind::UnsupportedVersion(version),
+            ))
+        }
+        None => return Err(LoadError::file(path, LoadErrorKind::WrongLayout)),
+    }
+
+    let default_period = doc[DEFAULT_KEY]
+        .as_i64()
+        .ok_or_else(|| LoadError::file(path, LoadErrorKind::WrongLayout))?;
+
+    let default_period = u16::try_from(default_period)
+        .ok()
+        .filter(|&period| check_period(period).is_ok())
+        .ok_or_else(|| LoadError::file(path, LoadErrorKind::InvalidValue))?;
+
+    let rules = doc[RULES_KEY]
+        .as_vec()
+        .ok_or_else(|| LoadError::file(path, LoadErrorKind::WrongLayout))?
+        .iter()
+        .enumerate()
+        .map(|(ind, d)| {
+            let record = Record::new(path, ind);
+
+            let rule = LoanRule::new(
+                read_optional(&record, d, "Category")?.as_deref(),
+                read_optional(&record, d, "Genre")?.as_deref(),
+                record.int(d, "Period")?,
+            );
+
+            match check_period(rule.period) {
+                Ok(_) => Ok(rule),
+                Err(_) => Err(record.error("Period", LoadErrorKind::InvalidValue)),
+            }
+        })
+        .collect::<Result<Vec<_>, LoadError>>()?;
+
+    let limits = match &doc[LIMITS_KEY] {
+        Yaml::BadValue => vec![],
+
+        limits => limits
+            .as_vec()
+            .ok_or_else(|| LoadError::file(path, LoadErrorKind::WrongLayout))?
+            .iter()
+            .enumerate()
+            .map(|(ind, d)| {
+                let record = Record::new(path, ind);
+
+                let limit = match record.str(d, "Limit")?.as_str() {
+                    "MaxLoans" => Limit::MaxLoans(record.int(d, "Max")?),
+                    "NoOverdue" => Limit::NoOverdue,
+                    "NotLoanable" => Limit::NotLoanable,
+                    _ => return Err(record.error("Limit", LoadErrorKind::InvalidValue)),
+                };
+
+                Ok(LimitRule::new(
+                    read_optional(&record, d, "Category")?.as_deref(),
+                    read_optional(&record, d, "Genre")?.as_deref(),
+                    read_optional(&record, d, "Type")?.as_deref(),
+                    limit,
+                ))
+            })
+            .collect::<Result<Vec<_>, LoadError>>()?,
+    };
+
+    Ok(LoanPolicy {
+        path: None,
+        default_period,
+        rules,
+        limits,
+    })
+}
@@ -0,0 +1,91 @@
+use crate::{auth::AccessDenied, Lang};
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    io,
+};
+
+/// Error of operation with reader
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReaderError {
+    /// Reader with such index isn't found
+    NotFound,
+
+    /// Reader with same name, 2-nd name, mid. name and birth date exists
+    AlreadyExists,
+
+    /// Name is empty
+    EmptyName,
+
+    /// 2-nd name is empty
+    EmptyFamily,
+
+    /// Mid. name is empty
+    EmptyFather,
+
+    /// Info is empty
+    EmptyInfo,
+
+    /// Reader is already reading book with same params
+    StillReading,
+
+    /// Reader isn't reading chosen book
+    NotReading,
+
+    /// User's role doesn't allow operation
+    NoAccess,
+
+    /// Change can't be saved to storage
+    Io(io::ErrorKind),
+}
+
+impl ReaderError {
+    /// Localized description of error
+
+    #[inline]
+    pub fn message(&self, lang: Lang) -> &'static str {
+        lang.tr(match self {
+            ReaderError::NotFound => "reader-error-not-found",
+            ReaderError::AlreadyExists => "reader-error-already-exists",
+            ReaderError::EmptyName => "reader-error-empty-name",
+            ReaderError::EmptyFamily => "reader-error-empty-family",
+            ReaderError::EmptyFather => "reader-error-empty-father",
+            ReaderError::EmptyInfo => "reader-error-empty-info",
+            ReaderError::StillReading => "reader-error-still-reading",
+            ReaderError::NotReading => "reader-error-not-reading",
+            ReaderError::NoAccess => "access-denied",
+            ReaderError::Io(_) => "reader-error-io",
+        })
+    }
+}
+
+impl Display for ReaderError {
+    /// Describes error in english
+
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message(Lang::default()))
+    }
+}
+
+impl Error for ReaderError {}
+
+impl From<AccessDenied> for ReaderError {
+    /// Operation isn't allowed for user's role
+
+    #[inline]
+    fn from(_: AccessDenied) -> Self {
+        ReaderError::NoAccess
+    }
+}
+
+impl From<io::Error> for ReaderError {
+    /// Change isn't saved
+
+    #[inline]
+    fn from(err: io::Error) -> Self {
+        ReaderError::Io(err.kind())
+    }
+}
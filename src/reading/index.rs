@@ -0,0 +1,125 @@
+use crate::{
+    books::date::Date,
+    id::{Id, Positions},
+    reading::reader::Reader,
+};
+
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+/// Identity of reader:
+/// name, 2-nd name, mid. name and birth date
+
+type ReaderKey = (String, String, String, Date);
+
+/// Hash indexes of Reader Base.
+/// Finds readers by id and by identity
+/// without scanning all readers
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ReaderIndex {
+    positions: Positions,
+    keys: HashMap<ReaderKey, Id>,
+}
+
+impl ReaderIndex {
+    /// Creates empty index
+
+    #[inline]
+    pub(crate) fn new() -> Self {
+        ReaderIndex::default()
+    }
+
+    /// Builds index of all readers
+
+    #[inline]
+    pub(crate) fn build(readers: &[Rc<RefCell<Reader>>]) -> Self {
+        let mut index = ReaderIndex::new();
+
+        readers
+            .iter()
+            .enumerate()
+            .for_each(|(pos, reader)| index.insert(&(**reader).borrow(), pos));
+
+        index
+    }
+
+    /// Identity of reader
+
+    #[inline]
+    fn key(reader: &Reader) -> ReaderKey {
+        (
+            reader.name.clone(),
+            reader.family.clone(),
+            reader.father.clone(),
+            reader.birth,
+        )
+    }
+
+    /// Position of reader with chosen id
+
+    #[inline]
+    pub(crate) fn position(&self, id: Id) -> Option<usize> {
+        self.positions.get(id)
+    }
+
+    /// Position of reader with chosen params
+
+    #[inline]
+    pub(crate) fn find(
+        &self,
+        name: &String,
+        family: &String,
+        father: &String,
+        birth: Date,
+    ) -> Option<usize> {
+        self.keys
+            .get(&(name.clone(), family.clone(), father.clone(), birth))
+            .and_then(|&id| self.position(id))
+    }
+
+    /// Remembers reader inserted at chosen position
+
+    #[inline]
+    pub(crate) fn insert(&mut self, reader: &Reader, pos: usize) {
+        self.positions.insert(reader.id, pos);
+        self.keys
+            .entry(ReaderIndex::key(reader))
+            .or_insert(reader.id);
+    }
+
+    /// Forgets removed reader
+
+    #[inline]
+    pub(crate) fn remove(&mut self, reader: &Reader) {
+        self.positions.remove(reader.id);
+        self.forget_key(reader);
+    }
+
+    /// Forgets identity of reader before it's changed
+
+    #[inline]
+    pub(crate) fn forget_key(&mut self, reader: &Reader) {
+        let key = ReaderIndex::key(reader);
+
+        if self.keys.get(&key) == Some(&reader.id) {
+            self.keys.remove(&key);
+        }
+    }
+
+    /// Remembers identity of reader after it's changed
+
+    #[inline]
+    pub(crate) fn remember_key(&mut self, reader: &Reader) {
+        self.keys
+            .entry(ReaderIndex::key(reader))
+            .or_insert(reader.id);
+    }
+
+    /// Forgets all readers
+
+    #[inline]
+    pub(crate) fn clear(&mut self) {
+        self.positions.clear();
+        self.keys.clear();
+    }
+}
@@ -1,2 +1,4 @@
+pub mod error;
+pub(crate) mod index;
 pub mod read_base;
 pub(crate) mod reader;
@@ -1,26 +1,27 @@
-extern crate yaml_rust;
-
 use crate::{
     books::{book::Book, date::Date, ResultSelf},
-    reading::reader::Reader,
+    data::error::{LoadError, LoadErrorKind, Record},
+    id::{Id, IdGen},
+    reading::{error::ReaderError, index::ReaderIndex, reader::Reader},
+    storage::{ReaderRecord, Storage},
 };
 
 use std::{
     cell::RefCell,
+    collections::HashSet,
     fmt::{Debug, Formatter},
-    fs::File,
-    io::{Read, Write},
+    io,
     iter::FromIterator,
     rc::{Rc, Weak},
 };
 
-use yaml_rust::{yaml::Hash, Yaml, YamlEmitter, YamlLoader};
-
 /// Reader Base structure,
 /// which contains only readers
 
 pub struct ReaderBase {
     pub(crate) readers: Vec<Rc<RefCell<Reader>>>,
+    pub(crate) ids: IdGen,
+    index: ReaderIndex,
 }
 
 impl Debug for ReaderBase {
@@ -52,6 +53,8 @@ impl Clone for ReaderBase {
                 .iter()
                 .map(|x| Rc::new(RefCell::new((**x).borrow().clone())))
                 .collect(),
+            ids: self.ids,
+            index: self.index.clone(),
         }
     }
 }
@@ -73,8 +76,14 @@ impl FromIterator<Rc<RefCell<Reader>>> for ReaderBase {
 
     #[inline]
     fn from_iter<T: IntoIterator<Item = Rc<RefCell<Reader>>>>(iter: T) -> Self {
+        let readers = iter.into_iter().collect::<Vec<_>>();
+        let mut ids = IdGen::new();
+        readers.iter().for_each(|x| ids.observe((**x).borrow().id));
+        let index = ReaderIndex::build(&readers);
         ReaderBase {
-            readers: iter.into_iter().collect(),
+            readers,
+            ids,
+            index,
         }
     }
 }
@@ -83,8 +92,12 @@ impl ReaderBase {
     /// Creates empty Reader Base
 
     #[inline]
-    pub const fn new() -> Self {
-        ReaderBase { readers: vec![] }
+    pub fn new() -> Self {
+        ReaderBase {
+            readers: vec![],
+            ids: IdGen::new(),
+            index: ReaderIndex::new(),
+        }
     }
 
     /// Iterate on Book System with smart pointers of The Book
@@ -113,12 +126,14 @@ impl ReaderBase {
         father: &String,
         birth: Date,
     ) -> Option<usize> {
-        self.iter().position(|x| {
-            (**x).borrow().name == *name
-                && (**x).borrow().family == *family
-                && (**x).borrow().father == *father
-                && (**x).borrow().birth == birth
-        })
+        self.index.find(name, family, father, birth)
+    }
+
+    /// Searches reader by id
+
+    #[inline]
+    pub(crate) fn find_reader_by_id(&self, id: Id) -> Option<usize> {
+        self.index.position(id)
     }
 
     /// Adds reader by params
@@ -134,34 +149,40 @@ impl ReaderBase {
         info: String,
         birth: Date,
     ) -> &mut Self {
-        let reader = Rc::new(RefCell::new(Reader::new(name, family, father, info, birth)));
-
-        if self.readers.is_empty() {
-            self.readers.push(reader);
+        let reader = Rc::new(RefCell::new(Reader::new(
+            self.ids.next(),
+            name,
+            family,
+            father,
+            info,
+            birth,
+        )));
+
+        let pos = if self.readers.is_empty() {
+            0
         } else {
-            self.readers.insert(
-                self.readers
-                    .binary_search_by(|r| {
-                        format!(
-                            "{} {} {} {}",
-                            (**r).borrow().name,
-                            (**r).borrow().family,
-                            (**r).borrow().father,
-                            (**r).borrow().birth.to_string()
-                        )
-                        .cmp(&format!(
-                            "{} {} {} {}",
-                            (*reader).borrow().name,
-                            (*reader).borrow().family,
-                            (*reader).borrow().father,
-                            (*reader).borrow().birth.to_string()
-                        ))
-                    })
-                    .unwrap_err(),
-                reader,
-            );
-        }
+            self.readers
+                .binary_search_by(|r| {
+                    format!(
+                        "{} {} {} {}",
+                        (**r).borrow().name,
+                        (**r).borrow().family,
+                        (**r).borrow().father,
+                        (**r).borrow().birth.to_string()
+                    )
+                    .cmp(&format!(
+                        "{} {} {} {}",
+                        (*reader).borrow().name,
+                        (*reader).borrow().family,
+                        (*reader).borrow().father,
+                        (*reader).borrow().birth.to_string()
+                    ))
+                })
+                .unwrap_err()
+        };
 
+        self.index.insert(&(*reader).borrow(), pos);
+        self.readers.insert(pos, reader);
         self
     }
 
@@ -177,11 +198,11 @@ impl ReaderBase {
         father: String,
         info: String,
         birth: Date,
-    ) -> ResultSelf<Self> {
+    ) -> ResultSelf<Self, ReaderError> {
         return if !self.readers.is_empty()
             && self.find_reader(&name, &family, &father, birth).is_some()
         {
-            Err(0) // already exists
+            Err(ReaderError::AlreadyExists)
         } else {
             Ok(unsafe { self.add_reader_unchecked(name, family, father, info, birth) })
         };
@@ -195,7 +216,7 @@ impl ReaderBase {
         (**self.readers.get_unchecked_mut(ind))
             .borrow_mut()
             .remove_all_books();
-        self.readers.remove(ind);
+        self.index.remove(&self.readers.remove(ind).borrow());
         self
     }
 
@@ -204,9 +225,9 @@ impl ReaderBase {
     /// it will report error
 
     #[inline]
-    pub(crate) fn remove_reader(&mut self, ind: usize) -> ResultSelf<Self> {
+    pub(crate) fn remove_reader(&mut self, ind: usize) -> ResultSelf<Self, ReaderError> {
         return if ind >= self.len() {
-            Err(0) // out of range
+            Err(ReaderError::NotFound)
         } else {
             Ok(unsafe { self.remove_reader_unchecked(ind) })
         };
@@ -221,10 +242,11 @@ impl ReaderBase {
         ind: usize,
         new_name: String,
     ) -> &mut Self {
-        (**self.readers.get_unchecked_mut(ind))
-            .borrow_mut()
-            .change_name(new_name)
-            .unwrap();
+        let mut reader = (**self.readers.get_unchecked(ind)).borrow_mut();
+        self.index.forget_key(&reader);
+        reader.change_name(new_name).unwrap();
+        self.index.remember_key(&reader);
+        drop(reader);
         self
     }
 
@@ -233,9 +255,15 @@ impl ReaderBase {
     /// it will report error
 
     #[inline]
-    pub(crate) fn change_name(&mut self, ind: usize, new_name: String) -> ResultSelf<Self> {
+    pub(crate) fn change_name(
+        &mut self,
+        ind: usize,
+        new_name: String,
+    ) -> ResultSelf<Self, ReaderError> {
         return if ind >= self.len() {
-            Err(0) // out of range
+            Err(ReaderError::NotFound)
+        } else if new_name.is_empty() {
+            Err(ReaderError::EmptyName)
         } else {
             unsafe {
                 if self
@@ -247,7 +275,7 @@ impl ReaderBase {
                     )
                     .is_some()
                 {
-                    Err(1) // already exists
+                    Err(ReaderError::AlreadyExists)
                 } else {
                     Ok(self.change_name_unchecked(ind, new_name))
                 }
@@ -264,10 +292,11 @@ impl ReaderBase {
         ind: usize,
         new_family: String,
     ) -> &mut Self {
-        (**self.readers.get_unchecked_mut(ind))
-            .borrow_mut()
-            .change_family(new_family)
-            .unwrap();
+        let mut reader = (**self.readers.get_unchecked(ind)).borrow_mut();
+        self.index.forget_key(&reader);
+        reader.change_family(new_family).unwrap();
+        self.index.remember_key(&reader);
+        drop(reader);
         self
     }
 
@@ -276,9 +305,15 @@ impl ReaderBase {
     /// it will report error
 
     #[inline]
-    pub(crate) fn change_family(&mut self, ind: usize, new_family: String) -> ResultSelf<Self> {
+    pub(crate) fn change_family(
+        &mut self,
+        ind: usize,
+        new_family: String,
+    ) -> ResultSelf<Self, ReaderError> {
         return if ind >= self.len() {
-            Err(0) // out of range
+            Err(ReaderError::NotFound)
+        } else if new_family.is_empty() {
+            Err(ReaderError::EmptyFamily)
         } else {
             unsafe {
                 if self
@@ -290,7 +325,7 @@ impl ReaderBase {
                     )
                     .is_some()
                 {
-                    Err(1) // already exists
+                    Err(ReaderError::AlreadyExists)
                 } else {
                     Ok(self.change_family_unchecked(ind, new_family))
                 }
@@ -307,10 +342,11 @@ impl ReaderBase {
         ind: usize,
         new_father: String,
     ) -> &mut Self {
-        (**self.readers.get_unchecked_mut(ind))
-            .borrow_mut()
-            .change_father(new_father)
-            .unwrap();
+        let mut reader = (**self.readers.get_unchecked(ind)).borrow_mut();
+        self.index.forget_key(&reader);
+        reader.change_father(new_father).unwrap();
+        self.index.remember_key(&reader);
+        drop(reader);
         self
     }
 
@@ -319,9 +355,15 @@ impl ReaderBase {
     /// it will report error
 
     #[inline]
-    pub(crate) fn change_father(&mut self, ind: usize, new_father: String) -> ResultSelf<Self> {
+    pub(crate) fn change_father(
+        &mut self,
+        ind: usize,
+        new_father: String,
+    ) -> ResultSelf<Self, ReaderError> {
         return if ind >= self.len() {
-            Err(0) // out of range
+            Err(ReaderError::NotFound)
+        } else if new_father.is_empty() {
+            Err(ReaderError::EmptyFather)
         } else {
             unsafe {
                 if self
@@ -333,7 +375,7 @@ impl ReaderBase {
                     )
                     .is_some()
                 {
-                    Err(1) // already exists
+                    Err(ReaderError::AlreadyExists)
                 } else {
                     Ok(self.change_father_unchecked(ind, new_father))
                 }
@@ -362,22 +404,48 @@ impl ReaderBase {
     /// it will report error
 
     #[inline]
-    pub(crate) fn change_info(&mut self, ind: usize, new_info: String) -> ResultSelf<Self> {
+    pub(crate) fn change_info(
+        &mut self,
+        ind: usize,
+        new_info: String,
+    ) -> ResultSelf<Self, ReaderError> {
         return if ind >= self.len() {
-            Err(0) // out of range
+            Err(ReaderError::NotFound)
+        } else if new_info.is_empty() {
+            Err(ReaderError::EmptyInfo)
         } else {
             Ok(unsafe { self.change_info_unchecked(ind, new_info) })
         };
     }
 
+    /// Changes reader's category.
+    /// Category is trimmed, empty one means no category
+
+    #[inline]
+    pub(crate) fn change_category(
+        &mut self,
+        ind: usize,
+        new_category: String,
+    ) -> ResultSelf<Self, ReaderError> {
+        return if ind >= self.len() {
+            Err(ReaderError::NotFound)
+        } else {
+            unsafe { (**self.readers.get_unchecked_mut(ind)).borrow_mut() }
+                .change_category(new_category.trim().to_string());
+            Ok(self)
+        };
+    }
+
     /// Changes reader's age.
     /// No checks provided
 
     #[inline]
     pub(crate) unsafe fn change_age_unchecked(&mut self, ind: usize, new_birth: Date) -> &mut Self {
-        (**self.readers.get_unchecked_mut(ind))
-            .borrow_mut()
-            .change_age(new_birth);
+        let mut reader = (**self.readers.get_unchecked(ind)).borrow_mut();
+        self.index.forget_key(&reader);
+        reader.change_age(new_birth);
+        self.index.remember_key(&reader);
+        drop(reader);
         self
     }
 
@@ -386,9 +454,13 @@ impl ReaderBase {
     /// it will report error
 
     #[inline]
-    pub(crate) fn change_age(&mut self, ind: usize, new_birth: Date) -> ResultSelf<Self> {
+    pub(crate) fn change_age(
+        &mut self,
+        ind: usize,
+        new_birth: Date,
+    ) -> ResultSelf<Self, ReaderError> {
         return if ind >= self.len() {
-            Err(0) // out of range
+            Err(ReaderError::NotFound)
         } else {
             unsafe {
                 if self
@@ -400,7 +472,7 @@ impl ReaderBase {
                     )
                     .is_some()
                 {
-                    Err(1) // already exists
+                    Err(ReaderError::AlreadyExists)
                 } else {
                     Ok(self.change_age_unchecked(ind, new_birth))
                 }
@@ -425,138 +497,191 @@ impl ReaderBase {
     #[allow(dead_code)]
     pub(crate) fn clear(&mut self) -> &mut Self {
         self.readers.clear();
+        self.index.clear();
         self
     }
 
-    /// Saves everything to .yaml file
+    /// Id of reader with chosen index
 
-    pub(crate) fn save(&self) {
-        let mut array = yaml_rust::yaml::Array::new();
+    #[inline]
+    pub(crate) fn id(&self, ind: usize) -> Option<Id> {
+        self.readers.get(ind).map(|r| (**r).borrow().id)
+    }
 
-        (0..self.len()).for_each(|guy| {
-            let mut data = Hash::new();
+    /// Reader with chosen id as it's stored
+    /// and its index in Reader Base
 
-            unsafe {
-                data.insert(Yaml::String("№".to_string()), Yaml::Integer(guy as i64 + 1));
-
-                data.insert(
-                    Yaml::String("Name".to_string()),
-                    Yaml::String((**self.readers.get_unchecked(guy)).borrow().name.clone()),
-                );
-
-                data.insert(
-                    Yaml::String("Family".to_string()),
-                    Yaml::String((**self.readers.get_unchecked(guy)).borrow().family.clone()),
-                );
-
-                data.insert(
-                    Yaml::String("Father".to_string()),
-                    Yaml::String((**self.readers.get_unchecked(guy)).borrow().father.clone()),
-                );
-
-                data.insert(
-                    Yaml::String("Info".to_string()),
-                    Yaml::String((**self.readers.get_unchecked(guy)).borrow().info.clone()),
-                );
-
-                data.insert(
-                    Yaml::String("Day".to_string()),
-                    Yaml::Integer((**self.readers.get_unchecked(guy)).borrow().birth.day as i64),
-                );
-
-                data.insert(
-                    Yaml::String("Month".to_string()),
-                    Yaml::Integer(
-                        RefCell::borrow(&(**self.readers.get_unchecked(guy)))
-                            .birth
-                            .month as i64,
-                    ),
-                );
-
-                data.insert(
-                    Yaml::String("Year".to_string()),
-                    Yaml::Integer(
-                        RefCell::borrow(&(**self.readers.get_unchecked(guy)))
-                            .birth
-                            .year as i64,
-                    ),
-                );
-
-                data.insert(
-                    Yaml::String("Reading".to_string()),
-                    Yaml::Array(
-                        if RefCell::borrow(&(**self.readers.get_unchecked(guy)))
-                            .reading
-                            .is_some()
-                        {
-                            (**self.readers.get_unchecked(guy))
-                                .borrow()
-                                .reading
-                                .as_ref()
-                                .unwrap()
-                                .iter()
-                                .map(|b| {
-                                    Yaml::String(format!(
-                                        "{} {} {}",
-                                        (*b.upgrade().unwrap()).borrow().title(),
-                                        (*b.upgrade().unwrap()).borrow().author(),
-                                        (*b.upgrade().unwrap()).borrow().pages()
-                                    ))
-                                })
-                                .collect()
-                        } else {
-                            vec![Yaml::String("None".to_string())]
-                        },
-                    ),
-                );
-            }
+    #[inline]
+    pub(crate) fn record(&self, id: Id) -> Option<(usize, ReaderRecord)> {
+        self.find_reader_by_id(id).map(|ind| {
+            (
+                ind,
+                ReaderRecord::from(unsafe { &*(**self.readers.get_unchecked(ind)).borrow() }),
+            )
+        })
+    }
 
-            array.push(Yaml::Hash(data));
-        });
+    /// Inserts stored reader to chosen index.
+    /// It has no books, they are linked by Book System
 
-        let mut string = String::new();
-        let mut emitter = YamlEmitter::new(&mut string);
-        emitter.dump(&Yaml::Array(array)).unwrap();
+    #[inline]
+    pub(crate) fn insert_record(&mut self, ind: usize, record: &ReaderRecord) -> &mut Self {
+        let mut reader = Reader::new(
+            record.id,
+            record.name.clone(),
+            record.family.clone(),
+            record.father.clone(),
+            record.info.clone(),
+            record.birth,
+        );
+
+        reader.category = record.category.clone();
+
+        self.ids.observe(reader.id);
+        self.index.insert(&reader, ind);
+        self.readers.insert(ind, Rc::new(RefCell::new(reader)));
+        self
+    }
 
-        File::create("src/utils/readers.yaml")
-            .unwrap()
-            .write_all(string.as_bytes())
-            .unwrap();
+    /// Replaces params of reader with stored ones.
+    /// Reader is found by record's id
+
+    #[inline]
+    pub(crate) fn update_record(&mut self, record: &ReaderRecord) -> &mut Self {
+        if let Some(ind) = self.find_reader_by_id(record.id) {
+            let mut reader = unsafe { (**self.readers.get_unchecked(ind)).borrow_mut() };
+            self.index.forget_key(&reader);
+
+            reader.name = record.name.clone();
+            reader.family = record.family.clone();
+            reader.father = record.father.clone();
+            reader.info = record.info.clone();
+            reader.birth = record.birth;
+            reader.category = record.category.clone();
+
+            self.index.remember_key(&reader);
+        }
+
+        self
     }
 
-    /// Loads everything from .yaml file
-
-    #[inline]
-    pub fn load(&mut self) {
-        let mut file = File::open("src/utils/readers.yaml").unwrap();
-        let mut string = String::new();
-        file.read_to_string(&mut string).unwrap();
-
-        if !string.is_empty() {
-            let doc = YamlLoader::load_from_str(string.as_str())
-                .unwrap()
-                .first()
-                .unwrap()
-                .clone()
-                .into_vec()
-                .unwrap();
-
-            doc.into_iter().for_each(|d| {
-                self.readers.push(Rc::new(RefCell::new(Reader::new(
-                    d["Name"].as_str().unwrap().to_string(),
-                    d["Family"].as_str().unwrap().to_string(),
-                    d["Father"].as_str().unwrap().to_string(),
-                    d["Info"].as_str().unwrap().to_string(),
-                    Date::new(
-                        d["Day"].as_i64().unwrap() as u8,
-                        d["Month"].as_i64().unwrap() as u8,
-                        d["Year"].as_i64().unwrap() as u16,
-                    )
-                    .unwrap(),
-                ))));
+    /// Removes reader with chosen id and all his history
 
-                (*self.readers.last_mut().unwrap()).borrow_mut().reading = None;
+    #[inline]
+    pub(crate) fn remove_record(&mut self, id: Id) -> &mut Self {
+        if let Some(ind) = self.find_reader_by_id(id) {
+            unsafe { self.remove_reader_unchecked(ind) };
+        }
+
+        self
+    }
+
+    /// Ids of all The Books,
+    /// simple books of which reader has ever taken
+
+    #[inline]
+    pub(crate) fn book_ids(&self, ind: usize) -> Vec<Id> {
+        let mut ids = self
+            .readers
+            .get(ind)
+            .map(|reader| {
+                (**reader)
+                    .borrow()
+                    .books
+                    .iter()
+                    .filter_map(|b| b.upgrade())
+                    .filter_map(|b| (*b).borrow().the_book.as_ref().and_then(|t| t.upgrade()))
+                    .map(|t| (*t).borrow().id)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    /// All readers as they're stored
+
+    #[inline]
+    pub(crate) fn records(&self) -> Vec<ReaderRecord> {
+        self.iter()
+            .map(|x| ReaderRecord::from(&*(**x).borrow()))
+            .collect()
+    }
+
+    /// Saves all readers to storage
+
+    #[inline]
+    pub(crate) fn save(&self, storage: &dyn Storage) -> io::Result<()> {
+        storage.write_readers(&self.records())
+    }
+
+    /// Loads all readers from storage.
+    /// If any record is incorrect,
+    /// it will return error and Reader Base will stay unchanged
+
+    #[inline]
+    pub fn load(&mut self, storage: &dyn Storage) -> Result<(), LoadError> {
+        self.load_records(storage, false).map(|_| ())
+    }
+
+    /// Loads all readers from storage
+    /// and skips incorrect records.
+    /// Returns errors of all skipped records
+
+    #[inline]
+    pub fn load_skip_bad(&mut self, storage: &dyn Storage) -> Result<Vec<LoadError>, LoadError> {
+        self.load_records(storage, true)
+    }
+
+    /// Loads readers from storage.
+    /// Errors in records are either skipped or returned
+
+    fn load_records(
+        &mut self,
+        storage: &dyn Storage,
+        skip_bad: bool,
+    ) -> Result<Vec<LoadError>, LoadError> {
+        let path = storage.readers_path();
+        let mut skipped = vec![];
+        let mut readers = vec![];
+        let mut ids = self.ids;
+        let mut used = self
+            .iter()
+            .map(|x| (**x).borrow().id)
+            .collect::<HashSet<_>>();
+
+        for (ind, record) in storage.read_readers()?.into_iter().enumerate() {
+            let record = record.and_then(|r| {
+                if used.contains(&r.id) {
+                    Err(Record::new(&path, ind).error("Id", LoadErrorKind::DuplicateId))
+                } else {
+                    Ok(r)
+                }
             });
+
+            match record {
+                Ok(r) => {
+                    ids.observe(r.id);
+                    used.insert(r.id);
+
+                    let mut reader = Reader::new(r.id, r.name, r.family, r.father, r.info, r.birth);
+
+                    reader.category = r.category;
+                    readers.push(Rc::new(RefCell::new(reader)));
+                }
+
+                Err(err) if skip_bad => skipped.push(err),
+                Err(err) => return Err(err),
+            }
         }
+
+        self.ids = ids;
+        readers.into_iter().for_each(|reader| {
+            self.index.insert(&(*reader).borrow(), self.readers.len());
+            self.readers.push(reader);
+        });
+        Ok(skipped)
     }
 }
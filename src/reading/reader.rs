@@ -1,4 +1,8 @@
-use crate::books::{book::Book, date::Date, ResultSelf};
+use crate::{
+    books::{book::Book, date::Date, ResultSelf},
+    id::Id,
+    reading::error::ReaderError,
+};
 
 use std::{
     cell::RefCell,
@@ -7,15 +11,18 @@ use std::{
 };
 
 /// Reader structure, which contains
-/// name, family, father, age, ~~simple~~ books he' d read
+/// name, family, father, age, category (may be empty),
+/// ~~simple~~ books he' d read
 /// and books which he is reading now (or None)
 
 pub struct Reader {
+    pub(crate) id: Id,
     pub(crate) name: String,
     pub(crate) family: String,
     pub(crate) father: String,
     pub(crate) info: String,
     pub(crate) birth: Date,
+    pub(crate) category: String,
     pub(crate) books: Vec<Weak<RefCell<Book>>>,
     pub(crate) reading: Option<Vec<Weak<RefCell<Book>>>>,
 }
@@ -88,11 +95,13 @@ impl Clone for Reader {
     #[inline]
     fn clone(&self) -> Self {
         Reader {
+            id: self.id,
             name: self.name.clone(),
             family: self.family.clone(),
             father: self.father.clone(),
             info: self.info.clone(),
             birth: self.birth.clone(),
+            category: self.category.clone(),
             books: vec![],
             reading: None,
         }
@@ -107,12 +116,13 @@ impl Clone for Reader {
 }
 
 impl Reader {
-    /// Creates new Reader with chosen
+    /// Creates new Reader with chosen id,
     /// 1-st name, 2-nd name, mid. name and age.
-    /// It has no books
+    /// It has no books and no category
 
     #[inline]
     pub(crate) const fn new(
+        id: Id,
         new_name: String,
         new_family: String,
         new_father: String,
@@ -120,11 +130,13 @@ impl Reader {
         new_birth: Date,
     ) -> Self {
         Reader {
+            id,
             name: new_name,
             family: new_family,
             father: new_father,
             info: new_info,
             birth: new_birth,
+            category: String::new(),
             books: vec![],
             reading: None,
         }
@@ -132,7 +144,7 @@ impl Reader {
 
     #[inline]
     pub(crate) fn age(&self) -> u16 {
-        Date::from(chrono::Local::now()) - self.birth
+        Date::today() - self.birth
     }
 
     /// Find book by smart pointer.
@@ -200,14 +212,14 @@ impl Reader {
         &mut self,
         book: &Rc<RefCell<Book>>,
         date: &Date,
-    ) -> ResultSelf<Self> {
+    ) -> ResultSelf<Self, ReaderError> {
         match &mut self.reading {
             None => self.reading = Some(vec![Rc::downgrade(&book)]),
             Some(books) => {
                 match books.iter().position(|b| {
-                    (*b.upgrade().unwrap()).borrow().title() == (**book).borrow().title()
-                        && (*b.upgrade().unwrap()).borrow().author() == (**book).borrow().author()
-                        && (*b.upgrade().unwrap()).borrow().pages() == (**book).borrow().pages()
+                    (*b.upgrade().unwrap())
+                        .borrow()
+                        .is_copy_of_same(&(**book).borrow())
                 }) {
                     None => {
                         match books.binary_search_by(|b| {
@@ -220,7 +232,7 @@ impl Reader {
                         }
                     }
 
-                    Some(_) => return Err(0), // already reading this kind of book
+                    Some(_) => return Err(ReaderError::StillReading),
                 }
             }
         }
@@ -314,9 +326,9 @@ impl Reader {
     /// Changes reader's name
 
     #[inline]
-    pub(crate) fn change_name(&mut self, new_name: String) -> ResultSelf<Self> {
+    pub(crate) fn change_name(&mut self, new_name: String) -> ResultSelf<Self, ReaderError> {
         return if new_name.is_empty() {
-            Err(0)
+            Err(ReaderError::EmptyName)
         } else {
             self.name = new_name;
             Ok(self)
@@ -326,9 +338,9 @@ impl Reader {
     /// Changes reader's 2-nd name
 
     #[inline]
-    pub(crate) fn change_family(&mut self, new_family: String) -> ResultSelf<Self> {
+    pub(crate) fn change_family(&mut self, new_family: String) -> ResultSelf<Self, ReaderError> {
         return if new_family.is_empty() {
-            Err(0)
+            Err(ReaderError::EmptyFamily)
         } else {
             self.family = new_family;
             Ok(self)
@@ -338,9 +350,9 @@ impl Reader {
     /// Changes reader's mid. name
 
     #[inline]
-    pub(crate) fn change_father(&mut self, new_father: String) -> ResultSelf<Self> {
+    pub(crate) fn change_father(&mut self, new_father: String) -> ResultSelf<Self, ReaderError> {
         return if new_father.is_empty() {
-            Err(0)
+            Err(ReaderError::EmptyFather)
         } else {
             self.father = new_father;
             Ok(self)
@@ -350,15 +362,24 @@ impl Reader {
     /// Changes reader's info
 
     #[inline]
-    pub(crate) fn change_info(&mut self, new_info: String) -> ResultSelf<Self> {
+    pub(crate) fn change_info(&mut self, new_info: String) -> ResultSelf<Self, ReaderError> {
         return if new_info.is_empty() {
-            Err(0)
+            Err(ReaderError::EmptyInfo)
         } else {
             self.info = new_info;
             Ok(self)
         };
     }
 
+    /// Changes reader's category.
+    /// Empty category means that reader has no category
+
+    #[inline]
+    pub(crate) fn change_category(&mut self, new_category: String) -> &mut Self {
+        self.category = new_category;
+        self
+    }
+
     /// Changes reader's birthday
 
     #[inline]
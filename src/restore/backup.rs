@@ -0,0 +1,92 @@
+extern crate fltk;
+
+use crate::{auth::AccessDenied, library::Library, restore::error::RestoreError, Lang};
+
+use fltk::{
+    app,
+    app::App,
+    browser::HoldBrowser,
+    button::Button,
+    dialog::{alert, message},
+    prelude::*,
+    window::SingleWindow,
+};
+
+/// Function that restores all data
+/// from one of timestamped backups.
+/// Current state is saved to caretaker,
+/// so restoring can be undone
+
+#[inline]
+pub fn restore_backup(library: &mut Library, app: &App, lang: Lang) {
+    let backups = library.storage().backups().unwrap_or_default();
+
+    if backups.is_empty() {
+        alert(500, 500, lang.tr("there-are-no-backups-yet"));
+        return;
+    }
+
+    let (s, r) = app::channel();
+
+    let mut wind = SingleWindow::new(500, 100, 300, 400, lang.tr("restore-backup"));
+
+    let mut browser = HoldBrowser::new(0, 0, 300, 360, "");
+    backups
+        .iter()
+        .for_each(|(name, _)| browser.add(name.as_str()));
+
+    let mut ok = Button::new(210, 370, 80, 25, lang.tr("restore"));
+
+    ok.emit(s, true);
+
+    wind.end();
+    wind.show();
+
+    while app.wait() {
+        if let Some(true) = r.recv() {
+            let ind = browser.value();
+
+            if ind <= 0 {
+                alert(500, 500, lang.tr("backup-isnt-selected"));
+                continue;
+            }
+
+            wind.hide();
+            let backup = &*unsafe { backups.get_unchecked(ind as usize - 1) }.1;
+
+            match library.restore_from(backup) {
+                Ok(_) => {}
+
+                Err(RestoreError::NoAccess) => {
+                    alert(500, 500, AccessDenied.message(lang));
+                    return;
+                }
+
+                Err(RestoreError::Load(err)) => {
+                    alert(
+                        500,
+                        500,
+                        format!("{}\n{}", lang.tr("backup-is-damaged"), err).as_str(),
+                    );
+                    return;
+                }
+
+                Err(RestoreError::Io(err)) => {
+                    alert(
+                        500,
+                        500,
+                        format!("{}\n{}", lang.tr("changes-arent-saved"), err).as_str(),
+                    );
+                    return;
+                }
+            }
+
+            message(500, 500, lang.tr("successfully-restored"));
+            return;
+        }
+
+        if !wind.shown() {
+            return;
+        }
+    }
+}
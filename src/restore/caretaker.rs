@@ -1,159 +1,194 @@
-extern crate fltk;
-
 use crate::{
     books::{book_sys::BookSystem, genres::Genres},
     reading::read_base::ReaderBase,
-    restore::memento::Memento,
-    Lang,
+    restore::command::Command,
 };
 
-use fltk::dialog::{alert, message};
+use std::env;
+
+/// Command line flag to choose how many commands are kept.
+/// Can be used as `--history-depth <n>` or `--history-depth=<n>`
+
+pub const HISTORY_DEPTH_FLAG: &str = "--history-depth";
+
+/// Environment variable to choose how many commands are kept
+
+pub const HISTORY_DEPTH_ENV: &str = "LIBRS_HISTORY_DEPTH";
+
+/// How many commands are kept by default
+
+pub const DEFAULT_HISTORY_DEPTH: usize = 100;
 
-/// Struct that holds al mementos
+/// Struct that holds all commands
 /// and makes all
 /// manipulations with them
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct Caretaker {
-    mementos: Vec<Memento>,
+    commands: Vec<Command>,
     ind: usize,
+    depth: usize,
 }
 
-/// Handles with all mementos
-/// by returning states (next or last)
+impl Default for Caretaker {
+    /// Empty history with default depth
+
+    #[inline]
+    fn default() -> Self {
+        Caretaker::new()
+    }
+}
+
+/// Handles with all commands
+/// by undoing or doing them again
 
 impl Caretaker {
     /// Creates new caretaker
 
     #[inline]
     pub const fn new() -> Self {
+        Caretaker::with_depth(DEFAULT_HISTORY_DEPTH)
+    }
+
+    /// Creates new caretaker
+    /// which keeps only last commands.
+    /// If depth is 0, nothing is remembered
+
+    #[inline]
+    pub const fn with_depth(depth: usize) -> Self {
         Caretaker {
-            mementos: vec![],
+            commands: vec![],
             ind: 0,
+            depth,
         }
     }
 
-    /// Amount of mementos
+    /// Chooses depth of history in the next order:
+    /// command line flag, environment variable, default depth.
+    /// Incorrect values are ignored
+
+    #[inline]
+    pub fn depth_from_args<I: IntoIterator<Item = String>>(args: I) -> usize {
+        crate::data::parse_flag(args, HISTORY_DEPTH_FLAG)
+            .and_then(|d| d.parse().ok())
+            .or_else(|| env::var(HISTORY_DEPTH_ENV).ok()?.parse().ok())
+            .unwrap_or(DEFAULT_HISTORY_DEPTH)
+    }
+
+    /// Chooses depth of history for current process
+
+    #[inline]
+    pub fn depth_from_env() -> usize {
+        Caretaker::depth_from_args(env::args().skip(1))
+    }
+
+    /// Restores saved history.
+    /// Done is amount of commands
+    /// which weren't undone. Only last commands
+    /// that fit in depth are kept
+
+    #[inline]
+    pub(crate) fn restore(&mut self, commands: Vec<Command>, done: usize) -> &mut Self {
+        self.ind = done.min(commands.len());
+        self.commands = commands;
+        self.trim();
+        self
+    }
+
+    /// Amount of commands
 
     #[inline]
     pub(crate) fn len(&self) -> usize {
-        self.mementos.len()
+        self.commands.len()
+    }
+
+    /// How many commands are kept
+
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// All remembered commands from oldest to newest
+
+    #[inline]
+    pub fn commands(&self) -> &[Command] {
+        &self.commands
+    }
+
+    /// Amount of commands which weren't undone
+
+    #[inline]
+    pub fn done(&self) -> usize {
+        self.ind
     }
 
-    /// Gets reference to previous memento
+    /// Forgets commands which don't fit in depth.
+    /// Oldest done commands are forgotten first,
+    /// then the newest undone ones
 
     #[inline]
-    pub fn get_memento_back(
+    fn trim(&mut self) {
+        let extra = self.len().saturating_sub(self.depth).min(self.ind);
+        self.commands.drain(..extra);
+        self.ind -= extra;
+        self.commands.truncate(self.depth);
+    }
+
+    /// Returns to previous state.
+    /// If it's the first version,
+    /// nothing is changed and it will return None,
+    /// else it will return undone command
+
+    #[inline]
+    pub fn undo(
         &mut self,
         reader_base: &mut ReaderBase,
         book_system: &mut BookSystem,
         genres: &mut Genres,
-        lang: Lang,
-    ) {
-        if self.ind == 0 {
-            alert(
-                500,
-                500,
-                match lang {
-                    Lang::English => "First version",
-                    Lang::Russian => "Первая версия",
-                },
-            );
+    ) -> Option<&Command> {
+        return if self.ind == 0 {
+            None
         } else {
-            if self.ind == self.len() {
-                self.add_memento(reader_base, book_system, genres);
-                self.ind -= 1;
-            }
-
             self.ind -= 1;
-            let mem = unsafe { self.mementos.get_unchecked(self.ind) }.get_state();
-
-            *reader_base = mem.0.clone();
-            *book_system = mem.1.clone(reader_base);
-            *genres = mem.2.clone();
-
-            reader_base.save();
-            book_system.save();
-            genres.save();
-
-            message(
-                500,
-                500,
-                match lang {
-                    Lang::English => "Successfully restored",
-                    Lang::Russian => "Успешно восстановленно",
-                },
-            );
-        }
+            let command = unsafe { self.commands.get_unchecked(self.ind) };
+            command.inverse().apply(reader_base, book_system, genres);
+            Some(command)
+        };
     }
 
-    /// Gets reference to next memento
+    /// Returns to next state.
+    /// If it's the last version,
+    /// nothing is changed and it will return None,
+    /// else it will return done command
 
     #[inline]
-    pub fn get_memento_forward(
+    pub fn redo(
         &mut self,
         reader_base: &mut ReaderBase,
         book_system: &mut BookSystem,
         genres: &mut Genres,
-        lang: Lang,
-    ) {
-        return if self.len() == 0 || self.ind == self.len() - 1 {
-            alert(
-                500,
-                500,
-                match lang {
-                    Lang::English => "Last version",
-                    Lang::Russian => "Последняя версия",
-                },
-            );
+    ) -> Option<&Command> {
+        return if self.ind == self.len() {
+            None
         } else {
+            let command = unsafe { self.commands.get_unchecked(self.ind) };
+            command.apply(reader_base, book_system, genres);
             self.ind += 1;
-            let mem = unsafe { self.mementos.get_unchecked(self.ind) }.get_state();
-
-            *reader_base = mem.0.clone();
-            *book_system = mem.1.clone(reader_base);
-            *genres = mem.2.clone();
-
-            reader_base.save();
-            book_system.save();
-            genres.save();
-
-            message(
-                500,
-                500,
-                match lang {
-                    Lang::English => "Successfully restored",
-                    Lang::Russian => "Успешно восстановленно",
-                },
-            )
+            Some(command)
         };
     }
 
-    /// Adds new state
+    /// Adds new command after current one.
+    /// All undone commands are forgotten,
+    /// so are the oldest ones if history is too long
 
     #[inline]
-    pub fn add_memento(
-        &mut self,
-        reader_base: &ReaderBase,
-        book_system: &BookSystem,
-        genres: &Genres,
-    ) -> &mut Self {
+    pub(crate) fn push(&mut self, command: Command) -> &mut Self {
+        self.commands.truncate(self.ind);
+        self.commands.push(command);
         self.ind += 1;
-        self.mementos
-            .resize(self.ind, Memento::new(reader_base, book_system, genres));
+        self.trim();
         self
     }
-
-    /// Removes last memento
-
-    #[inline]
-    pub(crate) fn pop(&mut self) -> Option<Memento> {
-        return match self.mementos.pop() {
-            None => None,
-            Some(val) => {
-                self.ind -= 1;
-                Some(val)
-            }
-        };
-    }
 }
@@ -0,0 +1,597 @@
+extern crate chrono;
+
+use crate::{
+    auth::Permission,
+    books::{book_sys::BookSystem, genres::Genres},
+    id::Id,
+    reading::read_base::ReaderBase,
+    storage::{BookRecord, ReaderRecord},
+    Lang,
+};
+
+use std::collections::{BTreeSet, HashMap};
+
+use chrono::{Local, NaiveDateTime};
+
+/// Operation of library
+/// which is remembered in history
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Operation {
+    AddReader,
+    RemoveReader,
+    ChangeName,
+    ChangeFamily,
+    ChangeFather,
+    ChangeInfo,
+    ChangeAge,
+    ChangeCategory,
+    AddBook,
+    AddBooks,
+    RemoveBook,
+    RemoveOneBook,
+    ChangeTitle,
+    ChangeAuthor,
+    ChangePages,
+    ChangeLocation,
+    ChangeCopyType,
+    GiveBook,
+    GiveBookOverLimits,
+    ReturnBook,
+    ChangeReturnDate,
+    AddGenre,
+    RemoveGenre,
+    SetBookGenres,
+    Replace,
+}
+
+impl Operation {
+    /// All operations
+
+    pub const ALL: [Operation; 25] = [
+        Operation::AddReader,
+        Operation::RemoveReader,
+        Operation::ChangeName,
+        Operation::ChangeFamily,
+        Operation::ChangeFather,
+        Operation::ChangeInfo,
+        Operation::ChangeAge,
+        Operation::ChangeCategory,
+        Operation::AddBook,
+        Operation::AddBooks,
+        Operation::RemoveBook,
+        Operation::RemoveOneBook,
+        Operation::ChangeTitle,
+        Operation::ChangeAuthor,
+        Operation::ChangePages,
+        Operation::ChangeLocation,
+        Operation::ChangeCopyType,
+        Operation::GiveBook,
+        Operation::GiveBookOverLimits,
+        Operation::ReturnBook,
+        Operation::ChangeReturnDate,
+        Operation::AddGenre,
+        Operation::RemoveGenre,
+        Operation::SetBookGenres,
+        Operation::Replace,
+    ];
+
+    /// Name of operation as it's stored
+
+    #[inline]
+    pub fn name(self) -> String {
+        format!("{:?}", self)
+    }
+
+    /// Searches operation by its stored name
+
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Operation::ALL.iter().copied().find(|op| op.name() == name)
+    }
+
+    /// Permission which user needs for operation.
+    /// Replacing all data is restoring, so only administrator can do it.
+    /// Giving book despite loan policy needs its own permission
+
+    #[inline]
+    pub fn permission(self) -> Permission {
+        match self {
+            Operation::Replace => Permission::Administer,
+            Operation::GiveBookOverLimits => Permission::OverrideLimits,
+            _ => Permission::Edit,
+        }
+    }
+
+    /// Localized name of operation
+
+    #[inline]
+    pub fn message(self, lang: Lang) -> &'static str {
+        lang.tr(match self {
+            Operation::AddReader => "operation-add-reader",
+            Operation::RemoveReader => "operation-remove-reader",
+            Operation::ChangeName => "operation-change-name",
+            Operation::ChangeFamily => "operation-change-family",
+            Operation::ChangeFather => "operation-change-father",
+            Operation::ChangeInfo => "operation-change-info",
+            Operation::ChangeAge => "operation-change-age",
+            Operation::ChangeCategory => "operation-change-category",
+            Operation::AddBook => "operation-add-book",
+            Operation::AddBooks => "operation-add-books",
+            Operation::RemoveBook => "operation-remove-book",
+            Operation::RemoveOneBook => "operation-remove-one-book",
+            Operation::ChangeTitle => "operation-change-title",
+            Operation::ChangeAuthor => "operation-change-author",
+            Operation::ChangePages => "operation-change-pages",
+            Operation::ChangeLocation => "operation-change-location",
+            Operation::ChangeCopyType => "operation-change-copy-type",
+            Operation::GiveBook => "operation-give-book",
+            Operation::GiveBookOverLimits => "operation-give-book-over-limits",
+            Operation::ReturnBook => "operation-return-book",
+            Operation::ChangeReturnDate => "operation-change-return-date",
+            Operation::AddGenre => "operation-add-genre",
+            Operation::RemoveGenre => "operation-remove-genre",
+            Operation::SetBookGenres => "operation-set-book-genres",
+            Operation::Replace => "operation-replace",
+        })
+    }
+}
+
+/// Change of one stored record
+/// (reader or The Book)
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Change<T> {
+    /// Record was added to index
+    Added(usize, T),
+
+    /// Record was removed from index
+    Removed(usize, T),
+
+    /// Record was changed in place
+    /// (it's state before and after)
+    Changed(T, T),
+}
+
+impl<T> Change<T> {
+    /// Record after change
+    /// (or removed record)
+
+    #[inline]
+    fn record(&self) -> &T {
+        match self {
+            Change::Added(_, record) | Change::Removed(_, record) => record,
+            Change::Changed(_, after) => after,
+        }
+    }
+}
+
+impl<T: Clone> Change<T> {
+    /// Change that cancels this one
+
+    #[inline]
+    fn inverse(&self) -> Self {
+        match self {
+            Change::Added(ind, record) => Change::Removed(*ind, record.clone()),
+            Change::Removed(ind, record) => Change::Added(*ind, record.clone()),
+            Change::Changed(before, after) => Change::Changed(after.clone(), before.clone()),
+        }
+    }
+}
+
+/// Records which can be changed by operation.
+/// Records created by operation are found by ids,
+/// so they shouldn't be listed
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Touched {
+    pub(crate) readers: Vec<Id>,
+    pub(crate) books: Vec<Id>,
+    pub(crate) genres: bool,
+    pub(crate) all: bool,
+}
+
+impl Touched {
+    /// Operation can change anything
+
+    #[inline]
+    pub(crate) fn all() -> Self {
+        Touched {
+            all: true,
+            ..Touched::default()
+        }
+    }
+
+    /// Operation changes genres' list
+
+    #[inline]
+    pub(crate) fn genres() -> Self {
+        Touched {
+            genres: true,
+            ..Touched::default()
+        }
+    }
+
+    /// Operation changes chosen readers and The Books
+
+    #[inline]
+    pub(crate) fn records(readers: Vec<Id>, books: Vec<Id>) -> Self {
+        Touched {
+            readers,
+            books,
+            ..Touched::default()
+        }
+    }
+}
+
+/// Stored state of touched records
+/// with their indexes. If record is absent, it's None
+
+#[derive(Debug)]
+pub(crate) struct Snapshot {
+    readers: Vec<(Id, Option<(usize, ReaderRecord)>)>,
+    books: Vec<(Id, Option<(usize, BookRecord)>)>,
+    genres: Option<BTreeSet<String>>,
+    all: bool,
+}
+
+impl Snapshot {
+    /// Takes state of touched records
+
+    #[inline]
+    pub(crate) fn take(
+        touched: &Touched,
+        reader_base: &ReaderBase,
+        book_system: &BookSystem,
+        genres: &Genres,
+    ) -> Self {
+        return if touched.all {
+            Snapshot {
+                readers: (0..reader_base.len())
+                    .filter_map(|ind| reader_base.id(ind))
+                    .map(|id| (id, reader_base.record(id)))
+                    .collect(),
+                books: (0..book_system.len())
+                    .filter_map(|ind| book_system.id(ind))
+                    .map(|id| (id, book_system.record(id)))
+                    .collect(),
+                genres: Some(genres.iter().cloned().collect()),
+                all: true,
+            }
+        } else {
+            Snapshot {
+                readers: touched
+                    .readers
+                    .iter()
+                    .map(|&id| (id, reader_base.record(id)))
+                    .collect(),
+                books: touched
+                    .books
+                    .iter()
+                    .map(|&id| (id, book_system.record(id)))
+                    .collect(),
+                genres: match touched.genres {
+                    true => Some(genres.iter().cloned().collect()),
+                    false => None,
+                },
+                all: false,
+            }
+        };
+    }
+}
+
+/// Checks if reader is in chosen state.
+/// Books which reader is reading aren't compared,
+/// because they are only informative
+
+#[inline]
+fn same_reader(record: &ReaderRecord, state: &ReaderRecord) -> bool {
+    record.id == state.id
+        && record.name == state.name
+        && record.family == state.family
+        && record.father == state.father
+        && record.info == state.info
+        && record.birth == state.birth
+        && record.category == state.category
+}
+
+/// Finds changes of records between two states
+
+#[inline]
+fn diff<T: Clone + PartialEq>(
+    before: &[(Id, Option<(usize, T)>)],
+    after: &[(Id, Option<(usize, T)>)],
+    all: bool,
+) -> Vec<Change<T>> {
+    if all {
+        return before
+            .iter()
+            .filter_map(|(_, b)| b.clone().map(|(ind, record)| Change::Removed(ind, record)))
+            .chain(
+                after
+                    .iter()
+                    .filter_map(|(_, a)| a.clone().map(|(ind, record)| Change::Added(ind, record))),
+            )
+            .collect();
+    }
+
+    let before = before
+        .iter()
+        .map(|(id, b)| (*id, b))
+        .collect::<HashMap<_, _>>();
+
+    after
+        .iter()
+        .filter_map(
+            |(id, a)| match (before.get(id).and_then(|b| b.as_ref()), a) {
+                (None, None) => None,
+                (None, Some((ind, record))) => Some(Change::Added(*ind, record.clone())),
+                (Some((ind, record)), None) => Some(Change::Removed(*ind, record.clone())),
+                (Some((_, b)), Some((_, a))) if b == a => None,
+                (Some((_, b)), Some((_, a))) => Some(Change::Changed(b.clone(), a.clone())),
+            },
+        )
+        .collect()
+}
+
+/// One step of history.
+/// Contains only records changed by operation,
+/// so it can be undone and done again
+/// without copying the whole library
+
+#[derive(Clone, Debug)]
+pub struct Command {
+    pub(crate) operation: Operation,
+    pub(crate) time: NaiveDateTime,
+    pub(crate) readers: Vec<Change<ReaderRecord>>,
+    pub(crate) books: Vec<Change<BookRecord>>,
+    pub(crate) added_genres: Vec<String>,
+    pub(crate) removed_genres: Vec<String>,
+}
+
+impl Command {
+    /// Creates command from states
+    /// before and after operation.
+    /// States must be taken with the same touched records
+    /// (after state can also have new records)
+
+    #[inline]
+    pub(crate) fn new(operation: Operation, before: &Snapshot, after: &Snapshot) -> Self {
+        let (added_genres, removed_genres) = match (&before.genres, &after.genres) {
+            (Some(b), Some(a)) => (
+                a.difference(b).cloned().collect(),
+                b.difference(a).cloned().collect(),
+            ),
+            _ => (vec![], vec![]),
+        };
+
+        Command {
+            operation,
+            time: Local::now().naive_local(),
+            readers: diff(&before.readers, &after.readers, before.all),
+            books: diff(&before.books, &after.books, before.all),
+            added_genres,
+            removed_genres,
+        }
+    }
+
+    /// Operation of command
+
+    #[inline]
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    /// Time when operation was made
+
+    #[inline]
+    pub fn time(&self) -> NaiveDateTime {
+        self.time
+    }
+
+    /// Readers, books and genres changed by command:
+    /// name, family and father of readers,
+    /// title, author and pages of books
+
+    pub fn subjects(&self) -> Vec<String> {
+        self.readers
+            .iter()
+            .map(Change::record)
+            .map(|r| format!("{} {} {}", r.name, r.family, r.father))
+            .chain(
+                self.books
+                    .iter()
+                    .map(Change::record)
+                    .map(|b| format!("{} {} {}", b.title, b.author, b.pages)),
+            )
+            .chain(self.added_genres.iter().cloned())
+            .chain(self.removed_genres.iter().cloned())
+            .collect()
+    }
+
+    /// Localized operation with changed readers, books and genres.
+    /// Only first of them are listed
+
+    pub fn message(&self, lang: Lang) -> String {
+        const SHOWN: usize = 2;
+        let subjects = self.subjects();
+
+        match subjects.len() {
+            0 => self.operation.message(lang).to_string(),
+
+            len if len <= SHOWN => {
+                format!("{}: {}", self.operation.message(lang), subjects.join(", "))
+            }
+
+            len => format!(
+                "{}: {} {}",
+                self.operation.message(lang),
+                subjects[..SHOWN].join(", "),
+                lang.format("and-more", &[("count", &(len - SHOWN))])
+            ),
+        }
+    }
+
+    /// Checks if nothing was changed
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.readers.is_empty()
+            && self.books.is_empty()
+            && self.added_genres.is_empty()
+            && self.removed_genres.is_empty()
+    }
+
+    /// Checks which parts of library were changed:
+    /// readers, books and genres' list
+
+    #[inline]
+    pub(crate) fn changed_parts(&self) -> (bool, bool, bool) {
+        (
+            !self.readers.is_empty(),
+            !self.books.is_empty(),
+            !self.added_genres.is_empty() || !self.removed_genres.is_empty(),
+        )
+    }
+
+    /// Checks if library is in state
+    /// which is left by this command.
+    /// Used to find out if saved history
+    /// still belongs to loaded data
+
+    pub(crate) fn is_done(
+        &self,
+        reader_base: &ReaderBase,
+        book_system: &BookSystem,
+        genres: &Genres,
+    ) -> bool {
+        self.readers.iter().all(|c| match c {
+            Change::Added(ind, record) => matches!(
+                reader_base.record(record.id),
+                Some((i, r)) if i == *ind && same_reader(&r, record)
+            ),
+
+            Change::Removed(_, record) => reader_base.record(record.id).is_none(),
+
+            Change::Changed(_, after) => matches!(
+                reader_base.record(after.id),
+                Some((_, r)) if same_reader(&r, after)
+            ),
+        }) && self.books.iter().all(|c| match c {
+            Change::Added(ind, record) => {
+                book_system.record(record.id) == Some((*ind, record.clone()))
+            }
+
+            Change::Removed(_, record) => book_system.record(record.id).is_none(),
+
+            Change::Changed(_, after) => matches!(
+                book_system.record(after.id),
+                Some((_, b)) if b == *after
+            ),
+        }) && self
+            .added_genres
+            .iter()
+            .all(|g| genres.iter().any(|x| x == g))
+            && self
+                .removed_genres
+                .iter()
+                .all(|g| genres.iter().all(|x| x != g))
+    }
+
+    /// Command that cancels this one
+
+    #[inline]
+    pub(crate) fn inverse(&self) -> Self {
+        Command {
+            operation: self.operation,
+            time: self.time,
+            readers: self.readers.iter().map(Change::inverse).collect(),
+            books: self.books.iter().map(Change::inverse).collect(),
+            added_genres: self.removed_genres.clone(),
+            removed_genres: self.added_genres.clone(),
+        }
+    }
+
+    /// Applies all changes of command.
+    /// Removed books and readers are deleted first,
+    /// then readers are restored, so books can be linked with them
+
+    pub(crate) fn apply(
+        &self,
+        reader_base: &mut ReaderBase,
+        book_system: &mut BookSystem,
+        genres: &mut Genres,
+    ) {
+        let mut removed_books = self
+            .books
+            .iter()
+            .filter_map(|c| match c {
+                Change::Removed(ind, record) => Some((*ind, record.id)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        removed_books.sort_unstable_by(|a, b| b.cmp(a));
+
+        removed_books.into_iter().for_each(|(_, id)| {
+            book_system.remove_record(id);
+        });
+
+        let mut removed_readers = self
+            .readers
+            .iter()
+            .filter_map(|c| match c {
+                Change::Removed(ind, record) => Some((*ind, record.id)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        removed_readers.sort_unstable_by(|a, b| b.cmp(a));
+
+        removed_readers.into_iter().for_each(|(_, id)| {
+            reader_base.remove_record(id);
+        });
+
+        let mut added_readers = vec![];
+
+        self.readers.iter().for_each(|c| match c {
+            Change::Changed(_, after) => {
+                reader_base.update_record(after);
+            }
+
+            Change::Added(ind, record) => added_readers.push((*ind, record)),
+            Change::Removed(_, _) => {}
+        });
+
+        added_readers.sort_unstable_by_key(|(ind, _)| *ind);
+
+        added_readers.into_iter().for_each(|(ind, record)| {
+            reader_base.insert_record(ind, record);
+        });
+
+        let mut added_books = vec![];
+
+        self.books.iter().for_each(|c| match c {
+            Change::Changed(_, after) => {
+                book_system.replace_record(after, reader_base);
+            }
+
+            Change::Added(ind, record) => added_books.push((*ind, record)),
+            Change::Removed(_, _) => {}
+        });
+
+        added_books.sort_unstable_by_key(|(ind, _)| *ind);
+
+        added_books.into_iter().for_each(|(ind, record)| {
+            book_system.insert_record(ind, record, reader_base);
+        });
+
+        self.removed_genres.iter().for_each(|g| {
+            genres.remove(g);
+        });
+
+        self.added_genres.iter().for_each(|g| {
+            genres.add(g.clone());
+        });
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{
+    books::date::Date,
+    data::error::LoadError,
+    id::Id,
+    storage::{BookRecord, CopyRecord, LoanRecord, ReaderRecord, Storage},
+};
+
+use std::collections::{BTreeMap, HashMap};
+
+/// Difference of one record
+/// between snapshot and current state
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Difference<T> {
+    /// Record is only in current state
+    Added(T),
+
+    /// Record is only in snapshot
+    Removed(T),
+
+    /// Record is in both states, but it's changed
+    /// (it's state in snapshot and now)
+    Changed(T, T),
+}
+
+/// Simple book with id of its The Book.
+/// Givings of simple book are compared separately
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CopyState {
+    pub title: Id,
+    pub copy: CopyRecord,
+}
+
+/// Giving of simple book to reader
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LoanState {
+    pub title: Id,
+    pub copy: Id,
+    pub loan: LoanRecord,
+}
+
+/// Differences between snapshot and current state
+/// of readers, The Books, simple books, givings and genres.
+/// Removed records go first, then added and changed ones
+/// (both are ordered by their ids)
+
+#[derive(Clone, Debug, Default)]
+pub struct SnapshotDiff {
+    pub readers: Vec<Difference<ReaderRecord>>,
+    pub titles: Vec<Difference<BookRecord>>,
+    pub copies: Vec<Difference<CopyState>>,
+    pub loans: Vec<Difference<LoanState>>,
+    pub genres: Vec<Difference<String>>,
+    reader_names: HashMap<Id, String>,
+    title_names: HashMap<Id, String>,
+}
+
+impl SnapshotDiff {
+    /// Compares records of snapshot with current ones.
+    /// If any record can't be read, it will return error
+
+    pub fn new(snapshot: &dyn Storage, current: &dyn Storage) -> Result<Self, LoadError> {
+        let before = States::read(snapshot)?;
+        let after = States::read(current)?;
+
+        let mut reader_names = before.reader_names;
+        reader_names.extend(after.reader_names);
+
+        let mut title_names = before.title_names;
+        title_names.extend(after.title_names);
+
+        Ok(SnapshotDiff {
+            readers: compare(&before.readers, &after.readers),
+            titles: compare(&before.titles, &after.titles),
+            copies: compare(&before.copies, &after.copies),
+            loans: compare(&before.loans, &after.loans),
+            genres: compare(&before.genres, &after.genres),
+            reader_names,
+            title_names,
+        })
+    }
+
+    /// Checks if snapshot is the same as current state
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.readers.is_empty()
+            && self.titles.is_empty()
+            && self.copies.is_empty()
+            && self.loans.is_empty()
+            && self.genres.is_empty()
+    }
+
+    /// Name, family and father of reader.
+    /// If reader is unknown, it's his id
+
+    #[inline]
+    pub fn reader_name(&self, id: Id) -> String {
+        self.reader_names
+            .get(&id)
+            .cloned()
+            .unwrap_or_else(|| id.to_string())
+    }
+
+    /// Title, author and pages of The Book.
+    /// If The Book is unknown, it's its id
+
+    #[inline]
+    pub fn title_name(&self, id: Id) -> String {
+        self.title_names
+            .get(&id)
+            .cloned()
+            .unwrap_or_else(|| id.to_string())
+    }
+}
+
+/// All records of one state split by kind
+
+#[derive(Default)]
+struct States {
+    readers: BTreeMap<Id, ReaderRecord>,
+    titles: BTreeMap<Id, BookRecord>,
+    copies: BTreeMap<Id, CopyState>,
+    loans: BTreeMap<(Id, Id, Date), LoanState>,
+    genres: BTreeMap<String, String>,
+    reader_names: HashMap<Id, String>,
+    title_names: HashMap<Id, String>,
+}
+
+impl States {
+    /// Reads all records from storage.
+    /// Books which reader is reading aren't compared,
+    /// because they are only informative.
+    /// Giving is found by simple book, reader and its start,
+    /// so changed return date is its change
+
+    fn read(storage: &dyn Storage) -> Result<Self, LoadError> {
+        let mut states = States::default();
+
+        for reader in storage.read_readers()? {
+            let reader = ReaderRecord {
+                reading: vec![],
+                ..reader?
+            };
+
+            states.reader_names.insert(
+                reader.id,
+                format!("{} {} {}", reader.name, reader.family, reader.father),
+            );
+
+            states.readers.insert(reader.id, reader);
+        }
+
+        for book in storage.read_books()? {
+            let mut book = book?;
+
+            states.title_names.insert(
+                book.id,
+                format!("{} {} {}", book.title, book.author, book.pages),
+            );
+
+            for mut copy in std::mem::take(&mut book.copies) {
+                for loan in std::mem::take(&mut copy.loans) {
+                    states.loans.insert(
+                        (copy.id, loan.reader, loan.start),
+                        LoanState {
+                            title: book.id,
+                            copy: copy.id,
+                            loan,
+                        },
+                    );
+                }
+
+                states.copies.insert(
+                    copy.id,
+                    CopyState {
+                        title: book.id,
+                        copy,
+                    },
+                );
+            }
+
+            states.titles.insert(book.id, book);
+        }
+
+        for genre in storage.read_genres()? {
+            let genre = genre?;
+            states.genres.insert(genre.clone(), genre);
+        }
+
+        Ok(states)
+    }
+}
+
+/// Finds records which are added, removed or changed.
+/// Records are matched by their keys
+
+#[inline]
+fn compare<K: Ord, T: Clone + PartialEq>(
+    before: &BTreeMap<K, T>,
+    after: &BTreeMap<K, T>,
+) -> Vec<Difference<T>> {
+    let removed = before
+        .iter()
+        .filter(|(key, _)| !after.contains_key(key))
+        .map(|(_, record)| Difference::Removed(record.clone()));
+
+    let changed = after
+        .iter()
+        .filter_map(|(key, record)| match before.get(key) {
+            None => Some(Difference::Added(record.clone())),
+            Some(old) if old != record => Some(Difference::Changed(old.clone(), record.clone())),
+            Some(_) => None,
+        });
+
+    removed.chain(changed).collect()
+}
@@ -0,0 +1,75 @@
+use crate::{auth::AccessDenied, data::error::LoadError};
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    io,
+};
+
+/// Error of replacing all data
+/// with backup or snapshot
+
+#[derive(Debug)]
+pub enum RestoreError {
+    /// Backup or snapshot can't be loaded
+    Load(LoadError),
+
+    /// User's role doesn't allow to restore data
+    NoAccess,
+
+    /// Restored data can't be saved to storage
+    Io(io::Error),
+}
+
+impl Display for RestoreError {
+    /// Describes error in english
+
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RestoreError::Load(err) => write!(f, "{}", err),
+            RestoreError::NoAccess => write!(f, "{}", AccessDenied),
+            RestoreError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for RestoreError {
+    /// Error of loading or saving
+
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RestoreError::Load(err) => Some(err),
+            RestoreError::NoAccess => None,
+            RestoreError::Io(err) => Some(err),
+        }
+    }
+}
+
+impl From<LoadError> for RestoreError {
+    /// Wraps error of loading
+
+    #[inline]
+    fn from(err: LoadError) -> Self {
+        RestoreError::Load(err)
+    }
+}
+
+impl From<AccessDenied> for RestoreError {
+    /// Restoring isn't allowed for user's role
+
+    #[inline]
+    fn from(_: AccessDenied) -> Self {
+        RestoreError::NoAccess
+    }
+}
+
+impl From<io::Error> for RestoreError {
+    /// Wraps error of saving
+
+    #[inline]
+    fn from(err: io::Error) -> Self {
+        RestoreError::Io(err)
+    }
+}
@@ -0,0 +1,98 @@
+extern crate fltk;
+
+use crate::{library::Library, Lang};
+
+use fltk::{
+    app, app::App, browser::HoldBrowser, button::Button, dialog::alert, prelude::*,
+    window::SingleWindow,
+};
+
+/// Format of command's time in history
+
+const TIME_FORMAT: &str = "%d.%m.%Y %H:%M:%S";
+
+/// Function that shows all steps of history
+/// from oldest to newest with their time.
+/// Library can jump to state after any step,
+/// all undone steps can still be done again
+
+#[inline]
+pub fn history(library: &mut Library, app: &App, lang: Lang) {
+    let (s, r) = app::channel();
+
+    let mut wind = SingleWindow::new(400, 100, 600, 400, lang.tr("history"));
+
+    let mut browser = HoldBrowser::new(0, 0, 600, 360, "");
+    fill_history(&mut browser, library, lang);
+
+    let mut ok = Button::new(510, 370, 80, 25, lang.tr("go-to"));
+
+    ok.emit(s, true);
+
+    wind.end();
+    wind.show();
+
+    while app.wait() {
+        if let Some(true) = r.recv() {
+            let ind = browser.value();
+
+            if ind <= 0 {
+                alert(500, 500, lang.tr("step-isnt-selected"));
+                continue;
+            }
+
+            // First line is state before all steps
+
+            if let Err(err) = library.jump_to(ind as usize - 1) {
+                alert(
+                    500,
+                    500,
+                    format!("{}\n{}", lang.tr("changes-arent-saved"), err).as_str(),
+                );
+            }
+
+            fill_history(&mut browser, library, lang);
+            browser.select(ind);
+        }
+
+        if !wind.shown() {
+            return;
+        }
+    }
+}
+
+/// Lists all steps of history.
+/// Current state is marked with arrow,
+/// undone steps are marked as undone
+
+fn fill_history(browser: &mut HoldBrowser, library: &Library, lang: Lang) {
+    let caretaker = library.caretaker();
+    let mark = |done: usize| match caretaker.done() == done {
+        true => "→ ",
+        false => "   ",
+    };
+
+    browser.clear();
+
+    browser.add(format!("{}{}", mark(0), lang.tr("start-of-history")).as_str());
+
+    caretaker
+        .commands()
+        .iter()
+        .enumerate()
+        .for_each(|(ind, command)| {
+            browser.add(
+                format!(
+                    "{}{}  {}{}",
+                    mark(ind + 1),
+                    command.time().format(TIME_FORMAT),
+                    command.message(lang),
+                    match ind < caretaker.done() {
+                        true => "",
+                        false => lang.tr("undone-mark"),
+                    }
+                )
+                .as_str(),
+            )
+        });
+}
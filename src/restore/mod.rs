@@ -1,2 +1,7 @@
+pub mod backup;
 pub mod caretaker;
-pub mod memento;
+pub mod command;
+pub mod diff;
+pub mod error;
+pub mod history;
+pub mod snapshot;
@@ -0,0 +1,316 @@
+extern crate fltk;
+
+use crate::{
+    auth::AccessDenied,
+    change::{input1::Input1, Inputable},
+    library::Library,
+    restore::{
+        diff::{CopyState, Difference, LoanState, SnapshotDiff},
+        error::RestoreError,
+    },
+    storage::{BookRecord, ReaderRecord},
+    Lang,
+};
+
+use fltk::{
+    app,
+    app::App,
+    browser::HoldBrowser,
+    button::Button,
+    dialog::{alert, message},
+    input::Input,
+    prelude::*,
+    tree::Tree,
+    window::SingleWindow,
+};
+
+/// Format of snapshot's time in list of snapshots
+
+const TIME_FORMAT: &str = "%d.%m.%Y %H:%M:%S";
+
+/// Function that saves current data
+/// as named snapshot. If label is empty,
+/// program will let you know
+
+#[inline]
+pub fn create_snapshot(library: &Library, app: &App, lang: Lang) {
+    let (s, r) = app::channel();
+    let mut inp = Input1::<Input>::new(lang.tr("create-snapshot"), lang.tr("label"));
+
+    inp.show();
+    (*inp.ok).borrow_mut().emit(s, true);
+
+    while app.wait() {
+        if let Some(msg) = r.recv() {
+            if msg {
+                inp.hide();
+
+                if let Ok(label) = inp.set_input(lang) {
+                    let label = label.first().unwrap().trim();
+
+                    if label.is_empty() {
+                        alert(500, 500, lang.tr("label-is-empty"));
+                        return;
+                    }
+
+                    match library.create_snapshot(label) {
+                        Ok(_) => message(500, 500, lang.tr("snapshot-is-created")),
+
+                        Err(err) => alert(
+                            500,
+                            500,
+                            format!("{}\n{}", lang.tr("snapshot-isnt-created"), err).as_str(),
+                        ),
+                    }
+                }
+            }
+            return;
+        } else if !inp.shown() {
+            return;
+        }
+    }
+}
+
+/// Function that shows all snapshots with their time.
+/// Chosen snapshot can be compared with current data
+/// or restored (restoring can be undone)
+
+#[inline]
+pub fn restore_snapshot(library: &mut Library, app: &App, lang: Lang) {
+    let snapshots = library.snapshots().unwrap_or_default();
+
+    if snapshots.is_empty() {
+        alert(500, 500, lang.tr("there-are-no-snapshots-yet"));
+        return;
+    }
+
+    let (s, r) = app::channel();
+
+    let mut wind = SingleWindow::new(500, 100, 400, 400, lang.tr("snapshots"));
+
+    let mut browser = HoldBrowser::new(0, 0, 400, 360, "");
+
+    snapshots.iter().for_each(|snapshot| {
+        browser.add(
+            format!(
+                "{}  {}",
+                snapshot.time().format(TIME_FORMAT),
+                snapshot.label()
+            )
+            .as_str(),
+        )
+    });
+
+    let mut compare = Button::new(220, 370, 80, 25, lang.tr("compare"));
+
+    let mut ok = Button::new(310, 370, 80, 25, lang.tr("restore"));
+
+    compare.emit(s, false);
+    ok.emit(s, true);
+
+    wind.end();
+    wind.show();
+
+    while app.wait() {
+        if let Some(restore) = r.recv() {
+            let ind = browser.value();
+
+            if ind <= 0 {
+                alert(500, 500, lang.tr("snapshot-isnt-selected"));
+                continue;
+            }
+
+            let snapshot = unsafe { snapshots.get_unchecked(ind as usize - 1) };
+
+            let result = if restore {
+                library.restore_snapshot(snapshot)
+            } else {
+                library
+                    .diff_snapshot(snapshot)
+                    .map(|diff| show_diff(&diff, snapshot.label(), lang))
+                    .map_err(RestoreError::from)
+            };
+
+            match result {
+                Ok(_) => {}
+
+                Err(RestoreError::NoAccess) => {
+                    alert(500, 500, AccessDenied.message(lang));
+                    continue;
+                }
+
+                Err(RestoreError::Load(err)) => {
+                    alert(
+                        500,
+                        500,
+                        format!("{}\n{}", lang.tr("snapshot-is-damaged"), err).as_str(),
+                    );
+                    continue;
+                }
+
+                Err(RestoreError::Io(err)) => {
+                    alert(
+                        500,
+                        500,
+                        format!("{}\n{}", lang.tr("changes-arent-saved"), err).as_str(),
+                    );
+                    continue;
+                }
+            }
+
+            if restore {
+                wind.hide();
+
+                message(500, 500, lang.tr("successfully-restored"));
+                return;
+            }
+        }
+
+        if !wind.shown() {
+            return;
+        }
+    }
+}
+
+/// Shows differences between snapshot and current data.
+/// Added records are marked with **+**, removed with **-**
+/// and changed with **~** (state in snapshot → current state)
+
+fn show_diff(diff: &SnapshotDiff, label: &str, lang: Lang) {
+    if diff.is_empty() {
+        message(500, 500, lang.tr("snapshot-is-the-same-as-current-data"));
+        return;
+    }
+
+    let mut wind = SingleWindow::new(500, 100, 600, 400, "");
+
+    wind.set_label(format!("{} '{}'", lang.tr("differences-with"), label).as_str());
+
+    let mut tree = Tree::new(0, 0, 600, 400, "");
+    tree.set_root_label(lang.tr("changes-since-snapshot"));
+
+    add_differences(&mut tree, lang.tr("readers"), &diff.readers, |reader| {
+        describe_reader(reader, lang)
+    });
+
+    add_differences(&mut tree, lang.tr("books"), &diff.titles, describe_title);
+
+    add_differences(&mut tree, lang.tr("simple-books"), &diff.copies, |copy| {
+        describe_copy(diff, copy, lang)
+    });
+
+    add_differences(&mut tree, lang.tr("givings"), &diff.loans, |loan| {
+        describe_loan(diff, loan, lang)
+    });
+
+    add_differences(&mut tree, lang.tr("genres"), &diff.genres, |genre| {
+        genre.clone()
+    });
+
+    wind.end();
+    wind.show();
+}
+
+/// Adds branch with differences of one kind.
+/// If there are no differences, branch isn't added
+
+fn add_differences<T>(
+    tree: &mut Tree,
+    branch: &str,
+    differences: &[Difference<T>],
+    describe: impl Fn(&T) -> String,
+) {
+    if differences.is_empty() {
+        return;
+    }
+
+    tree.add(branch).unwrap();
+
+    differences.iter().for_each(|difference| {
+        let item = match difference {
+            Difference::Added(record) => format!("+ {}", describe(record)),
+            Difference::Removed(record) => format!("- {}", describe(record)),
+
+            Difference::Changed(before, after) => {
+                format!("~ {} → {}", describe(before), describe(after))
+            }
+        };
+
+        // Slashes separate levels of tree
+
+        tree.add(format!("{}/{}", branch, item.replace('/', "\\/")).as_str())
+            .unwrap();
+    });
+}
+
+/// Name, family, father, birth date, info
+/// and category (if it's set) of reader
+
+#[inline]
+pub(crate) fn describe_reader(reader: &ReaderRecord, lang: Lang) -> String {
+    let description = format!(
+        "{} {} {}, {}, {}",
+        reader.name,
+        reader.family,
+        reader.father,
+        reader.birth.localized(lang),
+        reader.info
+    );
+
+    match reader.category.is_empty() {
+        true => description,
+        false => format!("{} [{}]", description, reader.category),
+    }
+}
+
+/// Title, author, pages and genres of The Book
+
+#[inline]
+pub(crate) fn describe_title(book: &BookRecord) -> String {
+    match &book.genres {
+        Some(genres) => format!(
+            "{} {} {} ({})",
+            book.title,
+            book.author,
+            book.pages,
+            genres.join(", ")
+        ),
+
+        None => format!("{} {} {}", book.title, book.author, book.pages),
+    }
+}
+
+/// The Book, number, location
+/// and type (if it's set) of simple book
+
+#[inline]
+fn describe_copy(diff: &SnapshotDiff, copy: &CopyState, lang: Lang) -> String {
+    let description = format!(
+        "{} №{}: {} {}, {} {}",
+        diff.title_name(copy.title),
+        copy.copy.id,
+        lang.tr("cabinet-lowercase"),
+        copy.copy.cabinet,
+        lang.tr("shelf-lowercase"),
+        copy.copy.shelf
+    );
+
+    match copy.copy.copy_type.is_empty() {
+        true => description,
+        false => format!("{} [{}]", description, copy.copy.copy_type),
+    }
+}
+
+/// Reader, simple book and dates of giving
+
+#[inline]
+fn describe_loan(diff: &SnapshotDiff, loan: &LoanState, lang: Lang) -> String {
+    format!(
+        "{}: {} №{} ({} - {})",
+        diff.reader_name(loan.loan.reader),
+        diff.title_name(loan.title),
+        loan.copy,
+        loan.loan.start.localized(lang),
+        loan.loan.finish.localized(lang)
+    )
+}
@@ -0,0 +1,191 @@
+extern crate chrono;
+
+use crate::{
+    audit::AuditEntry,
+    data::error::{LoadError, LoadErrorKind},
+    restore::command::Command,
+    storage::{BookRecord, ReaderRecord, Records, SnapshotRecord, Storage},
+};
+
+use std::{cell::RefCell, io, path::PathBuf};
+
+use chrono::Local;
+
+/// Storage which keeps everything in memory.
+/// Nothing is written to disk,
+/// so it's used in tests and for temporary libraries
+
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStorage {
+    readers: RefCell<Vec<ReaderRecord>>,
+    books: RefCell<Vec<BookRecord>>,
+    genres: RefCell<Vec<String>>,
+    history: RefCell<(Vec<Command>, usize)>,
+    snapshots: RefCell<Vec<(SnapshotRecord, MemoryStorage)>>,
+    audit: RefCell<Vec<AuditEntry>>,
+}
+
+impl MemoryStorage {
+    /// Creates empty storage
+
+    #[inline]
+    pub fn new() -> Self {
+        MemoryStorage::default()
+    }
+
+    /// Creates storage with records
+
+    #[inline]
+    pub fn with_records(
+        readers: Vec<ReaderRecord>,
+        books: Vec<BookRecord>,
+        genres: Vec<String>,
+    ) -> Self {
+        MemoryStorage {
+            readers: RefCell::new(readers),
+            books: RefCell::new(books),
+            genres: RefCell::new(genres),
+            history: RefCell::default(),
+            snapshots: RefCell::default(),
+            audit: RefCell::default(),
+        }
+    }
+
+    /// Stored readers
+
+    #[inline]
+    pub fn readers(&self) -> Vec<ReaderRecord> {
+        self.readers.borrow().clone()
+    }
+
+    /// Stored books
+
+    #[inline]
+    pub fn books(&self) -> Vec<BookRecord> {
+        self.books.borrow().clone()
+    }
+
+    /// Stored genres
+
+    #[inline]
+    pub fn genres(&self) -> Vec<String> {
+        self.genres.borrow().clone()
+    }
+}
+
+impl Storage for MemoryStorage {
+    #[inline]
+    fn readers_path(&self) -> PathBuf {
+        PathBuf::from(":memory:")
+    }
+
+    #[inline]
+    fn books_path(&self) -> PathBuf {
+        PathBuf::from(":memory:")
+    }
+
+    #[inline]
+    fn read_readers(&self) -> Records<ReaderRecord> {
+        Ok(self.readers.borrow().iter().cloned().map(Ok).collect())
+    }
+
+    #[inline]
+    fn read_books(&self) -> Records<BookRecord> {
+        Ok(self.books.borrow().iter().cloned().map(Ok).collect())
+    }
+
+    #[inline]
+    fn read_genres(&self) -> Records<String> {
+        Ok(self.genres.borrow().iter().cloned().map(Ok).collect())
+    }
+
+    #[inline]
+    fn write_readers(&self, readers: &[ReaderRecord]) -> io::Result<()> {
+        *self.readers.borrow_mut() = readers.to_vec();
+        Ok(())
+    }
+
+    #[inline]
+    fn write_books(&self, books: &[BookRecord]) -> io::Result<()> {
+        *self.books.borrow_mut() = books.to_vec();
+        Ok(())
+    }
+
+    #[inline]
+    fn write_genres(&self, genres: &[String]) -> io::Result<()> {
+        *self.genres.borrow_mut() = genres.to_vec();
+        Ok(())
+    }
+
+    #[inline]
+    fn read_history(&self) -> Result<(Vec<Command>, usize), LoadError> {
+        Ok(self.history.borrow().clone())
+    }
+
+    #[inline]
+    fn write_history(&self, commands: &[Command], done: usize) -> io::Result<()> {
+        *self.history.borrow_mut() = (commands.to_vec(), done);
+        Ok(())
+    }
+
+    #[inline]
+    fn append_audit(&self, entry: &AuditEntry) -> io::Result<()> {
+        self.audit.borrow_mut().push(entry.clone());
+        Ok(())
+    }
+
+    #[inline]
+    fn read_audit(&self) -> Result<Vec<AuditEntry>, LoadError> {
+        Ok(self.audit.borrow().clone())
+    }
+
+    #[inline]
+    fn snapshots(&self) -> io::Result<Vec<SnapshotRecord>> {
+        Ok(self
+            .snapshots
+            .borrow()
+            .iter()
+            .rev()
+            .map(|(snapshot, _)| snapshot.clone())
+            .collect())
+    }
+
+    #[inline]
+    fn read_snapshot(&self, snapshot: &SnapshotRecord) -> Result<MemoryStorage, LoadError> {
+        self.snapshots
+            .borrow()
+            .iter()
+            .find(|(s, _)| s.key == snapshot.key)
+            .map(|(_, records)| records.clone())
+            .ok_or_else(|| {
+                LoadError::file(
+                    &self.readers_path(),
+                    LoadErrorKind::Io(io::ErrorKind::NotFound.into()),
+                )
+            })
+    }
+
+    #[inline]
+    fn write_snapshot(
+        &self,
+        label: &str,
+        readers: &[ReaderRecord],
+        books: &[BookRecord],
+        genres: &[String],
+    ) -> io::Result<SnapshotRecord> {
+        let mut snapshots = self.snapshots.borrow_mut();
+
+        let snapshot = SnapshotRecord {
+            key: (snapshots.len() + 1).to_string(),
+            label: label.to_string(),
+            time: Local::now().naive_local(),
+        };
+
+        snapshots.push((
+            snapshot.clone(),
+            MemoryStorage::with_records(readers.to_vec(), books.to_vec(), genres.to_vec()),
+        ));
+
+        Ok(snapshot)
+    }
+}
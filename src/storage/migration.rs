@@ -0,0 +1,239 @@
+extern crate yaml_rust;
+
+use yaml_rust::{
+    yaml::{Array, Hash},
+    Yaml,
+};
+
+/// Current version of YAML data files.
+/// Must be increased with every new migration
+
+pub const FORMAT_VERSION: i64 = 2;
+
+/// Key of version header in every data file
+
+pub const VERSION_KEY: &str = "Version";
+
+/// Kind of YAML data file
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DataFile {
+    Readers,
+    Books,
+    Genres,
+}
+
+impl DataFile {
+    /// Key of records in data file
+
+    #[inline]
+    pub(crate) const fn key(self) -> &'static str {
+        match self {
+            DataFile::Readers => "Readers",
+            DataFile::Books => "Books",
+            DataFile::Genres => "Genres",
+        }
+    }
+}
+
+/// Upgrades document from version n to n + 1.
+/// Books are upgraded with records of readers
+/// (of any version), because they refer to readers.
+/// If layout is unknown, it will return None
+
+type Migration = fn(Yaml, DataFile, &[Yaml]) -> Option<Yaml>;
+
+/// All migrations in order.
+/// Migration with index n upgrades version n
+
+const MIGRATIONS: [Migration; FORMAT_VERSION as usize] = [add_version_header, add_ids];
+
+/// Version of document.
+/// Files without header have version 0.
+/// If header isn't integer, it will return None
+
+#[inline]
+pub(crate) fn version(doc: &Yaml) -> Option<i64> {
+    match &doc[VERSION_KEY] {
+        Yaml::BadValue => Some(0),
+        Yaml::Integer(version) => Some(*version),
+        _ => None,
+    }
+}
+
+/// Upgrades document of chosen version by one step.
+/// If layout is unknown, it will return None
+
+#[inline]
+pub(crate) fn upgrade(doc: Yaml, file: DataFile, version: i64, readers: &[Yaml]) -> Option<Yaml> {
+    MIGRATIONS
+        .get(usize::try_from(version).ok()?)
+        .and_then(|migration| migration(doc, file, readers))
+}
+
+/// Records of readers from document of any version
+
+#[inline]
+pub(crate) fn readers_records(doc: &Yaml) -> &[Yaml] {
+    match doc {
+        Yaml::Array(records) => records,
+        _ => doc[DataFile::Readers.key()]
+            .as_vec()
+            .map(|r| r.as_slice())
+            .unwrap_or_default(),
+    }
+}
+
+/// Wraps records with current version header
+
+#[inline]
+pub(crate) fn with_header(file: DataFile, records: Yaml) -> Yaml {
+    let mut hash = Hash::new();
+
+    hash.insert(
+        Yaml::String(VERSION_KEY.to_string()),
+        Yaml::Integer(FORMAT_VERSION),
+    );
+
+    hash.insert(Yaml::String(file.key().to_string()), records);
+    Yaml::Hash(hash)
+}
+
+/// Version 0 → 1.
+/// Readers and books were stored as root arrays,
+/// genres were stored as hash without version
+
+fn add_version_header(doc: Yaml, file: DataFile, _readers: &[Yaml]) -> Option<Yaml> {
+    let records = match (file, doc) {
+        (DataFile::Genres, Yaml::Hash(mut hash)) => {
+            hash.remove(&Yaml::String(DataFile::Genres.key().to_string()))?
+        }
+
+        (DataFile::Readers, doc @ Yaml::Array(_)) | (DataFile::Books, doc @ Yaml::Array(_)) => doc,
+        _ => return None,
+    };
+
+    let mut hash = Hash::new();
+    hash.insert(Yaml::String(VERSION_KEY.to_string()), Yaml::Integer(1));
+    hash.insert(Yaml::String(file.key().to_string()), records);
+    Some(Yaml::Hash(hash))
+}
+
+/// Keys which identified reader before version 2
+
+const READER_KEYS: [&str; 6] = ["Name", "Family", "Father", "Day", "Month", "Year"];
+
+/// Version 1 → 2.
+/// Readers, books and simple books get ids in stored order.
+/// Givings of books referred to readers by name, family,
+/// father and birth date, now they refer to reader's id.
+/// If reader isn't found, giving gets id 0, which is invalid
+
+fn add_ids(doc: Yaml, file: DataFile, readers: &[Yaml]) -> Option<Yaml> {
+    let mut hash = doc.into_hash()?;
+    let key = Yaml::String(file.key().to_string());
+    let mut records = hash.remove(&key)?;
+
+    match file {
+        DataFile::Genres => {}
+
+        DataFile::Readers => {
+            for (ind, reader) in array_mut(&mut records)?.iter_mut().enumerate() {
+                set_id(reader, ind as i64 + 1)?;
+            }
+        }
+
+        DataFile::Books => {
+            let mut simple_id = 0;
+
+            for (ind, book) in array_mut(&mut records)?.iter_mut().enumerate() {
+                set_id(book, ind as i64 + 1)?;
+
+                let simple_books = match book {
+                    Yaml::Hash(book) => book.get_mut(&Yaml::String("Simple Books".to_string()))?,
+                    _ => return None,
+                };
+
+                for simple in array_mut(simple_books)?.iter_mut() {
+                    simple_id += 1;
+                    set_id(simple, simple_id)?;
+
+                    let givings = match simple {
+                        Yaml::Hash(simple) => {
+                            simple.get_mut(&Yaml::String("Readers".to_string()))?
+                        }
+                        _ => return None,
+                    };
+
+                    for giving in array_mut(givings)?.iter_mut() {
+                        let id = reader_id(readers, giving);
+
+                        let old = match giving {
+                            Yaml::Hash(giving) => std::mem::take(giving),
+                            _ => return None,
+                        };
+
+                        let mut hash = Hash::new();
+                        hash.insert(Yaml::String("Reader".to_string()), Yaml::Integer(id));
+
+                        hash.extend(
+                            old.into_iter().filter(|(k, _)| {
+                                !READER_KEYS.iter().any(|r| k.as_str() == Some(*r))
+                            }),
+                        );
+
+                        *giving = Yaml::Hash(hash);
+                    }
+                }
+            }
+        }
+    }
+
+    hash.insert(Yaml::String(VERSION_KEY.to_string()), Yaml::Integer(2));
+    hash.insert(key, records);
+    Some(Yaml::Hash(hash))
+}
+
+/// Array inside of yaml value
+
+#[inline]
+fn array_mut(yaml: &mut Yaml) -> Option<&mut Array> {
+    match yaml {
+        Yaml::Array(array) => Some(array),
+        _ => None,
+    }
+}
+
+/// Puts id after number of record
+
+fn set_id(record: &mut Yaml, id: i64) -> Option<()> {
+    let old = match record {
+        Yaml::Hash(hash) => std::mem::take(hash),
+        _ => return None,
+    };
+
+    let mut hash = Hash::new();
+    let number = Yaml::String("№".to_string());
+
+    if let Some(n) = old.get(&number) {
+        hash.insert(number.clone(), n.clone());
+    }
+
+    hash.insert(Yaml::String("Id".to_string()), Yaml::Integer(id));
+    hash.extend(old.into_iter().filter(|(k, _)| *k != number));
+    *record = Yaml::Hash(hash);
+    Some(())
+}
+
+/// Finds id of reader who took the book.
+/// Readers of version 1 have no ids,
+/// so their id is their position starting from 1
+
+fn reader_id(readers: &[Yaml], giving: &Yaml) -> i64 {
+    readers
+        .iter()
+        .enumerate()
+        .find(|(_, r)| READER_KEYS.iter().all(|k| r[*k] == giving[*k]))
+        .map(|(ind, r)| r["Id"].as_i64().unwrap_or(ind as i64 + 1))
+        .unwrap_or(0)
+}
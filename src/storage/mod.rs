@@ -0,0 +1,450 @@
+extern crate chrono;
+
+use crate::{
+    audit::AuditEntry,
+    books::{book::Book, date::Date, the_book::TheBook},
+    data::{
+        error::{LoadError, LoadErrorKind},
+        DataDir,
+    },
+    id::Id,
+    journal::{JournalEntry, JournalEvent},
+    reading::reader::Reader,
+    restore::command::Command,
+};
+
+use std::{
+    cell::RefCell,
+    env, io,
+    path::{Path, PathBuf},
+    rc::Weak,
+};
+
+use chrono::NaiveDateTime;
+
+pub mod memory;
+pub mod migration;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+pub mod yaml;
+
+use memory::MemoryStorage;
+use yaml::YamlStorage;
+
+/// Command line flag to choose storage backend.
+/// Can be used as `--storage <name>` or `--storage=<name>`
+
+pub const STORAGE_FLAG: &str = "--storage";
+
+/// Environment variable to choose storage backend
+
+pub const STORAGE_ENV: &str = "LIBRS_STORAGE";
+
+/// Format of snapshot's creation time as it's stored
+
+pub(crate) const TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Result of reading all records of one kind.
+/// Outer error means that nothing can be read,
+/// inner errors are errors of single records
+
+pub type Records<T> = Result<Vec<Result<T, LoadError>>, LoadError>;
+
+/// Place where readers, books and genres are persisted.
+/// Reader Base, Book System and Genres
+/// are loaded from and saved through it
+
+pub trait Storage {
+    /// Where readers are stored.
+    /// Used to describe errors found while checking readers
+
+    fn readers_path(&self) -> PathBuf;
+
+    /// Where books are stored.
+    /// Used to describe errors found while linking books with readers
+
+    fn books_path(&self) -> PathBuf;
+
+    /// Reads all readers in the stored order
+
+    fn read_readers(&self) -> Records<ReaderRecord>;
+
+    /// Reads all books in the stored order
+
+    fn read_books(&self) -> Records<BookRecord>;
+
+    /// Reads all genres
+
+    fn read_genres(&self) -> Records<String>;
+
+    /// Called once before readers, books or genres of one save
+    /// are written. YAML storage makes one backup for the whole save.
+    /// By default nothing is done
+
+    #[inline]
+    fn begin_save(&self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Replaces all stored readers
+
+    fn write_readers(&self, readers: &[ReaderRecord]) -> io::Result<()>;
+
+    /// Replaces all stored books
+
+    fn write_books(&self, books: &[BookRecord]) -> io::Result<()>;
+
+    /// Replaces all stored genres
+
+    fn write_genres(&self, genres: &[String]) -> io::Result<()>;
+
+    /// Replaces all stored readers, books and genres.
+    /// By default they are written one by one
+
+    #[inline]
+    fn write_all(
+        &self,
+        readers: &[ReaderRecord],
+        books: &[BookRecord],
+        genres: &[String],
+    ) -> io::Result<()> {
+        self.write_readers(readers)?;
+        self.write_books(books)?;
+        self.write_genres(genres)
+    }
+
+    /// Reads saved history of changes
+    /// with amount of commands which weren't undone.
+    /// By default history isn't stored
+
+    #[inline]
+    fn read_history(&self) -> Result<(Vec<Command>, usize), LoadError> {
+        Ok((vec![], 0))
+    }
+
+    /// Replaces saved history of changes.
+    /// By default nothing is written
+
+    #[inline]
+    fn write_history(&self, _commands: &[Command], _done: usize) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Appends entry to audit log.
+    /// Written entries are never changed.
+    /// By default audit log isn't stored
+
+    #[inline]
+    fn append_audit(&self, _entry: &AuditEntry) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Reads all entries of audit log
+    /// from oldest to newest
+
+    #[inline]
+    fn read_audit(&self) -> Result<Vec<AuditEntry>, LoadError> {
+        Ok(vec![])
+    }
+
+    /// Appends events to journal instead of rewriting changed records.
+    /// If storage doesn't keep journal, it will return false,
+    /// then changed records must be written in full
+
+    #[inline]
+    fn append_events(&self, _events: &[JournalEvent]) -> io::Result<bool> {
+        Ok(false)
+    }
+
+    /// Reads all events of journal
+    /// from oldest to newest
+
+    #[inline]
+    fn read_journal(&self) -> Result<Vec<JournalEntry>, LoadError> {
+        Ok(vec![])
+    }
+
+    /// Readers, books and genres as they were at chosen time.
+    /// They are restored from the nearest compacted state
+    /// by replaying later events of journal.
+    /// Before the first event it's the state journal started with.
+    /// If journal isn't kept, it will return None
+
+    #[inline]
+    fn state_at(&self, _time: NaiveDateTime) -> Result<Option<MemoryStorage>, LoadError> {
+        Ok(None)
+    }
+
+    /// All backups of storage from newest to oldest
+    /// with their names. By default there are no backups
+
+    #[inline]
+    fn backups(&self) -> io::Result<Vec<(String, Box<dyn Storage>)>> {
+        Ok(vec![])
+    }
+
+    /// All named snapshots from newest to oldest.
+    /// By default there are no snapshots
+
+    #[inline]
+    fn snapshots(&self) -> io::Result<Vec<SnapshotRecord>> {
+        Ok(vec![])
+    }
+
+    /// Reads readers, books and genres of snapshot.
+    /// They can be loaded as any other storage
+
+    #[inline]
+    fn read_snapshot(&self, snapshot: &SnapshotRecord) -> Result<MemoryStorage, LoadError> {
+        Err(LoadError::file(
+            Path::new(&snapshot.key),
+            LoadErrorKind::Io(io::ErrorKind::NotFound.into()),
+        ))
+    }
+
+    /// Saves readers, books and genres as new named snapshot.
+    /// By default snapshots aren't supported
+
+    #[inline]
+    fn write_snapshot(
+        &self,
+        _label: &str,
+        _readers: &[ReaderRecord],
+        _books: &[BookRecord],
+        _genres: &[String],
+    ) -> io::Result<SnapshotRecord> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "snapshots aren't supported",
+        ))
+    }
+}
+
+/// Reader as it's stored
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReaderRecord {
+    pub(crate) id: Id,
+    pub(crate) name: String,
+    pub(crate) family: String,
+    pub(crate) father: String,
+    pub(crate) info: String,
+    pub(crate) birth: Date,
+
+    /// Category of reader (e.g. "Student").
+    /// It's empty if reader has no category
+    pub(crate) category: String,
+
+    /// Books which reader is reading now
+    /// as **title author pages**.
+    /// Only informative, it's never loaded
+    pub(crate) reading: Vec<String>,
+}
+
+/// Giving of simple book to reader.
+/// Reader is found by its id
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LoanRecord {
+    pub(crate) reader: Id,
+    pub(crate) start: Date,
+    pub(crate) finish: Date,
+}
+
+/// Simple book (copy of the book) as it's stored
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CopyRecord {
+    pub(crate) id: Id,
+    pub(crate) cabinet: u16,
+    pub(crate) shelf: u8,
+
+    /// Type of copy (e.g. "Reference").
+    /// It's empty for ordinary copy
+    pub(crate) copy_type: String,
+
+    pub(crate) is_using: bool,
+    pub(crate) loans: Vec<LoanRecord>,
+}
+
+/// Type of book (The Book) as it's stored
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BookRecord {
+    pub(crate) id: Id,
+    pub(crate) title: String,
+    pub(crate) author: String,
+    pub(crate) pages: u16,
+    pub(crate) copies: Vec<CopyRecord>,
+    pub(crate) genres: Option<Vec<String>>,
+}
+
+/// Named snapshot as it's stored
+/// (without its readers, books and genres)
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotRecord {
+    /// Where snapshot is found in storage
+    /// (name of file or id of row)
+    pub(crate) key: String,
+    pub(crate) label: String,
+    pub(crate) time: NaiveDateTime,
+}
+
+impl SnapshotRecord {
+    /// Label given by librarian
+
+    #[inline]
+    pub fn label(&self) -> &str {
+        self.label.as_str()
+    }
+
+    /// Time when snapshot was made
+
+    #[inline]
+    pub fn time(&self) -> NaiveDateTime {
+        self.time
+    }
+}
+
+impl From<&Reader> for ReaderRecord {
+    /// Takes reader's params
+
+    #[inline]
+    fn from(reader: &Reader) -> Self {
+        ReaderRecord {
+            id: reader.id,
+            name: reader.name.clone(),
+            family: reader.family.clone(),
+            father: reader.father.clone(),
+            info: reader.info.clone(),
+            birth: reader.birth,
+            category: reader.category.clone(),
+            reading: reader
+                .reading
+                .iter()
+                .flatten()
+                .filter_map(|b| b.upgrade())
+                .map(|b| {
+                    format!(
+                        "{} {} {}",
+                        (*b).borrow().title(),
+                        (*b).borrow().author(),
+                        (*b).borrow().pages()
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&(Weak<RefCell<Reader>>, (Date, Date))> for LoanRecord {
+    /// Takes reader's id and dates of giving
+
+    #[inline]
+    fn from(loan: &(Weak<RefCell<Reader>>, (Date, Date))) -> Self {
+        LoanRecord {
+            reader: (*loan.0.upgrade().unwrap()).borrow().id,
+            start: (loan.1).0,
+            finish: (loan.1).1,
+        }
+    }
+}
+
+impl From<&Book> for CopyRecord {
+    /// Takes location, status and all readers of simple book
+
+    #[inline]
+    fn from(book: &Book) -> Self {
+        CopyRecord {
+            id: book.id,
+            cabinet: book.cabinet,
+            shelf: book.shelf,
+            copy_type: book.copy_type.clone(),
+            is_using: book.is_using,
+            loans: book.readers.iter().map(LoanRecord::from).collect(),
+        }
+    }
+}
+
+impl From<&TheBook> for BookRecord {
+    /// Takes params, simple books and genres of The Book.
+    /// Genres are sorted, so output doesn't depend on hashing
+
+    #[inline]
+    fn from(the_book: &TheBook) -> Self {
+        BookRecord {
+            id: the_book.id,
+            title: the_book.title.clone(),
+            author: the_book.author.clone(),
+            pages: the_book.pages,
+            copies: the_book
+                .books
+                .iter()
+                .map(|b| CopyRecord::from(&*(**b).borrow()))
+                .collect(),
+            genres: the_book.genres.as_ref().map(|g| {
+                let mut genres = g.iter().cloned().collect::<Vec<_>>();
+                genres.sort_unstable();
+                genres
+            }),
+        }
+    }
+}
+
+/// Opens storage chosen in the next order:
+/// command line flag, environment variable, YAML files.
+/// Names are **yaml**, **memory** and **sqlite**
+/// (only with `sqlite` feature)
+
+pub fn from_args<I: IntoIterator<Item = String>>(
+    args: I,
+    data_dir: &DataDir,
+) -> io::Result<Box<dyn Storage>> {
+    let name = crate::data::parse_flag(args, STORAGE_FLAG)
+        .or_else(|| env::var(STORAGE_ENV).ok())
+        .unwrap_or_default();
+
+    match name.to_lowercase().as_str() {
+        "" | "yaml" => Ok(Box::new(YamlStorage::new(data_dir.clone()))),
+        "memory" => Ok(Box::new(MemoryStorage::new())),
+
+        #[cfg(feature = "sqlite")]
+        "sqlite" => Ok(Box::new(sqlite::SqliteStorage::open(data_dir)?)),
+
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("unknown storage '{}'", name),
+        )),
+    }
+}
+
+/// Opens storage for current process
+
+#[inline]
+pub fn from_env(data_dir: &DataDir) -> io::Result<Box<dyn Storage>> {
+    from_args(env::args().skip(1), data_dir)
+}
+
+/// Copies all readers, books and genres
+/// from one storage to another.
+/// Nothing is written if any record is incorrect
+
+pub fn copy_all(from: &dyn Storage, to: &dyn Storage) -> Result<(), Box<dyn std::error::Error>> {
+    let readers = from
+        .read_readers()?
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+    let books = from
+        .read_books()?
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+    let genres = from
+        .read_genres()?
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+
+    to.begin_save()?;
+    to.write_all(&readers, &books, &genres)?;
+    Ok(())
+}
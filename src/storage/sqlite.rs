@@ -0,0 +1,1425 @@
+extern crate chrono;
+extern crate rusqlite;
+
+use crate::{
+    audit::AuditEntry,
+    books::date::Date,
+    data::{
+        error::{LoadError, LoadErrorKind, Record},
+        DataDir,
+    },
+    id::Id,
+    journal::{JournalEntry, JournalEvent, Replay},
+    restore::command::Command,
+    storage::{
+        copy_all,
+        memory::MemoryStorage,
+        yaml::{self, YamlStorage},
+        BookRecord, CopyRecord, LoanRecord, ReaderRecord, Records, SnapshotRecord, Storage,
+        TIME_FORMAT,
+    },
+};
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use chrono::{Local, NaiveDateTime};
+
+use rusqlite::{params, Connection, OptionalExtension, ToSql};
+
+use yaml_rust::YamlLoader;
+
+/// Name of database file inside of data directory
+
+const DB_FILE: &str = "library.sqlite";
+
+/// Current version of database schema.
+/// It's stored in `user_version` pragma
+
+pub const SCHEMA_VERSION: i64 = 8;
+
+/// All migrations in order.
+/// Migration with index n upgrades version n
+
+const MIGRATIONS: [&str; SCHEMA_VERSION as usize] = [
+    SCHEMA_V1, SCHEMA_V2, SCHEMA_V3, SCHEMA_V4, SCHEMA_V5, SCHEMA_V6, SCHEMA_V7, SCHEMA_V8,
+];
+
+/// How many events are appended to journal
+/// before the next state is kept,
+/// so past states are found by replaying few events
+
+const STATE_INTERVAL: u64 = 100;
+
+/// Tables of first version.
+/// Loans refer to readers by name, family, father and birth date
+
+const SCHEMA_V1: &str = "
+    CREATE TABLE IF NOT EXISTS readers (
+        id     INTEGER PRIMARY KEY,
+        name   TEXT NOT NULL,
+        family TEXT NOT NULL,
+        father TEXT NOT NULL,
+        info   TEXT NOT NULL,
+        birth  TEXT NOT NULL,
+        UNIQUE (name, family, father, birth)
+    );
+
+    CREATE TABLE IF NOT EXISTS titles (
+        id     INTEGER PRIMARY KEY,
+        title  TEXT NOT NULL,
+        author TEXT NOT NULL,
+        pages  INTEGER NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS title_genres (
+        title_id INTEGER NOT NULL REFERENCES titles (id) ON DELETE CASCADE,
+        genre    TEXT NOT NULL,
+        PRIMARY KEY (title_id, genre)
+    );
+
+    CREATE TABLE IF NOT EXISTS copies (
+        id       INTEGER PRIMARY KEY,
+        title_id INTEGER NOT NULL REFERENCES titles (id) ON DELETE CASCADE,
+        cabinet  INTEGER NOT NULL,
+        shelf    INTEGER NOT NULL,
+        in_use   INTEGER NOT NULL
+    );
+
+    CREATE INDEX IF NOT EXISTS copies_title ON copies (title_id);
+
+    CREATE TABLE IF NOT EXISTS loans (
+        id            INTEGER PRIMARY KEY,
+        copy_id       INTEGER NOT NULL REFERENCES copies (id) ON DELETE CASCADE,
+        reader_name   TEXT NOT NULL,
+        reader_family TEXT NOT NULL,
+        reader_father TEXT NOT NULL,
+        reader_birth  TEXT NOT NULL,
+        start         TEXT NOT NULL,
+        finish        TEXT NOT NULL
+    );
+
+    CREATE INDEX IF NOT EXISTS loans_copy ON loans (copy_id);
+
+    CREATE INDEX IF NOT EXISTS loans_reader
+        ON loans (reader_name, reader_family, reader_father, reader_birth);
+
+    CREATE TABLE IF NOT EXISTS genres (
+        name TEXT PRIMARY KEY
+    );
+";
+
+/// Version 1 → 2.
+/// Row ids of readers, titles and copies become their stable ids,
+/// loans refer to reader's id. Unknown readers get id 0
+
+const SCHEMA_V2: &str = "
+    CREATE TABLE loans_v2 (
+        id        INTEGER PRIMARY KEY,
+        copy_id   INTEGER NOT NULL REFERENCES copies (id) ON DELETE CASCADE,
+        reader_id INTEGER NOT NULL,
+        start     TEXT NOT NULL,
+        finish    TEXT NOT NULL
+    );
+
+    INSERT INTO loans_v2 (id, copy_id, reader_id, start, finish)
+        SELECT loans.id, loans.copy_id, COALESCE((
+            SELECT readers.id FROM readers
+            WHERE readers.name = loans.reader_name
+                AND readers.family = loans.reader_family
+                AND readers.father = loans.reader_father
+                AND readers.birth = loans.reader_birth
+        ), 0), loans.start, loans.finish
+        FROM loans;
+
+    DROP TABLE loans;
+    ALTER TABLE loans_v2 RENAME TO loans;
+
+    CREATE INDEX loans_copy ON loans (copy_id);
+    CREATE INDEX loans_reader ON loans (reader_id);
+";
+
+/// Version 2 → 3.
+/// History of changes is stored as one YAML document,
+/// the same as history file of YAML storage
+
+const SCHEMA_V3: &str = "
+    CREATE TABLE history (
+        id       INTEGER PRIMARY KEY CHECK (id = 1),
+        document TEXT NOT NULL
+    );
+";
+
+/// Version 3 → 4.
+/// Named snapshots are stored as YAML documents,
+/// the same as snapshot files of YAML storage
+
+const SCHEMA_V4: &str = "
+    CREATE TABLE snapshots (
+        id       INTEGER PRIMARY KEY AUTOINCREMENT,
+        label    TEXT NOT NULL,
+        time     TEXT NOT NULL,
+        document TEXT NOT NULL
+    );
+";
+
+/// Version 4 → 5.
+/// Every entry of audit log is YAML document,
+/// the same as in audit file of YAML storage.
+/// Triggers forbid changing written entries
+
+const SCHEMA_V5: &str = "
+    CREATE TABLE audit (
+        id       INTEGER PRIMARY KEY AUTOINCREMENT,
+        time     TEXT NOT NULL,
+        user     TEXT NOT NULL,
+        document TEXT NOT NULL
+    );
+
+    CREATE TRIGGER audit_no_update BEFORE UPDATE ON audit
+    BEGIN
+        SELECT RAISE(ABORT, 'audit log is append-only');
+    END;
+
+    CREATE TRIGGER audit_no_delete BEFORE DELETE ON audit
+    BEGIN
+        SELECT RAISE(ABORT, 'audit log is append-only');
+    END;
+";
+
+/// Version 5 → 6.
+/// Readers get category, existing readers have none
+
+const SCHEMA_V6: &str = "
+    ALTER TABLE readers ADD COLUMN category TEXT NOT NULL DEFAULT '';
+";
+
+/// Version 6 → 7.
+/// Copies get type, existing copies are ordinary
+
+const SCHEMA_V7: &str = "
+    ALTER TABLE copies ADD COLUMN copy_type TEXT NOT NULL DEFAULT '';
+";
+
+/// Version 7 → 8.
+/// Every event of journal is YAML document,
+/// the same as in journal file of YAML storage.
+/// States of journal are YAML documents of snapshots,
+/// the first one is state before the first event.
+/// Triggers forbid changing written events
+
+const SCHEMA_V8: &str = "
+    CREATE TABLE journal (
+        seq      INTEGER PRIMARY KEY,
+        time     TEXT NOT NULL,
+        document TEXT NOT NULL
+    );
+
+    CREATE INDEX journal_time ON journal (time);
+
+    CREATE TABLE journal_states (
+        seq      INTEGER PRIMARY KEY,
+        document TEXT NOT NULL
+    );
+
+    CREATE TRIGGER journal_no_update BEFORE UPDATE ON journal
+    BEGIN
+        SELECT RAISE(ABORT, 'journal is append-only');
+    END;
+
+    CREATE TRIGGER journal_no_delete BEFORE DELETE ON journal
+    BEGIN
+        SELECT RAISE(ABORT, 'journal is append-only');
+    END;
+";
+
+/// Storage in embedded SQLite database.
+/// Every change is applied to changed rows only
+/// and appended to journal in one transaction,
+/// so database is never left half-written
+
+#[derive(Debug)]
+pub struct SqliteStorage {
+    conn: RefCell<Connection>,
+    path: PathBuf,
+}
+
+impl SqliteStorage {
+    /// Opens database in data directory.
+    /// If database doesn't exist yet,
+    /// all data is imported from YAML files
+
+    pub fn open(data_dir: &DataDir) -> io::Result<Self> {
+        let path = data_dir.file(DB_FILE);
+        let is_new = !path.exists();
+        let storage = SqliteStorage::open_file(&path)?;
+
+        if is_new {
+            if let Err(err) = copy_all(&YamlStorage::new(data_dir.clone()), &storage) {
+                drop(storage);
+                fs::remove_file(&path)?;
+
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("YAML data can't be imported: {}", err),
+                ));
+            }
+        }
+
+        Ok(storage)
+    }
+
+    /// Opens database file and upgrades its schema.
+    /// Database is backed up before every upgrade step
+
+    pub fn open_file(path: &Path) -> io::Result<Self> {
+        let conn = Connection::open(path).map_err(io_error)?;
+
+        conn.execute_batch("PRAGMA foreign_keys = ON;")
+            .map_err(io_error)?;
+
+        let mut version = conn
+            .query_row("PRAGMA user_version", [], |row| row.get::<_, i64>(0))
+            .map_err(io_error)?;
+
+        if version > SCHEMA_VERSION || version < 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("database version {} isn't supported", version),
+            ));
+        }
+
+        while version < SCHEMA_VERSION {
+            if fs::metadata(path)?.len() > 0 {
+                let mut backup = path.file_name().unwrap_or_default().to_os_string();
+                backup.push(format!(".v{}.bak", version));
+                fs::copy(path, path.with_file_name(backup))?;
+            }
+
+            version += 1;
+
+            conn.execute_batch(&format!(
+                "BEGIN; {} PRAGMA user_version = {}; COMMIT;",
+                MIGRATIONS[version as usize - 1],
+                version
+            ))
+            .map_err(io_error)?;
+        }
+
+        Ok(SqliteStorage {
+            conn: RefCell::new(conn),
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Error of whole database
+
+    #[inline]
+    fn error(&self, err: rusqlite::Error) -> LoadError {
+        LoadError::file(&self.path, LoadErrorKind::Sqlite(Box::new(err)))
+    }
+
+    /// Error of one row
+
+    #[inline]
+    fn row_error(&self, ind: usize, err: rusqlite::Error) -> LoadError {
+        LoadError {
+            file: self.path.clone(),
+            record: Some(ind),
+            field: None,
+            kind: LoadErrorKind::Sqlite(Box::new(err)),
+        }
+    }
+
+    /// Parses YAML document of snapshot or state of journal
+
+    fn snapshot_document(&self, document: &str) -> Result<MemoryStorage, LoadError> {
+        match YamlLoader::load_from_str(document) {
+            Ok(docs) if !docs.is_empty() => yaml::snapshot_records(&self.path, &docs[0]),
+            Ok(_) => Err(LoadError::file(&self.path, LoadErrorKind::WrongLayout)),
+            Err(err) => Err(LoadError::file(&self.path, LoadErrorKind::Yaml(err))),
+        }
+    }
+
+    /// Parses row of journal with YAML document of event
+
+    fn journal_entry(
+        &self,
+        ind: usize,
+        row: rusqlite::Result<String>,
+    ) -> Result<JournalEntry, LoadError> {
+        let document = row.map_err(|e| self.row_error(ind, e))?;
+        let record = Record::new(&self.path, ind);
+
+        match YamlLoader::load_from_str(&document) {
+            Ok(docs) if !docs.is_empty() => yaml::journal_from_yaml(&record, &docs[0]),
+            Ok(_) => Err(record.error("document", LoadErrorKind::WrongLayout)),
+            Err(err) => Err(record.error("document", LoadErrorKind::Yaml(err))),
+        }
+    }
+
+    /// Reads all readers with connection
+    /// (database or its transaction)
+
+    fn readers_of(&self, conn: &Connection) -> Records<ReaderRecord> {
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, name, family, father, info, birth, category FROM readers ORDER BY id",
+            )
+            .map_err(|e| self.error(e))?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, String>(4)?,
+                    row.get::<_, String>(5)?,
+                    row.get::<_, String>(6)?,
+                ))
+            })
+            .map_err(|e| self.error(e))?;
+
+        Ok(rows
+            .enumerate()
+            .map(|(ind, row)| {
+                let (reader_id, name, family, father, info, birth, category) =
+                    row.map_err(|e| self.row_error(ind, e))?;
+
+                let record = Record::new(&self.path, ind);
+
+                Ok(ReaderRecord {
+                    id: id(&record, "id", reader_id)?,
+                    birth: date(&record, "birth", &birth)?,
+                    name,
+                    family,
+                    father,
+                    info,
+                    category,
+                    reading: vec![],
+                })
+            })
+            .collect())
+    }
+
+    /// Reads all books with connection
+
+    fn books_of(&self, conn: &Connection) -> Records<BookRecord> {
+        let mut stmt = conn
+            .prepare("SELECT id, title, author, pages FROM titles ORDER BY id")
+            .map_err(|e| self.error(e))?;
+
+        let mut positions = HashMap::new();
+        let mut books = vec![];
+
+        for (ind, row) in stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, i64>(3)?,
+                ))
+            })
+            .map_err(|e| self.error(e))?
+            .enumerate()
+        {
+            books.push(row.map_err(|e| self.row_error(ind, e)).and_then(
+                |(title_id, title, author, pages)| {
+                    positions.insert(title_id, ind);
+                    let record = Record::new(&self.path, ind);
+
+                    Ok(BookRecord {
+                        id: id(&record, "id", title_id)?,
+                        title,
+                        author,
+                        pages: int(&record, "pages", pages)?,
+                        copies: vec![],
+                        genres: None,
+                    })
+                },
+            ));
+        }
+
+        let mut stmt = conn
+            .prepare("SELECT title_id, genre FROM title_genres ORDER BY genre")
+            .map_err(|e| self.error(e))?;
+
+        for row in stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+            })
+            .map_err(|e| self.error(e))?
+        {
+            let (title_id, genre) = row.map_err(|e| self.error(e))?;
+
+            if let Some(Ok(book)) = positions.get(&title_id).map(|&ind| &mut books[ind]) {
+                book.genres.get_or_insert_with(Vec::new).push(genre);
+            }
+        }
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, title_id, cabinet, shelf, in_use, copy_type FROM copies ORDER BY id",
+            )
+            .map_err(|e| self.error(e))?;
+
+        let mut copies = HashMap::new();
+
+        for row in stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, i64>(2)?,
+                    row.get::<_, i64>(3)?,
+                    row.get::<_, bool>(4)?,
+                    row.get::<_, String>(5)?,
+                ))
+            })
+            .map_err(|e| self.error(e))?
+        {
+            let (copy_id, title_id, cabinet, shelf, is_using, copy_type) =
+                row.map_err(|e| self.error(e))?;
+
+            let ind = match positions.get(&title_id) {
+                Some(&ind) => ind,
+                None => continue,
+            };
+
+            if let Ok(book) = &books[ind] {
+                let record = Record::new(&self.path, ind).nested("copies", book.copies.len());
+
+                let copy = id(&record, "id", copy_id).and_then(|copy_id| {
+                    Ok(CopyRecord {
+                        id: copy_id,
+                        cabinet: int(&record, "cabinet", cabinet)?,
+                        shelf: int(&record, "shelf", shelf)?,
+                        copy_type,
+                        is_using,
+                        loans: vec![],
+                    })
+                });
+
+                match copy {
+                    Ok(copy) => {
+                        copies.insert(copy_id, (ind, book.copies.len()));
+
+                        if let Ok(book) = &mut books[ind] {
+                            book.copies.push(copy);
+                        }
+                    }
+
+                    Err(err) => books[ind] = Err(err),
+                }
+            }
+        }
+
+        let mut stmt = conn
+            .prepare("SELECT copy_id, reader_id, start, finish FROM loans ORDER BY id")
+            .map_err(|e| self.error(e))?;
+
+        for row in stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                ))
+            })
+            .map_err(|e| self.error(e))?
+        {
+            let (copy_id, reader_id, start, finish) = row.map_err(|e| self.error(e))?;
+
+            let (ind, copy_ind) = match copies.get(&copy_id) {
+                Some(&pos) => pos,
+                None => continue,
+            };
+
+            if let Ok(book) = &books[ind] {
+                let record = Record::new(&self.path, ind)
+                    .nested("copies", copy_ind)
+                    .nested("loans", book.copies[copy_ind].loans.len());
+
+                let loan = id(&record, "reader_id", reader_id).and_then(|reader| {
+                    Ok(LoanRecord {
+                        reader,
+                        start: date(&record, "start", &start)?,
+                        finish: date(&record, "finish", &finish)?,
+                    })
+                });
+
+                match loan {
+                    Ok(loan) => {
+                        if let Ok(book) = &mut books[ind] {
+                            book.copies[copy_ind].loans.push(loan);
+                        }
+                    }
+
+                    Err(err) => books[ind] = Err(err),
+                }
+            }
+        }
+
+        Ok(books)
+    }
+
+    /// Reads all genres with connection
+
+    fn genres_of(&self, conn: &Connection) -> Records<String> {
+        let mut stmt = conn
+            .prepare("SELECT name FROM genres ORDER BY name")
+            .map_err(|e| self.error(e))?;
+
+        let rows = stmt
+            .query_map([], |row| row.get::<_, String>(0))
+            .map_err(|e| self.error(e))?;
+
+        Ok(rows
+            .enumerate()
+            .map(|(ind, row)| row.map_err(|e| self.row_error(ind, e)))
+            .collect())
+    }
+
+    /// Current readers, books and genres read with connection.
+    /// If any record is incorrect, it will return error
+
+    #[inline]
+    fn state_of(&self, conn: &Connection) -> Result<MemoryStorage, LoadError> {
+        Replay {
+            readers: self.readers_of(conn)?,
+            books: self.books_of(conn)?,
+            genres: self.genres_of(conn)?,
+        }
+        .into_storage()
+    }
+
+    /// Writes only readers which differ from stored ones
+    /// and removes readers which aren't in the list
+
+    fn put_readers(&self, conn: &Connection, readers: &[ReaderRecord]) -> io::Result<()> {
+        let stored = stored_by_id(self.readers_of(conn), |r| r.id)?;
+        let ids = readers.iter().map(|r| r.id).collect::<HashSet<_>>();
+
+        remove_missing(conn, "readers", &ids).map_err(io_error)?;
+
+        readers
+            .iter()
+            .filter(|reader| {
+                stored.get(&reader.id).map_or(true, |old| {
+                    *old != ReaderRecord {
+                        reading: vec![],
+                        ..(*reader).clone()
+                    }
+                })
+            })
+            .try_for_each(|reader| put_reader(conn, reader))
+            .map_err(io_error)
+    }
+
+    /// Writes only books which differ from stored ones
+    /// and removes books which aren't in the list
+
+    fn put_books(&self, conn: &Connection, books: &[BookRecord]) -> io::Result<()> {
+        let stored = stored_by_id(self.books_of(conn), |b| b.id)?;
+        let ids = books.iter().map(|b| b.id).collect::<HashSet<_>>();
+
+        remove_missing(conn, "titles", &ids).map_err(io_error)?;
+
+        books
+            .iter()
+            .filter(|book| stored.get(&book.id) != Some(*book))
+            .try_for_each(|book| put_book(conn, book))
+            .map_err(io_error)
+    }
+
+    /// Adds new genres and removes genres which aren't in the list
+
+    fn put_genres(&self, conn: &Connection, genres: &[String]) -> io::Result<()> {
+        let stored = self
+            .genres_of(conn)
+            .map_err(invalid_data)?
+            .into_iter()
+            .collect::<Result<HashSet<_>, _>>()
+            .map_err(invalid_data)?;
+
+        stored
+            .iter()
+            .filter(|genre| !genres.contains(genre))
+            .try_for_each(|genre| {
+                conn.prepare_cached("DELETE FROM genres WHERE name = ?1")?
+                    .execute(params![genre])
+                    .map(|_| ())
+            })
+            .map_err(io_error)?;
+
+        genres
+            .iter()
+            .filter(|genre| !stored.contains(*genre))
+            .try_for_each(|genre| {
+                conn.prepare_cached("INSERT OR IGNORE INTO genres (name) VALUES (?1)")?
+                    .execute(params![genre])
+                    .map(|_| ())
+            })
+            .map_err(io_error)
+    }
+}
+
+impl Storage for SqliteStorage {
+    #[inline]
+    fn readers_path(&self) -> PathBuf {
+        self.path.clone()
+    }
+
+    #[inline]
+    fn books_path(&self) -> PathBuf {
+        self.path.clone()
+    }
+
+    #[inline]
+    fn read_readers(&self) -> Records<ReaderRecord> {
+        self.readers_of(&self.conn.borrow())
+    }
+
+    #[inline]
+    fn read_books(&self) -> Records<BookRecord> {
+        self.books_of(&self.conn.borrow())
+    }
+
+    #[inline]
+    fn read_genres(&self) -> Records<String> {
+        self.genres_of(&self.conn.borrow())
+    }
+
+    fn write_readers(&self, readers: &[ReaderRecord]) -> io::Result<()> {
+        let mut conn = self.conn.borrow_mut();
+        let tx = conn.transaction().map_err(io_error)?;
+        self.put_readers(&tx, readers)?;
+        tx.commit().map_err(io_error)
+    }
+
+    fn write_books(&self, books: &[BookRecord]) -> io::Result<()> {
+        let mut conn = self.conn.borrow_mut();
+        let tx = conn.transaction().map_err(io_error)?;
+        self.put_books(&tx, books)?;
+        tx.commit().map_err(io_error)
+    }
+
+    fn write_genres(&self, genres: &[String]) -> io::Result<()> {
+        let mut conn = self.conn.borrow_mut();
+        let tx = conn.transaction().map_err(io_error)?;
+        self.put_genres(&tx, genres)?;
+        tx.commit().map_err(io_error)
+    }
+
+    /// Readers, books and genres are written in one transaction,
+    /// so database never has only part of them
+
+    fn write_all(
+        &self,
+        readers: &[ReaderRecord],
+        books: &[BookRecord],
+        genres: &[String],
+    ) -> io::Result<()> {
+        let mut conn = self.conn.borrow_mut();
+        let tx = conn.transaction().map_err(io_error)?;
+
+        self.put_readers(&tx, readers)?;
+        self.put_books(&tx, books)?;
+        self.put_genres(&tx, genres)?;
+
+        tx.commit().map_err(io_error)
+    }
+
+    fn read_history(&self) -> Result<(Vec<Command>, usize), LoadError> {
+        let document = self
+            .conn
+            .borrow()
+            .query_row("SELECT document FROM history WHERE id = 1", [], |row| {
+                row.get::<_, String>(0)
+            })
+            .optional()
+            .map_err(|e| self.error(e))?;
+
+        let document = match document {
+            None => return Ok((vec![], 0)),
+            Some(document) => document,
+        };
+
+        match YamlLoader::load_from_str(&document) {
+            Ok(docs) if !docs.is_empty() => yaml::history_from_yaml(&self.path, &docs[0]),
+            Ok(_) => Err(LoadError::file(&self.path, LoadErrorKind::WrongLayout)),
+            Err(err) => Err(LoadError::file(&self.path, LoadErrorKind::Yaml(err))),
+        }
+    }
+
+    fn write_history(&self, commands: &[Command], done: usize) -> io::Result<()> {
+        let document = yaml::emit(&yaml::history_to_yaml(commands, done))?;
+
+        self.conn
+            .borrow()
+            .execute(
+                "INSERT OR REPLACE INTO history (id, document) VALUES (1, ?1)",
+                params![document],
+            )
+            .map(|_| ())
+            .map_err(io_error)
+    }
+
+    fn append_audit(&self, entry: &AuditEntry) -> io::Result<()> {
+        let document = yaml::emit(&yaml::audit_to_yaml(entry))?;
+
+        self.conn
+            .borrow()
+            .execute(
+                "INSERT INTO audit (time, user, document) VALUES (?1, ?2, ?3)",
+                params![
+                    entry.time().format(TIME_FORMAT).to_string(),
+                    entry.user,
+                    document
+                ],
+            )
+            .map(|_| ())
+            .map_err(io_error)
+    }
+
+    fn read_audit(&self) -> Result<Vec<AuditEntry>, LoadError> {
+        let conn = self.conn.borrow();
+
+        let mut stmt = conn
+            .prepare("SELECT document FROM audit ORDER BY id")
+            .map_err(|e| self.error(e))?;
+
+        let rows = stmt
+            .query_map([], |row| row.get::<_, String>(0))
+            .map_err(|e| self.error(e))?;
+
+        rows.enumerate()
+            .map(|(ind, row)| {
+                let document = row.map_err(|e| self.row_error(ind, e))?;
+                let record = Record::new(&self.path, ind);
+
+                match YamlLoader::load_from_str(&document) {
+                    Ok(docs) if !docs.is_empty() => yaml::audit_from_yaml(&record, &docs[0]),
+                    Ok(_) => Err(record.error("document", LoadErrorKind::WrongLayout)),
+                    Err(err) => Err(record.error("document", LoadErrorKind::Yaml(err))),
+                }
+            })
+            .collect()
+    }
+
+    /// Events are applied to changed rows and appended to journal
+    /// in one transaction. When journal starts,
+    /// current data is kept as its first state.
+    /// If it can't be read, journal isn't started
+    /// and records are written in full
+
+    fn append_events(&self, events: &[JournalEvent]) -> io::Result<bool> {
+        let mut conn = self.conn.borrow_mut();
+        let tx = conn.transaction().map_err(io_error)?;
+
+        let started = tx
+            .query_row("SELECT COUNT(*) FROM journal_states", [], |row| {
+                row.get::<_, i64>(0)
+            })
+            .map_err(io_error)?
+            > 0;
+
+        if !started {
+            match self.state_of(&tx) {
+                Ok(state) => write_state(&tx, 0, &state)?,
+                Err(_) => return Ok(false),
+            }
+        }
+
+        let start = last_seq(&tx).map_err(io_error)?;
+        let time = Local::now().naive_local();
+        let mut seq = start;
+
+        for event in events {
+            apply_event(&tx, event).map_err(io_error)?;
+            seq += 1;
+
+            let entry = JournalEntry {
+                seq,
+                time,
+                event: event.clone(),
+            };
+
+            let document = yaml::emit(&yaml::journal_to_yaml(&entry))?;
+
+            tx.execute(
+                "INSERT INTO journal (seq, time, document) VALUES (?1, ?2, ?3)",
+                params![seq as i64, time.format(TIME_FORMAT).to_string(), document],
+            )
+            .map_err(io_error)?;
+        }
+
+        if start / STATE_INTERVAL != seq / STATE_INTERVAL {
+            if let Ok(state) = self.state_of(&tx) {
+                write_state(&tx, seq, &state)?;
+            }
+        }
+
+        tx.commit().map_err(io_error)?;
+        Ok(true)
+    }
+
+    fn read_journal(&self) -> Result<Vec<JournalEntry>, LoadError> {
+        let conn = self.conn.borrow();
+
+        let mut stmt = conn
+            .prepare("SELECT document FROM journal ORDER BY seq")
+            .map_err(|e| self.error(e))?;
+
+        let rows = stmt
+            .query_map([], |row| row.get::<_, String>(0))
+            .map_err(|e| self.error(e))?;
+
+        rows.enumerate()
+            .map(|(ind, row)| self.journal_entry(ind, row))
+            .collect()
+    }
+
+    /// State is restored from the last state kept
+    /// before the last event preceding chosen time.
+    /// Before the first event it's the state journal started with
+
+    fn state_at(&self, time: NaiveDateTime) -> Result<Option<MemoryStorage>, LoadError> {
+        let conn = self.conn.borrow();
+
+        let last = conn
+            .query_row(
+                "SELECT MAX(seq) FROM journal WHERE time <= ?1",
+                params![time.format(TIME_FORMAT).to_string()],
+                |row| row.get::<_, Option<i64>>(0),
+            )
+            .map_err(|e| self.error(e))?
+            .unwrap_or(0);
+
+        let start = conn
+            .query_row(
+                "SELECT seq, document FROM journal_states WHERE seq <= ?1 \
+                 ORDER BY seq DESC LIMIT 1",
+                params![last],
+                |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)),
+            )
+            .optional()
+            .map_err(|e| self.error(e))?;
+
+        let (start, document) = match start {
+            None => return Ok(None),
+            Some(start) => start,
+        };
+
+        let mut replay = Replay::from_storage(&self.snapshot_document(&document)?);
+
+        let mut stmt = conn
+            .prepare("SELECT document FROM journal WHERE seq > ?1 AND seq <= ?2 ORDER BY seq")
+            .map_err(|e| self.error(e))?;
+
+        let rows = stmt
+            .query_map(params![start, last], |row| row.get::<_, String>(0))
+            .map_err(|e| self.error(e))?;
+
+        for (ind, row) in rows.enumerate() {
+            replay.apply(&self.journal_entry(ind, row)?.event);
+        }
+
+        replay.into_storage().map(Some)
+    }
+
+    fn snapshots(&self) -> io::Result<Vec<SnapshotRecord>> {
+        let conn = self.conn.borrow();
+
+        let mut stmt = conn
+            .prepare("SELECT id, label, time FROM snapshots ORDER BY id DESC")
+            .map_err(io_error)?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            })
+            .map_err(io_error)?;
+
+        rows.map(|row| {
+            let (id, label, time) = row.map_err(io_error)?;
+
+            Ok(SnapshotRecord {
+                key: id.to_string(),
+                label,
+                time: NaiveDateTime::parse_from_str(&time, TIME_FORMAT)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            })
+        })
+        .collect()
+    }
+
+    fn read_snapshot(&self, snapshot: &SnapshotRecord) -> Result<MemoryStorage, LoadError> {
+        let document = self
+            .conn
+            .borrow()
+            .query_row(
+                "SELECT document FROM snapshots WHERE id = ?1",
+                params![snapshot.key],
+                |row| row.get::<_, String>(0),
+            )
+            .map_err(|e| self.error(e))?;
+
+        self.snapshot_document(&document)
+    }
+
+    fn write_snapshot(
+        &self,
+        label: &str,
+        readers: &[ReaderRecord],
+        books: &[BookRecord],
+        genres: &[String],
+    ) -> io::Result<SnapshotRecord> {
+        let mut snapshot = SnapshotRecord {
+            key: String::new(),
+            label: label.to_string(),
+            time: Local::now().naive_local(),
+        };
+
+        let document = yaml::emit(&yaml::snapshot_to_yaml(&snapshot, readers, books, genres))?;
+        let conn = self.conn.borrow();
+
+        conn.execute(
+            "INSERT INTO snapshots (label, time, document) VALUES (?1, ?2, ?3)",
+            params![
+                snapshot.label,
+                snapshot.time.format(TIME_FORMAT).to_string(),
+                document
+            ],
+        )
+        .map_err(io_error)?;
+
+        snapshot.key = conn.last_insert_rowid().to_string();
+        Ok(snapshot)
+    }
+}
+
+/// Converts database error to io error
+
+#[inline]
+fn io_error(err: rusqlite::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, err)
+}
+
+/// Converts error of stored records to io error
+
+#[inline]
+fn invalid_data(err: LoadError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+/// Correct stored records by their ids.
+/// Incorrect records are skipped, so they are written again
+
+fn stored_by_id<T>(records: Records<T>, id: fn(&T) -> Id) -> io::Result<HashMap<Id, T>> {
+    Ok(records
+        .map_err(invalid_data)?
+        .into_iter()
+        .flatten()
+        .map(|record| (id(&record), record))
+        .collect())
+}
+
+/// Removes rows of table which ids aren't in the set.
+/// Rows which refer to them are removed too
+
+fn remove_missing(conn: &Connection, table: &str, ids: &HashSet<Id>) -> rusqlite::Result<()> {
+    let stored = conn
+        .prepare(&format!("SELECT id FROM {}", table))?
+        .query_map([], |row| row.get::<_, i64>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let mut stmt = conn.prepare(&format!("DELETE FROM {} WHERE id = ?1", table))?;
+
+    stored
+        .into_iter()
+        .filter(|&id| !ids.contains(&Id(id as u64)))
+        .try_for_each(|id| stmt.execute(params![id]).map(|_| ()))
+}
+
+/// Updates row of reader or inserts it if it's new
+
+fn put_reader(conn: &Connection, reader: &ReaderRecord) -> rusqlite::Result<()> {
+    let row = params![
+        reader.id.get() as i64,
+        reader.name,
+        reader.family,
+        reader.father,
+        reader.info,
+        date_to_sql(reader.birth),
+        reader.category,
+    ];
+
+    let updated = conn
+        .prepare_cached(
+            "UPDATE readers SET name = ?2, family = ?3, father = ?4, info = ?5, \
+             birth = ?6, category = ?7 WHERE id = ?1",
+        )?
+        .execute(row)?;
+
+    if updated == 0 {
+        conn.prepare_cached(
+            "INSERT INTO readers (id, name, family, father, info, birth, category) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        )?
+        .execute(row)?;
+    }
+
+    Ok(())
+}
+
+/// Updates rows of The Book, its genres and simple books
+/// or inserts them if they are new.
+/// Removed simple books are deleted with their loans
+
+fn put_book(conn: &Connection, book: &BookRecord) -> rusqlite::Result<()> {
+    let title_id = book.id.get() as i64;
+    let row = params![title_id, book.title, book.author, book.pages];
+
+    let updated = conn
+        .prepare_cached("UPDATE titles SET title = ?2, author = ?3, pages = ?4 WHERE id = ?1")?
+        .execute(row)?;
+
+    if updated == 0 {
+        conn.prepare_cached(
+            "INSERT INTO titles (id, title, author, pages) VALUES (?1, ?2, ?3, ?4)",
+        )?
+        .execute(row)?;
+    }
+
+    set_genres(conn, book.id, &book.genres)?;
+
+    let stored = conn
+        .prepare_cached("SELECT id FROM copies WHERE title_id = ?1")?
+        .query_map(params![title_id], |row| row.get::<_, i64>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    stored
+        .into_iter()
+        .filter(|&id| !book.copies.iter().any(|c| c.id.get() as i64 == id))
+        .try_for_each(|id| {
+            conn.prepare_cached("DELETE FROM copies WHERE id = ?1")?
+                .execute(params![id])
+                .map(|_| ())
+        })?;
+
+    book.copies
+        .iter()
+        .try_for_each(|copy| put_copy(conn, book.id, copy))
+}
+
+/// Updates row of simple book or inserts it if it's new.
+/// Loans which are the same as stored ones keep their rows,
+/// only the rest is replaced
+
+fn put_copy(conn: &Connection, book: Id, copy: &CopyRecord) -> rusqlite::Result<()> {
+    let copy_id = copy.id.get() as i64;
+
+    let row = params![
+        copy_id,
+        book.get() as i64,
+        copy.cabinet,
+        copy.shelf,
+        copy.is_using,
+        copy.copy_type,
+    ];
+
+    let updated = conn
+        .prepare_cached(
+            "UPDATE copies SET title_id = ?2, cabinet = ?3, shelf = ?4, in_use = ?5, \
+             copy_type = ?6 WHERE id = ?1",
+        )?
+        .execute(row)?;
+
+    if updated == 0 {
+        conn.prepare_cached(
+            "INSERT INTO copies (id, title_id, cabinet, shelf, in_use, copy_type) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        )?
+        .execute(row)?;
+    }
+
+    let stored = conn
+        .prepare_cached(
+            "SELECT id, reader_id, start, finish FROM loans WHERE copy_id = ?1 ORDER BY id",
+        )?
+        .query_map(params![copy_id], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                (
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                ),
+            ))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let loans = copy
+        .loans
+        .iter()
+        .map(|loan| {
+            (
+                loan.reader.get() as i64,
+                date_to_sql(loan.start),
+                date_to_sql(loan.finish),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let kept = stored
+        .iter()
+        .zip(&loans)
+        .take_while(|((_, old), new)| old == *new)
+        .count();
+
+    stored[kept..].iter().try_for_each(|(id, _)| {
+        conn.prepare_cached("DELETE FROM loans WHERE id = ?1")?
+            .execute(params![id])
+            .map(|_| ())
+    })?;
+
+    loans[kept..]
+        .iter()
+        .try_for_each(|(reader, start, finish)| insert_loan(conn, copy_id, *reader, start, finish))
+}
+
+/// Adds loan to the end of simple book's loans
+
+#[inline]
+fn insert_loan(
+    conn: &Connection,
+    copy_id: i64,
+    reader: i64,
+    start: &str,
+    finish: &str,
+) -> rusqlite::Result<()> {
+    conn.prepare_cached(
+        "INSERT INTO loans (copy_id, reader_id, start, finish) VALUES (?1, ?2, ?3, ?4)",
+    )?
+    .execute(params![copy_id, reader, start, finish])
+    .map(|_| ())
+}
+
+/// Replaces genres of The Book
+
+fn set_genres(conn: &Connection, book: Id, genres: &Option<Vec<String>>) -> rusqlite::Result<()> {
+    let title_id = book.get() as i64;
+
+    conn.prepare_cached("DELETE FROM title_genres WHERE title_id = ?1")?
+        .execute(params![title_id])?;
+
+    genres.iter().flatten().try_for_each(|genre| {
+        conn.prepare_cached("INSERT OR IGNORE INTO title_genres (title_id, genre) VALUES (?1, ?2)")?
+            .execute(params![title_id, genre])
+            .map(|_| ())
+    })
+}
+
+/// Changes rows touched by event of journal
+
+fn apply_event(conn: &Connection, event: &JournalEvent) -> rusqlite::Result<()> {
+    let execute =
+        |sql: &str, params: &[&dyn ToSql]| conn.prepare_cached(sql)?.execute(params).map(|_| ());
+
+    match event {
+        JournalEvent::ReaderAdded { reader, .. } | JournalEvent::ReaderChanged(reader) => {
+            put_reader(conn, reader)
+        }
+
+        JournalEvent::ReaderRemoved(id) => execute(
+            "DELETE FROM readers WHERE id = ?1",
+            params![id.get() as i64],
+        ),
+
+        JournalEvent::BookAdded { book, .. } | JournalEvent::BookReplaced(book) => {
+            put_book(conn, book)
+        }
+
+        JournalEvent::BookChanged {
+            book,
+            title,
+            author,
+            pages,
+        } => execute(
+            "UPDATE titles SET title = ?2, author = ?3, pages = ?4 WHERE id = ?1",
+            params![book.get() as i64, title, author, pages],
+        ),
+
+        JournalEvent::BookRemoved(id) => {
+            execute("DELETE FROM titles WHERE id = ?1", params![id.get() as i64])
+        }
+
+        JournalEvent::GenresAssigned { book, genres } => set_genres(conn, *book, genres),
+
+        JournalEvent::CopyAdded { book, copy, .. } | JournalEvent::CopyReplaced { book, copy } => {
+            put_copy(conn, *book, copy)
+        }
+
+        JournalEvent::CopyRemoved { copy, .. } => execute(
+            "DELETE FROM copies WHERE id = ?1",
+            params![copy.get() as i64],
+        ),
+
+        JournalEvent::CopyMoved {
+            copy,
+            cabinet,
+            shelf,
+            ..
+        } => execute(
+            "UPDATE copies SET cabinet = ?2, shelf = ?3 WHERE id = ?1",
+            params![copy.get() as i64, cabinet, shelf],
+        ),
+
+        JournalEvent::CopyGiven { copy, loan, .. } => {
+            let copy_id = copy.get() as i64;
+
+            insert_loan(
+                conn,
+                copy_id,
+                loan.reader.get() as i64,
+                &date_to_sql(loan.start),
+                &date_to_sql(loan.finish),
+            )?;
+
+            execute(
+                "UPDATE copies SET in_use = 1 WHERE id = ?1",
+                params![copy_id],
+            )
+        }
+
+        JournalEvent::CopyReturned { copy, finish, .. } => {
+            let copy_id = copy.get() as i64;
+            set_last_finish(conn, copy_id, *finish)?;
+            execute(
+                "UPDATE copies SET in_use = 0 WHERE id = ?1",
+                params![copy_id],
+            )
+        }
+
+        JournalEvent::DueDateChanged { copy, finish, .. } => {
+            set_last_finish(conn, copy.get() as i64, *finish)
+        }
+
+        JournalEvent::GenreAdded(genre) => execute(
+            "INSERT OR IGNORE INTO genres (name) VALUES (?1)",
+            params![genre],
+        ),
+
+        JournalEvent::GenreRemoved(genre) => {
+            execute("DELETE FROM genres WHERE name = ?1", params![genre])
+        }
+    }
+}
+
+/// Changes return date of the last loan of simple book
+
+#[inline]
+fn set_last_finish(conn: &Connection, copy_id: i64, finish: Date) -> rusqlite::Result<()> {
+    conn.prepare_cached(
+        "UPDATE loans SET finish = ?2 \
+         WHERE id = (SELECT MAX(id) FROM loans WHERE copy_id = ?1)",
+    )?
+    .execute(params![copy_id, date_to_sql(finish)])
+    .map(|_| ())
+}
+
+/// Number of the last event of journal.
+/// If there are no events, it's number of the last state
+
+fn last_seq(conn: &Connection) -> rusqlite::Result<u64> {
+    conn.query_row(
+        "SELECT MAX(seq) FROM (SELECT seq FROM journal UNION ALL SELECT seq FROM journal_states)",
+        [],
+        |row| row.get::<_, Option<i64>>(0),
+    )
+    .map(|seq| seq.unwrap_or_default() as u64)
+}
+
+/// Keeps state after event with chosen number.
+/// It's YAML document of snapshot without label
+
+fn write_state(conn: &Connection, seq: u64, state: &MemoryStorage) -> io::Result<()> {
+    let snapshot = SnapshotRecord {
+        key: seq.to_string(),
+        label: String::new(),
+        time: Local::now().naive_local(),
+    };
+
+    let document = yaml::emit(&yaml::snapshot_to_yaml(
+        &snapshot,
+        &state.readers(),
+        &state.books(),
+        &state.genres(),
+    ))?;
+
+    conn.execute(
+        "INSERT OR REPLACE INTO journal_states (seq, document) VALUES (?1, ?2)",
+        params![seq as i64, document],
+    )
+    .map(|_| ())
+    .map_err(io_error)
+}
+
+/// Date as it's stored in database (**YYYY-MM-DD**)
+
+#[inline]
+fn date_to_sql(date: Date) -> String {
+    format!("{:04}-{:02}-{:02}", date.year, date.month, date.day)
+}
+
+/// Parses date stored as **YYYY-MM-DD**
+
+#[inline]
+fn date(record: &Record, field: &str, text: &str) -> Result<Date, LoadError> {
+    let mut parts = text.splitn(3, '-');
+
+    let mut next = || {
+        parts
+            .next()
+            .and_then(|x| x.parse::<u16>().ok())
+            .ok_or_else(|| record.error(field, LoadErrorKind::InvalidDate))
+    };
+
+    let year = next()?;
+    let month =
+        u8::try_from(next()?).map_err(|_| record.error(field, LoadErrorKind::InvalidDate))?;
+    let day = u8::try_from(next()?).map_err(|_| record.error(field, LoadErrorKind::InvalidDate))?;
+
+    Date::new(day, month, year).map_err(|_| record.error(field, LoadErrorKind::InvalidDate))
+}
+
+/// Checks range of integer column
+
+#[inline]
+fn int<T: TryFrom<i64>>(record: &Record, field: &str, value: i64) -> Result<T, LoadError> {
+    T::try_from(value).map_err(|_| record.error(field, LoadErrorKind::InvalidValue))
+}
+
+/// Checks id column. Ids start from 1
+
+#[inline]
+fn id(record: &Record, field: &str, value: i64) -> Result<Id, LoadError> {
+    match int::<u64>(record, field, value)? {
+        0 => Err(record.error(field, LoadErrorKind::InvalidValue)),
+        id => Ok(Id(id)),
+    }
+}
@@ -0,0 +1,1528 @@
+extern crate chrono;
+extern crate yaml_rust;
+
+use crate::{
+    audit::{AuditAction, AuditEntry},
+    books::date::Date,
+    data::{
+        append,
+        error::{LoadError, LoadErrorKind, Record},
+        read_yaml, write_atomic, DataDir,
+    },
+    journal::{JournalEntry, JournalEvent, Replay},
+    restore::command::{Change, Command, Operation},
+    storage::{
+        memory::MemoryStorage,
+        migration::{self, DataFile, FORMAT_VERSION, VERSION_KEY},
+        BookRecord, CopyRecord, LoanRecord, ReaderRecord, Records, SnapshotRecord, Storage,
+        TIME_FORMAT,
+    },
+};
+
+use std::{
+    cell::Cell,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use chrono::{Local, NaiveDateTime};
+
+use yaml_rust::{
+    yaml::{Array, Hash},
+    Yaml, YamlEmitter, YamlLoader,
+};
+
+/// Key of commands in history file
+
+const HISTORY_KEY: &str = "History";
+
+/// Key of amount of done commands in history file
+
+const DONE_KEY: &str = "Done";
+
+/// Key of label in snapshot file
+
+const LABEL_KEY: &str = "Label";
+
+/// Key of creation time in snapshot file
+
+const TIME_KEY: &str = "Time";
+
+/// Key of number of the last event of journal,
+/// which is included to data file
+
+const JOURNAL_KEY: &str = "Journal";
+
+/// How many events are appended to journal
+/// before they are compacted into data files
+
+const COMPACTION_INTERVAL: usize = 100;
+
+/// Storage in YAML files of data directory
+/// (readers.yaml, books.yaml and genres.yaml).
+/// Changes are appended to journal as events
+/// and periodically compacted into data files.
+/// Files are written atomically
+/// and previous data is kept in backups
+
+#[derive(Clone, Debug)]
+pub struct YamlStorage {
+    data_dir: DataDir,
+
+    /// Save is started, but data files aren't backed up yet.
+    /// Backup is made before the first changed file is written
+    backup_pending: Cell<bool>,
+}
+
+impl YamlStorage {
+    /// Creates storage in data directory
+
+    #[inline]
+    pub fn new(data_dir: DataDir) -> Self {
+        YamlStorage {
+            data_dir,
+            backup_pending: Cell::new(false),
+        }
+    }
+
+    /// Data directory with files
+
+    #[inline]
+    pub fn data_dir(&self) -> &DataDir {
+        &self.data_dir
+    }
+
+    /// Reads records of data file with number
+    /// of the last event of journal, which is included to them.
+    /// Old files are upgraded to current version step by step,
+    /// file is backed up before every step
+
+    fn read_records(&self, path: &Path, file: DataFile) -> Result<(Vec<Yaml>, u64), LoadError> {
+        let mut doc = match read_yaml(path)? {
+            None => return Ok((vec![], 0)),
+            Some(doc) => doc,
+        };
+
+        let mut version = migration::version(&doc)
+            .ok_or_else(|| LoadError::file(path, LoadErrorKind::WrongLayout))?;
+
+        if version > FORMAT_VERSION {
+            return Err(LoadError::file(
+                path,
+                LoadErrorKind::UnsupportedVersion(version),
+            ));
+        }
+
+        // Books refer to readers, so their migrations need readers
+
+        let readers = match file {
+            DataFile::Books if version < FORMAT_VERSION => read_yaml(&self.data_dir.readers())?,
+            _ => None,
+        };
+
+        let readers = readers
+            .as_ref()
+            .map(migration::readers_records)
+            .unwrap_or_default();
+
+        while version < FORMAT_VERSION {
+            let io_err = |e| LoadError::file(path, LoadErrorKind::Io(e));
+
+            fs::copy(path, self.data_dir.version_backup_of(path, version)).map_err(io_err)?;
+
+            doc = migration::upgrade(doc, file, version, readers)
+                .ok_or_else(|| LoadError::file(path, LoadErrorKind::WrongLayout))?;
+
+            write_atomic(path, emit(&doc).map_err(io_err)?.as_bytes()).map_err(io_err)?;
+            version += 1;
+        }
+
+        let seq = doc[JOURNAL_KEY].as_i64().unwrap_or_default() as u64;
+
+        match doc {
+            Yaml::Hash(mut hash) => match hash.remove(&Yaml::String(file.key().to_string())) {
+                Some(Yaml::Array(records)) => Ok((records, seq)),
+                _ => Err(LoadError::file(path, LoadErrorKind::WrongLayout)),
+            },
+
+            _ => Err(LoadError::file(path, LoadErrorKind::WrongLayout)),
+        }
+    }
+
+    /// Writes records with version header to data file.
+    /// Records include all events of journal
+
+    fn write(&self, path: &Path, file: DataFile, records: Array) -> io::Result<()> {
+        let mut doc = migration::with_header(file, Yaml::Array(records));
+
+        if let Yaml::Hash(hash) = &mut doc {
+            hash.insert(
+                Yaml::String(JOURNAL_KEY.to_string()),
+                Yaml::Integer(self.last_seq().map_err(invalid_data)? as i64),
+            );
+        }
+
+        let content = emit(&doc)?;
+
+        if self.backup_pending.get() && fs::read(path).map_or(true, |old| old != content.as_bytes())
+        {
+            self.data_dir.make_backup()?;
+            self.backup_pending.set(false);
+        }
+
+        self.data_dir.save_file(path, content.as_bytes())
+    }
+
+    /// Number of the last event of journal.
+    /// If all events are compacted,
+    /// it's number of the last compacted state
+
+    fn last_seq(&self) -> Result<u64, LoadError> {
+        match read_journal_file(&self.data_dir.journal())?.last() {
+            Some(entry) => Ok(entry.seq),
+
+            None => Ok(self
+                .data_dir
+                .journal_states()
+                .map_err(|e| LoadError::file(&self.data_dir.journal_dir(), LoadErrorKind::Io(e)))?
+                .last()
+                .copied()
+                .unwrap_or_default()),
+        }
+    }
+
+    /// Events of journal after event with chosen number.
+    /// Archived events are read only if they are needed
+
+    fn events_since(&self, seq: u64) -> Result<Vec<JournalEntry>, LoadError> {
+        let mut entries = vec![];
+
+        let archives = self
+            .data_dir
+            .journal_archives()
+            .map_err(|e| LoadError::file(&self.data_dir.journal_dir(), LoadErrorKind::Io(e)))?;
+
+        for last in archives.into_iter().filter(|last| *last > seq) {
+            entries.extend(read_journal_file(&self.data_dir.journal_events(last))?);
+        }
+
+        entries.extend(read_journal_file(&self.data_dir.journal())?);
+        entries.retain(|entry| entry.seq > seq);
+        Ok(entries)
+    }
+
+    /// Applies events of journal
+    /// which aren't included to data file yet
+
+    #[inline]
+    fn replay_since(&self, seq: u64, replay: &mut Replay) -> Result<(), LoadError> {
+        self.events_since(seq)?.iter().for_each(|entry| {
+            replay.apply(&entry.event);
+        });
+
+        Ok(())
+    }
+
+    /// Current readers, books and genres with all events of journal.
+    /// If any record is incorrect, it will return error
+
+    #[inline]
+    fn current_state(&self) -> Result<MemoryStorage, LoadError> {
+        Replay {
+            readers: self.read_readers()?,
+            books: self.read_books()?,
+            genres: self.read_genres()?,
+        }
+        .into_storage()
+    }
+
+    /// Writes state after event with chosen number
+    /// to journal directory
+
+    fn write_state(&self, seq: u64, state: &MemoryStorage) -> io::Result<()> {
+        fs::create_dir_all(self.data_dir.journal_dir())?;
+
+        let snapshot = SnapshotRecord {
+            key: seq.to_string(),
+            label: String::new(),
+            time: Local::now().naive_local(),
+        };
+
+        write_atomic(
+            &self.data_dir.journal_state(seq),
+            emit(&snapshot_to_yaml(
+                &snapshot,
+                &state.readers(),
+                &state.books(),
+                &state.genres(),
+            ))?
+            .as_bytes(),
+        )
+    }
+
+    /// Compacts journal: current state is written to data files
+    /// and journal directory, then events are archived.
+    /// If any record is incorrect, journal isn't compacted
+    /// and it will return error
+
+    fn compact(&self, seq: u64) -> io::Result<()> {
+        let state = self.current_state().map_err(invalid_data)?;
+
+        self.begin_save()?;
+        self.write_readers(&state.readers())?;
+        self.write_books(&state.books())?;
+        self.write_genres(&state.genres())?;
+        self.write_state(seq, &state)?;
+
+        fs::rename(self.data_dir.journal(), self.data_dir.journal_events(seq))
+    }
+}
+
+impl Storage for YamlStorage {
+    #[inline]
+    fn readers_path(&self) -> PathBuf {
+        self.data_dir.readers()
+    }
+
+    #[inline]
+    fn books_path(&self) -> PathBuf {
+        self.data_dir.books()
+    }
+
+    /// Readers of data file with later events of journal
+
+    fn read_readers(&self) -> Records<ReaderRecord> {
+        let path = self.data_dir.readers();
+        let (records, seq) = self.read_records(&path, DataFile::Readers)?;
+
+        let mut replay = Replay {
+            readers: records
+                .iter()
+                .enumerate()
+                .map(|(ind, d)| read_reader(&Record::new(&path, ind), d))
+                .collect(),
+            ..Replay::default()
+        };
+
+        self.replay_since(seq, &mut replay)?;
+        Ok(replay.readers)
+    }
+
+    /// Books of data file with later events of journal
+
+    fn read_books(&self) -> Records<BookRecord> {
+        let path = self.data_dir.books();
+        let (records, seq) = self.read_records(&path, DataFile::Books)?;
+
+        let mut replay = Replay {
+            books: records
+                .iter()
+                .enumerate()
+                .map(|(ind, d)| read_book(&Record::new(&path, ind), d))
+                .collect(),
+            ..Replay::default()
+        };
+
+        self.replay_since(seq, &mut replay)?;
+        Ok(replay.books)
+    }
+
+    /// Genres of data file with later events of journal
+
+    fn read_genres(&self) -> Records<String> {
+        let path = self.data_dir.genres();
+        let (records, seq) = self.read_records(&path, DataFile::Genres)?;
+
+        let genres = if records.first().and_then(|x| x.as_str()) == Some("None") {
+            vec![]
+        } else {
+            records
+                .iter()
+                .enumerate()
+                .map(|(ind, genre)| {
+                    genre.as_str().map(|g| g.to_string()).ok_or_else(|| {
+                        Record::new(&path, ind).error("Genres", LoadErrorKind::MissingField)
+                    })
+                })
+                .collect()
+        };
+
+        let mut replay = Replay {
+            genres,
+            ..Replay::default()
+        };
+
+        self.replay_since(seq, &mut replay)?;
+        Ok(replay.genres)
+    }
+
+    /// Data files are backed up once before the first changed file
+    /// is written, so every backup has files of the same state
+
+    #[inline]
+    fn begin_save(&self) -> io::Result<()> {
+        self.backup_pending.set(true);
+        Ok(())
+    }
+
+    fn write_readers(&self, readers: &[ReaderRecord]) -> io::Result<()> {
+        let array = readers
+            .iter()
+            .enumerate()
+            .map(|(ind, reader)| numbered(ind, reader_to_yaml(reader)))
+            .collect::<Array>();
+
+        self.write(&self.data_dir.readers(), DataFile::Readers, array)
+    }
+
+    fn write_books(&self, books: &[BookRecord]) -> io::Result<()> {
+        let array = books
+            .iter()
+            .enumerate()
+            .map(|(ind, book)| numbered(ind, book_to_yaml(book)))
+            .collect::<Array>();
+
+        self.write(&self.data_dir.books(), DataFile::Books, array)
+    }
+
+    fn write_genres(&self, genres: &[String]) -> io::Result<()> {
+        let array = if genres.is_empty() {
+            vec![Yaml::String("None".to_string())]
+        } else {
+            genres.iter().cloned().map(Yaml::String).collect()
+        };
+
+        self.write(&self.data_dir.genres(), DataFile::Genres, array)
+    }
+
+    fn read_history(&self) -> Result<(Vec<Command>, usize), LoadError> {
+        let path = self.data_dir.history();
+
+        match read_yaml(&path)? {
+            None => Ok((vec![], 0)),
+            Some(doc) => history_from_yaml(&path, &doc),
+        }
+    }
+
+    /// History isn't backed up,
+    /// it's only written atomically
+
+    #[inline]
+    fn write_history(&self, commands: &[Command], done: usize) -> io::Result<()> {
+        write_atomic(
+            &self.data_dir.history(),
+            emit(&history_to_yaml(commands, done))?.as_bytes(),
+        )
+    }
+
+    /// Entry is appended as new yaml document,
+    /// so previous entries are never rewritten
+
+    #[inline]
+    fn append_audit(&self, entry: &AuditEntry) -> io::Result<()> {
+        append(
+            &self.data_dir.audit(),
+            format!("{}\n", emit(&audit_to_yaml(entry))?).as_bytes(),
+        )
+    }
+
+    fn read_audit(&self) -> Result<Vec<AuditEntry>, LoadError> {
+        let path = self.data_dir.audit();
+
+        read_documents(&path)?
+            .iter()
+            .enumerate()
+            .map(|(ind, d)| audit_from_yaml(&Record::new(&path, ind), d))
+            .collect()
+    }
+
+    /// Events are appended to journal file.
+    /// When journal starts, current data is kept as its first state.
+    /// If it can't be read, journal isn't started
+    /// and records are written in full
+
+    fn append_events(&self, events: &[JournalEvent]) -> io::Result<bool> {
+        if self.data_dir.journal_states()?.is_empty() {
+            match self.current_state() {
+                Ok(state) => self.write_state(0, &state)?,
+                Err(_) => return Ok(false),
+            }
+        }
+
+        let written = read_journal_file(&self.data_dir.journal())
+            .map_err(invalid_data)?
+            .len();
+
+        let mut seq = self.last_seq().map_err(invalid_data)?;
+        let time = Local::now().naive_local();
+        let mut text = String::new();
+
+        for event in events {
+            seq += 1;
+
+            let entry = JournalEntry {
+                seq,
+                time,
+                event: event.clone(),
+            };
+
+            text.push_str(&emit(&journal_to_yaml(&entry))?);
+            text.push('\n');
+        }
+
+        append(&self.data_dir.journal(), text.as_bytes())?;
+
+        if written + events.len() >= COMPACTION_INTERVAL {
+            self.compact(seq)?;
+        }
+
+        Ok(true)
+    }
+
+    /// Archived events and events since the last compaction
+
+    fn read_journal(&self) -> Result<Vec<JournalEntry>, LoadError> {
+        self.events_since(0)
+    }
+
+    /// State is restored from the last compaction
+    /// made before the last event preceding chosen time.
+    /// Before the first event it's the state journal started with
+
+    fn state_at(&self, time: NaiveDateTime) -> Result<Option<MemoryStorage>, LoadError> {
+        let entries = self.read_journal()?;
+
+        let last = entries
+            .iter()
+            .take_while(|e| e.time <= time)
+            .last()
+            .map_or(0, |entry| entry.seq);
+
+        let start = self
+            .data_dir
+            .journal_states()
+            .map_err(|e| LoadError::file(&self.data_dir.journal_dir(), LoadErrorKind::Io(e)))?
+            .into_iter()
+            .filter(|seq| *seq <= last)
+            .last();
+
+        let start = match start {
+            None => return Ok(None),
+            Some(start) => start,
+        };
+
+        let path = self.data_dir.journal_state(start);
+
+        let state = match read_yaml(&path)? {
+            None => return Err(LoadError::file(&path, LoadErrorKind::WrongLayout)),
+            Some(doc) => snapshot_records(&path, &doc)?,
+        };
+
+        let mut replay = Replay::from_storage(&state);
+
+        entries
+            .iter()
+            .filter(|e| e.seq > start && e.seq <= last)
+            .for_each(|e| {
+                replay.apply(&e.event);
+            });
+
+        replay.into_storage().map(Some)
+    }
+
+    /// Timestamped backups of data directory
+
+    #[inline]
+    fn backups(&self) -> io::Result<Vec<(String, Box<dyn Storage>)>> {
+        Ok(self
+            .data_dir
+            .backups()?
+            .into_iter()
+            .map(|b| (b.name(), Box::new(YamlStorage::new(b)) as Box<dyn Storage>))
+            .collect())
+    }
+
+    /// Snapshot files of data directory.
+    /// Files which can't be read are skipped
+
+    fn snapshots(&self) -> io::Result<Vec<SnapshotRecord>> {
+        Ok(self
+            .data_dir
+            .snapshots()?
+            .into_iter()
+            .filter_map(|path| {
+                let key = path.file_name()?.to_string_lossy().to_string();
+                let doc = read_yaml(&path).ok()??;
+                snapshot_from_yaml(&path, key, &doc).ok()
+            })
+            .collect())
+    }
+
+    fn read_snapshot(&self, snapshot: &SnapshotRecord) -> Result<MemoryStorage, LoadError> {
+        let path = self.data_dir.snapshots_dir().join(&snapshot.key);
+
+        match read_yaml(&path)? {
+            None => Err(LoadError::file(&path, LoadErrorKind::WrongLayout)),
+            Some(doc) => snapshot_records(&path, &doc),
+        }
+    }
+
+    /// Snapshot is written atomically
+    /// to new file of snapshots directory
+
+    fn write_snapshot(
+        &self,
+        label: &str,
+        readers: &[ReaderRecord],
+        books: &[BookRecord],
+        genres: &[String],
+    ) -> io::Result<SnapshotRecord> {
+        let path = self.data_dir.new_snapshot()?;
+
+        let snapshot = SnapshotRecord {
+            key: path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string(),
+            label: label.to_string(),
+            time: Local::now().naive_local(),
+        };
+
+        write_atomic(
+            &path,
+            emit(&snapshot_to_yaml(&snapshot, readers, books, genres))?.as_bytes(),
+        )?;
+
+        Ok(snapshot)
+    }
+}
+
+/// Converts yaml document to string
+
+#[inline]
+pub(crate) fn emit(doc: &Yaml) -> io::Result<String> {
+    let mut string = String::new();
+
+    YamlEmitter::new(&mut string)
+        .dump(doc)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, format!("{:?}", e)))?;
+
+    Ok(string)
+}
+
+/// Adds number of record (starting from 1) to its fields.
+/// Number is the first field, so files are easy to read
+
+#[inline]
+fn numbered(ind: usize, fields: Hash) -> Yaml {
+    let mut data = Hash::new();
+    data.insert(Yaml::String("№".to_string()), Yaml::Integer(ind as i64 + 1));
+    data.extend(fields);
+    Yaml::Hash(data)
+}
+
+/// Converts reader to yaml fields
+
+fn reader_to_yaml(reader: &ReaderRecord) -> Hash {
+    let mut data = Hash::new();
+
+    data.insert(
+        Yaml::String("Id".to_string()),
+        Yaml::Integer(reader.id.get() as i64),
+    );
+    data.insert(
+        Yaml::String("Name".to_string()),
+        Yaml::String(reader.name.clone()),
+    );
+
+    data.insert(
+        Yaml::String("Family".to_string()),
+        Yaml::String(reader.family.clone()),
+    );
+
+    data.insert(
+        Yaml::String("Father".to_string()),
+        Yaml::String(reader.father.clone()),
+    );
+
+    data.insert(
+        Yaml::String("Info".to_string()),
+        Yaml::String(reader.info.clone()),
+    );
+    data.insert(
+        Yaml::String("Day".to_string()),
+        Yaml::Integer(reader.birth.day as i64),
+    );
+
+    data.insert(
+        Yaml::String("Month".to_string()),
+        Yaml::Integer(reader.birth.month as i64),
+    );
+
+    data.insert(
+        Yaml::String("Year".to_string()),
+        Yaml::Integer(reader.birth.year as i64),
+    );
+
+    if !reader.category.is_empty() {
+        data.insert(
+            Yaml::String("Category".to_string()),
+            Yaml::String(reader.category.clone()),
+        );
+    }
+
+    data.insert(
+        Yaml::String("Reading".to_string()),
+        Yaml::Array(if reader.reading.is_empty() {
+            vec![Yaml::String("None".to_string())]
+        } else {
+            reader.reading.iter().cloned().map(Yaml::String).collect()
+        }),
+    );
+
+    data
+}
+
+/// Converts type of book to yaml fields
+
+fn book_to_yaml(book: &BookRecord) -> Hash {
+    let mut data = Hash::new();
+
+    data.insert(
+        Yaml::String("Id".to_string()),
+        Yaml::Integer(book.id.get() as i64),
+    );
+    data.insert(
+        Yaml::String("Title".to_string()),
+        Yaml::String(book.title.clone()),
+    );
+
+    data.insert(
+        Yaml::String("Author".to_string()),
+        Yaml::String(book.author.clone()),
+    );
+
+    data.insert(
+        Yaml::String("Pages".to_string()),
+        Yaml::Integer(book.pages as i64),
+    );
+
+    data.insert(
+        Yaml::String("Simple Books".to_string()),
+        Yaml::Array(book.copies.iter().map(copy_to_yaml).collect()),
+    );
+
+    data.insert(
+        Yaml::String("Genres".to_string()),
+        book_genres_to_yaml(&book.genres),
+    );
+
+    data
+}
+
+/// Converts genres of The Book to yaml.
+/// If they aren't set, it's null
+
+#[inline]
+fn book_genres_to_yaml(genres: &Option<Vec<String>>) -> Yaml {
+    match genres {
+        Some(g) => Yaml::Array(g.iter().cloned().map(Yaml::String).collect()),
+        None => Yaml::Null,
+    }
+}
+
+/// Parses one reader from .yaml record.
+/// Readers without category have none
+
+#[inline]
+fn read_reader(record: &Record, d: &Yaml) -> Result<ReaderRecord, LoadError> {
+    Ok(ReaderRecord {
+        id: record.id(d, "Id")?,
+        name: record.str(d, "Name")?,
+        family: record.str(d, "Family")?,
+        father: record.str(d, "Father")?,
+        info: record.str(d, "Info")?,
+        birth: record.date(d, "Day", "Month", "Year")?,
+        category: match &d["Category"] {
+            Yaml::BadValue => String::new(),
+            _ => record.str(d, "Category")?,
+        },
+        reading: vec![],
+    })
+}
+
+/// Parses one type of book from .yaml record
+
+fn read_book(record: &Record, d: &Yaml) -> Result<BookRecord, LoadError> {
+    let copies = record
+        .vec(d, "Simple Books")?
+        .iter()
+        .enumerate()
+        .map(|(i, simple)| read_copy(&record.nested("Simple Books", i), simple))
+        .collect::<Result<_, _>>()?;
+
+    Ok(BookRecord {
+        id: record.id(d, "Id")?,
+        title: record.str(d, "Title")?,
+        author: record.str(d, "Author")?,
+        pages: record.int(d, "Pages")?,
+        copies,
+        genres: read_book_genres(record, d)?,
+    })
+}
+
+/// Parses simple book with all its givings
+
+fn read_copy(record: &Record, simple: &Yaml) -> Result<CopyRecord, LoadError> {
+    let loans = record
+        .vec(simple, "Readers")?
+        .iter()
+        .enumerate()
+        .map(|(j, reader)| read_loan(&record.nested("Readers", j), reader))
+        .collect::<Result<_, _>>()?;
+
+    Ok(CopyRecord {
+        id: record.id(simple, "Id")?,
+        cabinet: record.int(simple, "Cabinet")?,
+        shelf: record.int(simple, "Shelf")?,
+        copy_type: match &simple["Type"] {
+            Yaml::BadValue => String::new(),
+            _ => record.str(simple, "Type")?,
+        },
+        is_using: record.bool(simple, "Using")?,
+        loans,
+    })
+}
+
+/// Parses giving of simple book
+
+#[inline]
+fn read_loan(record: &Record, reader: &Yaml) -> Result<LoanRecord, LoadError> {
+    Ok(LoanRecord {
+        reader: record.id(reader, "Reader")?,
+        start: record.date_array(reader, "Start date")?,
+        finish: record.date_array(reader, "Finish date")?,
+    })
+}
+
+/// Parses genres of The Book.
+/// If they aren't set, it's None
+
+fn read_book_genres(record: &Record, d: &Yaml) -> Result<Option<Vec<String>>, LoadError> {
+    if d["Genres"].is_null() {
+        return Ok(None);
+    }
+
+    let mut genres = vec![];
+
+    for (i, genre) in record.vec(d, "Genres")?.iter().enumerate() {
+        genres.push(
+            genre
+                .as_str()
+                .ok_or_else(|| {
+                    record.error(&format!("Genres[{}]", i), LoadErrorKind::MissingField)
+                })?
+                .to_string(),
+        );
+    }
+
+    Ok(Some(genres))
+}
+
+/// Converts simple book to yaml
+
+fn copy_to_yaml(copy: &CopyRecord) -> Yaml {
+    let mut hash_simple = Hash::new();
+    hash_simple.insert(
+        Yaml::String("Id".to_string()),
+        Yaml::Integer(copy.id.get() as i64),
+    );
+    hash_simple.insert(
+        Yaml::String("Cabinet".to_string()),
+        Yaml::Integer(copy.cabinet as i64),
+    );
+    hash_simple.insert(
+        Yaml::String("Shelf".to_string()),
+        Yaml::Integer(copy.shelf as i64),
+    );
+
+    if !copy.copy_type.is_empty() {
+        hash_simple.insert(
+            Yaml::String("Type".to_string()),
+            Yaml::String(copy.copy_type.clone()),
+        );
+    }
+
+    hash_simple.insert(
+        Yaml::String("Using".to_string()),
+        Yaml::Boolean(copy.is_using),
+    );
+
+    hash_simple.insert(
+        Yaml::String("Readers".to_string()),
+        Yaml::Array(copy.loans.iter().map(loan_to_yaml).collect()),
+    );
+
+    Yaml::Hash(hash_simple)
+}
+
+/// Converts giving of simple book to yaml
+
+fn loan_to_yaml(loan: &LoanRecord) -> Yaml {
+    let mut hash_reader = Hash::new();
+
+    hash_reader.insert(
+        Yaml::String("Reader".to_string()),
+        Yaml::Integer(loan.reader.get() as i64),
+    );
+
+    hash_reader.insert(
+        Yaml::String("Start date".to_string()),
+        date_to_yaml(loan.start),
+    );
+    hash_reader.insert(
+        Yaml::String("Finish date".to_string()),
+        date_to_yaml(loan.finish),
+    );
+    Yaml::Hash(hash_reader)
+}
+
+/// Converts date to array [day, month, year]
+
+#[inline]
+fn date_to_yaml(date: Date) -> Yaml {
+    Yaml::Array(vec![
+        Yaml::Integer(date.day as i64),
+        Yaml::Integer(date.month as i64),
+        Yaml::Integer(date.year as i64),
+    ])
+}
+
+/// Converts change of record to yaml.
+/// Added and removed records are stored with their indexes,
+/// changed records are stored with states before and after change
+
+fn change_to_yaml<T>(change: &Change<T>, to_yaml: fn(&T) -> Hash) -> Yaml {
+    let mut data = Hash::new();
+
+    match change {
+        Change::Added(ind, record) => {
+            data.insert(
+                Yaml::String("Added".to_string()),
+                Yaml::Integer(*ind as i64),
+            );
+            data.insert(
+                Yaml::String("Record".to_string()),
+                Yaml::Hash(to_yaml(record)),
+            );
+        }
+
+        Change::Removed(ind, record) => {
+            data.insert(
+                Yaml::String("Removed".to_string()),
+                Yaml::Integer(*ind as i64),
+            );
+            data.insert(
+                Yaml::String("Record".to_string()),
+                Yaml::Hash(to_yaml(record)),
+            );
+        }
+
+        Change::Changed(before, after) => {
+            data.insert(
+                Yaml::String("Before".to_string()),
+                Yaml::Hash(to_yaml(before)),
+            );
+            data.insert(
+                Yaml::String("After".to_string()),
+                Yaml::Hash(to_yaml(after)),
+            );
+        }
+    }
+
+    Yaml::Hash(data)
+}
+
+/// Parses change of record
+
+fn read_change<T>(
+    record: &Record,
+    d: &Yaml,
+    read: fn(&Record, &Yaml) -> Result<T, LoadError>,
+) -> Result<Change<T>, LoadError> {
+    if !d["Added"].is_badvalue() {
+        Ok(Change::Added(
+            record.int(d, "Added")?,
+            read(record, &d["Record"])?,
+        ))
+    } else if !d["Removed"].is_badvalue() {
+        Ok(Change::Removed(
+            record.int(d, "Removed")?,
+            read(record, &d["Record"])?,
+        ))
+    } else {
+        Ok(Change::Changed(
+            read(record, &d["Before"])?,
+            read(record, &d["After"])?,
+        ))
+    }
+}
+
+/// Parses list of strings
+
+fn read_strings(record: &Record, d: &Yaml, field: &str) -> Result<Vec<String>, LoadError> {
+    record
+        .vec(d, field)?
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            s.as_str().map(|s| s.to_string()).ok_or_else(|| {
+                record.error(&format!("{}[{}]", field, i), LoadErrorKind::MissingField)
+            })
+        })
+        .collect()
+}
+
+/// Converts command to yaml fields.
+/// Changed records are stored with states before and after command
+
+fn command_to_yaml(command: &Command) -> Hash {
+    let mut data = Hash::new();
+
+    data.insert(
+        Yaml::String("Operation".to_string()),
+        Yaml::String(command.operation().name()),
+    );
+
+    data.insert(
+        Yaml::String("Time".to_string()),
+        Yaml::String(command.time().format(TIME_FORMAT).to_string()),
+    );
+
+    data.insert(
+        Yaml::String("Readers".to_string()),
+        Yaml::Array(
+            command
+                .readers
+                .iter()
+                .map(|c| change_to_yaml(c, reader_to_yaml))
+                .collect(),
+        ),
+    );
+
+    data.insert(
+        Yaml::String("Books".to_string()),
+        Yaml::Array(
+            command
+                .books
+                .iter()
+                .map(|c| change_to_yaml(c, book_to_yaml))
+                .collect(),
+        ),
+    );
+
+    data.insert(
+        Yaml::String("Added genres".to_string()),
+        Yaml::Array(
+            command
+                .added_genres
+                .iter()
+                .cloned()
+                .map(Yaml::String)
+                .collect(),
+        ),
+    );
+
+    data.insert(
+        Yaml::String("Removed genres".to_string()),
+        Yaml::Array(
+            command
+                .removed_genres
+                .iter()
+                .cloned()
+                .map(Yaml::String)
+                .collect(),
+        ),
+    );
+
+    data
+}
+
+/// Parses command with states of changed records
+
+fn read_command(record: &Record, d: &Yaml) -> Result<Command, LoadError> {
+    let operation = d["Operation"]
+        .as_str()
+        .and_then(Operation::from_name)
+        .ok_or_else(|| record.error("Operation", LoadErrorKind::InvalidValue))?;
+
+    let time = d["Time"]
+        .as_str()
+        .and_then(|t| NaiveDateTime::parse_from_str(t, TIME_FORMAT).ok())
+        .ok_or_else(|| record.error("Time", LoadErrorKind::MissingField))?;
+
+    let readers = record
+        .vec(d, "Readers")?
+        .iter()
+        .enumerate()
+        .map(|(i, c)| read_change(&record.nested("Readers", i), c, read_reader))
+        .collect::<Result<_, _>>()?;
+
+    let books = record
+        .vec(d, "Books")?
+        .iter()
+        .enumerate()
+        .map(|(i, c)| read_change(&record.nested("Books", i), c, read_book))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Command {
+        operation,
+        time,
+        readers,
+        books,
+        added_genres: read_strings(record, d, "Added genres")?,
+        removed_genres: read_strings(record, d, "Removed genres")?,
+    })
+}
+
+/// Converts history of changes to yaml document
+/// with version header
+
+pub(crate) fn history_to_yaml(commands: &[Command], done: usize) -> Yaml {
+    let commands = commands
+        .iter()
+        .map(|command| Yaml::Hash(command_to_yaml(command)))
+        .collect::<Array>();
+
+    let mut hash = Hash::new();
+
+    hash.insert(
+        Yaml::String(VERSION_KEY.to_string()),
+        Yaml::Integer(FORMAT_VERSION),
+    );
+
+    hash.insert(
+        Yaml::String(DONE_KEY.to_string()),
+        Yaml::Integer(done as i64),
+    );
+    hash.insert(Yaml::String(HISTORY_KEY.to_string()), Yaml::Array(commands));
+    Yaml::Hash(hash)
+}
+
+/// Parses history of changes from yaml document.
+/// History isn't migrated: it must have current version
+
+pub(crate) fn history_from_yaml(
+    path: &Path,
+    doc: &Yaml,
+) -> Result<(Vec<Command>, usize), LoadError> {
+    match migration::version(doc) {
+        Some(FORMAT_VERSION) => {}
+        Some(version) => {
+            return Err(LoadError::file(
+                path,
+                LoadErrorKind::UnsupportedVersion(version),
+            ))
+        }
+        None => return Err(LoadError::file(path, LoadErrorKind::WrongLayout)),
+    }
+
+    let done = doc[DONE_KEY]
+        .as_i64()
+        .and_then(|d| usize::try_from(d).ok())
+        .ok_or_else(|| LoadError::file(path, LoadErrorKind::WrongLayout))?;
+
+    let commands = doc[HISTORY_KEY]
+        .as_vec()
+        .ok_or_else(|| LoadError::file(path, LoadErrorKind::WrongLayout))?
+        .iter()
+        .enumerate()
+        .map(|(ind, d)| read_command(&Record::new(path, ind), d))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((commands, done))
+}
+
+/// Converts named snapshot with its records
+/// to yaml document with version header
+
+pub(crate) fn snapshot_to_yaml(
+    snapshot: &SnapshotRecord,
+    readers: &[ReaderRecord],
+    books: &[BookRecord],
+    genres: &[String],
+) -> Yaml {
+    let mut hash = Hash::new();
+
+    hash.insert(
+        Yaml::String(VERSION_KEY.to_string()),
+        Yaml::Integer(FORMAT_VERSION),
+    );
+
+    hash.insert(
+        Yaml::String(LABEL_KEY.to_string()),
+        Yaml::String(snapshot.label.clone()),
+    );
+
+    hash.insert(
+        Yaml::String(TIME_KEY.to_string()),
+        Yaml::String(snapshot.time.format(TIME_FORMAT).to_string()),
+    );
+
+    hash.insert(
+        Yaml::String(DataFile::Readers.key().to_string()),
+        Yaml::Array(
+            readers
+                .iter()
+                .enumerate()
+                .map(|(ind, reader)| numbered(ind, reader_to_yaml(reader)))
+                .collect(),
+        ),
+    );
+
+    hash.insert(
+        Yaml::String(DataFile::Books.key().to_string()),
+        Yaml::Array(
+            books
+                .iter()
+                .enumerate()
+                .map(|(ind, book)| numbered(ind, book_to_yaml(book)))
+                .collect(),
+        ),
+    );
+
+    hash.insert(
+        Yaml::String(DataFile::Genres.key().to_string()),
+        Yaml::Array(genres.iter().cloned().map(Yaml::String).collect()),
+    );
+
+    Yaml::Hash(hash)
+}
+
+/// Parses label and creation time of snapshot.
+/// Snapshots aren't migrated: they must have current version
+
+pub(crate) fn snapshot_from_yaml(
+    path: &Path,
+    key: String,
+    doc: &Yaml,
+) -> Result<SnapshotRecord, LoadError> {
+    match migration::version(doc) {
+        Some(FORMAT_VERSION) => {}
+        Some(version) => {
+            return Err(LoadError::file(
+                path,
+                LoadErrorKind::UnsupportedVersion(version),
+            ))
+        }
+        None => return Err(LoadError::file(path, LoadErrorKind::WrongLayout)),
+    }
+
+    let label = doc[LABEL_KEY]
+        .as_str()
+        .ok_or_else(|| LoadError::file(path, LoadErrorKind::WrongLayout))?
+        .to_string();
+
+    let time = doc[TIME_KEY]
+        .as_str()
+        .and_then(|t| NaiveDateTime::parse_from_str(t, TIME_FORMAT).ok())
+        .ok_or_else(|| LoadError::file(path, LoadErrorKind::WrongLayout))?;
+
+    Ok(SnapshotRecord { key, label, time })
+}
+
+/// Parses readers, books and genres of snapshot.
+/// If any record is incorrect, it will return error
+
+pub(crate) fn snapshot_records(path: &Path, doc: &Yaml) -> Result<MemoryStorage, LoadError> {
+    snapshot_from_yaml(path, String::new(), doc)?;
+
+    let records = |file: DataFile| {
+        doc[file.key()]
+            .as_vec()
+            .ok_or_else(|| LoadError::file(path, LoadErrorKind::WrongLayout))
+    };
+
+    let readers = records(DataFile::Readers)?
+        .iter()
+        .enumerate()
+        .map(|(ind, d)| read_reader(&Record::new(path, ind), d))
+        .collect::<Result<_, _>>()?;
+
+    let books = records(DataFile::Books)?
+        .iter()
+        .enumerate()
+        .map(|(ind, d)| read_book(&Record::new(path, ind), d))
+        .collect::<Result<_, _>>()?;
+
+    let genres = records(DataFile::Genres)?
+        .iter()
+        .enumerate()
+        .map(|(ind, genre)| {
+            genre
+                .as_str()
+                .map(|g| g.to_string())
+                .ok_or_else(|| Record::new(path, ind).error("Genres", LoadErrorKind::MissingField))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(MemoryStorage::with_records(readers, books, genres))
+}
+
+/// Converts entry of audit log to yaml document
+
+pub(crate) fn audit_to_yaml(entry: &AuditEntry) -> Yaml {
+    let mut data = Hash::new();
+    data.insert(
+        Yaml::String("User".to_string()),
+        Yaml::String(entry.user.clone()),
+    );
+
+    data.insert(
+        Yaml::String("Action".to_string()),
+        Yaml::String(entry.action.name()),
+    );
+
+    data.extend(command_to_yaml(&entry.command));
+    Yaml::Hash(data)
+}
+
+/// Parses entry of audit log
+
+pub(crate) fn audit_from_yaml(record: &Record, d: &Yaml) -> Result<AuditEntry, LoadError> {
+    Ok(AuditEntry {
+        user: record.str(d, "User")?,
+        action: d["Action"]
+            .as_str()
+            .and_then(AuditAction::from_name)
+            .ok_or_else(|| record.error("Action", LoadErrorKind::InvalidValue))?,
+        command: read_command(record, d)?,
+    })
+}
+
+/// Converts error of stored records to io error
+
+#[inline]
+fn invalid_data(err: LoadError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+/// Reads all yaml documents of file.
+/// If file doesn't exist, there are no documents
+
+fn read_documents(path: &Path) -> Result<Vec<Yaml>, LoadError> {
+    if !path.is_file() {
+        return Ok(vec![]);
+    }
+
+    let text = fs::read_to_string(path).map_err(|e| LoadError::file(path, LoadErrorKind::Io(e)))?;
+    YamlLoader::load_from_str(&text).map_err(|e| LoadError::file(path, LoadErrorKind::Yaml(e)))
+}
+
+/// Reads all events of journal file
+
+#[inline]
+fn read_journal_file(path: &Path) -> Result<Vec<JournalEntry>, LoadError> {
+    read_documents(path)?
+        .iter()
+        .enumerate()
+        .map(|(ind, d)| journal_from_yaml(&Record::new(path, ind), d))
+        .collect()
+}
+
+/// Converts event of journal to yaml document
+
+pub(crate) fn journal_to_yaml(entry: &JournalEntry) -> Yaml {
+    let mut data = Hash::new();
+    data.insert(
+        Yaml::String("Seq".to_string()),
+        Yaml::Integer(entry.seq as i64),
+    );
+
+    data.insert(
+        Yaml::String("Time".to_string()),
+        Yaml::String(entry.time.format(TIME_FORMAT).to_string()),
+    );
+
+    data.insert(
+        Yaml::String("Event".to_string()),
+        Yaml::String(entry.event.name().to_string()),
+    );
+
+    let fields = match &entry.event {
+        JournalEvent::ReaderAdded { index, reader } => vec![
+            ("Index", Yaml::Integer(*index as i64)),
+            ("Record", Yaml::Hash(reader_to_yaml(reader))),
+        ],
+
+        JournalEvent::ReaderChanged(reader) => vec![("Record", Yaml::Hash(reader_to_yaml(reader)))],
+        JournalEvent::ReaderRemoved(id) => vec![("Id", Yaml::Integer(id.get() as i64))],
+
+        JournalEvent::BookAdded { index, book } => vec![
+            ("Index", Yaml::Integer(*index as i64)),
+            ("Record", Yaml::Hash(book_to_yaml(book))),
+        ],
+
+        JournalEvent::BookChanged {
+            book,
+            title,
+            author,
+            pages,
+        } => vec![
+            ("Book", Yaml::Integer(book.get() as i64)),
+            ("Title", Yaml::String(title.clone())),
+            ("Author", Yaml::String(author.clone())),
+            ("Pages", Yaml::Integer(*pages as i64)),
+        ],
+
+        JournalEvent::BookReplaced(book) => vec![("Record", Yaml::Hash(book_to_yaml(book)))],
+        JournalEvent::BookRemoved(id) => vec![("Id", Yaml::Integer(id.get() as i64))],
+
+        JournalEvent::GenresAssigned { book, genres } => vec![
+            ("Book", Yaml::Integer(book.get() as i64)),
+            ("Genres", book_genres_to_yaml(genres)),
+        ],
+
+        JournalEvent::CopyAdded { book, index, copy } => vec![
+            ("Book", Yaml::Integer(book.get() as i64)),
+            ("Index", Yaml::Integer(*index as i64)),
+            ("Record", copy_to_yaml(copy)),
+        ],
+
+        JournalEvent::CopyRemoved { book, copy } => vec![
+            ("Book", Yaml::Integer(book.get() as i64)),
+            ("Copy", Yaml::Integer(copy.get() as i64)),
+        ],
+
+        JournalEvent::CopyMoved {
+            book,
+            copy,
+            cabinet,
+            shelf,
+        } => vec![
+            ("Book", Yaml::Integer(book.get() as i64)),
+            ("Copy", Yaml::Integer(copy.get() as i64)),
+            ("Cabinet", Yaml::Integer(*cabinet as i64)),
+            ("Shelf", Yaml::Integer(*shelf as i64)),
+        ],
+
+        JournalEvent::CopyGiven { book, copy, loan } => vec![
+            ("Book", Yaml::Integer(book.get() as i64)),
+            ("Copy", Yaml::Integer(copy.get() as i64)),
+            ("Record", loan_to_yaml(loan)),
+        ],
+
+        JournalEvent::CopyReturned { book, copy, finish }
+        | JournalEvent::DueDateChanged { book, copy, finish } => vec![
+            ("Book", Yaml::Integer(book.get() as i64)),
+            ("Copy", Yaml::Integer(copy.get() as i64)),
+            ("Finish date", date_to_yaml(*finish)),
+        ],
+
+        JournalEvent::CopyReplaced { book, copy } => vec![
+            ("Book", Yaml::Integer(book.get() as i64)),
+            ("Record", copy_to_yaml(copy)),
+        ],
+
+        JournalEvent::GenreAdded(genre) | JournalEvent::GenreRemoved(genre) => {
+            vec![("Genre", Yaml::String(genre.clone()))]
+        }
+    };
+
+    fields.into_iter().for_each(|(key, value)| {
+        data.insert(Yaml::String(key.to_string()), value);
+    });
+
+    Yaml::Hash(data)
+}
+
+/// Parses event of journal
+
+pub(crate) fn journal_from_yaml(record: &Record, d: &Yaml) -> Result<JournalEntry, LoadError> {
+    let time = d["Time"]
+        .as_str()
+        .and_then(|t| NaiveDateTime::parse_from_str(t, TIME_FORMAT).ok())
+        .ok_or_else(|| record.error("Time", LoadErrorKind::MissingField))?;
+
+    let event = match d["Event"].as_str() {
+        Some("ReaderAdded") => JournalEvent::ReaderAdded {
+            index: record.int(d, "Index")?,
+            reader: read_reader(record, &d["Record"])?,
+        },
+
+        Some("ReaderChanged") => JournalEvent::ReaderChanged(read_reader(record, &d["Record"])?),
+        Some("ReaderRemoved") => JournalEvent::ReaderRemoved(record.id(d, "Id")?),
+
+        Some("BookAdded") => JournalEvent::BookAdded {
+            index: record.int(d, "Index")?,
+            book: read_book(record, &d["Record"])?,
+        },
+
+        Some("BookChanged") => JournalEvent::BookChanged {
+            book: record.id(d, "Book")?,
+            title: record.str(d, "Title")?,
+            author: record.str(d, "Author")?,
+            pages: record.int(d, "Pages")?,
+        },
+
+        Some("BookReplaced") => JournalEvent::BookReplaced(read_book(record, &d["Record"])?),
+        Some("BookRemoved") => JournalEvent::BookRemoved(record.id(d, "Id")?),
+
+        Some("GenresAssigned") => JournalEvent::GenresAssigned {
+            book: record.id(d, "Book")?,
+            genres: read_book_genres(record, d)?,
+        },
+
+        Some("CopyAdded") => JournalEvent::CopyAdded {
+            book: record.id(d, "Book")?,
+            index: record.int(d, "Index")?,
+            copy: read_copy(record, &d["Record"])?,
+        },
+
+        Some("CopyRemoved") => JournalEvent::CopyRemoved {
+            book: record.id(d, "Book")?,
+            copy: record.id(d, "Copy")?,
+        },
+
+        Some("CopyMoved") => JournalEvent::CopyMoved {
+            book: record.id(d, "Book")?,
+            copy: record.id(d, "Copy")?,
+            cabinet: record.int(d, "Cabinet")?,
+            shelf: record.int(d, "Shelf")?,
+        },
+
+        Some("CopyGiven") => JournalEvent::CopyGiven {
+            book: record.id(d, "Book")?,
+            copy: record.id(d, "Copy")?,
+            loan: read_loan(record, &d["Record"])?,
+        },
+
+        Some("CopyReturned") => JournalEvent::CopyReturned {
+            book: record.id(d, "Book")?,
+            copy: record.id(d, "Copy")?,
+            finish: record.date_array(d, "Finish date")?,
+        },
+
+        Some("DueDateChanged") => JournalEvent::DueDateChanged {
+            book: record.id(d, "Book")?,
+            copy: record.id(d, "Copy")?,
+            finish: record.date_array(d, "Finish date")?,
+        },
+
+        Some("CopyReplaced") => JournalEvent::CopyReplaced {
+            book: record.id(d, "Book")?,
+            copy: read_copy(record, &d["Record"])?,
+        },
+
+        Some("GenreAdded") => JournalEvent::GenreAdded(record.str(d, "Genre")?),
+        Some("GenreRemoved") => JournalEvent::GenreRemoved(record.str(d, "Genre")?),
+        _ => return Err(record.error("Event", LoadErrorKind::InvalidValue)),
+    };
+
+    Ok(JournalEntry {
+        seq: record.int(d, "Seq")?,
+        time,
+        event,
+    })
+}
@@ -1,4 +0,0 @@
-#[allow(dead_code)]
-mod book_test;
-#[allow(dead_code)]
-mod reader_test;
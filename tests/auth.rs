@@ -0,0 +1,242 @@
+//! Tests for staff accounts and lockout after failed attempts.
+//! Run with `cargo test --test auth`
+
+extern crate booklibrs;
+
+mod common;
+
+use booklibrs::{
+    auth::{hash_password, AuthError, Role, Users, FREE_ATTEMPTS, MAX_LOCKOUTS},
+    data::DataDir,
+};
+
+use std::fs;
+
+use common::temp_dir;
+
+/// Unsalted polynomial hash of old versions,
+/// as it was written to admin file
+
+fn legacy_hash(str: &str, p: u128, module: u128) -> String {
+    let mut hash = 0;
+
+    str.bytes()
+        .enumerate()
+        .map(|(i, byte)| {
+            hash = match i {
+                0 => byte as u128,
+                _ => (hash.overflowing_mul(p).0.overflowing_add(byte as u128).0) % module,
+            };
+
+            hash as u8 as char
+        })
+        .collect()
+}
+
+#[test]
+fn lockout_boundary_test() {
+    let data_dir = DataDir::new(temp_dir("lockout-boundary"));
+    let mut users = Users::load(&data_dir).unwrap();
+    users.add("admin", "secret", Role::Administrator).unwrap();
+
+    for _ in 0..FREE_ATTEMPTS {
+        assert!(matches!(
+            users.authorize("admin", "wrong"),
+            Err(AuthError::WrongCredentials)
+        ));
+    }
+
+    assert!(users.locked_for("admin").is_none());
+    assert_eq!(
+        users.authorize("admin", "secret").unwrap(),
+        Role::Administrator
+    );
+
+    for _ in 0..=FREE_ATTEMPTS {
+        assert!(matches!(
+            users.authorize("admin", "wrong"),
+            Err(AuthError::WrongCredentials)
+        ));
+    }
+
+    assert!(users.locked_for("admin").is_some());
+
+    assert!(matches!(
+        users.authorize("admin", "secret"),
+        Err(AuthError::Locked)
+    ));
+}
+
+#[test]
+fn lockout_per_login_test() {
+    let data_dir = DataDir::new(temp_dir("lockout-per-login"));
+    let mut users = Users::load(&data_dir).unwrap();
+    users.add("admin", "secret", Role::Administrator).unwrap();
+    users.add("librarian", "books", Role::Librarian).unwrap();
+
+    for _ in 0..=FREE_ATTEMPTS {
+        let _ = users.authorize("admin", "wrong");
+        let _ = users.authorize("nobody", "wrong");
+    }
+
+    assert!(users.locked_for("admin").is_some());
+    assert!(users.locked_for("librarian").is_none());
+    assert_eq!(
+        users.authorize("librarian", "books").unwrap(),
+        Role::Librarian
+    );
+
+    let users = Users::load(&data_dir).unwrap();
+    assert!(users.locked_for("admin").is_some());
+    assert!(users.locked_for("librarian").is_none());
+    assert!(users.locked_for("nobody").is_some());
+}
+
+#[test]
+fn unknown_login_lockout_test() {
+    let data_dir = DataDir::new(temp_dir("lockout-unknown-login"));
+    let mut users = Users::load(&data_dir).unwrap();
+    users.add("admin", "secret", Role::Administrator).unwrap();
+
+    // Login of no account is locked after the same failures
+
+    for _ in 0..FREE_ATTEMPTS {
+        let _ = users.authorize("admin", "wrong");
+        let _ = users.authorize("nobody", "wrong");
+    }
+
+    assert!(users.locked_for("admin").is_none());
+    assert!(users.locked_for("nobody").is_none());
+
+    let _ = users.authorize("admin", "wrong");
+    let _ = users.authorize("nobody", "wrong");
+
+    assert_eq!(
+        users.locked_for("admin").is_some(),
+        users.locked_for("nobody").is_some()
+    );
+
+    assert!(matches!(
+        users.authorize("nobody", "wrong"),
+        Err(AuthError::Locked)
+    ));
+
+    // Too many logins don't make file grow,
+    // logins which aren't locked are forgotten first
+
+    for i in 0..=MAX_LOCKOUTS {
+        let _ = users.authorize(&format!("nobody {}", i), "wrong");
+    }
+
+    let content = fs::read_to_string(data_dir.users()).unwrap();
+    assert!(content.matches("Failures:").count() <= MAX_LOCKOUTS);
+
+    let users = Users::load(&data_dir).unwrap();
+    assert!(users.locked_for("admin").is_some());
+    assert!(users.locked_for("nobody").is_some());
+}
+
+#[test]
+fn old_users_file_test() {
+    let data_dir = DataDir::new(temp_dir("old-users-file"));
+    let password = hash_password("secret").unwrap();
+
+    // Users' file before senior librarians and lockout of every login
+
+    let accounts = [
+        ("admin", "Administrator"),
+        ("librarian", "Librarian"),
+        ("volunteer", "ReadOnly"),
+    ]
+    .iter()
+    .map(|(login, role)| {
+        format!(
+            "  - Login: {}\n    Role: {}\n    Password: \"{}\"\n",
+            login, role, password
+        )
+    })
+    .collect::<String>();
+
+    fs::write(
+        data_dir.users(),
+        format!("---\nVersion: 1\nFailures: 5\nUsers:\n{}", accounts),
+    )
+    .unwrap();
+
+    let mut users = Users::load(&data_dir).unwrap();
+
+    let roles = users
+        .accounts()
+        .iter()
+        .map(|account| (account.login(), account.role()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        roles,
+        vec![
+            ("admin", Role::Administrator),
+            ("librarian", Role::Librarian),
+            ("volunteer", Role::ReadOnly),
+        ]
+    );
+
+    assert!(users.locked_for("admin").is_none());
+    assert_eq!(
+        users.authorize("librarian", "secret").unwrap(),
+        Role::Librarian
+    );
+
+    users.set_role("librarian", Role::SeniorLibrarian).unwrap();
+
+    let users = Users::load(&data_dir).unwrap();
+    assert_eq!(
+        users.find("librarian").unwrap().role(),
+        Role::SeniorLibrarian
+    );
+    assert_eq!(users.find("volunteer").unwrap().role(), Role::ReadOnly);
+}
+
+#[test]
+fn legacy_admin_test() {
+    let data_dir = DataDir::new(temp_dir("legacy-admin"));
+
+    fs::write(
+        data_dir.admin(),
+        format!(
+            "{}\0{}",
+            legacy_hash("admin", 97, 1e9 as u128 + 7),
+            legacy_hash("secret", 101, 1e9 as u128 + 7)
+        ),
+    )
+    .unwrap();
+
+    let mut users = Users::load(&data_dir).unwrap();
+    assert!(!users.is_empty());
+    assert!(users.accounts().is_empty());
+
+    assert!(matches!(
+        users.authorize("admin", "wrong"),
+        Err(AuthError::WrongCredentials)
+    ));
+
+    assert_eq!(
+        users.authorize("admin", "secret").unwrap(),
+        Role::Administrator
+    );
+
+    // Login becomes administrator with Argon2 hash,
+    // admin file is cleared
+
+    assert_eq!(users.find("admin").unwrap().role(), Role::Administrator);
+    assert!(fs::read(data_dir.admin()).unwrap().is_empty());
+
+    let saved = fs::read_to_string(data_dir.users()).unwrap();
+    assert!(saved.contains("$argon2id$"));
+    assert!(!saved.contains(&legacy_hash("secret", 101, 1e9 as u128 + 7)));
+
+    let mut users = Users::load(&data_dir).unwrap();
+    assert_eq!(
+        users.authorize("admin", "secret").unwrap(),
+        Role::Administrator
+    );
+}
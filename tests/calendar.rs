@@ -0,0 +1,275 @@
+//! Tests for opening calendar of library.
+//! Run with `cargo test --test calendar`
+
+extern crate booklibrs;
+extern crate chrono;
+
+mod common;
+
+use booklibrs::calendar::{ical, CalendarError, Holiday, OpeningCalendar, WEEK};
+
+use booklibrs::books::date::Date;
+
+use chrono::{Local, NaiveDateTime, TimeZone, Weekday};
+use common::date;
+
+/// Local date of time in UTC
+
+#[inline]
+fn local_date(utc: &str) -> Date {
+    let utc = NaiveDateTime::parse_from_str(utc, "%Y-%m-%d %H:%M:%S").unwrap();
+    Date::from_naive(Local.from_utc_datetime(&utc).date_naive()).unwrap()
+}
+
+/// Calendar of library which is closed on sundays
+/// and on the New Year
+
+fn calendar() -> OpeningCalendar {
+    let mut calendar = OpeningCalendar::new();
+    calendar.set_closed_days(&[Weekday::Sun]).unwrap();
+
+    calendar
+        .add_holiday(Holiday::new(date(1, 1, 2020), "New Year", true))
+        .unwrap();
+
+    calendar
+}
+
+#[test]
+fn open_every_day_test() {
+    let calendar = OpeningCalendar::new();
+    let day = date(29, 2, 2024);
+
+    assert!(calendar.is_open(day));
+    assert_eq!(calendar.next_open_day(day), Some(day));
+    assert_eq!(calendar.due_date(day), day);
+}
+
+#[test]
+fn closed_days_test() {
+    let calendar = calendar();
+
+    // 18.10.2026 is sunday
+
+    assert!(!calendar.is_open(date(18, 10, 2026)));
+    assert!(calendar.is_open(date(17, 10, 2026)));
+    assert_eq!(calendar.due_date(date(18, 10, 2026)), date(19, 10, 2026));
+    assert_eq!(calendar.due_date(date(17, 10, 2026)), date(17, 10, 2026));
+}
+
+#[test]
+fn holidays_test() {
+    let mut calendar = calendar();
+
+    // Yearly holiday is repeated since its year
+
+    assert!(!calendar.is_open(date(1, 1, 2025)));
+    assert!(calendar.is_open(date(1, 1, 2019)));
+
+    calendar
+        .add_holiday(Holiday::new(date(2, 1, 2027), "Vacation", false))
+        .unwrap();
+
+    // 01.01.2027 is friday, 03.01.2027 is sunday
+
+    assert_eq!(calendar.due_date(date(1, 1, 2027)), date(4, 1, 2027));
+    assert!(calendar.is_open(date(2, 1, 2029)));
+
+    assert!(matches!(
+        calendar.add_holiday(Holiday::new(date(2, 1, 2027), "Again", true)),
+        Err(CalendarError::AlreadyExists)
+    ));
+
+    let dates = calendar
+        .holidays()
+        .iter()
+        .map(Holiday::date)
+        .collect::<Vec<_>>();
+
+    assert_eq!(dates, vec![date(1, 1, 2020), date(2, 1, 2027)]);
+
+    assert_eq!(calendar.remove_holiday(1).unwrap().name(), "Vacation");
+    assert!(matches!(
+        calendar.remove_holiday(1),
+        Err(CalendarError::NotFound)
+    ));
+    assert!(calendar.is_open(date(2, 1, 2027)));
+}
+
+#[test]
+fn leap_holiday_test() {
+    let mut calendar = OpeningCalendar::new();
+
+    calendar
+        .add_holiday(Holiday::new(date(29, 2, 2024), "Leap day", true))
+        .unwrap();
+
+    assert!(!calendar.is_open(date(29, 2, 2028)));
+    assert!(calendar.is_open(date(28, 2, 2027)));
+    assert!(calendar.is_open(date(1, 3, 2027)));
+}
+
+#[test]
+fn no_open_days_test() {
+    let mut calendar = calendar();
+
+    assert!(matches!(
+        calendar.set_closed_days(&WEEK),
+        Err(CalendarError::NoOpenDays)
+    ));
+
+    assert_eq!(calendar.closed_days(), &[Weekday::Sun]);
+
+    calendar
+        .set_closed_days(&[Weekday::Sun, Weekday::Sat, Weekday::Sun])
+        .unwrap();
+
+    assert_eq!(calendar.closed_days(), &[Weekday::Sat, Weekday::Sun]);
+    assert_eq!(calendar.due_date(date(17, 10, 2026)), date(19, 10, 2026));
+}
+
+#[test]
+fn overdue_test() {
+    let calendar = calendar();
+
+    // Book given until sunday can be returned on monday
+
+    assert!(!calendar.is_overdue(date(18, 10, 2026), date(19, 10, 2026)));
+    assert!(calendar.is_overdue(date(18, 10, 2026), date(20, 10, 2026)));
+    assert!(!calendar.is_overdue(date(17, 10, 2026), date(17, 10, 2026)));
+    assert!(calendar.is_overdue(date(17, 10, 2026), date(19, 10, 2026)));
+
+    assert_eq!(
+        calendar.days_overdue(date(18, 10, 2026), date(19, 10, 2026)),
+        0
+    );
+    assert_eq!(
+        calendar.days_overdue(date(18, 10, 2026), date(22, 10, 2026)),
+        3
+    );
+    assert_eq!(
+        calendar.days_overdue(date(18, 10, 2026), date(1, 10, 2026)),
+        0
+    );
+}
+
+#[test]
+fn ical_test() {
+    let text = "BEGIN:VCALENDAR\r\n\
+                VERSION:2.0\r\n\
+                BEGIN:VEVENT\r\n\
+                DTSTART;VALUE=DATE:20260101\r\n\
+                DTEND;VALUE=DATE:20260103\r\n\
+                SUMMARY:New Year\\, holidays\r\n\
+                RRULE:FREQ=YEARLY\r\n\
+                END:VEVENT\r\n\
+                BEGIN:VEVENT\r\n\
+                DTSTART:20261104T110000Z\r\n\
+                DTEND:20261104T120000Z\r\n\
+                SUMMARY:Unity\r\n\x20\x20Day\r\n\
+                END:VEVENT\r\n\
+                END:VCALENDAR\r\n";
+
+    let (holidays, skipped) = ical::parse(text).unwrap();
+
+    assert_eq!(
+        holidays,
+        vec![
+            Holiday::new(date(1, 1, 2026), "New Year, holidays", true),
+            Holiday::new(date(2, 1, 2026), "New Year, holidays", true),
+            Holiday::new(local_date("2026-11-04 11:00:00"), "Unity Day", false),
+        ]
+    );
+
+    assert_eq!(skipped, 0);
+
+    let mut calendar = calendar();
+
+    // Holiday on 01.01.2020 is already in calendar
+
+    assert_eq!(calendar.import_ical(text).unwrap(), (2, 0));
+    assert_eq!(calendar.import_ical(text).unwrap(), (0, 0));
+    assert!(!calendar.is_open(date(2, 1, 2030)));
+    let unity_day = local_date("2026-11-04 11:00:00");
+    assert!(!calendar.is_open(unity_day));
+    assert!(calendar.is_open(unity_day.add_days(365).unwrap()));
+}
+
+#[test]
+fn wrong_ical_test() {
+    assert_eq!(ical::parse("SUMMARY:Nothing"), None);
+
+    let no_start = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nSUMMARY:Day\nEND:VEVENT\nEND:VCALENDAR";
+    assert_eq!(ical::parse(no_start), Some((vec![], 1)));
+
+    let wrong_date = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART:20260230\nEND:VEVENT\nEND:VCALENDAR";
+    assert_eq!(ical::parse(wrong_date), Some((vec![], 1)));
+
+    let empty = "BEGIN:VCALENDAR\nEND:VCALENDAR";
+    assert_eq!(ical::parse(empty), Some((vec![], 0)));
+
+    // Wrong events don't stop import of correct ones
+
+    let mixed = "BEGIN:VCALENDAR\n\
+                 BEGIN:VEVENT\nDTSTART:2026\nSUMMARY:Wrong\nEND:VEVENT\n\
+                 BEGIN:VEVENT\nDTSTART;VALUE=DATE:20260308\nSUMMARY:Women's Day\nEND:VEVENT\n\
+                 BEGIN:VEVENT\nDTSTART:20260101\nDTEND:20280101\nSUMMARY:Too long\nEND:VEVENT\n\
+                 END:VCALENDAR";
+
+    assert_eq!(
+        ical::parse(mixed),
+        Some((
+            vec![Holiday::new(date(8, 3, 2026), "Women's Day", false)],
+            2
+        ))
+    );
+
+    assert_eq!(OpeningCalendar::new().import_ical(mixed).unwrap(), (1, 2));
+
+    assert!(matches!(
+        OpeningCalendar::new().import_ical("nothing"),
+        Err(CalendarError::WrongICalendar)
+    ));
+}
+
+#[test]
+fn ical_time_zone_test() {
+    // Time zone which is 14 hours ahead of UTC,
+    // so its early morning is the previous day in UTC
+
+    let text = "BEGIN:VCALENDAR\r\n\
+                BEGIN:VTIMEZONE\r\n\
+                TZID:Pacific/Kiritimati\r\n\
+                BEGIN:STANDARD\r\n\
+                DTSTART:19700101T000000\r\n\
+                TZOFFSETFROM:+1400\r\n\
+                TZOFFSETTO:+1400\r\n\
+                END:STANDARD\r\n\
+                END:VTIMEZONE\r\n\
+                BEGIN:VEVENT\r\n\
+                DTSTART;TZID=Pacific/Kiritimati:20260101T050000\r\n\
+                SUMMARY:Zoned\r\n\
+                END:VEVENT\r\n\
+                BEGIN:VEVENT\r\n\
+                DTSTART;VALUE=DATE-TIME:20260601T230000Z\r\n\
+                SUMMARY:Universal\r\n\
+                END:VEVENT\r\n\
+                BEGIN:VEVENT\r\n\
+                DTSTART;TZID=Unknown:20260301T230000\r\n\
+                SUMMARY:Floating\r\n\
+                END:VEVENT\r\n\
+                END:VCALENDAR\r\n";
+
+    let (holidays, skipped) = ical::parse(text).unwrap();
+
+    assert_eq!(
+        holidays,
+        vec![
+            Holiday::new(local_date("2025-12-31 15:00:00"), "Zoned", false),
+            Holiday::new(local_date("2026-06-01 23:00:00"), "Universal", false),
+            Holiday::new(date(1, 3, 2026), "Floating", false),
+        ]
+    );
+
+    assert_eq!(skipped, 0);
+}
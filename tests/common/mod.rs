@@ -0,0 +1,55 @@
+//! Helpers shared by integration tests.
+//! Not every test uses all of them
+
+#![allow(dead_code)]
+
+use booklibrs::{
+    books::date::Date,
+    storage::{BookRecord, ReaderRecord, Storage},
+};
+
+use std::{env, fs, path::PathBuf, process};
+
+/// Builds date which is known to be correct
+
+#[inline]
+pub fn date(day: u8, month: u8, year: u16) -> Date {
+    Date::new(day, month, year).unwrap()
+}
+
+/// Creates empty directory for test's files.
+/// Name must be unique among tests
+
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("booklibrs-{}-{}", name, process::id()));
+
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Readers, books and genres as they're stored.
+/// Genres are sorted, because their order isn't kept
+
+pub type State = (Vec<ReaderRecord>, Vec<BookRecord>, Vec<String>);
+
+/// All records of storage
+
+pub fn state(storage: &dyn Storage) -> State {
+    let readers = storage.read_readers().unwrap();
+    let books = storage.read_books().unwrap();
+    let mut genres = storage
+        .read_genres()
+        .unwrap()
+        .into_iter()
+        .map(Result::unwrap)
+        .collect::<Vec<_>>();
+
+    genres.sort();
+
+    (
+        readers.into_iter().map(Result::unwrap).collect(),
+        books.into_iter().map(Result::unwrap).collect(),
+        genres,
+    )
+}
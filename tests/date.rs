@@ -0,0 +1,173 @@
+//! Tests for day-precision arithmetic of Date.
+//! Run with `cargo test --test date`
+
+extern crate booklibrs;
+extern crate chrono;
+
+mod common;
+
+use booklibrs::books::date::Date;
+use chrono::{Datelike, NaiveDate, Weekday};
+use common::date;
+
+/// Checks if year is leap by gregorian rules
+
+#[inline]
+fn is_leap(year: u16) -> bool {
+    year % 4 == 0 && year % 100 != 0 || year % 400 == 0
+}
+
+#[test]
+fn leap_day_test() {
+    assert!(Date::new(29, 2, 2020).is_ok());
+    assert!(Date::new(29, 2, 2019).is_err());
+    assert!(Date::new(29, 2, 2000).is_ok());
+    assert!(Date::new(29, 2, 1900).is_err());
+    assert!(Date::new(29, 2, 2100).is_err());
+    assert!(Date::new(29, 2, 2400).is_ok());
+    assert!(Date::new(30, 2, 2020).is_err());
+}
+
+#[test]
+fn all_days_test() {
+    // Every day from 1600 to 2400 is checked against chrono
+
+    let mut day = date(1, 1, 1600);
+    let mut naive = NaiveDate::from_ymd_opt(1600, 1, 1).unwrap();
+
+    while naive.year() <= 2400 {
+        assert_eq!(day.to_naive(), naive);
+        assert_eq!(Date::from_naive(naive), Some(day));
+        assert_eq!(day.weekday(), naive.weekday());
+
+        let next = day.add_days(1).unwrap();
+        assert!(next > day);
+        assert_eq!(next.sub_days(1), Some(day));
+        assert_eq!(day.days_until(next), 1);
+        assert_eq!(next.days_until(day), -1);
+
+        day = next;
+        naive = naive.succ_opt().unwrap();
+    }
+}
+
+#[test]
+fn correct_dates_test() {
+    // Date::new agrees with chrono for every day of every month
+
+    (1600..=2400).for_each(|year| {
+        (1..=12).for_each(|month| {
+            (1..=31).for_each(|day| {
+                assert_eq!(
+                    Date::new(day, month, year).is_ok(),
+                    NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32).is_some(),
+                    "{}.{}.{}",
+                    day,
+                    month,
+                    year
+                );
+            })
+        })
+    });
+}
+
+#[test]
+fn days_in_year_test() {
+    (1600..2400).for_each(|year| {
+        let days = date(1, 1, year).days_until(date(1, 1, year + 1));
+        assert_eq!(days, if is_leap(year) { 366 } else { 365 }, "{}", year);
+
+        let february = date(1, 2, year).days_until(date(1, 3, year));
+        assert_eq!(february, if is_leap(year) { 29 } else { 28 }, "{}", year);
+    });
+}
+
+#[test]
+fn add_days_test() {
+    assert_eq!(date(28, 2, 2020).add_days(1), Some(date(29, 2, 2020)));
+    assert_eq!(date(28, 2, 2019).add_days(1), Some(date(1, 3, 2019)));
+    assert_eq!(date(28, 2, 1900).add_days(1), Some(date(1, 3, 1900)));
+    assert_eq!(date(28, 2, 2000).add_days(1), Some(date(29, 2, 2000)));
+    assert_eq!(date(1, 3, 2024).sub_days(1), Some(date(29, 2, 2024)));
+    assert_eq!(date(1, 3, 2023).sub_days(1), Some(date(28, 2, 2023)));
+    assert_eq!(date(31, 12, 2023).add_days(1), Some(date(1, 1, 2024)));
+    assert_eq!(date(1, 1, 2024).add_days(-1), Some(date(31, 12, 2023)));
+    assert_eq!(date(1, 1, 2024).sub_days(-366), Some(date(1, 1, 2025)));
+    assert_eq!(date(1, 1, 2023).add_days(365), Some(date(1, 1, 2024)));
+    assert_eq!(date(1, 1, 2024).add_days(365), Some(date(31, 12, 2024)));
+    assert_eq!(date(15, 6, 2024).add_days(0), Some(date(15, 6, 2024)));
+}
+
+#[test]
+fn add_weeks_test() {
+    assert_eq!(date(15, 2, 2024).add_weeks(2), Some(date(29, 2, 2024)));
+    assert_eq!(date(15, 2, 2023).add_weeks(2), Some(date(1, 3, 2023)));
+    assert_eq!(date(7, 3, 2024).sub_weeks(1), Some(date(29, 2, 2024)));
+    assert_eq!(date(7, 3, 2024).add_weeks(-1), Some(date(29, 2, 2024)));
+    assert_eq!(date(29, 2, 2024).add_weeks(52), Some(date(27, 2, 2025)));
+
+    let day = date(29, 2, 2024);
+    assert_eq!(day.add_weeks(3).unwrap().weekday(), day.weekday());
+}
+
+#[test]
+fn add_months_test() {
+    assert_eq!(date(31, 1, 2024).add_months(1), Some(date(29, 2, 2024)));
+    assert_eq!(date(31, 1, 2023).add_months(1), Some(date(28, 2, 2023)));
+    assert_eq!(date(31, 1, 1900).add_months(1), Some(date(28, 2, 1900)));
+    assert_eq!(date(31, 1, 2000).add_months(1), Some(date(29, 2, 2000)));
+    assert_eq!(date(29, 2, 2024).add_months(12), Some(date(28, 2, 2025)));
+    assert_eq!(date(29, 2, 2024).add_months(48), Some(date(29, 2, 2028)));
+    assert_eq!(date(29, 2, 2024).sub_months(12), Some(date(28, 2, 2023)));
+    assert_eq!(date(31, 3, 2024).sub_months(1), Some(date(29, 2, 2024)));
+    assert_eq!(date(31, 3, 2024).add_months(-1), Some(date(29, 2, 2024)));
+    assert_eq!(date(31, 5, 2024).add_months(1), Some(date(30, 6, 2024)));
+    assert_eq!(date(15, 11, 2023).add_months(3), Some(date(15, 2, 2024)));
+}
+
+#[test]
+fn days_until_test() {
+    assert_eq!(date(1, 2, 2024).days_until(date(1, 3, 2024)), 29);
+    assert_eq!(date(1, 3, 2024).days_until(date(1, 2, 2024)), -29);
+    assert_eq!(date(1, 1, 2000).days_until(date(1, 1, 2100)), 36_525);
+    assert_eq!(date(1, 1, 1900).days_until(date(1, 1, 2000)), 36_524);
+    assert_eq!(date(29, 2, 2024).days_until(date(29, 2, 2024)), 0);
+    assert_eq!(date(29, 2, 2020).days_until(date(29, 2, 2024)), 1461);
+}
+
+#[test]
+fn weekday_test() {
+    assert_eq!(date(29, 2, 2024).weekday(), Weekday::Thu);
+    assert_eq!(date(29, 2, 2000).weekday(), Weekday::Tue);
+    assert_eq!(date(1, 3, 1900).weekday(), Weekday::Thu);
+    assert_eq!(date(1, 1, 2001).weekday(), Weekday::Mon);
+}
+
+#[test]
+fn naive_test() {
+    let leap = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    assert_eq!(Date::from_naive(leap), Some(date(29, 2, 2024)));
+    assert_eq!(date(29, 2, 2024).to_naive(), leap);
+
+    assert_eq!(
+        Date::from_naive(NaiveDate::from_ymd_opt(-1, 1, 1).unwrap()),
+        None
+    );
+    assert_eq!(
+        Date::from_naive(NaiveDate::from_ymd_opt(65_536, 1, 1).unwrap()),
+        None
+    );
+    assert!(Date::from_naive(NaiveDate::from_ymd_opt(65_535, 12, 31).unwrap()).is_some());
+}
+
+#[test]
+fn out_of_range_test() {
+    assert_eq!(date(31, 12, 65_535).add_days(1), None);
+    assert_eq!(date(1, 1, 0).sub_days(1), None);
+    assert_eq!(date(1, 12, 65_535).add_months(1), None);
+    assert_eq!(date(1, 1, 0).sub_weeks(1), None);
+    assert_eq!(date(1, 1, 2024).add_days(i64::MAX), None);
+    assert_eq!(date(1, 1, 2024).sub_days(i64::MIN), None);
+    assert_eq!(date(1, 1, 2024).add_weeks(i64::MAX), None);
+    assert_eq!(date(1, 1, 2024).sub_months(i32::MIN), None);
+}
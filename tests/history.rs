@@ -0,0 +1,416 @@
+//! Tests for undo and redo of changes and for saved history.
+//! Run with `cargo test --test history`
+
+extern crate booklibrs;
+
+mod common;
+
+use booklibrs::{
+    data::DataDir,
+    library::Library,
+    storage::{memory::MemoryStorage, yaml::YamlStorage, Storage},
+};
+
+use std::collections::HashSet;
+
+use common::{date, state, temp_dir};
+
+/// Adds three readers, two books and genre
+
+fn fill(library: &mut Library) {
+    for (name, year) in [("Ivan", 2000), ("Petr", 2001), ("Anna", 2002)] {
+        library
+            .add_reader(
+                name.to_string(),
+                "Ivanov".to_string(),
+                "Ivanovich".to_string(),
+                "Student".to_string(),
+                date(1, 1, year),
+            )
+            .unwrap();
+    }
+
+    library
+        .add_book(
+            "War and Peace".to_string(),
+            "Tolstoy".to_string(),
+            1300,
+            &[(1, 1), (1, 2)],
+        )
+        .unwrap()
+        .add_book(
+            "Anna Karenina".to_string(),
+            "Tolstoy".to_string(),
+            860,
+            &[(2, 1)],
+        )
+        .unwrap()
+        .add_genre("Fiction".to_string())
+        .unwrap();
+}
+
+/// Index of reader with chosen name
+
+#[inline]
+fn reader(library: &Library, name: &str, year: u16) -> Option<usize> {
+    library.find_reader(
+        &name.to_string(),
+        &"Ivanov".to_string(),
+        &"Ivanovich".to_string(),
+        date(1, 1, year),
+    )
+}
+
+#[test]
+fn undo_redo_test() {
+    let mut library = Library::new(Box::new(MemoryStorage::new()));
+    fill(&mut library);
+
+    let mut states = vec![state(library.storage())];
+
+    let war = library
+        .find_book(&"War and Peace".to_string(), &"Tolstoy".to_string(), 1300)
+        .unwrap();
+
+    library.change_name(0, "Ioann".to_string()).unwrap();
+    states.push(state(library.storage()));
+
+    library
+        .set_book_genres(war, HashSet::from(["fiction".to_string()]))
+        .unwrap();
+
+    states.push(state(library.storage()));
+
+    library.give_book(1, war, date(1, 1, 2100)).unwrap();
+    states.push(state(library.storage()));
+
+    // Reader in the middle with book
+
+    library.remove_reader(1).unwrap();
+    states.push(state(library.storage()));
+
+    library.remove_genre(&"Fiction".to_string()).unwrap();
+    states.push(state(library.storage()));
+
+    library.add_genre("Poetry".to_string()).unwrap();
+    states.push(state(library.storage()));
+
+    library.remove_book(war).unwrap();
+    states.push(state(library.storage()));
+
+    let done = library.caretaker().done();
+
+    for expected in states.iter().rev().skip(1) {
+        assert!(library.undo().unwrap().is_some());
+        assert_eq!(state(library.storage()), *expected);
+    }
+
+    assert_eq!(library.caretaker().done(), done - states.len() + 1);
+
+    for expected in states.iter().skip(1) {
+        assert!(library.redo().unwrap().is_some());
+        assert_eq!(state(library.storage()), *expected);
+    }
+
+    assert!(library.redo().unwrap().is_none());
+    assert_eq!(library.caretaker().done(), done);
+}
+
+/// Makes change, then undoes and redoes it.
+/// Every step must lead to state before or after change
+
+fn round_trip<F: FnOnce(&mut Library)>(library: &mut Library, change: F) {
+    let before = state(library.storage());
+    change(library);
+    let after = state(library.storage());
+
+    assert_ne!(before, after);
+
+    assert!(library.undo().unwrap().is_some());
+    assert_eq!(state(library.storage()), before);
+
+    assert!(library.redo().unwrap().is_some());
+    assert_eq!(state(library.storage()), after);
+}
+
+#[test]
+fn every_operation_test() {
+    let mut library = Library::new(Box::new(MemoryStorage::new()));
+    fill(&mut library);
+
+    let war = |lib: &Library| {
+        lib.find_book(&"War and Peace".to_string(), &"Tolstoy".to_string(), 1300)
+            .unwrap()
+    };
+
+    let petr = |lib: &Library| reader(lib, "Petr", 2001).unwrap();
+
+    round_trip(&mut library, |lib| {
+        let ind = reader(lib, "Anna", 2002).unwrap();
+        lib.change_family(ind, "Ivanova".to_string()).unwrap();
+    });
+
+    round_trip(&mut library, |lib| {
+        let ind = reader(lib, "Ivan", 2000).unwrap();
+        lib.change_info(ind, "Reads a lot".to_string()).unwrap();
+    });
+
+    round_trip(&mut library, |lib| {
+        let ind = reader(lib, "Ivan", 2000).unwrap();
+        lib.change_category(ind, "Student".to_string()).unwrap();
+    });
+
+    round_trip(&mut library, |lib| {
+        let ind = reader(lib, "Ivan", 2000).unwrap();
+        lib.change_age(ind, date(3, 3, 1999)).unwrap();
+    });
+
+    round_trip(&mut library, |lib| {
+        let ind = lib
+            .find_reader(
+                &"Ivan".to_string(),
+                &"Ivanov".to_string(),
+                &"Ivanovich".to_string(),
+                date(3, 3, 1999),
+            )
+            .unwrap();
+
+        lib.change_father(ind, "Petrovich".to_string()).unwrap();
+    });
+
+    round_trip(&mut library, |lib| {
+        lib.change_location(war(lib), 0, 5, 5).unwrap();
+    });
+
+    round_trip(&mut library, |lib| {
+        lib.change_copy_type(war(lib), 1, "Rare".to_string())
+            .unwrap();
+    });
+
+    round_trip(&mut library, |lib| {
+        lib.add_books(war(lib), &[(3, 3), (3, 4)]).unwrap();
+    });
+
+    round_trip(&mut library, |lib| {
+        lib.remove_one_book(war(lib), 2).unwrap();
+    });
+
+    let mut given = 0;
+
+    round_trip(&mut library, |lib| {
+        let (rind, ind) = (petr(lib), war(lib));
+        given = lib.give_book(rind, ind, date(1, 1, 2100)).unwrap();
+    });
+
+    round_trip(&mut library, |lib| {
+        lib.change_return_date(war(lib), given, date(2, 2, 2100))
+            .unwrap();
+    });
+
+    round_trip(&mut library, |lib| {
+        let (rind, ind) = (petr(lib), war(lib));
+        lib.return_book(rind, ind).unwrap();
+    });
+
+    round_trip(&mut library, |lib| {
+        lib.change_title(war(lib), "War & Peace".to_string())
+            .unwrap();
+    });
+
+    let war = |lib: &Library| {
+        lib.find_book(&"War & Peace".to_string(), &"Tolstoy".to_string(), 1300)
+            .unwrap()
+    };
+
+    round_trip(&mut library, |lib| {
+        lib.change_pages(war(lib), 1250).unwrap();
+    });
+
+    round_trip(&mut library, |lib| {
+        let ind = lib
+            .find_book(&"Anna Karenina".to_string(), &"Tolstoy".to_string(), 860)
+            .unwrap();
+
+        lib.change_author(ind, "Lev Tolstoy".to_string()).unwrap();
+    });
+
+    round_trip(&mut library, |lib| {
+        let ind = lib
+            .find_book(&"War & Peace".to_string(), &"Tolstoy".to_string(), 1250)
+            .unwrap();
+
+        lib.remove_book(ind).unwrap();
+    });
+}
+
+#[test]
+fn reinsert_order_test() {
+    let mut library = Library::new(Box::new(MemoryStorage::new()));
+    fill(&mut library);
+
+    let readers = [("Ivan", 2000), ("Petr", 2001), ("Anna", 2002)];
+
+    let indexes = readers
+        .iter()
+        .map(|&(name, year)| reader(&library, name, year))
+        .collect::<Vec<_>>();
+
+    library.remove_reader(1).unwrap().remove_reader(0).unwrap();
+    assert_eq!(library.reader_base().len(), 1);
+
+    library.undo().unwrap();
+    library.undo().unwrap();
+
+    let restored = readers
+        .iter()
+        .map(|&(name, year)| reader(&library, name, year))
+        .collect::<Vec<_>>();
+
+    assert_eq!(restored, indexes);
+}
+
+#[test]
+fn genre_inverse_test() {
+    let mut library = Library::new(Box::new(MemoryStorage::new()));
+    fill(&mut library);
+
+    let war = library
+        .find_book(&"War and Peace".to_string(), &"Tolstoy".to_string(), 1300)
+        .unwrap();
+
+    library
+        .set_book_genres(war, HashSet::from(["fiction".to_string()]))
+        .unwrap()
+        .remove_genre(&"FICTION".to_string())
+        .unwrap();
+
+    assert_eq!(library.genres().len(), 0);
+    assert!(library.find_by_genre(&"fiction".to_string()).is_empty());
+
+    library.undo().unwrap();
+
+    assert_eq!(library.genres().iter().collect::<Vec<_>>(), vec!["fiction"]);
+    assert_eq!(library.find_by_genre(&"fiction".to_string()), vec![war]);
+
+    library.undo().unwrap();
+    assert!(library.find_by_genre(&"fiction".to_string()).is_empty());
+
+    library.undo().unwrap();
+    assert_eq!(library.genres().len(), 0);
+
+    library.redo().unwrap();
+    assert_eq!(library.genres().iter().collect::<Vec<_>>(), vec!["fiction"]);
+}
+
+#[test]
+fn replace_all_test() {
+    let mut library = Library::new(Box::new(MemoryStorage::new()));
+    fill(&mut library);
+
+    let before = state(library.storage());
+
+    let mut other = Library::new(Box::new(MemoryStorage::new()));
+    fill(&mut other);
+
+    other
+        .remove_reader(0)
+        .unwrap()
+        .change_name(0, "Pavel".to_string())
+        .unwrap()
+        .add_reader(
+            "Olga".to_string(),
+            "Petrova".to_string(),
+            String::new(),
+            String::new(),
+            date(2, 2, 2003),
+        )
+        .unwrap()
+        .remove_genre(&"Fiction".to_string())
+        .unwrap()
+        .add_genre("Poetry".to_string())
+        .unwrap();
+
+    let after = state(other.storage());
+
+    library.restore_from(other.storage()).unwrap();
+    assert_eq!(state(library.storage()), after);
+
+    library.undo().unwrap();
+    assert_eq!(state(library.storage()), before);
+
+    library.redo().unwrap();
+    assert_eq!(state(library.storage()), after);
+}
+
+#[test]
+fn saved_history_test() {
+    let data_dir = DataDir::new(temp_dir("saved-history"));
+    let mut library = Library::new(Box::new(YamlStorage::new(data_dir.clone())));
+
+    fill(&mut library);
+    library.add_genre("Poetry".to_string()).unwrap();
+    library.undo().unwrap();
+    library.save().unwrap();
+
+    let done = library.caretaker().done();
+    let len = library.caretaker().commands().len();
+
+    // History ends in saved state, so it's kept
+
+    let mut library = Library::new(Box::new(YamlStorage::new(data_dir.clone())));
+    library.load().unwrap();
+
+    assert_eq!(library.caretaker().done(), done);
+    assert_eq!(library.caretaker().commands().len(), len);
+
+    library.redo().unwrap();
+    assert_eq!(library.genres().len(), 2);
+
+    // Genres are changed outside of library,
+    // so the last command isn't done anymore
+
+    YamlStorage::new(data_dir.clone())
+        .write_genres(&["fiction".to_string()])
+        .unwrap();
+
+    let mut library = Library::new(Box::new(YamlStorage::new(data_dir)));
+    library.load().unwrap();
+
+    assert_eq!(library.caretaker().done(), 0);
+    assert!(library.caretaker().commands().is_empty());
+}
+
+#[test]
+fn history_depth_test() {
+    let mut library = Library::new(Box::new(MemoryStorage::new())).with_history_depth(2);
+    fill(&mut library);
+
+    let filled = state(library.storage());
+
+    library
+        .add_genre("Poetry".to_string())
+        .unwrap()
+        .add_genre("Drama".to_string())
+        .unwrap();
+
+    // Only the last two changes are kept
+
+    assert_eq!(library.caretaker().commands().len(), 2);
+    assert!(library.undo().unwrap().is_some());
+    assert!(library.undo().unwrap().is_some());
+    assert!(library.undo().unwrap().is_none());
+    assert_eq!(state(library.storage()), filled);
+
+    // New change forgets undone ones
+
+    library.remove_reader(0).unwrap();
+
+    assert_eq!(library.caretaker().commands().len(), 1);
+    assert!(library.redo().unwrap().is_none());
+
+    let mut library = Library::new(Box::new(MemoryStorage::new())).with_history_depth(0);
+    fill(&mut library);
+
+    assert!(library.caretaker().commands().is_empty());
+    assert!(library.undo().unwrap().is_none());
+}
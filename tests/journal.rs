@@ -0,0 +1,189 @@
+//! Tests for journal of events and state of library in the past.
+//! Run with `cargo test --test journal`
+
+extern crate booklibrs;
+extern crate chrono;
+
+mod common;
+
+use booklibrs::{
+    data::DataDir,
+    library::Library,
+    storage::{yaml::YamlStorage, Storage},
+};
+
+use std::{thread, time::Duration};
+
+use chrono::NaiveDateTime;
+
+use common::{date, temp_dir};
+
+/// Adds readers with numbers from chosen range
+
+fn add_readers(library: &mut Library, numbers: std::ops::Range<usize>) {
+    for i in numbers {
+        library
+            .add_reader(
+                format!("Reader {}", i),
+                "Ivanov".to_string(),
+                "Ivanovich".to_string(),
+                String::new(),
+                date(1, 1, 2000),
+            )
+            .unwrap();
+    }
+}
+
+/// How many readers library had at chosen time.
+/// If journal isn't kept, it will return None
+
+#[inline]
+fn readers_at(storage: &dyn Storage, time: NaiveDateTime) -> Option<usize> {
+    storage
+        .state_at(time)
+        .unwrap()
+        .map(|state| state.readers().len())
+}
+
+/// Waits until the next second,
+/// so next events have later time
+
+#[inline]
+fn next_second() {
+    thread::sleep(Duration::from_millis(1100));
+}
+
+#[test]
+fn replay_test() {
+    let data_dir = DataDir::new(temp_dir("journal-replay"));
+    data_dir.create().unwrap();
+
+    let storage = YamlStorage::new(data_dir.clone());
+    let mut library = Library::new(Box::new(storage.clone()));
+
+    // Journal is compacted after 100th event,
+    // so the second group crosses the compaction
+
+    add_readers(&mut library, 0..60);
+    next_second();
+    add_readers(&mut library, 60..120);
+    next_second();
+    add_readers(&mut library, 120..130);
+
+    assert!(data_dir.journal_states().unwrap().contains(&100));
+
+    let entries = storage.read_journal().unwrap();
+    let seqs = entries.iter().map(|e| e.seq()).collect::<Vec<_>>();
+    assert_eq!(seqs, (1..=130).collect::<Vec<_>>());
+
+    let first = entries[0].time();
+    let after_first = entries[59].time();
+    let after_second = entries[119].time();
+    let last = entries[129].time();
+
+    // Before the first event it's the state journal started with
+
+    assert_eq!(
+        readers_at(&storage, first - chrono::Duration::seconds(1)),
+        Some(0)
+    );
+    assert_eq!(readers_at(&storage, after_first), Some(60));
+    assert_eq!(readers_at(&storage, after_second), Some(120));
+    assert_eq!(readers_at(&storage, last), Some(130));
+
+    // Data files with journal after compaction
+    // give the same readers as library has
+
+    let mut loaded = Library::new(Box::new(YamlStorage::new(data_dir)));
+    loaded.load().unwrap();
+
+    assert_eq!(loaded.reader_base().len(), 130);
+    assert_eq!(loaded.storage().read_readers().unwrap().len(), 130);
+}
+
+#[test]
+fn state_between_events_test() {
+    let data_dir = DataDir::new(temp_dir("journal-between"));
+    data_dir.create().unwrap();
+
+    let storage = YamlStorage::new(data_dir);
+    let mut library = Library::new(Box::new(storage.clone()));
+
+    add_readers(&mut library, 0..2);
+    next_second();
+    add_readers(&mut library, 2..3);
+    next_second();
+
+    library.remove_reader(0).unwrap();
+
+    let entries = storage.read_journal().unwrap();
+    assert_eq!(entries.len(), 4);
+
+    let (first, second, third) = (entries[1].time(), entries[2].time(), entries[3].time());
+    assert!(first < second && second < third);
+
+    // Any time between events gives state after the earlier one
+
+    let between = first + (second - first) / 2;
+
+    assert_eq!(readers_at(&storage, between), Some(2));
+    assert_eq!(readers_at(&storage, second), Some(3));
+    assert_eq!(
+        readers_at(&storage, third - chrono::Duration::milliseconds(1)),
+        Some(3)
+    );
+    assert_eq!(readers_at(&storage, third), Some(2));
+
+    assert_eq!(
+        storage.state_at(third).unwrap().unwrap().readers(),
+        library
+            .storage()
+            .read_readers()
+            .unwrap()
+            .into_iter()
+            .map(Result::unwrap)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn sqlite_journal_test() {
+    use booklibrs::storage::sqlite::SqliteStorage;
+
+    let data_dir = DataDir::new(temp_dir("journal-sqlite"));
+    data_dir.create().unwrap();
+
+    let mut library = Library::new(Box::new(SqliteStorage::open(&data_dir).unwrap()));
+
+    // The second group crosses the kept state after 100th event
+
+    add_readers(&mut library, 0..60);
+    next_second();
+    add_readers(&mut library, 60..120);
+    next_second();
+    library.remove_reader(0).unwrap();
+
+    let storage = library.storage();
+    let entries = storage.read_journal().unwrap();
+    let seqs = entries.iter().map(|e| e.seq()).collect::<Vec<_>>();
+    assert_eq!(seqs, (1..=121).collect::<Vec<_>>());
+
+    let first = entries[0].time();
+
+    assert_eq!(
+        readers_at(storage, first - chrono::Duration::seconds(1)),
+        Some(0)
+    );
+    assert_eq!(readers_at(storage, entries[59].time()), Some(60));
+    assert_eq!(readers_at(storage, entries[119].time()), Some(120));
+    assert_eq!(readers_at(storage, entries[120].time()), Some(119));
+
+    // Database has the same readers after reopening
+
+    drop(library);
+
+    let mut loaded = Library::new(Box::new(SqliteStorage::open(&data_dir).unwrap()));
+    loaded.load().unwrap();
+    assert_eq!(loaded.reader_base().len(), 119);
+}
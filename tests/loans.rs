@@ -0,0 +1,265 @@
+//! Tests for giving books to readers under loan policy.
+//! Run with `cargo test --test loans`
+
+extern crate booklibrs;
+extern crate chrono;
+extern crate yaml_rust;
+
+mod common;
+
+use booklibrs::{
+    auth::Role,
+    books::{date::Date, error::LoanError},
+    calendar::{Holiday, OpeningCalendar},
+    data::DataDir,
+    library::Library,
+    policy::{Limit, LimitRule, LoanPolicy, Refusal},
+    storage::{memory::MemoryStorage, yaml::YamlStorage},
+};
+
+use std::fs;
+
+use chrono::Datelike;
+
+use yaml_rust::{Yaml, YamlEmitter, YamlLoader};
+
+use common::{date, temp_dir};
+
+/// Day which is chosen number of days from today
+
+#[inline]
+fn today_plus(days: i64) -> Date {
+    Date::today().add_days(days).unwrap()
+}
+
+/// Policy where reference copies aren't given
+/// and readers with overdue books take nothing
+
+fn policy() -> LoanPolicy {
+    let mut policy = LoanPolicy::new();
+
+    policy
+        .add_limit(LimitRule::new(
+            None,
+            None,
+            Some("Reference"),
+            Limit::NotLoanable,
+        ))
+        .unwrap()
+        .add_limit(LimitRule::new(None, None, None, Limit::NoOverdue))
+        .unwrap();
+
+    policy
+}
+
+/// Fills library with one reader and two books.
+/// The only copy of the first book is reference one
+
+fn fill(library: &mut Library) {
+    library
+        .add_reader(
+            "Ivan".to_string(),
+            "Ivanov".to_string(),
+            "Ivanovich".to_string(),
+            "Student".to_string(),
+            date(1, 1, 2000),
+        )
+        .unwrap()
+        .add_book("Dictionary".to_string(), "Dahl".to_string(), 900, &[(1, 1)])
+        .unwrap()
+        .add_book(
+            "War and Peace".to_string(),
+            "Tolstoy".to_string(),
+            1300,
+            &[(1, 2)],
+        )
+        .unwrap()
+        .change_copy_type(0, 0, "Reference".to_string())
+        .unwrap();
+}
+
+/// Library in memory with policy
+
+fn library() -> Library {
+    let mut library = Library::new(Box::new(MemoryStorage::new())).with_policy(policy());
+    fill(&mut library);
+    library
+}
+
+/// Index of reference book
+
+#[inline]
+fn dictionary(library: &Library) -> usize {
+    library
+        .find_book(&"Dictionary".to_string(), &"Dahl".to_string(), 900)
+        .unwrap()
+}
+
+/// Index of ordinary book
+
+#[inline]
+fn novel(library: &Library) -> usize {
+    library
+        .find_book(&"War and Peace".to_string(), &"Tolstoy".to_string(), 1300)
+        .unwrap()
+}
+
+/// Sets start and finish of all givings in yaml document
+
+fn move_loans(doc: &mut Yaml, start: Date, finish: Date) {
+    let date = |d: Date| {
+        let d = d.to_naive();
+
+        Yaml::Array(vec![
+            Yaml::Integer(d.day() as i64),
+            Yaml::Integer(d.month() as i64),
+            Yaml::Integer(d.year() as i64),
+        ])
+    };
+
+    match doc {
+        Yaml::Hash(hash) => {
+            for (key, value) in hash.iter_mut() {
+                match key.as_str() {
+                    Some("Start date") => *value = date(start),
+                    Some("Finish date") => *value = date(finish),
+                    _ => move_loans(value, start, finish),
+                }
+            }
+        }
+
+        Yaml::Array(array) => array
+            .iter_mut()
+            .for_each(|value| move_loans(value, start, finish)),
+
+        _ => {}
+    }
+}
+
+#[test]
+fn refused_giving_test() {
+    let mut library = library();
+    let dictionary = dictionary(&library);
+
+    assert_eq!(
+        library.loan_refusals(0, dictionary).unwrap(),
+        vec![Refusal::NotLoanable]
+    );
+
+    assert_eq!(
+        library.give_book(0, dictionary, today_plus(7)),
+        Err(LoanError::Refused(Refusal::NotLoanable))
+    );
+
+    // Nothing is given
+
+    assert_eq!(library.caretaker().done(), 4);
+}
+
+#[test]
+fn override_limits_test() {
+    let mut library = library();
+    let dictionary = dictionary(&library);
+
+    library.set_role(Role::Librarian);
+
+    assert_eq!(
+        library.give_book_over_limits(0, dictionary, today_plus(7)),
+        Err(LoanError::NoAccess)
+    );
+
+    library.set_role(Role::SeniorLibrarian);
+    assert_eq!(
+        library.give_book_over_limits(0, dictionary, today_plus(7)),
+        Ok(0)
+    );
+
+    library.set_role(Role::Administrator);
+    assert!(library.undo().unwrap().is_some());
+    assert_eq!(
+        library.give_book_over_limits(0, dictionary, today_plus(7)),
+        Ok(0)
+    );
+}
+
+#[test]
+fn giving_access_test() {
+    let mut library = library();
+    let novel = novel(&library);
+
+    library.set_role(Role::ReadOnly);
+
+    assert_eq!(
+        library.give_book(0, novel, today_plus(7)),
+        Err(LoanError::NoAccess)
+    );
+
+    assert_eq!(
+        library.give_book_over_limits(0, novel, today_plus(7)),
+        Err(LoanError::NoAccess)
+    );
+
+    library.set_role(Role::Librarian);
+    assert_eq!(library.give_book(0, novel, today_plus(7)), Ok(0));
+}
+
+#[test]
+fn overdue_calendar_test() {
+    let data_dir = DataDir::new(temp_dir("overdue-calendar"));
+
+    let mut library =
+        Library::new(Box::new(YamlStorage::new(data_dir.clone()))).with_policy(policy());
+
+    fill(&mut library);
+
+    let novel = novel(&library);
+    library.give_book(0, novel, today_plus(7)).unwrap();
+    library.save().unwrap();
+
+    // Book was given until yesterday
+
+    let mut doc = YamlLoader::load_from_str(&fs::read_to_string(data_dir.books()).unwrap())
+        .unwrap()
+        .remove(0);
+
+    move_loans(&mut doc, today_plus(-10), today_plus(-1));
+
+    let mut text = String::new();
+    YamlEmitter::new(&mut text).dump(&doc).unwrap();
+    fs::write(data_dir.books(), text).unwrap();
+
+    let load = |calendar: OpeningCalendar| {
+        let mut library = Library::new(Box::new(YamlStorage::new(data_dir.clone())))
+            .with_policy(policy())
+            .with_calendar(calendar);
+
+        library.load().unwrap();
+        library
+    };
+
+    let library = load(OpeningCalendar::new());
+    let dictionary = dictionary(&library);
+
+    assert_eq!(
+        library.loan_refusals(0, dictionary).unwrap(),
+        vec![Refusal::NotLoanable, Refusal::Overdue(1)]
+    );
+
+    // Library is closed yesterday and today,
+    // so book can be returned on the next open day
+
+    let mut calendar = OpeningCalendar::new();
+
+    calendar
+        .add_holiday(Holiday::new(today_plus(-1), "Yesterday", false))
+        .unwrap()
+        .add_holiday(Holiday::new(today_plus(0), "Today", false))
+        .unwrap();
+
+    let library = load(calendar);
+
+    assert_eq!(
+        library.loan_refusals(0, dictionary).unwrap(),
+        vec![Refusal::NotLoanable]
+    );
+}
@@ -0,0 +1,89 @@
+//! Tests for message catalogues and plural forms.
+//! Run with `cargo test --test locale`
+
+extern crate booklibrs;
+
+mod common;
+
+use booklibrs::{
+    data::DataDir,
+    locale::{plural_form, Catalogue},
+    Lang,
+};
+
+use std::fs;
+
+use common::temp_dir;
+
+/// Built in language with chosen code
+
+fn lang(data_dir: &DataDir, code: &str) -> Lang {
+    Lang::all(data_dir)
+        .into_iter()
+        .find(|lang| lang.code() == code)
+        .unwrap()
+}
+
+#[test]
+fn plural_form_test() {
+    let forms = [1, 2, 5, 11, 12, 21, 22, 25, 101, 111, 114]
+        .iter()
+        .map(|&count| plural_form("ru", count))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        forms,
+        ["one", "few", "many", "many", "many", "one", "few", "many", "one", "many", "many"]
+    );
+
+    assert_eq!(plural_form("en", 1), "one");
+    assert_eq!(plural_form("en", 21), "other");
+    assert_eq!(plural_form("kk", 0), "other");
+}
+
+#[test]
+fn plural_message_test() {
+    let data_dir = DataDir::new(temp_dir("locale-plural"));
+    let (en, ru) = (lang(&data_dir, "en"), lang(&data_dir, "ru"));
+
+    let days = |lang: Lang, days: usize| lang.plural("loan-period-days", days, &[("days", &days)]);
+
+    assert_eq!(days(en, 1), "1 day");
+    assert_eq!(days(en, 21), "21 days");
+
+    assert_eq!(days(ru, 1), "1 день");
+    assert_eq!(days(ru, 3), "3 дня");
+    assert_eq!(days(ru, 14), "14 дней");
+    assert_eq!(days(ru, 21), "21 день");
+
+    // Message without plural forms is the same for any count
+
+    let catalogue = Catalogue::parse("de", "books = { $count } Bücher\nbooks.one = ein Buch");
+    assert_eq!(catalogue.plural("books", 1), Some("ein Buch"));
+    assert_eq!(catalogue.plural("books", 2), Some("{ $count } Bücher"));
+    assert_eq!(catalogue.plural("unknown", 1), None);
+}
+
+#[test]
+fn custom_catalogue_plural_test() {
+    let data_dir = DataDir::new(temp_dir("locale-custom"));
+    fs::create_dir_all(data_dir.locales_dir()).unwrap();
+
+    fs::write(
+        data_dir.locales_dir().join("de.ftl"),
+        "language-name = Deutsch\nloan-period-days = { $days } Tage\nloan-period-days.one = { $days } Tag\n",
+    )
+    .unwrap();
+
+    let de = lang(&data_dir, "de");
+
+    assert_eq!(de.plural("loan-period-days", 1, &[("days", &1)]), "1 Tag");
+    assert_eq!(de.plural("loan-period-days", 7, &[("days", &7)]), "7 Tage");
+
+    // Not translated message takes form of english one
+
+    assert_eq!(
+        de.plural("limit-max-loans", 1, &[("max", &1)]),
+        "at most 1 book at once"
+    );
+}
@@ -0,0 +1,150 @@
+//! Tests for upgrading data files of old versions.
+//! Run with `cargo test --test migration`
+
+extern crate booklibrs;
+
+mod common;
+
+use booklibrs::{data::DataDir, library::Library, storage::yaml::YamlStorage};
+
+use std::fs;
+
+use common::{date, temp_dir};
+
+/// Readers before version header and ids
+
+const READERS_V0: &str = "---
+- №: 1
+  Name: Ivan
+  Family: Ivanov
+  Father: Ivanovich
+  Info: \"\"
+  Day: 1
+  Month: 1
+  Year: 2000
+  Reading:
+    - War and Peace Tolstoy 1300
+- №: 2
+  Name: Petr
+  Family: Petrov
+  Father: Petrovich
+  Info: Teacher
+  Day: 2
+  Month: 2
+  Year: 1980
+  Reading: []
+";
+
+/// Books before version header and ids.
+/// Giving refers to reader by his name and birth date
+
+const BOOKS_V0: &str = "---
+- №: 1
+  Title: War and Peace
+  Author: Tolstoy
+  Pages: 1300
+  Simple Books:
+    - Cabinet: 1
+      Shelf: 1
+      Using: true
+      Readers:
+        - Name: Ivan
+          Family: Ivanov
+          Father: Ivanovich
+          Day: 1
+          Month: 1
+          Year: 2000
+          Start date:
+            - 1
+            - 9
+            - 2023
+          Finish date:
+            - 15
+            - 9
+            - 2023
+    - Cabinet: 1
+      Shelf: 2
+      Using: false
+      Readers: []
+  Genres:
+    - fiction
+";
+
+/// Genres before version header
+
+const GENRES_V0: &str = "---
+Genres:
+  - fiction
+  - poetry
+";
+
+#[test]
+fn migrate_v0_test() {
+    let data_dir = DataDir::new(temp_dir("migrate-v0"));
+
+    fs::write(data_dir.readers(), READERS_V0).unwrap();
+    fs::write(data_dir.books(), BOOKS_V0).unwrap();
+    fs::write(data_dir.genres(), GENRES_V0).unwrap();
+
+    let mut library = Library::new(Box::new(YamlStorage::new(data_dir.clone())));
+    library.load().unwrap();
+
+    assert_eq!(library.reader_base().len(), 2);
+    assert_eq!(library.genres().len(), 2);
+
+    let ivan = library
+        .find_reader(
+            &"Ivan".to_string(),
+            &"Ivanov".to_string(),
+            &"Ivanovich".to_string(),
+            date(1, 1, 2000),
+        )
+        .unwrap();
+
+    let war = library
+        .find_book(&"War and Peace".to_string(), &"Tolstoy".to_string(), 1300)
+        .unwrap();
+
+    assert_eq!(library.find_by_genre(&"fiction".to_string()), vec![war]);
+
+    // Giving is linked to reader by id,
+    // book is returned after deadline
+
+    assert_eq!(library.return_book(ivan, war), Ok(true));
+
+    // Every step is backed up, files have current version
+
+    for path in [data_dir.readers(), data_dir.books(), data_dir.genres()] {
+        assert!(data_dir.version_backup_of(&path, 0).exists());
+        assert!(data_dir.version_backup_of(&path, 1).exists());
+        assert!(fs::read_to_string(&path).unwrap().contains("Version: 2"));
+    }
+
+    assert_eq!(
+        fs::read_to_string(data_dir.version_backup_of(&data_dir.books(), 0)).unwrap(),
+        BOOKS_V0
+    );
+
+    // Upgraded files are loaded as they are
+
+    let mut library = Library::new(Box::new(YamlStorage::new(data_dir)));
+    library.load().unwrap();
+    assert_eq!(library.reader_base().len(), 2);
+}
+
+#[test]
+fn unknown_version_test() {
+    let data_dir = DataDir::new(temp_dir("migrate-unknown"));
+
+    fs::write(data_dir.readers(), "---\nVersion: 100\nReaders: []\n").unwrap();
+
+    let mut library = Library::new(Box::new(YamlStorage::new(data_dir.clone())));
+    assert!(library.load().is_err());
+
+    // File of newer version isn't changed
+
+    assert_eq!(
+        fs::read_to_string(data_dir.readers()).unwrap(),
+        "---\nVersion: 100\nReaders: []\n"
+    );
+}
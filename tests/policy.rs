@@ -0,0 +1,358 @@
+//! Tests for loan periods and borrowing limits of library.
+//! Run with `cargo test --test policy`
+
+extern crate booklibrs;
+
+use booklibrs::policy::{
+    Limit, LimitRule, LoanItem, LoanPolicy, LoanRule, PolicyError, Refusal, DEFAULT_PERIOD,
+};
+
+/// Genres of book
+
+#[inline]
+fn genres(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+/// Simple book which isn't overdue
+
+#[inline]
+fn item(names: &[&str], copy_type: &str) -> LoanItem {
+    LoanItem::new(genres(names), copy_type, false)
+}
+
+/// Policy where students take books for 21 days,
+/// fiction is given for 10 days,
+/// and students take fiction for 30 days
+
+fn policy() -> LoanPolicy {
+    let mut policy = LoanPolicy::new();
+
+    policy
+        .add_rule(LoanRule::new(Some("Student"), None, 21))
+        .unwrap()
+        .add_rule(LoanRule::new(None, Some("Fiction"), 10))
+        .unwrap()
+        .add_rule(LoanRule::new(Some("Student"), Some("Fiction"), 30))
+        .unwrap();
+
+    policy
+}
+
+/// Limits where reference copies aren't given,
+/// students take at most 2 fiction books
+/// and everyone takes at most 3 books
+
+fn limits() -> LoanPolicy {
+    let mut policy = LoanPolicy::new();
+
+    policy
+        .add_limit(LimitRule::new(
+            None,
+            None,
+            Some("Reference"),
+            Limit::NotLoanable,
+        ))
+        .unwrap()
+        .add_limit(LimitRule::new(
+            Some("Student"),
+            Some("Fiction"),
+            None,
+            Limit::MaxLoans(2),
+        ))
+        .unwrap()
+        .add_limit(LimitRule::new(None, None, None, Limit::MaxLoans(3)))
+        .unwrap();
+
+    policy
+}
+
+#[test]
+fn default_period_test() {
+    let mut policy = LoanPolicy::new();
+
+    assert_eq!(policy.default_period(), DEFAULT_PERIOD);
+    assert_eq!(policy.period("", &[]), DEFAULT_PERIOD);
+    assert_eq!(policy.rule_for("Student", &genres(&["Fiction"])), None);
+
+    policy.set_default_period(7).unwrap();
+    assert_eq!(policy.period("Student", &genres(&["Fiction"])), 7);
+
+    assert!(matches!(
+        policy.set_default_period(0),
+        Err(PolicyError::WrongPeriod)
+    ));
+
+    assert!(matches!(
+        policy.set_default_period(367),
+        Err(PolicyError::WrongPeriod)
+    ));
+
+    assert_eq!(policy.default_period(), 7);
+}
+
+#[test]
+fn specific_rule_test() {
+    let policy = policy();
+
+    assert_eq!(
+        policy.period("Student", &genres(&["Fiction", "Poetry"])),
+        30
+    );
+    assert_eq!(policy.period("Student", &genres(&["Poetry"])), 21);
+    assert_eq!(policy.period("Teacher", &genres(&["Fiction"])), 10);
+    assert_eq!(
+        policy.period("Teacher", &genres(&["Poetry"])),
+        DEFAULT_PERIOD
+    );
+    assert_eq!(policy.period("", &[]), DEFAULT_PERIOD);
+
+    let rule = policy.rule_for("Student", &genres(&["Poetry"])).unwrap();
+    assert_eq!(rule.category(), Some("student"));
+    assert_eq!(rule.genre(), None);
+}
+
+#[test]
+fn shortest_period_test() {
+    let mut policy = policy();
+
+    policy
+        .add_rule(LoanRule::new(None, Some("Reference"), 3))
+        .unwrap();
+
+    // Book with two genres takes the shortest period
+
+    assert_eq!(
+        policy.period("Teacher", &genres(&["Fiction", "Reference"])),
+        3
+    );
+    assert_eq!(policy.period("Student", &genres(&["Reference"])), 21);
+}
+
+#[test]
+fn change_rules_test() {
+    let mut policy = policy();
+
+    assert!(matches!(
+        policy.add_rule(LoanRule::new(Some(" Student "), Some(""), 5)),
+        Err(PolicyError::AlreadyExists)
+    ));
+
+    assert!(matches!(
+        policy.add_rule(LoanRule::new(Some("Teacher"), None, 0)),
+        Err(PolicyError::WrongPeriod)
+    ));
+
+    assert_eq!(policy.rules().len(), 3);
+
+    let removed = policy.remove_rule(0).unwrap();
+    assert_eq!(removed, LoanRule::new(Some("Student"), None, 21));
+    assert_eq!(removed.period(), 21);
+
+    assert!(matches!(policy.remove_rule(2), Err(PolicyError::NotFound)));
+    assert_eq!(
+        policy.period("Student", &genres(&["Poetry"])),
+        DEFAULT_PERIOD
+    );
+}
+
+#[test]
+fn genre_case_test() {
+    let mut policy = policy();
+
+    assert_eq!(policy.rules()[1].genre(), Some("fiction"));
+    assert_eq!(policy.period("Teacher", &genres(&["fiction"])), 10);
+    assert_eq!(policy.period("Student", &genres(&["FICTION"])), 30);
+
+    assert!(matches!(
+        policy.add_rule(LoanRule::new(None, Some("fIcTiOn"), 5)),
+        Err(PolicyError::AlreadyExists)
+    ));
+}
+
+#[test]
+fn category_case_test() {
+    let mut policy = policy();
+
+    assert_eq!(policy.rules()[0].category(), Some("student"));
+    assert_eq!(policy.period("student", &genres(&[])), 21);
+    assert_eq!(policy.period(" STUDENT ", &genres(&["Fiction"])), 30);
+
+    let rule = LoanRule::new(Some("  Teacher "), None, 14);
+    assert_eq!(rule.category(), Some("teacher"));
+    assert!(rule.applies("teacher", &[]));
+    assert!(rule.applies("TEACHER ", &[]));
+    assert!(!rule.applies("Student", &[]));
+
+    assert!(matches!(
+        policy.add_rule(LoanRule::new(Some(" sTuDeNt"), None, 7)),
+        Err(PolicyError::AlreadyExists)
+    ));
+
+    // Limits use the same category
+
+    let limits = limits();
+    let fiction = item(&["Fiction"], "");
+
+    assert_eq!(
+        limits.refusals("STUDENT", &fiction, &[fiction.clone(), fiction.clone()]),
+        vec![Refusal::TooManyLoans(2)]
+    );
+}
+
+#[test]
+fn max_loans_test() {
+    let policy = limits();
+    let fiction = item(&["Fiction"], "");
+    let poetry = item(&["Poetry"], "");
+
+    assert!(policy.refusals("Student", &fiction, &[]).is_empty());
+    assert!(policy
+        .refusals("Student", &fiction, &[poetry.clone(), fiction.clone()])
+        .is_empty());
+
+    assert_eq!(
+        policy.refusals("Student", &fiction, &[fiction.clone(), fiction.clone()]),
+        vec![Refusal::TooManyLoans(2)]
+    );
+
+    // Only fiction is counted for students' rule
+
+    assert!(policy
+        .refusals("Student", &poetry, &[fiction.clone(), fiction.clone()])
+        .is_empty());
+    assert!(policy
+        .refusals("Teacher", &fiction, &[fiction.clone(), fiction.clone()])
+        .is_empty());
+
+    assert_eq!(
+        policy.refusals(
+            "Teacher",
+            &poetry,
+            &[fiction.clone(), poetry.clone(), poetry.clone()]
+        ),
+        vec![Refusal::TooManyLoans(3)]
+    );
+}
+
+#[test]
+fn not_loanable_test() {
+    let policy = limits();
+
+    assert_eq!(
+        policy.refusals("Teacher", &item(&["Fiction"], " Reference "), &[]),
+        vec![Refusal::NotLoanable]
+    );
+
+    assert!(policy
+        .refusals("Teacher", &item(&["Fiction"], "Rare"), &[])
+        .is_empty());
+
+    let fiction = item(&["Fiction"], "");
+
+    assert_eq!(
+        policy.refusals(
+            "Student",
+            &item(&["Fiction"], "Reference"),
+            &[fiction.clone(), fiction]
+        ),
+        vec![Refusal::NotLoanable, Refusal::TooManyLoans(2)]
+    );
+}
+
+#[test]
+fn overdue_test() {
+    let mut policy = LoanPolicy::new();
+
+    policy
+        .add_limit(LimitRule::new(None, None, None, Limit::NoOverdue))
+        .unwrap()
+        .add_limit(LimitRule::new(
+            Some("Student"),
+            None,
+            None,
+            Limit::NoOverdue,
+        ))
+        .unwrap();
+
+    let overdue = LoanItem::new(genres(&["Poetry"]), "", true);
+    let reading = [item(&["Fiction"], ""), overdue.clone(), overdue];
+
+    // The same refusal of two rules is given once
+
+    assert_eq!(
+        policy.refusals("Student", &item(&[], ""), &reading),
+        vec![Refusal::Overdue(2)]
+    );
+
+    assert!(policy
+        .refusals("Student", &item(&[], ""), &reading[..1])
+        .is_empty());
+}
+
+#[test]
+fn limit_genre_case_test() {
+    let policy = limits();
+    let fiction = item(&["FICTION"], "");
+
+    assert_eq!(policy.limits()[1].genre(), Some("fiction"));
+
+    assert_eq!(
+        policy.refusals(
+            "Student",
+            &item(&["Fiction"], ""),
+            &[fiction.clone(), fiction]
+        ),
+        vec![Refusal::TooManyLoans(2)]
+    );
+
+    let mut policy = policy;
+
+    assert!(matches!(
+        policy.add_limit(LimitRule::new(
+            Some("Student"),
+            Some("fiction"),
+            None,
+            Limit::MaxLoans(4)
+        )),
+        Err(PolicyError::AlreadyExists)
+    ));
+}
+
+#[test]
+fn change_limits_test() {
+    let mut policy = limits();
+
+    assert!(matches!(
+        policy.add_limit(LimitRule::new(
+            Some(" Student "),
+            Some("Fiction"),
+            Some(""),
+            Limit::MaxLoans(5)
+        )),
+        Err(PolicyError::AlreadyExists)
+    ));
+
+    policy
+        .add_limit(LimitRule::new(
+            Some("Student"),
+            Some("Fiction"),
+            None,
+            Limit::NoOverdue,
+        ))
+        .unwrap();
+
+    assert_eq!(policy.limits().len(), 4);
+
+    let removed = policy.remove_limit(1).unwrap();
+    assert_eq!(removed.category(), Some("student"));
+    assert_eq!(removed.copy_type(), None);
+    assert_eq!(removed.limit(), Limit::MaxLoans(2));
+
+    assert!(matches!(policy.remove_limit(3), Err(PolicyError::NotFound)));
+
+    let fiction = item(&["Fiction"], "");
+    assert!(policy
+        .refusals("Student", &fiction, &[fiction.clone(), fiction.clone()])
+        .is_empty());
+}
@@ -0,0 +1,323 @@
+//! Tests for readers, books and links between them.
+//! Run with `cargo test --test readers`
+
+extern crate booklibrs;
+
+mod common;
+
+use booklibrs::{
+    books::error::{BookError, LoanError},
+    library::Library,
+    reading::error::ReaderError,
+    storage::memory::MemoryStorage,
+};
+
+use common::date;
+
+/// Library in memory with reader and three books.
+/// Every book has one simple book
+
+fn library() -> Library {
+    let mut library = Library::new(Box::new(MemoryStorage::new()));
+
+    library
+        .add_reader(
+            "Name".to_string(),
+            "Family".to_string(),
+            "Father".to_string(),
+            String::new(),
+            date(1, 1, 1970),
+        )
+        .unwrap();
+
+    for (title, author, pages) in [
+        ("Title1", "Author1", 50),
+        ("Title2", "Author2", 60),
+        ("Title3", "Author3", 70),
+    ] {
+        library
+            .add_book(title.to_string(), author.to_string(), pages, &[(1, 1)])
+            .unwrap();
+    }
+
+    library
+}
+
+/// Index of The Book with chosen title
+
+#[inline]
+fn book(library: &Library, title: &str) -> usize {
+    let number = &title[title.len() - 1..];
+
+    library
+        .find_book(
+            &title.to_string(),
+            &format!("Author{}", number),
+            40 + 10 * number.parse::<u16>().unwrap(),
+        )
+        .unwrap()
+}
+
+/// Sorted books which stored reader
+/// with chosen index is reading
+
+fn reading(library: &Library, ind: usize) -> Vec<String> {
+    let record = format!(
+        "{:?}",
+        library.storage().read_readers().unwrap()[ind]
+            .as_ref()
+            .unwrap()
+    );
+
+    let list = &record[record.find("reading: [").unwrap() + 10..record.rfind(']').unwrap()];
+
+    let mut books = list
+        .split(", ")
+        .filter(|book| !book.is_empty())
+        .map(|book| book.trim_matches('"').to_string())
+        .collect::<Vec<_>>();
+
+    books.sort();
+    books
+}
+
+/// Stored The Book with chosen index as debug output.
+/// It has all simple books with their loans
+
+#[inline]
+fn stored_book(library: &Library, ind: usize) -> String {
+    format!(
+        "{:?}",
+        library.storage().read_books().unwrap()[ind]
+            .as_ref()
+            .unwrap()
+    )
+}
+
+#[test]
+fn reader_change_test() {
+    let mut library = library();
+
+    assert!(matches!(
+        library.change_name(0, String::new()),
+        Err(ReaderError::EmptyName)
+    ));
+
+    assert!(matches!(
+        library.change_family(0, String::new()),
+        Err(ReaderError::EmptyFamily)
+    ));
+
+    assert!(matches!(
+        library.change_father(0, String::new()),
+        Err(ReaderError::EmptyFather)
+    ));
+
+    assert!(matches!(
+        library.change_name(1, "Michael".to_string()),
+        Err(ReaderError::NotFound)
+    ));
+
+    assert_eq!(library.caretaker().done(), 4);
+
+    library
+        .change_name(0, "Michael".to_string())
+        .unwrap()
+        .change_family(0, "Jackson".to_string())
+        .unwrap()
+        .change_father(0, "Joseph".to_string())
+        .unwrap()
+        .change_age(0, date(29, 8, 1958))
+        .unwrap();
+
+    assert_eq!(
+        library.find_reader(
+            &"Michael".to_string(),
+            &"Jackson".to_string(),
+            &"Joseph".to_string(),
+            date(29, 8, 1958)
+        ),
+        Some(0)
+    );
+
+    assert_eq!(
+        library.find_reader(
+            &"Name".to_string(),
+            &"Family".to_string(),
+            &"Father".to_string(),
+            date(1, 1, 1970)
+        ),
+        None
+    );
+
+    // The same reader can't be added twice
+
+    assert!(matches!(
+        library.add_reader(
+            "Michael".to_string(),
+            "Jackson".to_string(),
+            "Joseph".to_string(),
+            String::new(),
+            date(29, 8, 1958),
+        ),
+        Err(ReaderError::AlreadyExists)
+    ));
+
+    assert_eq!(library.reader_base().len(), 1);
+}
+
+#[test]
+fn reader_books_test() {
+    let mut library = library();
+
+    for title in ["Title1", "Title2", "Title3"] {
+        let ind = book(&library, title);
+        library.give_book(0, ind, date(1, 1, 2100)).unwrap();
+    }
+
+    assert_eq!(
+        reading(&library, 0),
+        [
+            "Title1 Author1 50",
+            "Title2 Author2 60",
+            "Title3 Author3 70"
+        ]
+    );
+
+    // Reader's books follow changes of books
+
+    let first = book(&library, "Title1");
+
+    library
+        .change_title(first, "KEK".to_string())
+        .unwrap()
+        .change_author(first, "LOL".to_string())
+        .unwrap()
+        .change_pages(first, 100)
+        .unwrap();
+
+    assert_eq!(
+        reading(&library, 0),
+        ["KEK LOL 100", "Title2 Author2 60", "Title3 Author3 70"]
+    );
+
+    // Returned book isn't read, but its giving is kept
+
+    let first = library
+        .find_book(&"KEK".to_string(), &"LOL".to_string(), 100)
+        .unwrap();
+
+    assert_eq!(library.return_book(0, first), Ok(false));
+
+    assert_eq!(
+        reading(&library, 0),
+        ["Title2 Author2 60", "Title3 Author3 70"]
+    );
+
+    assert!(stored_book(&library, first).contains("is_using: false"));
+    assert!(stored_book(&library, first).contains("loans: [LoanRecord"));
+
+    assert!(matches!(
+        library.return_book(0, first),
+        Err(LoanError::Reader(ReaderError::NotReading))
+    ));
+
+    // Removed reader leaves no givings
+
+    library.remove_reader(0).unwrap();
+
+    for title in ["Title2", "Title3"] {
+        let record = stored_book(&library, book(&library, title));
+        assert!(record.contains("is_using: false"));
+        assert!(record.contains("loans: []"));
+    }
+}
+
+#[test]
+fn find_reader_test() {
+    let mut library = library();
+
+    library
+        .add_reader(
+            "Another Name".to_string(),
+            "Another Family".to_string(),
+            "Another Father".to_string(),
+            String::new(),
+            date(2, 2, 1960),
+        )
+        .unwrap();
+
+    let first = library.find_reader(
+        &"Name".to_string(),
+        &"Family".to_string(),
+        &"Father".to_string(),
+        date(1, 1, 1970),
+    );
+
+    let second = library.find_reader(
+        &"Another Name".to_string(),
+        &"Another Family".to_string(),
+        &"Another Father".to_string(),
+        date(2, 2, 1960),
+    );
+
+    assert!(first.is_some() && second.is_some());
+    assert_ne!(first, second);
+
+    assert_eq!(
+        library.find_reader(
+            &"Error".to_string(),
+            &"Error".to_string(),
+            &"Error".to_string(),
+            date(1, 1, 1970)
+        ),
+        None
+    );
+
+    library.remove_reader(first.unwrap()).unwrap();
+
+    assert_eq!(library.reader_base().len(), 1);
+
+    assert_eq!(
+        library.find_reader(
+            &"Another Name".to_string(),
+            &"Another Family".to_string(),
+            &"Another Father".to_string(),
+            date(2, 2, 1960),
+        ),
+        Some(0)
+    );
+}
+
+#[test]
+fn simple_books_test() {
+    let mut library = library();
+    let ind = book(&library, "Title1");
+
+    let locations = (0..50).map(|i| (2, i)).collect::<Vec<_>>();
+    library.add_books(ind, &locations).unwrap();
+
+    library.give_book(0, ind, date(1, 1, 2100)).unwrap();
+
+    assert!(matches!(
+        library.give_book(0, ind, date(1, 1, 2100)),
+        Err(LoanError::Reader(ReaderError::StillReading))
+    ));
+
+    // Removing simple book which isn't given
+    // doesn't change reader's books
+
+    library.remove_one_book(ind, 50).unwrap();
+    assert_eq!(reading(&library, 0), ["Title1 Author1 50"]);
+
+    assert!(matches!(
+        library.remove_one_book(ind, 50),
+        Err(BookError::SimpleBookNotFound)
+    ));
+
+    // Removed book isn't read anymore
+
+    library.remove_book(ind).unwrap();
+    assert!(reading(&library, 0).is_empty());
+    assert_eq!(library.storage().read_books().unwrap().len(), 2);
+}
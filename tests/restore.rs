@@ -0,0 +1,120 @@
+//! Tests for restoring all data from backups and snapshots.
+//! Run with `cargo test --test restore`
+
+extern crate booklibrs;
+
+mod common;
+
+use booklibrs::{
+    auth::Role,
+    data::DataDir,
+    library::Library,
+    restore::error::RestoreError,
+    storage::{memory::MemoryStorage, yaml::YamlStorage, Storage},
+};
+
+use common::{date, state, temp_dir};
+
+/// Library in memory with one reader
+
+fn library() -> Library {
+    let mut library = Library::new(Box::new(MemoryStorage::new()));
+
+    library
+        .add_reader(
+            "Ivan".to_string(),
+            "Ivanov".to_string(),
+            "Ivanovich".to_string(),
+            "Student".to_string(),
+            date(1, 1, 2000),
+        )
+        .unwrap();
+
+    library
+}
+
+#[test]
+fn restore_access_test() {
+    let mut library = library();
+
+    for role in [Role::Librarian, Role::SeniorLibrarian, Role::ReadOnly] {
+        library.set_role(role);
+
+        assert!(matches!(
+            library.restore_from(&MemoryStorage::new()),
+            Err(RestoreError::NoAccess)
+        ));
+
+        assert_eq!(library.reader_base().len(), 1);
+    }
+
+    library.set_role(Role::Administrator);
+    library.restore_from(&MemoryStorage::new()).unwrap();
+    assert_eq!(library.reader_base().len(), 0);
+
+    library.undo().unwrap();
+    assert_eq!(library.reader_base().len(), 1);
+}
+
+#[test]
+fn restore_damaged_test() {
+    let mut library = library();
+    let reader = library.storage().read_readers().unwrap().remove(0).unwrap();
+
+    // The same reader twice can't be loaded
+
+    let damaged = MemoryStorage::with_records(vec![reader.clone(), reader], vec![], vec![]);
+
+    assert!(matches!(
+        library.restore_from(&damaged),
+        Err(RestoreError::Load(_))
+    ));
+
+    assert_eq!(library.reader_base().len(), 1);
+}
+
+#[test]
+fn backup_per_save_test() {
+    let data_dir = DataDir::new(temp_dir("backup-per-save"));
+    let mut library = Library::new(Box::new(YamlStorage::new(data_dir)));
+
+    library
+        .add_reader(
+            "Ivan".to_string(),
+            "Ivanov".to_string(),
+            "Ivanovich".to_string(),
+            "Student".to_string(),
+            date(1, 1, 2000),
+        )
+        .unwrap()
+        .add_book(
+            "War and Peace".to_string(),
+            "Tolstoy".to_string(),
+            1300,
+            &[(1, 1)],
+        )
+        .unwrap()
+        .add_genre("Fiction".to_string())
+        .unwrap();
+
+    library.give_book(0, 0, date(1, 1, 2100)).unwrap();
+    library.save().unwrap();
+
+    let given = state(library.storage());
+    let backups = library.storage().backups().unwrap().len();
+
+    // All files are written by one save,
+    // so there is one backup with all of them
+
+    library.add_genre("Poetry".to_string()).unwrap();
+    library.save().unwrap();
+
+    let all = library.storage().backups().unwrap();
+    assert_eq!(all.len(), backups + 1);
+    assert_eq!(state(&*all[0].1), given);
+
+    // Nothing is changed, so there is no new backup
+
+    library.save().unwrap();
+    assert_eq!(library.storage().backups().unwrap().len(), backups + 1);
+}
@@ -0,0 +1,224 @@
+//! Tests for changes which can't be saved to storage.
+//! Run with `cargo test --test saving`
+
+extern crate booklibrs;
+
+mod common;
+
+use booklibrs::{
+    audit::AuditEntry,
+    books::error::{BookError, GenreError, LoanError},
+    data::error::LoadError,
+    library::Library,
+    reading::error::ReaderError,
+    restore::command::Command,
+    storage::{memory::MemoryStorage, BookRecord, ReaderRecord, Records, Storage},
+};
+
+use common::date;
+
+use std::{cell::Cell, io, path::PathBuf, rc::Rc};
+
+/// Storage on disk which can become full.
+/// It can always be read, but chosen kinds
+/// of data can't be written
+
+#[derive(Default)]
+struct FullStorage {
+    records: MemoryStorage,
+    records_full: bool,
+    history_full: bool,
+    audit_full: Rc<Cell<bool>>,
+}
+
+/// Error of writing to full disk
+
+#[inline]
+fn full() -> io::Error {
+    io::Error::new(io::ErrorKind::Other, "disk is full")
+}
+
+impl Storage for FullStorage {
+    #[inline]
+    fn readers_path(&self) -> PathBuf {
+        self.records.readers_path()
+    }
+
+    #[inline]
+    fn books_path(&self) -> PathBuf {
+        self.records.books_path()
+    }
+
+    #[inline]
+    fn read_readers(&self) -> Records<ReaderRecord> {
+        self.records.read_readers()
+    }
+
+    #[inline]
+    fn read_books(&self) -> Records<BookRecord> {
+        self.records.read_books()
+    }
+
+    #[inline]
+    fn read_genres(&self) -> Records<String> {
+        self.records.read_genres()
+    }
+
+    #[inline]
+    fn write_readers(&self, readers: &[ReaderRecord]) -> io::Result<()> {
+        match self.records_full {
+            true => Err(full()),
+            false => self.records.write_readers(readers),
+        }
+    }
+
+    #[inline]
+    fn write_books(&self, books: &[BookRecord]) -> io::Result<()> {
+        match self.records_full {
+            true => Err(full()),
+            false => self.records.write_books(books),
+        }
+    }
+
+    #[inline]
+    fn write_genres(&self, genres: &[String]) -> io::Result<()> {
+        match self.records_full {
+            true => Err(full()),
+            false => self.records.write_genres(genres),
+        }
+    }
+
+    #[inline]
+    fn write_history(&self, commands: &[Command], done: usize) -> io::Result<()> {
+        match self.history_full {
+            true => Err(full()),
+            false => self.records.write_history(commands, done),
+        }
+    }
+
+    #[inline]
+    fn append_audit(&self, entry: &AuditEntry) -> io::Result<()> {
+        match self.audit_full.get() {
+            true => Err(full()),
+            false => self.records.append_audit(entry),
+        }
+    }
+
+    #[inline]
+    fn read_audit(&self) -> Result<Vec<AuditEntry>, LoadError> {
+        self.records.read_audit()
+    }
+}
+
+/// Adds reader to library
+
+#[inline]
+fn add_reader(library: &mut Library) -> Result<(), ReaderError> {
+    library
+        .add_reader(
+            "Ivan".to_string(),
+            "Ivanov".to_string(),
+            "Ivanovich".to_string(),
+            "Student".to_string(),
+            date(1, 1, 2000),
+        )
+        .map(|_| ())
+}
+
+#[test]
+fn unsaved_changes_test() {
+    let mut library = Library::new(Box::new(FullStorage {
+        records_full: true,
+        history_full: true,
+        ..FullStorage::default()
+    }));
+
+    // Changes aren't saved, but they're made and can be undone
+
+    assert!(matches!(
+        add_reader(&mut library),
+        Err(ReaderError::Io(io::ErrorKind::Other))
+    ));
+
+    assert!(matches!(
+        library.add_book("Title".to_string(), "Author".to_string(), 100, &[(1, 1)]),
+        Err(BookError::Io(_))
+    ));
+
+    assert!(matches!(
+        library.add_genre("poetry".to_string()),
+        Err(GenreError::Io(_))
+    ));
+
+    assert!(matches!(
+        library.give_book(0, 0, date(1, 1, 2100)),
+        Err(LoanError::Io(_))
+    ));
+
+    assert_eq!(library.reader_base().len(), 1);
+
+    assert!(library
+        .find_book(&"Title".to_string(), &"Author".to_string(), 100)
+        .is_some());
+
+    assert_eq!(library.caretaker().done(), 4);
+
+    assert!(library.undo().is_err());
+    assert!(library.redo().is_err());
+    assert!(library.jump_to(0).is_err());
+    assert_eq!(library.reader_base().len(), 0);
+}
+
+#[test]
+fn unsaved_history_test() {
+    let mut library = Library::new(Box::new(FullStorage {
+        history_full: true,
+        ..FullStorage::default()
+    }));
+
+    assert!(matches!(
+        library.add_genre("poetry".to_string()),
+        Err(GenreError::Io(_))
+    ));
+
+    // Genre is saved, only history isn't
+
+    assert_eq!(library.storage().read_genres().unwrap().len(), 1);
+    assert_eq!(library.caretaker().done(), 1);
+    assert!(library.save().is_err());
+}
+
+#[test]
+fn unsaved_audit_test() {
+    let audit_full = Rc::new(Cell::new(true));
+
+    let mut library = Library::new(Box::new(FullStorage {
+        audit_full: audit_full.clone(),
+        ..FullStorage::default()
+    }));
+
+    // Change which isn't in audit log isn't made
+
+    assert!(matches!(add_reader(&mut library), Err(ReaderError::Io(_))));
+
+    assert_eq!(library.reader_base().len(), 0);
+    assert_eq!(library.caretaker().done(), 0);
+    assert!(library.storage().read_readers().unwrap().is_empty());
+
+    audit_full.set(false);
+    add_reader(&mut library).unwrap();
+    library.add_genre("poetry".to_string()).unwrap();
+    assert_eq!(library.audit().unwrap().len(), 2);
+
+    audit_full.set(true);
+    assert!(library.undo().is_err());
+    assert!(library.jump_to(0).is_err());
+    assert_eq!(library.reader_base().len(), 1);
+    assert_eq!(library.genres().len(), 1);
+    assert_eq!(library.caretaker().done(), 2);
+
+    audit_full.set(false);
+    library.undo().unwrap();
+    assert_eq!(library.genres().len(), 0);
+    assert_eq!(library.audit().unwrap().len(), 3);
+}
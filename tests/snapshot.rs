@@ -0,0 +1,125 @@
+//! Tests for named snapshots and their differences with current data.
+//! Run with `cargo test --test snapshot`
+
+extern crate booklibrs;
+
+mod common;
+
+use booklibrs::{
+    data::DataDir, library::Library, restore::diff::Difference, storage::yaml::YamlStorage,
+};
+
+use common::{date, state, temp_dir};
+
+/// Library in data directory with two readers and book
+
+fn library(name: &str) -> Library {
+    let data_dir = DataDir::new(temp_dir(name));
+    data_dir.create().unwrap();
+
+    let mut library = Library::new(Box::new(YamlStorage::new(data_dir)));
+
+    library
+        .add_reader(
+            "Ivan".to_string(),
+            "Ivanov".to_string(),
+            "Ivanovich".to_string(),
+            String::new(),
+            date(1, 1, 2000),
+        )
+        .unwrap()
+        .add_reader(
+            "Petr".to_string(),
+            "Petrov".to_string(),
+            "Petrovich".to_string(),
+            String::new(),
+            date(2, 2, 2001),
+        )
+        .unwrap()
+        .add_book(
+            "War and Peace".to_string(),
+            "Tolstoy".to_string(),
+            1300,
+            &[(1, 1)],
+        )
+        .unwrap()
+        .add_genre("Fiction".to_string())
+        .unwrap();
+
+    library
+}
+
+#[test]
+fn snapshot_diff_test() {
+    let mut library = library("snapshot-diff");
+    let snapshot = library.create_snapshot("Before changes").unwrap();
+
+    let snapshots = library.snapshots().unwrap();
+    assert_eq!(snapshots.len(), 1);
+    assert_eq!(snapshots[0].label(), "Before changes");
+
+    assert!(library.diff_snapshot(&snapshot).unwrap().is_empty());
+
+    library
+        .remove_reader(1)
+        .unwrap()
+        .change_title(0, "War & Peace".to_string())
+        .unwrap()
+        .add_genre("Poetry".to_string())
+        .unwrap();
+
+    library.give_book(0, 0, date(1, 1, 2100)).unwrap();
+
+    let diff = library.diff_snapshot(&snapshot).unwrap();
+
+    assert!(matches!(diff.readers.as_slice(), [Difference::Removed(_)]));
+    assert!(matches!(
+        diff.titles.as_slice(),
+        [Difference::Changed(_, _)]
+    ));
+    assert!(matches!(
+        diff.copies.as_slice(),
+        [Difference::Changed(_, _)]
+    ));
+    assert!(matches!(diff.loans.as_slice(), [Difference::Added(_)]));
+    assert_eq!(diff.genres, vec![Difference::Added("poetry".to_string())]);
+}
+
+#[test]
+fn return_date_diff_test() {
+    let mut library = library("snapshot-return-date");
+    library.give_book(0, 0, date(1, 1, 2100)).unwrap();
+
+    let snapshot = library.create_snapshot("Given").unwrap();
+
+    library.change_return_date(0, 0, date(1, 2, 2100)).unwrap();
+
+    let diff = library.diff_snapshot(&snapshot).unwrap();
+
+    assert!(matches!(diff.loans.as_slice(), [Difference::Changed(_, _)]));
+
+    assert!(diff.readers.is_empty());
+    assert!(diff.titles.is_empty());
+}
+
+#[test]
+fn restore_snapshot_test() {
+    let mut library = library("snapshot-restore");
+    let snapshot = library.create_snapshot("First").unwrap();
+    let before = state(library.storage());
+
+    library.remove_reader(0).unwrap();
+    library.remove_genre(&"Fiction".to_string()).unwrap();
+
+    let after = state(library.storage());
+
+    library.restore_snapshot(&snapshot).unwrap();
+
+    assert!(library.diff_snapshot(&snapshot).unwrap().is_empty());
+    assert_eq!(state(library.storage()), before);
+
+    // Restoring is undone as one change
+
+    library.undo().unwrap();
+    assert_eq!(state(library.storage()), after);
+}
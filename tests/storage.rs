@@ -0,0 +1,226 @@
+//! Tests for copying data between storages.
+//! Run with `cargo test --test storage`
+//! (or `cargo test --features sqlite --test storage`)
+
+extern crate booklibrs;
+
+mod common;
+
+use booklibrs::{
+    data::DataDir,
+    library::Library,
+    storage::{self, memory::MemoryStorage, yaml::YamlStorage, Storage},
+};
+
+use std::collections::HashSet;
+
+use common::{date, state, temp_dir, State};
+
+/// Library in memory with readers, books,
+/// genres and given book
+
+fn library() -> Library {
+    let mut library = Library::new(Box::new(MemoryStorage::new()));
+
+    library
+        .add_reader(
+            "Ivan".to_string(),
+            "Ivanov".to_string(),
+            "Ivanovich".to_string(),
+            String::new(),
+            date(1, 1, 2000),
+        )
+        .unwrap()
+        .add_reader(
+            "Мария".to_string(),
+            "Петрова".to_string(),
+            String::new(),
+            String::new(),
+            date(29, 2, 2004),
+        )
+        .unwrap()
+        .change_info(0, "Reads a lot".to_string())
+        .unwrap()
+        .change_category(0, "Student".to_string())
+        .unwrap()
+        .add_book(
+            "War and Peace".to_string(),
+            "Tolstoy".to_string(),
+            1300,
+            &[(1, 1), (1, 2)],
+        )
+        .unwrap()
+        .add_book("Dictionary".to_string(), "Dahl".to_string(), 900, &[(2, 1)])
+        .unwrap()
+        .add_genre("Fiction".to_string())
+        .unwrap()
+        .add_genre("Reference".to_string())
+        .unwrap();
+
+    let war = library
+        .find_book(&"War and Peace".to_string(), &"Tolstoy".to_string(), 1300)
+        .unwrap();
+
+    library
+        .set_book_genres(war, HashSet::from(["fiction".to_string()]))
+        .unwrap()
+        .change_copy_type(war, 1, "Rare".to_string())
+        .unwrap();
+
+    library.give_book(0, war, date(1, 1, 2100)).unwrap();
+    library
+}
+
+/// Records of library which is loaded from copy of storage
+/// and saved back. Books which readers are reading
+/// aren't loaded, so they are restored on saving
+
+fn reload(from: &dyn Storage) -> State {
+    let memory = MemoryStorage::new();
+    storage::copy_all(from, &memory).unwrap();
+
+    let mut library = Library::new(Box::new(memory));
+    library.load().unwrap();
+    library.save().unwrap();
+    state(library.storage())
+}
+
+#[test]
+fn yaml_round_trip_test() {
+    let library = library();
+    let expected = state(library.storage());
+
+    let data_dir = DataDir::new(temp_dir("yaml-round-trip"));
+    storage::copy_all(library.storage(), &YamlStorage::new(data_dir.clone())).unwrap();
+
+    // Files are read by new storage
+
+    assert_eq!(reload(&YamlStorage::new(data_dir)), expected);
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn sqlite_round_trip_test() {
+    use booklibrs::storage::sqlite::SqliteStorage;
+
+    let library = library();
+    let expected = state(library.storage());
+
+    let data_dir = DataDir::new(temp_dir("sqlite-round-trip"));
+    data_dir.create().unwrap();
+
+    let sqlite = SqliteStorage::open(&data_dir).unwrap();
+    storage::copy_all(library.storage(), &sqlite).unwrap();
+    drop(sqlite);
+
+    // Database is opened again
+
+    assert_eq!(reload(&SqliteStorage::open(&data_dir).unwrap()), expected);
+}
+
+/// Adds reader and genre, changes reader and book
+/// and gives book to reader
+
+#[cfg(feature = "sqlite")]
+fn change(library: &mut Library) {
+    let dictionary = library
+        .find_book(&"Dictionary".to_string(), &"Dahl".to_string(), 900)
+        .unwrap();
+
+    library
+        .add_reader(
+            "Petr".to_string(),
+            "Petrov".to_string(),
+            "Petrovich".to_string(),
+            String::new(),
+            date(2, 2, 2001),
+        )
+        .unwrap()
+        .change_info(0, "Teacher".to_string())
+        .unwrap()
+        .change_title(dictionary, "Dictionary of Russian".to_string())
+        .unwrap()
+        .add_genre("Poetry".to_string())
+        .unwrap();
+
+    library.give_book(0, dictionary, date(1, 1, 2100)).unwrap();
+}
+
+/// Records of storage sorted as their debug output,
+/// which starts with id.
+/// Database keeps rows in order of ids,
+/// memory keeps records in order of library
+
+#[cfg(feature = "sqlite")]
+fn sorted(storage: &dyn Storage) -> State {
+    let (mut readers, mut books, genres) = reload(storage);
+    readers.sort_unstable_by_key(|r| format!("{:?}", r));
+    books.sort_unstable_by_key(|b| format!("{:?}", b));
+    (readers, books, genres)
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn sqlite_changed_rows_test() {
+    use booklibrs::storage::sqlite::SqliteStorage;
+    use rusqlite::Connection;
+
+    let mut memory = library();
+
+    let data_dir = DataDir::new(temp_dir("sqlite-changed-rows"));
+    data_dir.create().unwrap();
+
+    let sqlite = SqliteStorage::open(&data_dir).unwrap();
+    storage::copy_all(memory.storage(), &sqlite).unwrap();
+
+    let mut library = Library::new(Box::new(sqlite));
+    library.load().unwrap();
+
+    // Every removed row is counted by trigger
+
+    let conn = Connection::open(data_dir.file("library.sqlite")).unwrap();
+
+    conn.execute_batch(
+        "CREATE TABLE removed (name TEXT NOT NULL);
+         CREATE TRIGGER readers_removed AFTER DELETE ON readers
+         BEGIN INSERT INTO removed VALUES ('readers'); END;
+         CREATE TRIGGER titles_removed AFTER DELETE ON titles
+         BEGIN INSERT INTO removed VALUES ('titles'); END;
+         CREATE TRIGGER copies_removed AFTER DELETE ON copies
+         BEGIN INSERT INTO removed VALUES ('copies'); END;
+         CREATE TRIGGER loans_removed AFTER DELETE ON loans
+         BEGIN INSERT INTO removed VALUES ('loans'); END;",
+    )
+    .unwrap();
+
+    let removed = || {
+        conn.query_row("SELECT COUNT(*) FROM removed", [], |row| {
+            row.get::<_, i64>(0)
+        })
+        .unwrap()
+    };
+
+    change(&mut memory);
+    change(&mut library);
+    library.save().unwrap();
+
+    assert_eq!(removed(), 0);
+    assert_eq!(sorted(library.storage()), sorted(memory.storage()));
+
+    // Only rows of removed book are deleted:
+    // The Book, its simple book and its giving
+
+    let dictionary = library
+        .find_book(
+            &"Dictionary of Russian".to_string(),
+            &"Dahl".to_string(),
+            900,
+        )
+        .unwrap();
+
+    library.remove_book(dictionary).unwrap();
+    memory.remove_book(dictionary).unwrap();
+
+    assert_eq!(removed(), 3);
+    assert_eq!(sorted(library.storage()), sorted(memory.storage()));
+}